@@ -0,0 +1,70 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating Treemap and Sunburst charts using the
+//! rust_xlsxwriter library.
+
+use rust_xlsxwriter::{Chart, ChartType, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+
+    // Add the hierarchical worksheet data that the charts will refer to. Each
+    // column of the categories is a level in the hierarchy.
+    worksheet.write_with_format(0, 0, "Region", &bold)?;
+    worksheet.write_with_format(0, 1, "Country", &bold)?;
+    worksheet.write_with_format(0, 2, "Sales", &bold)?;
+
+    let regions = ["Asia", "", "", "Europe", "", "", "Americas", ""];
+    let countries = [
+        "China", "India", "Japan", "Germany", "France", "Spain", "USA", "Brazil",
+    ];
+    let sales = [350, 280, 160, 210, 180, 90, 420, 110];
+
+    worksheet.write_column(1, 0, regions)?;
+    worksheet.write_column(1, 1, countries)?;
+    worksheet.write_column(1, 2, sales)?;
+
+    // -----------------------------------------------------------------------
+    // Create a new Treemap chart.
+    // -----------------------------------------------------------------------
+    let mut chart = Chart::new(ChartType::Treemap);
+
+    // Configure the data series for the chart. The categories range covers all
+    // the levels of the hierarchy.
+    chart
+        .add_series()
+        .set_categories("Sheet1!$A$2:$B$9")
+        .set_values("Sheet1!$C$2:$C$9")
+        .set_name("Sheet1!$C$1");
+
+    // Add a chart title and hide the legend.
+    chart.title().set_name("Sales by region");
+    chart.legend().set_hidden();
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart_with_offset(1, 4, &chart, 25, 10)?;
+
+    // -----------------------------------------------------------------------
+    // Create a new Sunburst chart with the same data.
+    // -----------------------------------------------------------------------
+    let mut chart = Chart::new(ChartType::Sunburst);
+
+    chart
+        .add_series()
+        .set_categories("Sheet1!$A$2:$B$9")
+        .set_values("Sheet1!$C$2:$C$9")
+        .set_name("Sheet1!$C$1");
+
+    chart.title().set_name("Sales by region");
+    chart.legend().set_hidden();
+
+    worksheet.insert_chart_with_offset(17, 4, &chart, 25, 10)?;
+
+    workbook.save("chart_treemap.xlsx")?;
+
+    Ok(())
+}
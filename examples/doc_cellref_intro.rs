@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating cell and range references
+//! from strings and tuples.
+
+use rust_xlsxwriter::{CellRef, RangeRef, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create cell references from a string or a (row, col) tuple.
+    let cell1 = CellRef::parse("B2")?;
+    let cell2 = CellRef::from((2, 1));
+
+    assert_eq!(cell1.row(), 1);
+    assert_eq!(cell2.to_string(), "B3");
+
+    worksheet.write_a1(cell1, "Hello")?;
+    worksheet.write_a1(cell2, "World")?;
+
+    // Create a range reference and use it for a worksheet print area.
+    let range = RangeRef::parse("A1:B3")?;
+    worksheet.set_print_area_a1(range)?;
+
+    assert_eq!(range.to_sheet_range("Sheet1"), "Sheet1!$A$1:$B$3");
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of adding data labels to a chart series with the label text
+//! taken from a worksheet range.
+
+use rust_xlsxwriter::{Chart, ChartDataLabel, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 10)?;
+    worksheet.write(1, 0, 40)?;
+    worksheet.write(2, 0, 50)?;
+    worksheet.write(3, 0, 20)?;
+    worksheet.write(4, 0, 10)?;
+    worksheet.write(5, 0, 50)?;
+
+    // Add some data for the labels.
+    worksheet.write(0, 1, "Jan")?;
+    worksheet.write(1, 1, "Feb")?;
+    worksheet.write(2, 1, "Mar")?;
+    worksheet.write(3, 1, "Apr")?;
+    worksheet.write(4, 1, "May")?;
+    worksheet.write(5, 1, "Jun")?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add a data series with data labels from a worksheet range.
+    chart
+        .add_series()
+        .set_values("Sheet1!$A$1:$A$6")
+        .set_data_label(ChartDataLabel::new().set_value_range("Sheet1!$B$1:$B$6"));
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
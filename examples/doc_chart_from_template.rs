@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating several charts with the same formatting from a
+//! template chart.
+
+use rust_xlsxwriter::{Chart, ChartFormat, ChartSolidFill, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the charts.
+    let data = [[10, 40, 50, 20], [30, 60, 70, 50], [20, 30, 40, 30]];
+    for (col_num, col_data) in data.iter().enumerate() {
+        worksheet.write_column(0, col_num as u16, *col_data)?;
+    }
+
+    // Create a template chart with the common formatting.
+    let mut template = Chart::new(ChartType::Column);
+    template.set_width(320).set_height(240);
+    template.legend().set_hidden();
+    template.y_axis().set_max(80);
+
+    // Add a template series with the common series formatting.
+    template
+        .add_series()
+        .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#4F81BD")));
+
+    // Create a chart for each column of data. Only the series data range
+    // needs to be set.
+    for col_num in 0..3 {
+        let mut chart = Chart::from_template(&template);
+
+        chart
+            .add_series()
+            .set_values(("Sheet1", 0, col_num, 3, col_num));
+
+        chart.title().set_name(&format!("Chart {}", col_num + 1));
+
+        worksheet.insert_chart(5, col_num * 5, &chart)?;
+    }
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating a chart with series ranges taken from the columns
+//! of a worksheet table.
+
+use rust_xlsxwriter::{Chart, ChartType, Table, TableColumn, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the table.
+    worksheet.write_column(1, 0, ["Jan", "Feb", "Mar", "Apr"])?;
+    worksheet.write_column(1, 1, [150, 180, 160, 210])?;
+
+    // Add a worksheet table with a name and column headers.
+    let columns = vec![
+        TableColumn::new().set_header("Month"),
+        TableColumn::new().set_header("Revenue"),
+    ];
+    let table = Table::new().set_name("Sales").set_columns(&columns);
+    worksheet.add_table(0, 0, 4, 1, &table)?;
+
+    // Create a new chart with data from the table columns.
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_categories_from_table("Sales", "Month")
+        .set_values_from_table("Sales", "Revenue");
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 3, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
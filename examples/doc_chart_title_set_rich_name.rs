@@ -0,0 +1,44 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! A chart example demonstrating setting a chart title with multiple fonts
+//! and a subtitle.
+
+use rust_xlsxwriter::{Chart, ChartFont, ChartType, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some data for the chart.
+    worksheet.write(0, 0, 50)?;
+    worksheet.write(1, 0, 30)?;
+    worksheet.write(2, 0, 40)?;
+
+    // Create a new chart.
+    let mut chart = Chart::new(ChartType::Column);
+
+    // Add a data series using Excel formula syntax to describe the range.
+    chart.add_series().set_values("Sheet1!$A$1:$A$3");
+
+    // Create some fonts for the title segments.
+    let mut heading = ChartFont::new();
+    heading.set_size(16);
+
+    let mut subtitle = ChartFont::new();
+    subtitle.set_size(10).set_italic().set_color("#7F7F7F");
+
+    // Set the chart title with a heading and a subtitle on a new line.
+    chart
+        .title()
+        .set_rich_name(&[(&heading, "Quarterly Sales\n"), (&subtitle, "All regions")]);
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 2, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
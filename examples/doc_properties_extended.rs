@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the language, revision and
+//! extended document properties that are read by document management
+//! systems.
+
+use rust_xlsxwriter::{DocProperties, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let properties = DocProperties::new()
+        .set_title("Quarterly Report")
+        .set_language("en-US")
+        .set_revision(4)
+        .set_doc_security(8)
+        .set_shared_doc(true);
+
+    workbook.set_properties(&properties);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Hello")?;
+
+    workbook.save("properties.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,49 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! An example of creating a Stock chart with the `StockChartBuilder`.
+
+use rust_xlsxwriter::{ExcelDateTime, Format, StockChartBuilder, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let date_format = Format::new().set_num_format("yyyy-mm-dd");
+
+    // Add some data for the chart.
+    let dates = [
+        ExcelDateTime::parse_from_str("2024-05-01")?,
+        ExcelDateTime::parse_from_str("2024-05-02")?,
+        ExcelDateTime::parse_from_str("2024-05-03")?,
+        ExcelDateTime::parse_from_str("2024-05-04")?,
+        ExcelDateTime::parse_from_str("2024-05-05")?,
+    ];
+    worksheet.write_column_with_format(0, 0, dates, &date_format)?;
+    worksheet.write_column(0, 1, [35.00, 41.53, 43.33, 46.73, 49.50])?;
+    worksheet.write_column(0, 2, [44.12, 45.98, 46.99, 50.40, 54.99])?;
+    worksheet.write_column(0, 3, [32.59, 38.51, 40.02, 45.60, 47.17])?;
+    worksheet.write_column(0, 4, [41.53, 43.33, 46.73, 49.50, 53.29])?;
+    worksheet.set_column_width(0, 11)?;
+
+    // Create an Open-High-Low-Close Stock chart.
+    let mut chart = StockChartBuilder::new()
+        .set_dates("Sheet1!$A$1:$A$5")
+        .set_open("Sheet1!$B$1:$B$5")
+        .set_high("Sheet1!$C$1:$C$5")
+        .set_low("Sheet1!$D$1:$D$5")
+        .set_close("Sheet1!$E$1:$E$5")
+        .build()?;
+
+    // Add a chart title and hide the legend.
+    chart.title().set_name("Stock: Open - High - Low - Close");
+    chart.legend().set_hidden();
+
+    // Add the chart to the worksheet.
+    worksheet.insert_chart(0, 6, &chart)?;
+
+    // Save the file.
+    workbook.save("chart.xlsx")?;
+
+    Ok(())
+}
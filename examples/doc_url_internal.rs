@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating the different types of url
+//! links with the typed constructors.
+
+use rust_xlsxwriter::{Url, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Link to a range in another worksheet.
+    worksheet.write(0, 0, Url::internal("Sheet2!A1:B10"))?;
+
+    // Link to a defined name, with a tool tip.
+    worksheet.write(1, 0, Url::defined_name("Totals").set_tip("Go to totals"))?;
+
+    // Link to a cell in another file.
+    worksheet.write(2, 0, Url::file("Budget.xlsx#Sheet1!A1").set_text("Budget"))?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, 123)?;
+
+    workbook.define_name("Totals", "=Sheet2!$A$1")?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
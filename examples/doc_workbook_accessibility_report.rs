@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates checking a workbook for accessibility
+//! issues before saving it.
+
+use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add a merged range and some low contrast text.
+    let format = Format::new()
+        .set_font_color(Color::Silver)
+        .set_background_color(Color::White);
+
+    worksheet.merge_range(0, 0, 0, 3, "Sales report", &Format::new())?;
+    worksheet.write_string_with_format(1, 0, "Draft", &format)?;
+
+    // Check the workbook for accessibility issues.
+    for issue in workbook.accessibility_report() {
+        println!("{issue}");
+    }
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates registering the content type and
+//! relationship for a part that is added to the file after it is saved.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let _worksheet = workbook.add_worksheet();
+
+    workbook.add_content_type_override("/docProps/thumbnail.jpeg", "image/jpeg")?;
+
+    workbook.add_package_relationship(
+        "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail",
+        "docProps/thumbnail.jpeg",
+    )?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a vba project to an xlsm file
+//! from a buffer.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Embed the VBA project in the application.
+    let project = include_bytes!("../examples/vbaProject.bin");
+
+    workbook.add_vba_project_from_buffer(project)?;
+
+    let _worksheet = workbook.add_worksheet();
+
+    // Note the `.xlsm` extension.
+    workbook.save("macros.xlsm")?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates creating defined names for worksheet
+//! ranges.
+
+use rust_xlsxwriter::{RangeRef, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet().set_name("Sales Data")?;
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+    worksheet.write_column(0, 1, [40, 50, 60])?;
+
+    // Define names from a range string and a RangeRef.
+    workbook.define_name_range("Sales", "Sales Data", "A1:A3")?;
+    workbook.define_name_range("Costs", "Sales Data", RangeRef::new(0, 1, 2, 1))?;
+
+    // Save the file to disk.
+    workbook.save("defined_name.xlsx")?;
+
+    Ok(())
+}
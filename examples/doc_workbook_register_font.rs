@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates registering the character widths of a
+//! custom font so that `autofit()` gives a better column width.
+
+use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Register the widths of a wide corporate font at 11pt.
+    let metrics = FontMetrics::new("Corporate Sans", 11.0)
+        .set_char_widths(('0'..='9').map(|digit| (digit, 9)))
+        .set_char_widths(('a'..='z').map(|letter| (letter, 10)))
+        .set_char_widths(('A'..='Z').map(|letter| (letter, 12)))
+        .set_char_width(' ', 4)
+        .set_font_family(1);
+
+    workbook.register_font(&metrics);
+
+    let format = Format::new().set_font_name("Corporate Sans");
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_with_format(0, 0, "Quarterly Revenue", &format)?;
+    worksheet.write_with_format(1, 0, 12345.5, &format)?;
+
+    // Autofit the columns using the registered font widths.
+    worksheet.autofit();
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
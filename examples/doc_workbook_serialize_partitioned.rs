@@ -0,0 +1,46 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of serializing data to separate worksheets based on a key field.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Create a serializable struct.
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Sale {
+        region: &'static str,
+        item: &'static str,
+        volume: u32,
+    }
+
+    let sales = [
+        Sale {
+            region: "East",
+            item: "Apple",
+            volume: 9000,
+        },
+        Sale {
+            region: "West",
+            item: "Pear",
+            volume: 8000,
+        },
+        Sale {
+            region: "East",
+            item: "Grape",
+            volume: 6000,
+        },
+    ];
+
+    // Serialize the data to a worksheet for each region.
+    workbook.serialize_partitioned(&sales, |sale| sale.region)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates changing the default font for a
+//! workbook.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Use Aptos Narrow 11 as the default font. The default row height
+    // and column width for this font are 20 and 64 pixels.
+    let format = Format::new().set_font_name("Aptos Narrow");
+    workbook.set_default_format(&format, 20, 64)?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write(0, 0, "Hello")?;
+
+    // This format will also use the default font.
+    let bold = Format::new().set_bold();
+    worksheet.write_with_format(1, 0, "World", &bold)?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
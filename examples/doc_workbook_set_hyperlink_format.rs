@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a workbook wide format for
+//! urls.
+
+use rust_xlsxwriter::{Color, Format, FormatUnderline, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Display all urls with a dark green double underlined font.
+    let format = Format::new()
+        .set_font_color(Color::RGB(0x006100))
+        .set_underline(FormatUnderline::Double);
+
+    workbook.set_hyperlink_format(&format);
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_url(0, 0, "https://www.rust-lang.org")?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_url(0, 0, "https://crates.io")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the default direction of the
+//! worksheets in a workbook to right-to-left.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Set the default direction before adding the worksheets.
+    workbook.set_right_to_left(true);
+
+    // Both worksheets are displayed from right to left.
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.write(0, 0, "نص عربي / English text")?;
+
+    let worksheet2 = workbook.add_worksheet();
+    worksheet2.write(0, 0, "نص عربي / English text")?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
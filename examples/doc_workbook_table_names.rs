@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the names of the tables in a
+//! workbook.
+
+use rust_xlsxwriter::{Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add some tables to the worksheets.
+    let worksheet = workbook.add_worksheet();
+    worksheet.add_table(0, 0, 4, 3, &Table::new())?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.add_table(0, 0, 4, 3, &Table::new().set_name("Sales"))?;
+    worksheet.add_table(6, 0, 9, 3, &Table::new())?;
+
+    // Get the table names.
+    assert_eq!(workbook.table_names(), vec!["Table1", "Sales", "Table3"]);
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
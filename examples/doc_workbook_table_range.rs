@@ -0,0 +1,30 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the range of a table with a
+//! default name and using it to create a defined name.
+
+use rust_xlsxwriter::{Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add some tables to the worksheets.
+    let worksheet = workbook.add_worksheet();
+    worksheet.add_table(0, 0, 4, 3, &Table::new())?;
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.add_table(2, 1, 6, 4, &Table::new())?;
+
+    // Get the range of the second table and use it to create a defined name.
+    let (sheet_name, range) = workbook.table_range("Table2").unwrap();
+    let formula = range.to_sheet_range(&sheet_name);
+    assert_eq!(formula, "Sheet2!$B$3:$E$7");
+
+    workbook.define_name("SalesData", &format!("={formula}"))?;
+
+    workbook.save("workbook.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates reading back the values of cells
+//! written to a worksheet.
+
+use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, "Total")?;
+    worksheet.write(0, 1, 123.5)?;
+
+    assert_eq!(
+        worksheet.cell_value(0, 0),
+        Some(CellValue::String("Total".to_string()))
+    );
+    assert_eq!(worksheet.cell_value(0, 1), Some(CellValue::Number(123.5)));
+    assert_eq!(worksheet.cell_value(0, 2), None);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,57 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of creating a chart from some serialized data.
+
+use rust_xlsxwriter::{ChartType, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct MonthlySales {
+        month: &'static str,
+        north: u32,
+        south: u32,
+    }
+
+    let data = [
+        MonthlySales {
+            month: "Jan",
+            north: 120,
+            south: 90,
+        },
+        MonthlySales {
+            month: "Feb",
+            north: 150,
+            south: 110,
+        },
+        MonthlySales {
+            month: "Mar",
+            north: 135,
+            south: 140,
+        },
+    ];
+
+    // Set the serialization location and headers and serialize the data.
+    worksheet.deserialize_headers::<MonthlySales>(0, 0)?;
+    worksheet.serialize(&data)?;
+
+    // Create a chart of the serialized data, with a series for each region.
+    let mut chart = worksheet.chart_from_serialized::<MonthlySales>(ChartType::Column)?;
+    chart.title().set_name("Sales by region");
+
+    worksheet.insert_chart(1, 4, &chart)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates copying a formatted range of cells
+//! from one worksheet to another.
+
+use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let bold = Format::new().set_bold();
+
+    // Create a template worksheet with a title and some totals.
+    let mut template = Worksheet::new();
+    template.merge_range(0, 0, 0, 2, "Quarterly Report", &bold)?;
+    template.write_row(1, 0, [10, 20, 30])?;
+    template.write_row(2, 0, [40, 50, 60])?;
+    template.write_formula_with_format(3, 0, "=SUM(A2:A3)", &bold)?;
+
+    // Copy the template to the top of a report worksheet.
+    let worksheet = workbook.add_worksheet();
+    worksheet.copy_range_from(&template, 0, 0, 3, 2, 0, 0)?;
+
+    // Copy it again lower down. The formula is adjusted to "=SUM(A8:A9)".
+    worksheet.copy_range_from(&template, 0, 0, 3, 2, 6, 0)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
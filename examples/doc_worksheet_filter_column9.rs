@@ -0,0 +1,36 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting an autofilter with a dynamic
+//! filter to show dates in the current month.
+
+use rust_xlsxwriter::{
+    ExcelDateTime, FilterCondition, FilterDynamicType, Format, Workbook, XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let date_format = Format::new().set_num_format("yyyy-mm-dd");
+
+    // Add a worksheet with some sample data to filter.
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(0, 12)?;
+    worksheet.write_string(0, 0, "Date")?;
+
+    for (row, day) in (1..=6).zip([1, 9, 18, 27, 36, 45]) {
+        let date = ExcelDateTime::from_serial_datetime(45658 + day)?;
+        worksheet.write_datetime_with_format(row, 0, &date, &date_format)?;
+    }
+
+    // Set the autofilter.
+    worksheet.autofilter(0, 0, 6, 0)?;
+
+    // Set a filter condition to show dates in the current month.
+    let filter_condition = FilterCondition::new().add_dynamic_filter(FilterDynamicType::ThisMonth);
+    worksheet.filter_column(0, &filter_condition)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
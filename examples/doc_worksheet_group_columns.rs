@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates grouping worksheet columns.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1"])?;
+
+    // Group the monthly columns to the left of the quarterly column.
+    worksheet.group_columns(0, 2)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,29 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates grouping worksheet rows into nested
+//! outline groups.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Add some regional data with a summary row after each region.
+    worksheet.write_column(0, 0, ["North", "North", "North Total"])?;
+    worksheet.write_column(3, 0, ["South", "South", "South Total"])?;
+    worksheet.write(6, 0, "Grand Total")?;
+
+    // Group all the data rows at the first level.
+    worksheet.group_rows(0, 5)?;
+
+    // Group the detail rows of each region at the second level.
+    worksheet.group_rows(0, 1)?;
+    worksheet.group_rows(3, 4)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
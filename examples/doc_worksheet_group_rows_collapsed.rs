@@ -0,0 +1,22 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a collapsed group of rows.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_column(0, 0, [10, 20, 30])?;
+    worksheet.write_formula(3, 0, "=SUM(A1:A3)")?;
+
+    // Hide the detail rows under the summary row.
+    worksheet.group_rows_collapsed(0, 2)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
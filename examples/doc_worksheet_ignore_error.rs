@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates ignoring the Excel warnings for numbers
+//! stored as text.
+
+use rust_xlsxwriter::{IgnoreError, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Write some numbers stored as text, such as zip codes.
+    worksheet.write(0, 0, "02134")?;
+    worksheet.write_column(1, 0, ["02139", "02142", "02151"])?;
+
+    // Turn off the warning for a single cell.
+    worksheet.ignore_error(0, 0, IgnoreError::NumberStoredAsText)?;
+
+    // Turn off the warning for a range of cells.
+    worksheet.ignore_error_range(1, 0, 3, 0, IgnoreError::NumberStoredAsText)?;
+
+    // Turn off several warnings for a cell with a formula.
+    worksheet.write_formula(4, 0, "=1/0")?;
+    worksheet.ignore_error(4, 0, [IgnoreError::EvalError, IgnoreError::FormulaUnlocked])?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
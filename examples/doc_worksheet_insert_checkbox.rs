@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding checkboxes to worksheet cells.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, "Task")?;
+    worksheet.write(0, 1, "Done")?;
+    worksheet.write_column(1, 0, ["Design", "Build", "Test"])?;
+
+    // Add some checked and unchecked checkboxes.
+    worksheet.insert_checkbox(1, 1, true)?;
+    worksheet.insert_checkbox(2, 1, true)?;
+
+    // Add a checkbox with a cell format.
+    let format = Format::new().set_background_color("#FFC7CE");
+    worksheet.insert_checkbox_with_format(3, 1, false, &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
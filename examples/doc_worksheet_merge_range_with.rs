@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing different data types to
+//! merged ranges.
+
+use rust_xlsxwriter::{ExcelDateTime, Format, FormatAlign, Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let format = Format::new().set_align(FormatAlign::Center);
+    let date_format = Format::new()
+        .set_align(FormatAlign::Center)
+        .set_num_format("yyyy-mm-dd");
+
+    // Write a number, a date and a formula to merged ranges.
+    worksheet.merge_range_with(1, 1, 1, 2, 12345.67, &format)?;
+
+    let date = ExcelDateTime::from_ymd(2024, 7, 1)?;
+    worksheet.merge_range_with(3, 1, 3, 2, &date, &date_format)?;
+
+    let formula = Formula::new("=B2*2");
+    worksheet.merge_range_with(5, 1, 5, 2, formula, &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,72 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of adding conditional formats to serialized fields.
+
+use rust_xlsxwriter::{
+    ConditionalFormatCell, ConditionalFormatCellRule, ConditionalFormatDataBar,
+    CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError,
+};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+        stock: u32,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            cost: 1.05,
+            stock: 120,
+        },
+        Produce {
+            fruit: "Plum",
+            cost: 0.15,
+            stock: 35,
+        },
+        Produce {
+            fruit: "Pear",
+            cost: 0.75,
+            stock: 80,
+        },
+    ];
+
+    // Highlight costs above 0.50 and add data bars to the stock values.
+    let red_format = Format::new()
+        .set_font_color("9C0006")
+        .set_background_color("FFC7CE");
+
+    let cost_format = ConditionalFormatCell::new()
+        .set_rule(ConditionalFormatCellRule::GreaterThan(0.5))
+        .set_format(red_format);
+
+    let custom_headers = [
+        CustomSerializeField::new("Cost").set_conditional_format(&cost_format),
+        CustomSerializeField::new("Stock").set_conditional_format(&ConditionalFormatDataBar::new()),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
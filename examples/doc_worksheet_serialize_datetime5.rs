@@ -10,6 +10,7 @@ use rust_xlsxwriter::utility::serialize_chrono_option_naive_to_excel;
 use serde::Serialize;
 
 fn main() {
+    #[allow(dead_code)]
     #[derive(Serialize)]
     struct Student {
         full_name: String,
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of a serializable struct with a Chrono `DateTime<Utc>` value with a
+//! helper function.
+
+use chrono::{DateTime, Utc};
+use rust_xlsxwriter::utility::serialize_chrono_datetime_to_excel;
+use serde::Serialize;
+
+fn main() {
+    #[allow(dead_code)]
+    #[derive(Serialize)]
+    struct Order {
+        order_id: u32,
+
+        #[serde(serialize_with = "serialize_chrono_datetime_to_excel")]
+        created: DateTime<Utc>,
+    }
+}
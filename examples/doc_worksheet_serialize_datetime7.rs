@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of a serializable struct with an Option Chrono `DateTime` value
+//! with a helper function.
+
+use chrono::{DateTime, FixedOffset};
+use rust_xlsxwriter::utility::serialize_chrono_option_datetime_to_excel;
+use serde::Serialize;
+
+fn main() {
+    #[allow(dead_code)]
+    #[derive(Serialize)]
+    struct Order {
+        order_id: u32,
+
+        #[serde(serialize_with = "serialize_chrono_option_datetime_to_excel")]
+        shipped: Option<DateTime<FixedOffset>>,
+    }
+}
@@ -0,0 +1,21 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of a serializable struct with a Decimal value with a helper
+//! function.
+
+use rust_decimal::Decimal;
+use rust_xlsxwriter::utility::serialize_decimal_to_excel;
+use serde::Serialize;
+
+fn main() {
+    #[allow(dead_code)]
+    #[derive(Serialize)]
+    struct Invoice {
+        item: String,
+
+        #[serde(serialize_with = "serialize_decimal_to_excel")]
+        price: Decimal,
+    }
+}
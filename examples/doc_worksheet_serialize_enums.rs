@@ -0,0 +1,66 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing structs that contain enum
+//! fields to a worksheet.
+
+use rust_xlsxwriter::{SerializeEnumFormat, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create some serializable enums and a struct that contains them.
+    #[derive(Deserialize, Serialize)]
+    enum Status {
+        Pending,
+        Shipped,
+    }
+
+    #[derive(Deserialize, Serialize)]
+    enum Payment {
+        Card(f64),
+        Cash(f64),
+    }
+
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Order {
+        id: u32,
+        status: Status,
+        payment: Payment,
+    }
+
+    let orders = [
+        Order {
+            id: 1001,
+            status: Status::Shipped,
+            payment: Payment::Card(12.5),
+        },
+        Order {
+            id: 1002,
+            status: Status::Pending,
+            payment: Payment::Cash(7.25),
+        },
+    ];
+
+    // Serialize the newtype variants as their inner value (the default).
+    worksheet.deserialize_headers::<Order>(0, 0)?;
+    worksheet.serialize(&orders)?;
+
+    // Serialize the newtype variants as the variant name.
+    let header_options =
+        SerializeFieldOptions::new().set_enum_format(SerializeEnumFormat::VariantName);
+
+    worksheet.deserialize_headers_with_options::<Order>(0, 4, &header_options)?;
+    worksheet.serialize(&orders)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
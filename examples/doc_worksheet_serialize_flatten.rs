@@ -0,0 +1,68 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing a struct with a flattened
+//! sub-struct to a worksheet. The fields of the sub-struct are written in the
+//! same header row as the fields of the parent struct.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add a simple format for the headers.
+    let format = Format::new().set_bold();
+
+    // Create a serializable struct with a flattened sub-struct.
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Price {
+        cost: f64,
+        discount: f64,
+    }
+
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        #[serde(flatten)]
+        price: Price,
+        in_stock: bool,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            price: Price {
+                cost: 1.05,
+                discount: 0.1,
+            },
+            in_stock: true,
+        },
+        Produce {
+            fruit: "Plum",
+            price: Price {
+                cost: 0.15,
+                discount: 0.0,
+            },
+            in_stock: false,
+        },
+    ];
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.serialize_headers_with_format(0, 0, &items[0], &format)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,56 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing structs with formula and
+//! hyperlink fields to a worksheet.
+
+use rust_xlsxwriter::{Formula, Url, Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct with formula and url fields.
+    #[derive(Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+        quantity: u32,
+        total: Formula,
+        supplier: Url,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            cost: 1.05,
+            quantity: 20,
+            total: Formula::new("=B2*C2"),
+            supplier: Url::new("https://www.example.com/peach").set_text("Peach Co."),
+        },
+        Produce {
+            fruit: "Plum",
+            cost: 0.15,
+            quantity: 50,
+            total: Formula::new("=B3*C3"),
+            supplier: Url::new("https://www.example.com/plum").set_text("Plum Co."),
+        },
+    ];
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.serialize_headers(0, 0, &items[0])?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
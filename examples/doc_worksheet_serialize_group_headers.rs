@@ -0,0 +1,75 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of serializing nested structs with merged group headers.
+
+use rust_xlsxwriter::{
+    CustomSerializeField, Format, FormatAlign, FormatBorder, SerializeFieldOptions, Workbook,
+    XlsxError,
+};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add some formats to use with the serialization data.
+    let header_format = Format::new()
+        .set_bold()
+        .set_align(FormatAlign::Center)
+        .set_border(FormatBorder::Thin);
+
+    // Create some serializable nested structs.
+    #[derive(Serialize)]
+    struct Dimensions {
+        width: f64,
+        height: f64,
+    }
+
+    #[derive(Serialize)]
+    struct Product {
+        name: &'static str,
+        size: Dimensions,
+    }
+
+    let items = [
+        Product {
+            name: "Box",
+            size: Dimensions {
+                width: 10.0,
+                height: 5.0,
+            },
+        },
+        Product {
+            name: "Crate",
+            size: Dimensions {
+                width: 40.0,
+                height: 25.0,
+            },
+        },
+    ];
+
+    // Set the serialization options, including the nested field names.
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(&header_format)
+        .set_group_headers(true)
+        .set_custom_headers(&[
+            CustomSerializeField::new("size").rename("Dimensions"),
+            CustomSerializeField::new("size.width").rename("Width"),
+            CustomSerializeField::new("size.height").rename("Height"),
+        ]);
+
+    // Set the serialization location and headers.
+    worksheet.serialize_headers_with_options(0, 0, &items[0], &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,42 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of serializing the items of an iterator to a worksheet.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add a simple format for the headers.
+    let format = Format::new().set_bold();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Square {
+        number: u32,
+        square: u32,
+    }
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.deserialize_headers_with_format::<Square>(0, 0, &format)?;
+
+    // Serialize the data from an iterator, without collecting it first.
+    let squares = (1..=10).map(|number| Square {
+        number,
+        square: number * number,
+    });
+
+    worksheet.serialize_iter(squares)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
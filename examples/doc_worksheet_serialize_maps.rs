@@ -0,0 +1,85 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing maps to a worksheet. The keys
+//! of the first map are used as the headers and the keys of the second map are
+//! written to a row label column.
+
+use std::collections::BTreeMap;
+
+use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Add a simple format for the headers.
+    let format = Format::new().set_bold();
+
+    // Create some maps where the keys are used as the headers.
+    let mut sales1 = BTreeMap::new();
+    sales1.insert("East", 1000);
+    sales1.insert("North", 3000);
+    sales1.insert("West", 2000);
+
+    let mut sales2 = BTreeMap::new();
+    sales2.insert("East", 1200);
+    sales2.insert("North", 3300);
+    sales2.insert("West", 2400);
+
+    // Set up the start location and headers of the data to be serialized.
+    worksheet.serialize_headers_with_format(0, 0, &sales1, &format)?;
+
+    // Serialize the data.
+    worksheet.serialize(&vec![sales1, sales2])?;
+
+    // Create a map of structs where the keys are used as row labels.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        cost: f64,
+        stock: u32,
+    }
+
+    let mut produce = BTreeMap::new();
+    produce.insert(
+        "Peach",
+        Produce {
+            cost: 1.05,
+            stock: 20,
+        },
+    );
+    produce.insert(
+        "Pear",
+        Produce {
+            cost: 0.75,
+            stock: 35,
+        },
+    );
+    produce.insert(
+        "Plum",
+        Produce {
+            cost: 0.15,
+            stock: 40,
+        },
+    );
+
+    // Set the header for the column of map keys.
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(&format)
+        .set_map_key_header("Fruit");
+
+    worksheet.deserialize_headers_with_options::<Produce>(0, 4, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&produce)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
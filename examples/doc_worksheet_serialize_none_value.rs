@@ -0,0 +1,59 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing None values with a default
+//! literal value.
+
+use rust_xlsxwriter::{
+    CustomSerializeField, SerializeFieldOptions, SerializeNoneValue, Workbook, XlsxError,
+};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct with optional fields.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: Option<f64>,
+        supplier: Option<&'static str>,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            cost: Some(1.05),
+            supplier: None,
+        },
+        Produce {
+            fruit: "Plum",
+            cost: None,
+            supplier: Some("Orchard Co."),
+        },
+    ];
+
+    // Write "N/A" for a missing cost and skip a missing supplier.
+    let custom_headers = [
+        CustomSerializeField::new("Cost").set_none_value("N/A"),
+        CustomSerializeField::new("Supplier").set_none_value(SerializeNoneValue::Skip),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates serializing data with alternating row
+//! formats.
+
+use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cost: f64,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            cost: 1.05,
+        },
+        Produce {
+            fruit: "Plum",
+            cost: 0.15,
+        },
+        Produce {
+            fruit: "Pear",
+            cost: 0.75,
+        },
+        Produce {
+            fruit: "Apple",
+            cost: 0.50,
+        },
+    ];
+
+    // Set up alternating row formats.
+    let row_formats = [
+        Format::new().set_background_color("DCE6F1"),
+        Format::new().set_background_color("FFFFFF"),
+    ];
+
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(Format::new().set_bold())
+        .set_row_formats(&row_formats);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
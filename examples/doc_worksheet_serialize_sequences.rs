@@ -0,0 +1,52 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of serializing a vector field across several columns.
+
+use rust_xlsxwriter::{CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError};
+use serde::Serialize;
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct with a sequence field.
+    #[derive(Serialize)]
+    struct Region {
+        name: &'static str,
+        sales: Vec<f64>,
+    }
+
+    let regions = [
+        Region {
+            name: "North",
+            sales: vec![1200.0, 1350.0, 1100.0, 1500.0],
+        },
+        Region {
+            name: "South",
+            sales: vec![980.0, 1020.0, 1250.0, 1190.0],
+        },
+    ];
+
+    // Serialize the sales to a column for each quarter.
+    let header_options = SerializeFieldOptions::new()
+        .set_header_format(Format::new().set_bold())
+        .set_expand_sequences(true)
+        .set_custom_headers(&[CustomSerializeField::new("sales")
+            .rename("Q")
+            .set_value_format(Format::new().set_num_format("#,##0"))]);
+
+    // Set the serialization location and headers.
+    worksheet.serialize_headers_with_options(0, 0, &regions[0], &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&regions)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
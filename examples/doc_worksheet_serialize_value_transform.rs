@@ -0,0 +1,69 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of serializing struct fields with a value transform function.
+
+use rust_xlsxwriter::{
+    CustomSerializeField, ExcelCellValue, Format, SerializeFieldOptions, Workbook, XlsxError,
+};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename_all = "PascalCase")]
+    struct Produce {
+        fruit: &'static str,
+        cents: u32,
+        in_stock: bool,
+    }
+
+    // Create some data instances.
+    let items = [
+        Produce {
+            fruit: "Peach",
+            cents: 105,
+            in_stock: true,
+        },
+        Produce {
+            fruit: "Plum",
+            cents: 99,
+            in_stock: false,
+        },
+    ];
+
+    // Convert the cents to dollars and the booleans to "Yes"/"No".
+    let currency_format = Format::new().set_num_format("$0.00");
+    let custom_headers = [
+        CustomSerializeField::new("Cents")
+            .rename("Cost")
+            .set_value_format(&currency_format)
+            .set_value_transform(|value| match value {
+                ExcelCellValue::Number(cents) => ExcelCellValue::Number(cents / 100.0),
+                value => value,
+            }),
+        CustomSerializeField::new("InStock").set_value_transform(|value| match value {
+            ExcelCellValue::Boolean(true) => "Yes".into(),
+            ExcelCellValue::Boolean(false) => "No".into(),
+            value => value,
+        }),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    // Set the serialization location and headers.
+    worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+
+    // Serialize the data.
+    worksheet.serialize(&items)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
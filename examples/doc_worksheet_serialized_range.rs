@@ -0,0 +1,60 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! Example of getting the range of serialized data by struct type.
+
+use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+use serde::{Deserialize, Serialize};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable struct.
+    #[derive(Deserialize, Serialize)]
+    #[serde(rename = "Sales")]
+    struct MonthlySales {
+        month: &'static str,
+        sales: u32,
+    }
+
+    let data = [
+        MonthlySales {
+            month: "Jan",
+            sales: 120,
+        },
+        MonthlySales {
+            month: "Feb",
+            sales: 150,
+        },
+        MonthlySales {
+            month: "Mar",
+            sales: 135,
+        },
+    ];
+
+    // Set the serialization location and headers and serialize the data.
+    worksheet.deserialize_headers::<MonthlySales>(1, 1)?;
+    worksheet.serialize(&data)?;
+
+    // Get the range of the serialized data.
+    let (first_row, first_col, last_row, last_col) =
+        worksheet.serialized_range::<MonthlySales>()?;
+
+    // Create a chart of the data, skipping the header row.
+    let mut chart = Chart::new(ChartType::Column);
+    chart
+        .add_series()
+        .set_categories(("Sheet1", first_row + 1, first_col, last_row, first_col))
+        .set_values(("Sheet1", first_row + 1, last_col, last_row, last_col));
+
+    worksheet.insert_chart(1, 4, &chart)?;
+
+    // Save the file.
+    workbook.save("serialize.xlsx")?;
+
+    Ok(())
+}
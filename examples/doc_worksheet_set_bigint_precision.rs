@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing large `BigInt` values to a
+//! worksheet as numbers, with a loss of precision.
+
+use num_bigint::BigInt;
+use rust_xlsxwriter::{BigIntPrecision, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // A value with more than 15 digits.
+    let number = BigInt::from(1_234_567_890_123_456_789_i64);
+
+    // The default is to write the value as a string.
+    worksheet.write(0, 0, &number)?;
+
+    // Write the value as a number, with a loss of precision.
+    worksheet.set_bigint_precision(BigIntPrecision::Number);
+    worksheet.write(1, 0, &number)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,31 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the active pane and the
+//! selections in the panes of a worksheet with frozen rows and columns.
+
+use rust_xlsxwriter::{PanePosition, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Freeze the top row and leftmost column.
+    worksheet.set_freeze_panes(1, 1)?;
+
+    // Pre-scroll the data area and select a range in it.
+    worksheet.set_freeze_panes_top_cell(20, 1)?;
+    worksheet.set_freeze_panes_selection(PanePosition::BottomRight, 20, 1, 24, 3)?;
+
+    // Select the column headers in the top pane.
+    worksheet.set_freeze_panes_selection(PanePosition::TopRight, 0, 1, 0, 3)?;
+
+    // Make the top right pane active.
+    worksheet.set_freeze_panes_active_pane(PanePosition::TopRight);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates adding a washed out, or watermark, image
+//! to a worksheet header.
+
+use rust_xlsxwriter::{
+    HeaderImageColorMode, HeaderImageOptions, HeaderImagePosition, Image, Workbook, XlsxError,
+};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let image = Image::new("examples/rust_logo.png")?;
+
+    // Scale the image and wash it out so it can be used as a watermark.
+    let options = HeaderImageOptions::new()
+        .set_scale_width(2.0)
+        .set_scale_height(2.0)
+        .set_color_mode(HeaderImageColorMode::Washout);
+
+    // Insert the watermark image in the header.
+    worksheet.set_header("&C&[Picture]");
+    worksheet.set_header_image_with_options(&image, HeaderImagePosition::Center, &options)?;
+
+    // Set Page View mode so the watermark is visible.
+    worksheet.set_view_page_layout();
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
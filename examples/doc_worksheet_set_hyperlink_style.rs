@@ -0,0 +1,34 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the format policy for urls in a
+//! worksheet.
+
+use rust_xlsxwriter::{Color, Format, HyperlinkStyle, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Use a red, non-underlined font for urls in the worksheet.
+    let format = Format::new().set_font_color(Color::Red);
+    worksheet.set_hyperlink_style(HyperlinkStyle::Custom(format));
+
+    worksheet.write_url(0, 0, "https://www.rust-lang.org")?;
+
+    // Keep the existing bold format of a cell.
+    let bold = Format::new().set_bold();
+    worksheet.write_blank(2, 0, &bold)?;
+
+    worksheet.set_hyperlink_style(HyperlinkStyle::Inherit);
+    worksheet.write_url(2, 0, "https://crates.io")?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
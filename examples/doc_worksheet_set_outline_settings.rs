@@ -0,0 +1,20 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting the outline settings for a
+//! worksheet so that the summary rows are above the grouped rows.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Display the summary rows above the grouped rows.
+    worksheet.set_outline_settings(false, true, false, true);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,25 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates setting a custom paper size and the
+//! "Narrow" margins for the printed output.
+
+use rust_xlsxwriter::{MarginPreset, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Set a custom 200mm x 250mm printer paper size.
+    worksheet.set_paper_dimensions(200.0, 250.0)?;
+
+    // Use the Excel "Narrow" margins.
+    worksheet.set_margin_preset(MarginPreset::Narrow);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
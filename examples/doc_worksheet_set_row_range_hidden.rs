@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates hiding a range of worksheet rows.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Hide rows 2 to 5 (with zero indexing).
+    worksheet.set_row_range_hidden(1, 4)?;
+
+    worksheet.write_string(5, 0, "Rows 2 to 5 are hidden")?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
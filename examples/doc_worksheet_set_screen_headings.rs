@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates turning off the worksheet screen row
+//! and column headings.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, "Hello")?;
+
+    // Turn off the screen row and column headings.
+    worksheet.set_screen_headings(false);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,24 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates selecting several ranges of cells in a
+//! worksheet and scrolling the worksheet to show them.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Select cells B22:C24 and E22:E30. The active cell is B22.
+    worksheet.set_selection_ranges(["B22:C24", "E22:E30"])?;
+
+    // Scroll the worksheet so that the selection is visible.
+    worksheet.set_top_left_cell(20, 0)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,23 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates splitting a worksheet into panes that
+//! can be scrolled independently.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write_string(0, 0, "Scroll down or right")?;
+
+    // Split the worksheet below row 2 and to the right of column B.
+    worksheet.set_split_panes(40, 128);
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
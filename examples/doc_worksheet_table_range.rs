@@ -0,0 +1,40 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates getting the range of a table and using
+//! it to create a defined name.
+
+use rust_xlsxwriter::{Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample data for the table.
+    let headers = ["Quarter 1", "Quarter 2", "Quarter 3", "Quarter 4"];
+    let data = [
+        [10000, 5000, 8000, 6000],
+        [2000, 3000, 4000, 5000],
+        [6000, 6000, 6500, 6000],
+    ];
+
+    // Add a named table to the worksheet.
+    let table = Table::new().set_name("Sales");
+    worksheet.write_table(2, 1, headers, data, &table)?;
+
+    // Get the table range and use it to create a defined name.
+    let range = worksheet.table_range("Sales").unwrap();
+    let formula = range.to_sheet_range(&worksheet.name());
+    assert_eq!(formula, "Sheet1!$B$3:$E$6");
+
+    workbook.define_name("SalesData", &format!("={formula}"))?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,26 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing data to a worksheet using `A1`
+//! style cell references.
+
+use rust_xlsxwriter::{Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let bold = Format::new().set_bold();
+
+    // Write data using A1 style cell references.
+    worksheet.write_with_format_a1("A1", "Total", &bold)?;
+    worksheet.write_a1("B1", 1234)?;
+
+    // Set the print area using an A1 style range.
+    worksheet.set_print_area_a1("A1:B1")?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
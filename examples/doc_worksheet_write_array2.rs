@@ -0,0 +1,27 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing an `ndarray` 2D array to a
+//! worksheet.
+
+use ndarray::array;
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some matrix data to write.
+    let data = array![[1.0, 0.5, 0.25], [0.5, 1.0, 0.75], [0.25, 0.75, 1.0]];
+
+    // Write the matrix to the worksheet.
+    worksheet.write_array2(0, 0, &data)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
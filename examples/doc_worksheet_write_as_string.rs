@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a type that implements `Display`
+//! to a worksheet.
+
+use std::fmt;
+
+use rust_xlsxwriter::{AsString, Workbook, XlsxError};
+
+// A user defined type that implements Display.
+struct OrderId(u32);
+
+impl fmt::Display for OrderId {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "ORD-{:06}", self.0)
+    }
+}
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Write the Display representation of the types as strings.
+    worksheet.write(0, 0, AsString(OrderId(42)))?;
+    worksheet.write(1, 0, AsString(std::net::Ipv4Addr::LOCALHOST))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
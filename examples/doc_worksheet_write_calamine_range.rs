@@ -0,0 +1,43 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates reading the data from a workbook with
+//! `calamine`, loading it into a new worksheet and adding to it.
+
+use std::io::Cursor;
+
+use calamine::{Reader, Xlsx};
+use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a source file to read.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_row(0, 0, ["Region", "Sales"])?;
+    worksheet.write_column(1, 0, ["North", "South", "East"])?;
+    worksheet.write_column(1, 1, [1200, 950, 1420])?;
+    let buffer = workbook.save_to_buffer()?;
+
+    // Read the data from an existing file with calamine.
+    let mut source: Xlsx<_> =
+        Xlsx::new(Cursor::new(buffer)).map_err(|e| XlsxError::CustomError(e.to_string()))?;
+    let range = source
+        .worksheet_range("Sheet1")
+        .map_err(|e| XlsxError::CustomError(e.to_string()))?;
+
+    // Load the data into a new worksheet.
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let (row, col) = range.start().unwrap_or_default();
+    worksheet.write_calamine_range(row, col as u16, &range)?;
+
+    // Add a total to the data.
+    worksheet.write(4, 0, "Total")?;
+    worksheet.write(4, 1, Formula::new("=SUM(B2:B4)"))?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,33 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing CSV data to a worksheet.
+
+use rust_xlsxwriter::{CsvOptions, Format, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample CSV data.
+    let data = "\
+Region,Item,Volume,Date
+East,Apple,9000,2024-07-01
+West,\"Pear, Green\",8000,2024-07-02
+";
+
+    // Read the CSV data with a bold header row.
+    let options = CsvOptions::new()
+        .set_header_row(true)
+        .set_header_format(&Format::new().set_bold());
+
+    worksheet.write_csv(0, 0, data.as_bytes(), &options)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
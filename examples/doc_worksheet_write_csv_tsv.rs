@@ -0,0 +1,28 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing tab separated data to a
+//! worksheet.
+
+use rust_xlsxwriter::{CsvOptions, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample tab separated data.
+    let data = "Name\tScore\tPassed\nAlice\t93.5\ttrue\nBob\t48\tfalse\n";
+
+    // Read the data with a tab delimiter.
+    let options = CsvOptions::new().set_delimiter('\t');
+
+    worksheet.write_csv(0, 0, data.as_bytes(), &options)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing timezone aware Chrono datetimes
+//! to a worksheet.
+
+use chrono::{DateTime, FixedOffset};
+use rust_xlsxwriter::{Format, TimezonePolicy, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a datetime format.
+    let format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+
+    // Set the column width for clarity.
+    worksheet.set_column_width(0, 20)?;
+    worksheet.set_column_width(1, 20)?;
+
+    // Create a timezone aware datetime.
+    let datetime: DateTime<FixedOffset> =
+        DateTime::parse_from_rfc3339("2024-01-01T12:00:00+02:00").unwrap();
+
+    // Write the datetime in the local time of its offset: 2024-01-01 12:00.
+    worksheet.write_datetime_with_tz_and_format(0, 0, datetime, TimezonePolicy::Local, &format)?;
+
+    // Write the datetime normalized to UTC: 2024-01-01 10:00.
+    worksheet.write_datetime_with_tz_and_format(0, 1, datetime, TimezonePolicy::Utc, &format)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,32 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing JSON objects to a worksheet.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+use serde_json::json;
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some dynamic JSON data.
+    let data = json!([
+        {"fruit": "Apple", "cost": 1.25, "in_stock": true},
+        {"fruit": "Pear", "cost": 0.75, "in_stock": false},
+        {"fruit": "Peach", "cost": 2.5, "in_stock": null},
+    ]);
+
+    // Write the JSON objects to the worksheet.
+    if let Some(rows) = data.as_array() {
+        worksheet.write_json_rows(0, 0, rows)?;
+    }
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,47 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing data from a simple row source
+//! to a worksheet.
+
+use rust_xlsxwriter::{ExcelCellValue, ExcelRowSource, Workbook, XlsxError};
+
+// A simple row source for some in-memory sales data.
+struct SalesData {
+    rows: std::vec::IntoIter<(&'static str, f64)>,
+}
+
+impl ExcelRowSource for SalesData {
+    fn headers(&self) -> Vec<String> {
+        vec!["Region".to_string(), "Sales".to_string()]
+    }
+
+    fn next_row(&mut self) -> Result<Option<Vec<ExcelCellValue>>, XlsxError> {
+        let row = self
+            .rows
+            .next()
+            .map(|(region, sales)| vec![region.into(), sales.into()]);
+
+        Ok(row)
+    }
+}
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    let mut source = SalesData {
+        rows: vec![("North", 1200.0), ("South", 950.5), ("East", 1420.0)].into_iter(),
+    };
+
+    // Write the rows from the source to the worksheet.
+    worksheet.write_rows_from(0, 0, &mut source)?;
+
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing a worksheet table from a set of
+//! headers and a 2D data set.
+
+use rust_xlsxwriter::{ExcelCellValue, Table, Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add a worksheet to the workbook.
+    let worksheet = workbook.add_worksheet();
+
+    // Some sample data for the table.
+    let headers = ["Product", "Quarter 1", "Quarter 2", "Quarter 3"];
+    let data: Vec<Vec<ExcelCellValue>> = vec![
+        vec!["Apples".into(), 10000.into(), 5000.into(), 8000.into()],
+        vec!["Pears".into(), 2000.into(), 3000.into(), 4000.into()],
+        vec!["Bananas".into(), 6000.into(), 6000.into(), 6500.into()],
+        vec!["Oranges".into(), 500.into(), 300.into(), 200.into()],
+    ];
+
+    // Set the column widths for clarity.
+    worksheet.set_column_range_width(1, 4, 12)?;
+
+    // Write the headers, data and table in one step. The table range is
+    // B2:E6.
+    worksheet.write_table(1, 1, headers, data, &Table::new())?;
+
+    // Save the file to disk.
+    workbook.save("tables.xlsx")?;
+
+    Ok(())
+}
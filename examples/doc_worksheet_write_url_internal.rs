@@ -0,0 +1,35 @@
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+//! The following example demonstrates writing internal links to a worksheet
+//! cell and to a defined name.
+
+use rust_xlsxwriter::{Workbook, XlsxError};
+
+fn main() -> Result<(), XlsxError> {
+    // Create a new Excel file object.
+    let mut workbook = Workbook::new();
+
+    // Add some worksheets to the workbook.
+    let worksheet1 = workbook.add_worksheet();
+
+    // Write internal links to a cell and to a defined name.
+    worksheet1.write_url_internal(0, 0, "Sheet2!A1", "Go to Sheet2")?;
+    worksheet1.write_url_internal(1, 0, "'Sales Data'!B2", "Go to Sales")?;
+    worksheet1.write_url_internal(2, 0, "Totals", "Go to Totals")?;
+
+    workbook.add_worksheet();
+    workbook
+        .add_worksheet()
+        .set_name("Sales Data")?
+        .write(1, 1, "Sales")?;
+
+    // Create the defined name used in the link.
+    workbook.define_name("Totals", "='Sales Data'!$B$10")?;
+
+    // Save the file to disk.
+    workbook.save("worksheet.xlsx")?;
+
+    Ok(())
+}
@@ -0,0 +1,162 @@
+// accessibility - A module for reporting accessibility issues in a workbook.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt;
+
+use crate::{Color, Format, FormatPattern};
+
+/// The `AccessibilityIssue` struct represents an accessibility problem found by
+/// [`Workbook::accessibility_report()`](crate::Workbook::accessibility_report).
+///
+/// Each issue has a type, the worksheet and location where it was found, if
+/// applicable, and a human readable description of the problem.
+///
+/// The `Display` trait is implemented for `AccessibilityIssue` so that issues
+/// can be printed or logged directly:
+///
+/// ```text
+/// Sheet1!B2: Image doesn't have alternative text.
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct AccessibilityIssue {
+    /// The type of accessibility issue.
+    pub issue_type: AccessibilityIssueType,
+
+    /// The name of the worksheet where the issue was found. This is `None` for
+    /// issues that apply to the whole workbook.
+    pub worksheet_name: Option<String>,
+
+    /// The cell or range where the issue was found, such as `B2` or `A1:C3`.
+    /// This is `None` for issues that apply to a whole worksheet or workbook.
+    pub location: Option<String>,
+
+    /// A description of the issue.
+    pub description: String,
+}
+
+impl AccessibilityIssue {
+    pub(crate) fn new(
+        issue_type: AccessibilityIssueType,
+        worksheet_name: Option<&str>,
+        location: Option<String>,
+        description: impl Into<String>,
+    ) -> AccessibilityIssue {
+        AccessibilityIssue {
+            issue_type,
+            worksheet_name: worksheet_name.map(std::string::ToString::to_string),
+            location,
+            description: description.into(),
+        }
+    }
+}
+
+impl fmt::Display for AccessibilityIssue {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match (&self.worksheet_name, &self.location) {
+            (Some(name), Some(location)) => write!(f, "{name}!{location}: ")?,
+            (Some(name), None) => write!(f, "{name}: ")?,
+            _ => write!(f, "Workbook: ")?,
+        }
+
+        write!(f, "{}", self.description)
+    }
+}
+
+/// The `AccessibilityIssueType` enum defines the types of issue reported by
+/// [`Workbook::accessibility_report()`](crate::Workbook::accessibility_report).
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum AccessibilityIssueType {
+    /// An image, chart or shape doesn't have alternative text and
+    /// isn't marked as decorative. See, for example,
+    /// [`Image::set_alt_text()`](crate::Image::set_alt_text) and
+    /// [`Image::set_decorative()`](crate::Image::set_decorative).
+    MissingAltText,
+
+    /// The worksheet contains merged cells. Merged cells are often used for
+    /// visual layout and can confuse screen readers when navigating a sheet.
+    MergedCells,
+
+    /// The workbook doesn't have a document title. See
+    /// [`DocProperties::set_title()`](crate::DocProperties::set_title).
+    MissingTitle,
+
+    /// The worksheet has a default name like `Sheet1` rather than a descriptive
+    /// name.
+    DefaultSheetName,
+
+    /// A cell format has a font color and fill color with a contrast ratio
+    /// below the WCAG recommended minimum of 4.5:1.
+    LowContrast,
+}
+
+// The minimum WCAG AA contrast ratio for normal text.
+pub(crate) const MIN_CONTRAST_RATIO: f64 = 4.5;
+
+// Get the contrast ratio between the font color and the solid fill color of a
+// format. Returns `None` if the format doesn't have a solid fill or if either
+// color is a theme color, since the actual color depends on the theme.
+pub(crate) fn format_contrast_ratio(format: &Format) -> Option<f64> {
+    let fill = &format.fill;
+    let fill_color = match fill.pattern {
+        FormatPattern::Solid if fill.background_color != Color::Default => fill.background_color,
+        FormatPattern::Solid => fill.foreground_color,
+        FormatPattern::None if fill.foreground_color == Color::Default => fill.background_color,
+        FormatPattern::None if fill.background_color == Color::Default => fill.foreground_color,
+        _ => return None,
+    };
+
+    if matches!(fill_color, Color::Default | Color::Automatic) {
+        return None;
+    }
+
+    let font_color = rgb_value(format.font.color)?;
+    let fill_color = rgb_value(fill_color)?;
+
+    Some(contrast_ratio(font_color, fill_color))
+}
+
+// Calculate the WCAG contrast ratio between two RGB colors.
+pub(crate) fn contrast_ratio(color1: u32, color2: u32) -> f64 {
+    let luminance1 = relative_luminance(color1);
+    let luminance2 = relative_luminance(color2);
+
+    let (lighter, darker) = if luminance1 > luminance2 {
+        (luminance1, luminance2)
+    } else {
+        (luminance2, luminance1)
+    };
+
+    (lighter + 0.05) / (darker + 0.05)
+}
+
+// Calculate the WCAG relative luminance of an RGB color.
+fn relative_luminance(color: u32) -> f64 {
+    let channel = |shift: u32| {
+        let value = f64::from((color >> shift) & 0xFF) / 255.0;
+
+        if value <= 0.039_28 {
+            value / 12.92
+        } else {
+            ((value + 0.055) / 1.055).powf(2.4)
+        }
+    };
+
+    0.2126 * channel(16) + 0.7152 * channel(8) + 0.0722 * channel(0)
+}
+
+// Get the RGB value of a color. Default and automatic font colors are black.
+fn rgb_value(color: Color) -> Option<u32> {
+    match color {
+        Color::Theme(_, _) => None,
+        _ => u32::from_str_radix(&color.rgb_hex_value(), 16).ok(),
+    }
+}
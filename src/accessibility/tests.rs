@@ -0,0 +1,116 @@
+// Accessibility unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod accessibility_tests {
+    use crate::accessibility::{contrast_ratio, format_contrast_ratio};
+    use crate::{
+        AccessibilityIssueType, Chart, ChartType, Color, DocProperties, Format, FormatPattern,
+        Image, Workbook, XlsxError,
+    };
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert_eq!(21.0, contrast_ratio(0x000000, 0xFFFFFF));
+        assert_eq!(21.0, contrast_ratio(0xFFFFFF, 0x000000));
+        assert_eq!(1.0, contrast_ratio(0x336699, 0x336699));
+        assert_eq!("4.54", format!("{:.2}", contrast_ratio(0x767676, 0xFFFFFF)));
+    }
+
+    #[test]
+    fn test_format_contrast_ratio() {
+        // No fill.
+        let format = Format::new().set_font_color(Color::White);
+        assert_eq!(None, format_contrast_ratio(&format));
+
+        // Default font color on a solid fill.
+        let format = Format::new().set_background_color(Color::Black);
+        assert_eq!(Some(1.0), format_contrast_ratio(&format));
+
+        let format = Format::new()
+            .set_font_color(Color::White)
+            .set_background_color(Color::Black);
+        assert_eq!(Some(21.0), format_contrast_ratio(&format));
+
+        let format = Format::new()
+            .set_font_color(Color::White)
+            .set_foreground_color(Color::Black);
+        assert_eq!(Some(21.0), format_contrast_ratio(&format));
+
+        // Theme colors and non-solid patterns aren't checked.
+        let format = Format::new()
+            .set_font_color(Color::Theme(1, 0))
+            .set_background_color(Color::White);
+        assert_eq!(None, format_contrast_ratio(&format));
+
+        let format = Format::new()
+            .set_pattern(FormatPattern::Gray125)
+            .set_background_color(Color::White);
+        assert_eq!(None, format_contrast_ratio(&format));
+    }
+
+    #[test]
+    fn test_accessibility_report() -> Result<(), XlsxError> {
+        let mut workbook = Workbook::new();
+        let low_contrast = Format::new()
+            .set_font_color(Color::Yellow)
+            .set_background_color(Color::White);
+        let high_contrast = Format::new()
+            .set_font_color(Color::Navy)
+            .set_background_color(Color::White);
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.merge_range(1, 1, 2, 3, "Title", &Format::new())?;
+        worksheet.write_with_format(4, 0, "Low", &low_contrast)?;
+        worksheet.write_with_format(5, 0, "Low", &low_contrast)?;
+        worksheet.write_with_format(6, 0, "High", &high_contrast)?;
+
+        let image = Image::new_from_buffer(include_bytes!("../../tests/input/images/red.png"))?;
+        worksheet.insert_image(8, 1, &image)?;
+        worksheet.insert_image(8, 5, &image.clone().set_alt_text("Red square"))?;
+        worksheet.insert_image(8, 9, &image.set_decorative(true))?;
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$A$1:$A$3");
+        worksheet.insert_chart(20, 1, &chart)?;
+
+        let issues = workbook.accessibility_report();
+        let summary: Vec<(AccessibilityIssueType, Option<&str>)> = issues
+            .iter()
+            .map(|issue| (issue.issue_type, issue.location.as_deref()))
+            .collect();
+
+        assert_eq!(
+            vec![
+                (AccessibilityIssueType::MissingTitle, None),
+                (AccessibilityIssueType::DefaultSheetName, None),
+                (AccessibilityIssueType::MissingAltText, Some("B9")),
+                (AccessibilityIssueType::MissingAltText, Some("B21")),
+                (AccessibilityIssueType::MergedCells, Some("B2:D3")),
+                (AccessibilityIssueType::LowContrast, Some("A5")),
+            ],
+            summary
+        );
+
+        assert_eq!(
+            "Sheet1!B9: Image doesn't have alternative text.",
+            issues[2].to_string()
+        );
+
+        // Fix the workbook level and sheet name issues.
+        let properties = DocProperties::new().set_title("Sales");
+        workbook.set_properties(&properties);
+
+        let worksheet = workbook.worksheet_from_index(0)?;
+        worksheet.set_name("Sales")?;
+
+        let issues = workbook.accessibility_report();
+        assert_eq!(4, issues.len());
+        assert_eq!(Some("Sales".to_string()), issues[0].worksheet_name);
+
+        Ok(())
+    }
+}
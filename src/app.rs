@@ -117,13 +117,16 @@ impl App {
 
     // Write the <DocSecurity> element.
     fn write_doc_security(&mut self) {
+        let doc_security = self.doc_security | self.properties.doc_security;
+
         self.writer
-            .xml_data_element_only("DocSecurity", &self.doc_security.to_string());
+            .xml_data_element_only("DocSecurity", &doc_security.to_string());
     }
 
     // Write the <ScaleCrop> element.
     fn write_scale_crop(&mut self) {
-        self.writer.xml_data_element_only("ScaleCrop", "false");
+        self.writer
+            .xml_data_element_only("ScaleCrop", &self.properties.scale_crop.to_string());
     }
 
     // Write the <HeadingPairs> element.
@@ -213,7 +216,8 @@ impl App {
 
     // Write the <SharedDoc> element.
     fn write_shared_doc(&mut self) {
-        self.writer.xml_data_element_only("SharedDoc", "false");
+        self.writer
+            .xml_data_element_only("SharedDoc", &self.properties.shared_doc.to_string());
     }
 
     // Write the <HyperlinkBase> element.
@@ -226,8 +230,10 @@ impl App {
 
     // Write the <HyperlinksChanged> element.
     fn write_hyperlinks_changed(&mut self) {
-        self.writer
-            .xml_data_element_only("HyperlinksChanged", "false");
+        self.writer.xml_data_element_only(
+            "HyperlinksChanged",
+            &self.properties.hyperlinks_changed.to_string(),
+        );
     }
 
     // Write the <AppVersion> element.
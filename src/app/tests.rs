@@ -9,6 +9,7 @@ mod app_tests {
 
     use crate::app::App;
     use crate::test_functions::xml_to_vec;
+    use crate::DocProperties;
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -162,4 +163,58 @@ mod app_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble4() {
+        let mut app = App::new();
+
+        app.properties = DocProperties::new()
+            .set_doc_security(8)
+            .set_scale_crop(true)
+            .set_shared_doc(true)
+            .set_hyperlinks_changed(true);
+        app.doc_security = 2;
+
+        app.add_heading_pair("Worksheets", 1);
+        app.add_part_name("Sheet1");
+
+        app.assemble_xml_file();
+
+        let got = app.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <Properties xmlns="http://schemas.openxmlformats.org/officeDocument/2006/extended-properties" xmlns:vt="http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes">
+                    <Application>Microsoft Excel</Application>
+                    <DocSecurity>10</DocSecurity>
+                    <ScaleCrop>true</ScaleCrop>
+                    <HeadingPairs>
+                        <vt:vector size="2" baseType="variant">
+                        <vt:variant>
+                            <vt:lpstr>Worksheets</vt:lpstr>
+                        </vt:variant>
+                        <vt:variant>
+                            <vt:i4>1</vt:i4>
+                        </vt:variant>
+                        </vt:vector>
+                    </HeadingPairs>
+                    <TitlesOfParts>
+                        <vt:vector size="1" baseType="lpstr">
+                        <vt:lpstr>Sheet1</vt:lpstr>
+                        </vt:vector>
+                    </TitlesOfParts>
+                    <Company>
+                    </Company>
+                    <LinksUpToDate>false</LinksUpToDate>
+                    <SharedDoc>true</SharedDoc>
+                    <HyperlinksChanged>true</HyperlinksChanged>
+                    <AppVersion>12.0000</AppVersion>
+                </Properties>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -0,0 +1,434 @@
+// cell_ref - A module for representing Excel cell and range references.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::fmt;
+use std::str::FromStr;
+
+use crate::worksheet::{COL_MAX, ROW_MAX};
+use crate::{utility, ColNum, RowNum, XlsxError};
+
+/// The `CellRef` struct represents a reference to a single worksheet cell.
+///
+/// `rust_xlsxwriter` generally uses zero indexed `(row, col)` numbers to refer
+/// to worksheet cells. However, it is sometimes more convenient to use Excel's
+/// `A1` notation, for example when the cell location comes from a
+/// configuration file or from user input. The `CellRef` struct can be created
+/// from either representation and converted to the other.
+///
+/// A `CellRef` can be used with worksheet methods that take an
+/// [`IntoCellRef`] parameter such as
+/// [`Worksheet::write_a1()`](crate::Worksheet::write_a1).
+///
+/// # Examples
+///
+/// The following example demonstrates creating cell and range references from
+/// strings and tuples.
+///
+/// ```
+/// # // This code is available in examples/doc_cellref_intro.rs
+/// #
+/// # use rust_xlsxwriter::{CellRef, RangeRef, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Create cell references from a string or a (row, col) tuple.
+///     let cell1 = CellRef::parse("B2")?;
+///     let cell2 = CellRef::from((2, 1));
+///
+///     assert_eq!(cell1.row(), 1);
+///     assert_eq!(cell2.to_string(), "B3");
+///
+///     worksheet.write_a1(cell1, "Hello")?;
+///     worksheet.write_a1(cell2, "World")?;
+///
+///     // Create a range reference and use it for a worksheet print area.
+///     let range = RangeRef::parse("A1:B3")?;
+///     worksheet.set_print_area_a1(range)?;
+///
+///     assert_eq!(range.to_sheet_range("Sheet1"), "Sheet1!$A$1:$B$3");
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct CellRef {
+    row: RowNum,
+    col: ColNum,
+}
+
+impl CellRef {
+    /// Create a new `CellRef` from zero indexed row and column numbers.
+    ///
+    /// The row and column numbers aren't validated when the `CellRef` is
+    /// created. They are checked against Excel's worksheet limits by the
+    /// methods that the reference is used with.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    pub fn new(row: RowNum, col: ColNum) -> CellRef {
+        CellRef { row, col }
+    }
+
+    /// Create a new `CellRef` from an `A1` or `$A$1` style cell reference.
+    ///
+    /// # Parameters
+    ///
+    /// - `cell`: An `A1` style cell reference string.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The string isn't a valid cell
+    ///   reference or it exceeds Excel's worksheet limits.
+    ///
+    pub fn parse(cell: &str) -> Result<CellRef, XlsxError> {
+        let (row, col) = utility::cell_to_row_col(cell)?;
+
+        Ok(CellRef { row, col })
+    }
+
+    /// Get the zero indexed row number of the cell reference.
+    pub fn row(&self) -> RowNum {
+        self.row
+    }
+
+    /// Get the zero indexed column number of the cell reference.
+    pub fn col(&self) -> ColNum {
+        self.col
+    }
+
+    /// Get the cell reference as an absolute `$A$1` style string.
+    pub fn to_absolute_string(&self) -> String {
+        utility::row_col_to_cell_absolute(self.row, self.col)
+    }
+}
+
+impl fmt::Display for CellRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", utility::row_col_to_cell(self.row, self.col))
+    }
+}
+
+impl FromStr for CellRef {
+    type Err = XlsxError;
+
+    fn from_str(cell: &str) -> Result<CellRef, XlsxError> {
+        CellRef::parse(cell)
+    }
+}
+
+impl TryFrom<&str> for CellRef {
+    type Error = XlsxError;
+
+    fn try_from(cell: &str) -> Result<CellRef, XlsxError> {
+        CellRef::parse(cell)
+    }
+}
+
+impl From<(RowNum, ColNum)> for CellRef {
+    fn from((row, col): (RowNum, ColNum)) -> CellRef {
+        CellRef::new(row, col)
+    }
+}
+
+/// The `RangeRef` struct represents a reference to a range of worksheet cells.
+///
+/// This is the range equivalent of [`CellRef`]. It can be created from
+/// zero indexed `(first_row, first_col, last_row, last_col)` numbers or from
+/// an `A1:B2` style range string.
+///
+/// A `RangeRef` can be used with worksheet methods that take an
+/// [`IntoRangeRef`] parameter such as
+/// [`Worksheet::add_table_a1()`](crate::Worksheet::add_table_a1). It can also
+/// be used, along with a worksheet name, to create a chart data range or to
+/// create the formula for a defined name via [`RangeRef::to_sheet_range()`].
+///
+/// See [`CellRef`] for an example.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct RangeRef {
+    first_row: RowNum,
+    first_col: ColNum,
+    last_row: RowNum,
+    last_col: ColNum,
+}
+
+impl RangeRef {
+    /// Create a new `RangeRef` from zero indexed row and column numbers.
+    ///
+    /// The row and column numbers aren't validated when the `RangeRef` is
+    /// created. They are checked against Excel's worksheet limits, and for a
+    /// first row/column that is greater than the last row/column, by the
+    /// methods that the range is used with.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    ///
+    pub fn new(
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> RangeRef {
+        RangeRef {
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+        }
+    }
+
+    /// Create a new `RangeRef` from an `A1:B2` or `$A$1:$B$2` style range
+    /// reference.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: An `A1:B2` style range reference string. A single cell
+    ///   reference like `A1` is also supported.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The string isn't a valid range
+    ///   reference or it exceeds Excel's worksheet limits.
+    ///
+    pub fn parse(range: &str) -> Result<RangeRef, XlsxError> {
+        let (first_row, first_col, last_row, last_col) = utility::cell_range_to_row_col(range)?;
+
+        Ok(RangeRef::new(first_row, first_col, last_row, last_col))
+    }
+
+    /// Get the zero indexed first row number of the range.
+    pub fn first_row(&self) -> RowNum {
+        self.first_row
+    }
+
+    /// Get the zero indexed first column number of the range.
+    pub fn first_col(&self) -> ColNum {
+        self.first_col
+    }
+
+    /// Get the zero indexed last row number of the range.
+    pub fn last_row(&self) -> RowNum {
+        self.last_row
+    }
+
+    /// Get the zero indexed last column number of the range.
+    pub fn last_col(&self) -> ColNum {
+        self.last_col
+    }
+
+    // Check that the range is within Excel's worksheet limits and that the
+    // first row/column isn't greater than the last row/column.
+    pub(crate) fn validate(&self) -> Result<(), XlsxError> {
+        if self.last_row >= ROW_MAX || self.last_col >= COL_MAX {
+            return Err(XlsxError::ParameterError(format!(
+                "Range '{self}' exceeds Excel's worksheet limits"
+            )));
+        }
+
+        if self.first_row > self.last_row || self.first_col > self.last_col {
+            return Err(XlsxError::ParameterError(format!(
+                "Range '{self}' has a first row/column greater than the last row/column"
+            )));
+        }
+
+        Ok(())
+    }
+
+    /// Get the range as an absolute `$A$1:$B$2` style string.
+    pub fn to_absolute_string(&self) -> String {
+        utility::cell_range_absolute(self.first_row, self.first_col, self.last_row, self.last_col)
+    }
+
+    /// Get the range as an absolute range string qualified by a worksheet
+    /// name, like `Sheet1!$A$1:$B$2`.
+    ///
+    /// The worksheet name is quoted if required. The resulting string can be
+    /// used in formulas or with
+    /// [`Workbook::define_name()`](crate::Workbook::define_name).
+    ///
+    /// # Parameters
+    ///
+    /// - `sheet_name`: The name of the worksheet that the range refers to.
+    ///
+    pub fn to_sheet_range(&self, sheet_name: &str) -> String {
+        utility::chart_range_abs(
+            sheet_name,
+            self.first_row,
+            self.first_col,
+            self.last_row,
+            self.last_col,
+        )
+    }
+}
+
+impl fmt::Display for RangeRef {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(
+            f,
+            "{}",
+            utility::cell_range(self.first_row, self.first_col, self.last_row, self.last_col)
+        )
+    }
+}
+
+impl FromStr for RangeRef {
+    type Err = XlsxError;
+
+    fn from_str(range: &str) -> Result<RangeRef, XlsxError> {
+        RangeRef::parse(range)
+    }
+}
+
+impl TryFrom<&str> for RangeRef {
+    type Error = XlsxError;
+
+    fn try_from(range: &str) -> Result<RangeRef, XlsxError> {
+        RangeRef::parse(range)
+    }
+}
+
+impl From<(RowNum, ColNum, RowNum, ColNum)> for RangeRef {
+    fn from(
+        (first_row, first_col, last_row, last_col): (RowNum, ColNum, RowNum, ColNum),
+    ) -> RangeRef {
+        RangeRef::new(first_row, first_col, last_row, last_col)
+    }
+}
+
+impl From<CellRef> for RangeRef {
+    fn from(cell: CellRef) -> RangeRef {
+        RangeRef::new(cell.row, cell.col, cell.row, cell.col)
+    }
+}
+
+/// Trait to map types into a [`CellRef`].
+///
+/// The `IntoCellRef` trait is used by worksheet methods that accept a cell
+/// reference in several forms: a [`CellRef`], a zero indexed `(row, col)`
+/// tuple or an `A1` style string.
+///
+pub trait IntoCellRef {
+    /// Trait method to convert a type into a [`CellRef`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The value isn't a valid cell
+    ///   reference.
+    ///
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError>;
+}
+
+impl IntoCellRef for CellRef {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        Ok(*self)
+    }
+}
+
+impl IntoCellRef for &CellRef {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        Ok(**self)
+    }
+}
+
+impl IntoCellRef for (RowNum, ColNum) {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        Ok(CellRef::from(*self))
+    }
+}
+
+impl IntoCellRef for &str {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        CellRef::parse(self)
+    }
+}
+
+impl IntoCellRef for &String {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        CellRef::parse(self)
+    }
+}
+
+impl IntoCellRef for String {
+    fn to_cell_ref(&self) -> Result<CellRef, XlsxError> {
+        CellRef::parse(self)
+    }
+}
+
+/// Trait to map types into a [`RangeRef`].
+///
+/// The `IntoRangeRef` trait is used by worksheet methods that accept a range
+/// reference in several forms: a [`RangeRef`], a [`CellRef`], a zero indexed
+/// `(first_row, first_col, last_row, last_col)` tuple or an `A1:B2` style
+/// string.
+///
+pub trait IntoRangeRef {
+    /// Trait method to convert a type into a [`RangeRef`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The value isn't a valid range
+    ///   reference.
+    ///
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError>;
+}
+
+impl IntoRangeRef for RangeRef {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        Ok(*self)
+    }
+}
+
+impl IntoRangeRef for &RangeRef {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        Ok(**self)
+    }
+}
+
+impl IntoRangeRef for CellRef {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        Ok(RangeRef::from(*self))
+    }
+}
+
+impl IntoRangeRef for (RowNum, ColNum, RowNum, ColNum) {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        Ok(RangeRef::from(*self))
+    }
+}
+
+impl IntoRangeRef for &str {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        RangeRef::parse(self)
+    }
+}
+
+impl IntoRangeRef for &String {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        RangeRef::parse(self)
+    }
+}
+
+impl IntoRangeRef for String {
+    fn to_range_ref(&self) -> Result<RangeRef, XlsxError> {
+        RangeRef::parse(self)
+    }
+}
@@ -0,0 +1,61 @@
+// CellRef and RangeRef unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod cell_ref_tests {
+
+    use crate::{CellRef, IntoCellRef, IntoRangeRef, RangeRef, XlsxError};
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_cell_ref_conversions() {
+        let cell = CellRef::new(1, 2);
+
+        assert_eq!(cell, CellRef::parse("C2").unwrap());
+        assert_eq!(cell, CellRef::parse("$C$2").unwrap());
+        assert_eq!(cell, "C2".parse::<CellRef>().unwrap());
+        assert_eq!(cell, CellRef::try_from("c2").unwrap());
+        assert_eq!(cell, CellRef::from((1, 2)));
+
+        assert_eq!(cell.to_string(), "C2");
+        assert_eq!(cell.to_absolute_string(), "$C$2");
+
+        assert_eq!(cell, "C2".to_cell_ref().unwrap());
+        assert_eq!(cell, String::from("C2").to_cell_ref().unwrap());
+        assert_eq!(cell, (1, 2).to_cell_ref().unwrap());
+
+        let result = "C".to_cell_ref();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn test_range_ref_conversions() {
+        let range = RangeRef::new(0, 0, 9, 1);
+
+        assert_eq!(range, RangeRef::parse("A1:B10").unwrap());
+        assert_eq!(range, RangeRef::parse("$A$1:$B$10").unwrap());
+        assert_eq!(range, "A1:B10".parse::<RangeRef>().unwrap());
+        assert_eq!(range, RangeRef::from((0, 0, 9, 1)));
+
+        assert_eq!(range.to_string(), "A1:B10");
+        assert_eq!(range.to_absolute_string(), "$A$1:$B$10");
+        assert_eq!(range.to_sheet_range("Sheet1"), "Sheet1!$A$1:$B$10");
+        assert_eq!(
+            range.to_sheet_range("Sales Data"),
+            "'Sales Data'!$A$1:$B$10"
+        );
+
+        assert_eq!(range, "A1:B10".to_range_ref().unwrap());
+        assert_eq!(range, (0, 0, 9, 1).to_range_ref().unwrap());
+
+        let range = RangeRef::from(CellRef::new(2, 3));
+        assert_eq!(range.to_string(), "D3");
+        assert_eq!(range, CellRef::new(2, 3).to_range_ref().unwrap());
+
+        let result = "A1:".to_range_ref();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+}
@@ -263,13 +263,16 @@
 //! - Radar
 //! - Stock
 //! - Scatter
+//! - Treemap
+//! - Sunburst
 //!
 //! See [`ChartType`] for the full list and examples.
 //!
-//! Support for newer Excel chart types such as Treemap, Sunburst, Box and
-//! Whisker, Statistical Histogram, Waterfall, Funnel, and Maps is not currently
-//! planned since the underlying structure is substantially different from the
-//! original chart types above.
+//! The Treemap and Sunburst chart types are newer Excel 2016 "chartex" style
+//! charts that display hierarchical data. They support a subset of the chart
+//! properties, see [`ChartType::Treemap`] for details. Support for the other
+//! newer Excel chart types such as Box and Whisker, Statistical Histogram,
+//! Waterfall, Funnel, and Maps is not currently planned.
 //!
 //!
 //!
@@ -759,8 +762,8 @@ use crate::drawing::{DrawingObject, DrawingType};
 use crate::utility::{self, ToXmlBoolean};
 
 use crate::{
-    xmlwriter::XMLWriter, ColNum, Color, IntoExcelDateTime, ObjectMovement, RowNum, XlsxError,
-    COL_MAX, ROW_MAX,
+    xmlwriter::XMLWriter, ColNum, Color, IntoExcelDateTime, ObjectMovement, RangeRef, RowNum,
+    XlsxError, COL_MAX, ROW_MAX,
 };
 
 #[derive(Clone)]
@@ -826,6 +829,7 @@ pub struct Chart {
     pub(crate) drawing_type: DrawingType,
     pub(crate) series: Vec<ChartSeries>,
     pub(crate) default_label_position: ChartDataLabelPosition,
+    series_templates: Vec<ChartSeries>,
 
     height: f64,
     width: f64,
@@ -985,6 +989,7 @@ impl Chart {
             drop_lines_format: ChartFormat::default(),
             table: None,
             combined_chart: None,
+            series_templates: vec![],
             series_index: 0,
             has_secondary_axis: false,
             has_crosses: true,
@@ -1024,6 +1029,8 @@ impl Chart {
             | ChartType::ScatterSmoothWithMarkers => Self::initialize_scatter_chart(chart),
 
             ChartType::Stock => Self::initialize_stock_chart(chart),
+
+            ChartType::Treemap | ChartType::Sunburst => Self::initialize_chartex_chart(chart),
         }
     }
 
@@ -1120,12 +1127,133 @@ impl Chart {
     /// This is a syntactic shortcut for `Chart::new(ChartType::Stock)` to
     /// create a default Stock chart.
     ///
-    /// See [`Chart::new()`] for further details.
+    /// See [`Chart::new()`] for further details and [`StockChartBuilder`] for
+    /// a way to create a Stock chart with the series already configured.
     ///
     pub fn new_stock() -> Chart {
         Self::new(ChartType::Stock)
     }
 
+    /// Create a new Treemap `Chart`.
+    ///
+    /// This is a syntactic shortcut for `Chart::new(ChartType::Treemap)` to
+    /// create a default Treemap chart.
+    ///
+    /// See [`Chart::new()`] for further details.
+    ///
+    pub fn new_treemap() -> Chart {
+        Self::new(ChartType::Treemap)
+    }
+
+    /// Create a new Sunburst `Chart`.
+    ///
+    /// This is a syntactic shortcut for `Chart::new(ChartType::Sunburst)` to
+    /// create a default Sunburst chart.
+    ///
+    /// See [`Chart::new()`] for further details.
+    ///
+    pub fn new_sunburst() -> Chart {
+        Self::new(ChartType::Sunburst)
+    }
+
+    /// Create a new chart using an existing chart as a template.
+    ///
+    /// The `from_template()` constructor creates a new chart with the same
+    /// type, title, axes, legend, size and other chart level formatting as the
+    /// template chart but without any data series. This makes it easy to
+    /// create a number of identically styled charts with different data
+    /// without repeating the configuration code.
+    ///
+    /// The formatting of the template's data series, such as the fill, line,
+    /// markers, data labels, trendlines and secondary axis setting, is kept
+    /// as a template for the series of the new chart. Each series added with
+    /// [`Chart::add_series()`] starts with the formatting of the template
+    /// series at the same position, without its data ranges, so only the
+    /// ranges need to be set. Series added after the last template series,
+    /// or added with [`Chart::push_series()`], don't use a template.
+    ///
+    /// Any chart combined with the template via [`Chart::combine()`] isn't
+    /// copied. It can be created from its own template chart and combined with
+    /// the new chart.
+    ///
+    /// Note, `Chart` also implements `Clone` for cases where a full copy,
+    /// including the data series, is required.
+    ///
+    /// # Parameters
+    ///
+    /// - `template`: The [`Chart`] to use as a template.
+    ///
+    /// # Examples
+    ///
+    /// An example of creating several charts with the same formatting from a
+    /// template chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_from_template.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartFormat, ChartSolidFill, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the charts.
+    /// #     let data = [[10, 40, 50, 20], [30, 60, 70, 50], [20, 30, 40, 30]];
+    /// #     for (col_num, col_data) in data.iter().enumerate() {
+    /// #         worksheet.write_column(0, col_num as u16, *col_data)?;
+    /// #     }
+    /// #
+    ///     // Create a template chart with the common formatting.
+    ///     let mut template = Chart::new(ChartType::Column);
+    ///     template.set_width(320).set_height(240);
+    ///     template.legend().set_hidden();
+    ///     template.y_axis().set_max(80);
+    ///
+    ///     // Add a template series with the common series formatting.
+    ///     template
+    ///         .add_series()
+    ///         .set_format(ChartFormat::new().set_solid_fill(ChartSolidFill::new().set_color("#4F81BD")));
+    ///
+    ///     // Create a chart for each column of data. Only the series data range
+    ///     // needs to be set.
+    ///     for col_num in 0..3 {
+    ///         let mut chart = Chart::from_template(&template);
+    ///
+    ///         chart
+    ///             .add_series()
+    ///             .set_values(("Sheet1", 0, col_num, 3, col_num));
+    ///
+    ///         chart.title().set_name(&format!("Chart {}", col_num + 1));
+    ///
+    ///         worksheet.insert_chart(5, col_num * 5, &chart)?;
+    ///     }
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn from_template(template: &Chart) -> Chart {
+        let mut chart = template.clone();
+
+        // Keep the formatting of the template series for the new series.
+        if !template.series.is_empty() {
+            chart.series_templates = template
+                .series
+                .iter()
+                .map(ChartSeries::to_template)
+                .collect();
+        }
+
+        chart.series.clear();
+        chart.combined_chart = None;
+        chart.has_secondary_axis = false;
+
+        chart
+    }
+
     /// Create and add a new chart series to a chart.
     ///
     /// Create and add a new chart series to a chart. The chart series
@@ -1177,6 +1305,13 @@ impl Chart {
     /// <img src="https://rustxlsxwriter.github.io/images/chart_simple.png">
     ///
     pub fn add_series(&mut self) -> &mut ChartSeries {
+        // Use the formatting of the equivalent series in a template chart, if
+        // the chart was created with Chart::from_template().
+        if let Some(series) = self.series_templates.get(self.series.len()) {
+            self.series.push(series.clone());
+            return self.series.last_mut().unwrap();
+        }
+
         let mut series = ChartSeries::new();
 
         // The default Scatter chart has a hidden line with a standard width.
@@ -2703,9 +2838,44 @@ impl Chart {
             ));
         }
 
+        // Check the restrictions on the newer chartex style charts.
+        if self.is_chartex() {
+            if self.series.len() > 1 {
+                return Err(XlsxError::ChartError(
+                    "Treemap and Sunburst charts only support one series".to_string(),
+                ));
+            }
+
+            if self.combined_chart.is_some() {
+                return Err(XlsxError::ChartError(
+                    "Treemap and Sunburst charts cannot be combined with other charts".to_string(),
+                ));
+            }
+
+            if self.is_chartsheet {
+                return Err(XlsxError::ChartError(
+                    "Treemap and Sunburst charts cannot be added to a chartsheet".to_string(),
+                ));
+            }
+        }
+
+        if let Some(combined_chart) = &self.combined_chart {
+            if combined_chart.is_chartex() {
+                return Err(XlsxError::ChartError(
+                    "Treemap and Sunburst charts cannot be combined with other charts".to_string(),
+                ));
+            }
+        }
+
         for series in &self.series {
+            // Ranges from worksheet tables are resolved, and checked, when the
+            // workbook is saved.
+            let has_values = series.value_range.has_data() || series.value_table.is_some();
+            let has_categories =
+                series.category_range.has_data() || series.category_table.is_some();
+
             // Check for a series without a values range.
-            if !series.value_range.has_data() {
+            if !has_values {
                 return Err(XlsxError::ChartError(
                     "Chart series must contain a 'values' range".to_string(),
                 ));
@@ -2713,20 +2883,36 @@ impl Chart {
 
             // Check for scatter charts without category ranges. It is optional
             // for all other types.
-            if self.chart_group_type == ChartType::Scatter && !series.category_range.has_data() {
+            if self.chart_group_type == ChartType::Scatter && !has_categories {
                 return Err(XlsxError::ChartError(
                     "Scatter style charts must contain a 'categories' range".to_string(),
                 ));
             }
 
+            // Check for hierarchical charts without category ranges.
+            if self.is_chartex() && !has_categories {
+                return Err(XlsxError::ChartError(
+                    "Treemap and Sunburst charts must contain a 'categories' range".to_string(),
+                ));
+            }
+
             // Validate the series values range.
-            series.value_range.validate()?;
+            if series.value_table.is_none() {
+                series.value_range.validate()?;
+            }
 
             // Validate the series category range.
             if series.category_range.has_data() {
                 series.category_range.validate()?;
             }
 
+            // Validate the data label value range.
+            if let Some(data_label) = &series.data_label {
+                if data_label.value_range.has_data() {
+                    data_label.value_range.validate()?;
+                }
+            }
+
             // Validate Polynomial trendline range.
             if let ChartTrendlineType::Polynomial(order) = series.trendline.trend_type {
                 if !(2..6).contains(&order) {
@@ -3139,6 +3325,14 @@ impl Chart {
         self
     }
 
+    // Initialize the newer chartex style charts such as Treemap and Sunburst.
+    // These don't have axes and they are linked to the drawing differently.
+    fn initialize_chartex_chart(mut self) -> Chart {
+        self.drawing_type = DrawingType::ChartEx;
+
+        self
+    }
+
     // Write the <c:areaChart> element for Column charts.
     fn write_area_chart(&mut self, primary_axis: bool) {
         let series = self.get_series(primary_axis);
@@ -3403,6 +3597,11 @@ impl Chart {
 
     // Assemble and write the XML file.
     pub(crate) fn assemble_xml_file(&mut self) {
+        if self.is_chartex() {
+            self.assemble_chartex_xml_file();
+            return;
+        }
+
         self.writer.xml_declaration();
 
         // Write the c:chartSpace element.
@@ -3436,6 +3635,300 @@ impl Chart {
         self.writer.xml_end_tag("c:chartSpace");
     }
 
+    // Check if the chart is one of the newer chartex style charts, such as
+    // Treemap, that are written to a chartEx.xml file.
+    pub(crate) fn is_chartex(&self) -> bool {
+        matches!(self.chart_type, ChartType::Treemap | ChartType::Sunburst)
+    }
+
+    // -----------------------------------------------------------------------
+    // Chartex XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file for the newer chartex style charts. These
+    // use a different structure and namespace to the original chart types.
+    fn assemble_chartex_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the cx:chartSpace element.
+        self.write_cx_chart_space();
+
+        // Write the cx:chartData element.
+        self.write_cx_chart_data();
+
+        // Write the cx:chart element.
+        self.write_cx_chart();
+
+        // Close the cx:chartSpace tag.
+        self.writer.xml_end_tag("cx:chartSpace");
+    }
+
+    // Write the <cx:chartSpace> element.
+    fn write_cx_chart_space(&mut self) {
+        let attributes = [
+            (
+                "xmlns:a",
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+            ),
+            (
+                "xmlns:cx",
+                "http://schemas.microsoft.com/office/drawing/2014/chartex",
+            ),
+        ];
+
+        self.writer.xml_start_tag("cx:chartSpace", &attributes);
+    }
+
+    // Write the <cx:chartData> element.
+    fn write_cx_chart_data(&mut self) {
+        self.writer.xml_start_tag_only("cx:chartData");
+
+        for (index, series) in self.series.clone().iter().enumerate() {
+            let attributes = [("id", index.to_string())];
+            self.writer.xml_start_tag("cx:data", &attributes);
+
+            // Write the cx:strDim element.
+            self.write_cx_str_dim(&series.category_range);
+
+            // Write the cx:numDim element.
+            self.write_cx_num_dim(&series.value_range);
+
+            self.writer.xml_end_tag("cx:data");
+        }
+
+        self.writer.xml_end_tag("cx:chartData");
+    }
+
+    // Write the <cx:strDim> element. Each column of the category range is a
+    // level in the hierarchy and the levels are written from the innermost
+    // level, in the last column, outwards.
+    fn write_cx_str_dim(&mut self, range: &ChartRange) {
+        let attributes = [("type", "cat")];
+        self.writer.xml_start_tag("cx:strDim", &attributes);
+
+        // Write the cx:f element.
+        self.writer
+            .xml_data_element_only("cx:f", &range.formula_abs());
+
+        let (num_rows, num_cols) = range.number_of_range_points();
+
+        for col in (0..num_cols).rev() {
+            let attributes = [("ptCount", num_rows.to_string())];
+            self.writer.xml_start_tag("cx:lvl", &attributes);
+
+            for row in 0..num_rows {
+                if let Some(value) = range.cache.data.get(row * num_cols + col) {
+                    // Write the cx:pt element.
+                    self.write_cx_pt(row, value);
+                }
+            }
+
+            self.writer.xml_end_tag("cx:lvl");
+        }
+
+        self.writer.xml_end_tag("cx:strDim");
+    }
+
+    // Write the <cx:numDim> element.
+    fn write_cx_num_dim(&mut self, range: &ChartRange) {
+        let attributes = [("type", "size")];
+        self.writer.xml_start_tag("cx:numDim", &attributes);
+
+        // Write the cx:f element.
+        self.writer
+            .xml_data_element_only("cx:f", &range.formula_abs());
+
+        let attributes = [
+            ("ptCount", range.number_of_points().to_string()),
+            ("formatCode", self.default_num_format.clone()),
+        ];
+        self.writer.xml_start_tag("cx:lvl", &attributes);
+
+        for (index, value) in range.cache.data.iter().enumerate() {
+            // Write the cx:pt element.
+            self.write_cx_pt(index, value);
+        }
+
+        self.writer.xml_end_tag("cx:lvl");
+        self.writer.xml_end_tag("cx:numDim");
+    }
+
+    // Write the <cx:pt> element. Empty cells are omitted.
+    fn write_cx_pt(&mut self, index: usize, value: &str) {
+        if value.is_empty() {
+            return;
+        }
+
+        let attributes = [("idx", index.to_string())];
+
+        self.writer.xml_data_element("cx:pt", value, &attributes);
+    }
+
+    // Write the <cx:chart> element.
+    fn write_cx_chart(&mut self) {
+        self.writer.xml_start_tag_only("cx:chart");
+
+        // Write the cx:title element.
+        if !self.title.hidden && (!self.title.name.is_empty() || self.title.range.has_data()) {
+            self.write_cx_title(&self.title.clone());
+        }
+
+        // Write the cx:plotArea element.
+        self.write_cx_plot_area();
+
+        // Write the cx:legend element.
+        if !self.legend.hidden {
+            self.write_cx_legend();
+        }
+
+        self.writer.xml_end_tag("cx:chart");
+    }
+
+    // Write the <cx:title> element.
+    fn write_cx_title(&mut self, title: &ChartTitle) {
+        let attributes = [
+            ("pos", "t".to_string()),
+            ("align", "ctr".to_string()),
+            ("overlay", title.has_overlay.to_xml_bool()),
+        ];
+
+        self.writer.xml_start_tag("cx:title", &attributes);
+
+        // Write the cx:tx element.
+        self.write_cx_tx(title);
+
+        self.writer.xml_end_tag("cx:title");
+    }
+
+    // Write the <cx:tx> element for a title or series name.
+    fn write_cx_tx(&mut self, title: &ChartTitle) {
+        self.writer.xml_start_tag_only("cx:tx");
+        self.writer.xml_start_tag_only("cx:txData");
+
+        if title.name.is_empty() {
+            self.writer
+                .xml_data_element_only("cx:f", &title.range.formula_abs());
+
+            let value = title.range.cache.data.first().cloned().unwrap_or_default();
+            self.writer.xml_data_element_only("cx:v", &value);
+        } else {
+            self.writer.xml_data_element_only("cx:v", &title.name);
+        }
+
+        self.writer.xml_end_tag("cx:txData");
+        self.writer.xml_end_tag("cx:tx");
+    }
+
+    // Write the <cx:plotArea> element.
+    fn write_cx_plot_area(&mut self) {
+        self.writer.xml_start_tag_only("cx:plotArea");
+        self.writer.xml_start_tag_only("cx:plotAreaRegion");
+
+        for (index, series) in self.series.clone().iter().enumerate() {
+            // Write the cx:series element.
+            self.write_cx_series(index, series);
+        }
+
+        self.writer.xml_end_tag("cx:plotAreaRegion");
+        self.writer.xml_end_tag("cx:plotArea");
+    }
+
+    // Write the <cx:series> element.
+    fn write_cx_series(&mut self, index: usize, series: &ChartSeries) {
+        let layout_id = match self.chart_type {
+            ChartType::Sunburst => "sunburst",
+            _ => "treemap",
+        };
+
+        let attributes = [("layoutId", layout_id)];
+        self.writer.xml_start_tag("cx:series", &attributes);
+
+        // Write the cx:tx element.
+        if !series.title.name.is_empty() || series.title.range.has_data() {
+            self.write_cx_tx(&series.title);
+        }
+
+        // Write the cx:dataLabels element. Excel shows the category names by
+        // default for these chart types.
+        match &series.data_label {
+            Some(data_label) => {
+                if !data_label.is_hidden {
+                    self.write_cx_data_labels(
+                        data_label.show_series_name,
+                        data_label.show_category_name,
+                        data_label.show_value,
+                    );
+                }
+            }
+            None => self.write_cx_data_labels(false, true, false),
+        }
+
+        // Write the cx:dataId element.
+        let attributes = [("val", index.to_string())];
+        self.writer.xml_empty_tag("cx:dataId", &attributes);
+
+        // Write the cx:layoutPr element.
+        if self.chart_type == ChartType::Treemap {
+            self.writer.xml_start_tag_only("cx:layoutPr");
+
+            let attributes = [("val", "overlapping")];
+            self.writer
+                .xml_empty_tag("cx:parentLabelLayout", &attributes);
+
+            self.writer.xml_end_tag("cx:layoutPr");
+        }
+
+        self.writer.xml_end_tag("cx:series");
+    }
+
+    // Write the <cx:dataLabels> element.
+    fn write_cx_data_labels(
+        &mut self,
+        show_series_name: bool,
+        show_category_name: bool,
+        show_value: bool,
+    ) {
+        let position = match self.chart_type {
+            ChartType::Sunburst => "ctr",
+            _ => "inEnd",
+        };
+
+        let attributes = [("pos", position)];
+        self.writer.xml_start_tag("cx:dataLabels", &attributes);
+
+        let attributes = [
+            ("seriesName", show_series_name.to_xml_bool()),
+            ("categoryName", show_category_name.to_xml_bool()),
+            ("value", show_value.to_xml_bool()),
+        ];
+        self.writer.xml_empty_tag("cx:visibility", &attributes);
+
+        self.writer.xml_end_tag("cx:dataLabels");
+    }
+
+    // Write the <cx:legend> element.
+    fn write_cx_legend(&mut self) {
+        let (position, align) = match self.legend.position {
+            ChartLegendPosition::Left => ("l", "ctr"),
+            ChartLegendPosition::Top => ("t", "ctr"),
+            ChartLegendPosition::Bottom => ("b", "ctr"),
+            ChartLegendPosition::Right => ("r", "ctr"),
+            ChartLegendPosition::TopRight => ("r", "min"),
+        };
+
+        let attributes = [
+            ("pos", position.to_string()),
+            ("align", align.to_string()),
+            ("overlay", self.legend.has_overlay.to_xml_bool()),
+        ];
+
+        self.writer.xml_empty_tag("cx:legend", &attributes);
+    }
+
     // Write the <c:chartSpace> element.
     fn write_chart_space(&mut self) {
         let attributes = [
@@ -3678,6 +4171,9 @@ impl Chart {
                 self.write_stock_chart(true);
                 self.write_stock_chart(false);
             }
+
+            // These are written separately as chartex charts.
+            ChartType::Treemap | ChartType::Sunburst => {}
         }
     }
 
@@ -3855,11 +4351,6 @@ impl Chart {
             // Write the c:val element.
             self.write_val(&series.value_range);
 
-            if !series.inverted_color.is_auto_or_default() {
-                // Write the c:extLst element for the inverted fill color.
-                self.write_extension_list(series.inverted_color);
-            }
-
             // Write the c:smooth element.
             if self.chart_group_type == ChartType::Line {
                 if let Some(smooth) = series.smooth {
@@ -3869,6 +4360,9 @@ impl Chart {
                 }
             }
 
+            // Write the c:extLst element.
+            self.write_extension_list(series);
+
             self.series_index += 1;
 
             self.writer.xml_end_tag("c:ser");
@@ -3952,6 +4446,9 @@ impl Chart {
                 }
             }
 
+            // Write the c:extLst element.
+            self.write_extension_list(series);
+
             self.series_index += 1;
 
             self.writer.xml_end_tag("c:ser");
@@ -4010,8 +4507,35 @@ impl Chart {
         self.writer.xml_empty_tag("c:invertIfNegative", &attributes);
     }
 
-    // Write the <c:extLst> element for inverted fill colors.
-    fn write_extension_list(&mut self, color: Color) {
+    // Write the <c:extLst> element for inverted fill colors and data label
+    // ranges.
+    fn write_extension_list(&mut self, series: &ChartSeries) {
+        let has_inverted_color = !series.inverted_color.is_auto_or_default();
+        let label_range = series
+            .data_label
+            .as_ref()
+            .map(|data_label| &data_label.value_range)
+            .filter(|range| range.has_data());
+
+        if !has_inverted_color && label_range.is_none() {
+            return;
+        }
+
+        self.writer.xml_start_tag_only("c:extLst");
+
+        if has_inverted_color {
+            self.write_invert_solid_fill_extension(series.inverted_color);
+        }
+
+        if let Some(range) = label_range {
+            self.write_data_labels_range_extension(range);
+        }
+
+        self.writer.xml_end_tag("c:extLst");
+    }
+
+    // Write the <c:ext> element for inverted fill colors.
+    fn write_invert_solid_fill_extension(&mut self, color: Color) {
         let attributes1 = [
             ("uri", "{6F2FDCE9-48DA-4B69-8628-5D25D57E5C99}"),
             (
@@ -4024,7 +4548,6 @@ impl Chart {
             "http://schemas.microsoft.com/office/drawing/2007/8/2/chart",
         )];
 
-        self.writer.xml_start_tag_only("c:extLst");
         self.writer.xml_start_tag("c:ext", &attributes1);
         self.writer.xml_start_tag_only("c14:invertSolidFillFmt");
         self.writer.xml_start_tag("c14:spPr", &attributes2);
@@ -4034,7 +4557,42 @@ impl Chart {
         self.writer.xml_end_tag("c14:spPr");
         self.writer.xml_end_tag("c14:invertSolidFillFmt");
         self.writer.xml_end_tag("c:ext");
-        self.writer.xml_end_tag("c:extLst");
+    }
+
+    // Write the <c:ext> element for data labels taken from a worksheet range.
+    fn write_data_labels_range_extension(&mut self, range: &ChartRange) {
+        let attributes = [
+            ("uri", "{02D57815-91ED-43cb-92C2-25804820EDAC}"),
+            (
+                "xmlns:c15",
+                "http://schemas.microsoft.com/office/drawing/2012/chart",
+            ),
+        ];
+
+        self.writer.xml_start_tag("c:ext", &attributes);
+        self.writer.xml_start_tag_only("c15:datalabelsRange");
+
+        // Write the c15:f element.
+        self.writer
+            .xml_data_element_only("c15:f", &range.formula_abs());
+
+        // Write the c15:dlblRangeCache element.
+        if range.cache.has_data() {
+            self.writer.xml_start_tag_only("c15:dlblRangeCache");
+
+            // Write the c:ptCount element.
+            self.write_pt_count(range.cache.data.len());
+
+            // Write the c:pt elements.
+            for (index, value) in range.cache.data.iter().enumerate() {
+                self.write_pt(index, value);
+            }
+
+            self.writer.xml_end_tag("c15:dlblRangeCache");
+        }
+
+        self.writer.xml_end_tag("c15:datalabelsRange");
+        self.writer.xml_end_tag("c:ext");
     }
 
     // Write the <c:cat> element.
@@ -5136,7 +5694,8 @@ impl Chart {
         if data_label.show_value
             || (!data_label.is_custom
                 && !data_label.show_category_name
-                && !data_label.show_percentage)
+                && !data_label.show_percentage
+                && !data_label.value_range.has_data())
         {
             // Write the c:showVal element.
             self.write_show_val();
@@ -5162,6 +5721,9 @@ impl Chart {
             self.write_separator(data_label.separator);
         }
 
+        let show_value_range = !data_label.is_custom && data_label.value_range.has_data();
+        let mut show_leader_lines_2015 = false;
+
         if data_label.show_leader_lines {
             match self.chart_group_type {
                 // Write the c:showLeaderLines element.
@@ -5169,10 +5731,15 @@ impl Chart {
                     self.write_show_leader_lines_2007();
                 }
                 _ => {
-                    self.write_show_leader_lines_2015();
+                    show_leader_lines_2015 = true;
                 }
             }
         }
+
+        if show_value_range || show_leader_lines_2015 {
+            // Write the c:extLst element for the Excel 2013+ label options.
+            self.write_data_label_extension_list(show_value_range, show_leader_lines_2015);
+        }
     }
 
     // Write the <c:trendline> element.
@@ -5378,13 +5945,45 @@ impl Chart {
 
     // Write the custom error sub-elements
     fn write_custom_error_bar_values(&mut self, error_bars: &ChartErrorBars) {
-        self.writer.xml_start_tag_only("c:plus");
-        self.write_cache_ref(&error_bars.plus_range, true);
-        self.writer.xml_end_tag("c:plus");
+        if error_bars.plus_range.has_data() || error_bars.minus_range.has_data() {
+            self.writer.xml_start_tag_only("c:plus");
+            self.write_cache_ref(&error_bars.plus_range, true);
+            self.writer.xml_end_tag("c:plus");
+
+            self.writer.xml_start_tag_only("c:minus");
+            self.write_cache_ref(&error_bars.minus_range, true);
+            self.writer.xml_end_tag("c:minus");
+        } else {
+            if !error_bars.plus_values.is_empty() {
+                self.writer.xml_start_tag_only("c:plus");
+                self.write_num_lit(&error_bars.plus_values);
+                self.writer.xml_end_tag("c:plus");
+            }
+
+            if !error_bars.minus_values.is_empty() {
+                self.writer.xml_start_tag_only("c:minus");
+                self.write_num_lit(&error_bars.minus_values);
+                self.writer.xml_end_tag("c:minus");
+            }
+        }
+    }
+
+    // Write the <c:numLit> element.
+    fn write_num_lit(&mut self, values: &[f64]) {
+        self.writer.xml_start_tag_only("c:numLit");
+
+        // Write the c:formatCode element.
+        self.write_format_code("General");
+
+        // Write the c:ptCount element.
+        self.write_pt_count(values.len());
 
-        self.writer.xml_start_tag_only("c:minus");
-        self.write_cache_ref(&error_bars.minus_range, true);
-        self.writer.xml_end_tag("c:minus");
+        // Write the c:pt elements.
+        for (index, value) in values.iter().enumerate() {
+            self.write_pt(index, &value.to_string());
+        }
+
+        self.writer.xml_end_tag("c:numLit");
     }
 
     // Write the <c:upDownBars> element.
@@ -5564,9 +6163,10 @@ impl Chart {
         self.writer.xml_empty_tag("c:showLeaderLines", &attributes);
     }
 
-    // Write the <c:showLeaderLines> element for Excel 2015+ (mainly for charts
+    // Write the <c:extLst> element for the Excel 2015+ data label options:
+    // <c15:showDataLabelsRange> and <c15:showLeaderLines> (mainly for charts
     // that aren't Pie or Doughnut).
-    fn write_show_leader_lines_2015(&mut self) {
+    fn write_data_label_extension_list(&mut self, show_value_range: bool, show_leader_lines: bool) {
         let attributes = [
             ("uri", "{CE6537A1-D6FC-4f65-9D91-7224C49458BB}"),
             (
@@ -5578,8 +6178,16 @@ impl Chart {
         self.writer.xml_start_tag_only("c:extLst");
         self.writer.xml_start_tag("c:ext", &attributes);
 
-        self.writer
-            .xml_empty_tag("c15:showLeaderLines", &[("val", "1")]);
+        if show_value_range {
+            self.writer
+                .xml_empty_tag("c15:showDataLabelsRange", &[("val", "1")]);
+        }
+
+        if show_leader_lines {
+            self.writer
+                .xml_empty_tag("c15:showLeaderLines", &[("val", "1")]);
+        }
+
         self.writer.xml_end_tag("c:ext");
         self.writer.xml_end_tag("c:extLst");
     }
@@ -6344,6 +6952,11 @@ impl Chart {
 
     // Write the <a:p> element.
     fn write_a_p_rich(&mut self, title: &ChartTitle) {
+        if !title.rich_name.is_empty() {
+            self.write_a_p_rich_segments(title);
+            return;
+        }
+
         self.writer.xml_start_tag_only("a:p");
 
         if !title.ignore_rich_para {
@@ -6373,6 +6986,54 @@ impl Chart {
         self.writer.xml_end_tag("a:pPr");
     }
 
+    // Write the <a:p> elements for a title with multiple fonts. Each newline
+    // in the text segments starts a new paragraph.
+    fn write_a_p_rich_segments(&mut self, title: &ChartTitle) {
+        let mut paragraphs: Vec<Vec<(&ChartFont, &str)>> = vec![vec![]];
+
+        for (font, text) in &title.rich_name {
+            for (index, line) in text.split('\n').enumerate() {
+                if index > 0 {
+                    paragraphs.push(vec![]);
+                }
+
+                if !line.is_empty() {
+                    if let Some(paragraph) = paragraphs.last_mut() {
+                        paragraph.push((font, line));
+                    }
+                }
+            }
+        }
+
+        // Ignore a trailing empty paragraph from a final newline.
+        if paragraphs.len() > 1 && paragraphs.last().is_some_and(Vec::is_empty) {
+            paragraphs.pop();
+        }
+
+        for paragraph in paragraphs {
+            self.writer.xml_start_tag_only("a:p");
+
+            if !title.ignore_rich_para {
+                // Write the a:pPr element.
+                self.write_a_p_pr_rich(&title.font);
+            }
+
+            for (font, text) in paragraph {
+                self.writer.xml_start_tag_only("a:r");
+
+                // Write the a:rPr element.
+                self.write_a_r_pr(font);
+
+                // Write the a:t element.
+                self.write_a_t(text);
+
+                self.writer.xml_end_tag("a:r");
+            }
+
+            self.writer.xml_end_tag("a:p");
+        }
+    }
+
     // Write the <a:r> element.
     fn write_a_r(&mut self, title: &ChartTitle) {
         self.writer.xml_start_tag_only("a:r");
@@ -6436,6 +7097,9 @@ impl Chart {
 ///   `("Sheet1", 0, 0, 2, 0)` (this gives the same range as the previous string
 ///   value).
 ///
+/// A 2 value tuple of a sheet name and a [`RangeRef`] like `("Sheet1",
+/// RangeRef::parse("A1:A3")?)` is also supported.
+///
 /// For single cell ranges used in chart items such as chart or axis titles you
 /// can also use:
 ///
@@ -6469,6 +7133,25 @@ impl IntoChartRange for (&str, RowNum, ColNum) {
     }
 }
 
+impl IntoChartRange for (&str, RangeRef) {
+    fn new_chart_range(&self) -> ChartRange {
+        let range = &self.1;
+        ChartRange::new_from_range(
+            self.0,
+            range.first_row(),
+            range.first_col(),
+            range.last_row(),
+            range.last_col(),
+        )
+    }
+}
+
+impl IntoChartRange for (&str, &RangeRef) {
+    fn new_chart_range(&self) -> ChartRange {
+        (self.0, *self.1).new_chart_range()
+    }
+}
+
 impl IntoChartRange for &str {
     fn new_chart_range(&self) -> ChartRange {
         ChartRange::new_from_string(self)
@@ -6701,6 +7384,8 @@ pub struct ChartSeries {
     pub(crate) delete_from_legend: bool,
     pub(crate) smooth: Option<bool>,
     pub(crate) secondary_axis: bool,
+    pub(crate) value_table: Option<(String, String)>,
+    pub(crate) category_table: Option<(String, String)>,
 }
 
 #[allow(clippy::new_without_default)]
@@ -6813,9 +7498,24 @@ impl ChartSeries {
             delete_from_legend: false,
             smooth: None,
             secondary_axis: false,
+            value_table: None,
+            category_table: None,
         }
     }
 
+    // Create a copy of the series with the formatting and options but without
+    // the data ranges, for use with Chart::from_template().
+    fn to_template(&self) -> ChartSeries {
+        let mut series = self.clone();
+        series.value_range = ChartRange::default();
+        series.category_range = ChartRange::default();
+        series.title.range = ChartRange::default();
+        series.value_table = None;
+        series.category_table = None;
+
+        series
+    }
+
     /// Add a values range to a chart series.
     ///
     /// All chart series in Excel must have a data range that defines the range
@@ -6890,6 +7590,7 @@ impl ChartSeries {
         T: IntoChartRange,
     {
         self.value_range = range.new_chart_range();
+        self.value_table = None;
         self
     }
 
@@ -6976,6 +7677,107 @@ impl ChartSeries {
         T: IntoChartRange,
     {
         self.category_range = range.new_chart_range();
+        self.category_table = None;
+        self
+    }
+
+    /// Add a values range to a chart series from a worksheet table column.
+    ///
+    /// This method sets the chart series values from the data rows of a
+    /// column in a worksheet [`Table`](crate::Table), identified by the table
+    /// and column names. The range is resolved when the workbook is saved so
+    /// the table and chart can be added in any order and the table can be in
+    /// any worksheet in the workbook.
+    ///
+    /// The range excludes the table header and total rows. If the series
+    /// doesn't have a name and the table has a header row then the column
+    /// header cell is used as the series name, like in Excel.
+    ///
+    /// Default table names like `Table1` can be used for tables that haven't
+    /// been given an explicit name, see
+    /// [`Workbook::table_names()`](crate::Workbook::table_names). The table and
+    /// column names are case insensitive.
+    ///
+    /// An [`XlsxError::ChartError`] error is raised when the workbook is saved
+    /// if the table or column doesn't exist.
+    ///
+    /// # Parameters
+    ///
+    /// - `table_name`: The name of the worksheet table.
+    /// - `column_name`: The name of the column header in the table.
+    ///
+    /// # Examples
+    ///
+    /// An example of creating a chart with series ranges taken from the
+    /// columns of a worksheet table.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_series_set_values_from_table.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Table, TableColumn, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the table.
+    /// #     worksheet.write_column(1, 0, ["Jan", "Feb", "Mar", "Apr"])?;
+    /// #     worksheet.write_column(1, 1, [150, 180, 160, 210])?;
+    /// #
+    ///     // Add a worksheet table with a name and column headers.
+    ///     let columns = vec![
+    ///         TableColumn::new().set_header("Month"),
+    ///         TableColumn::new().set_header("Revenue"),
+    ///     ];
+    ///     let table = Table::new().set_name("Sales").set_columns(&columns);
+    ///     worksheet.add_table(0, 0, 4, 1, &table)?;
+    ///
+    ///     // Create a new chart with data from the table columns.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories_from_table("Sales", "Month")
+    ///         .set_values_from_table("Sales", "Revenue");
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 3, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_values_from_table(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+    ) -> &mut ChartSeries {
+        self.value_range = ChartRange::default();
+        self.value_table = Some((table_name.to_string(), column_name.to_string()));
+        self
+    }
+
+    /// Add a category range to a chart series from a worksheet table column.
+    ///
+    /// This method sets the chart series categories from the data rows of a
+    /// column in a worksheet [`Table`](crate::Table). It works in the same way
+    /// as [`ChartSeries::set_values_from_table()`], see that method for
+    /// details and an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `table_name`: The name of the worksheet table.
+    /// - `column_name`: The name of the column header in the table.
+    ///
+    pub fn set_categories_from_table(
+        &mut self,
+        table_name: &str,
+        column_name: &str,
+    ) -> &mut ChartSeries {
+        self.category_range = ChartRange::default();
+        self.category_table = Some((table_name.to_string(), column_name.to_string()));
         self
     }
 
@@ -8625,11 +9427,12 @@ pub(crate) enum ChartRangeCacheDataType {
 #[derive(Clone, Copy, PartialEq, Eq)]
 /// The `ChartType` enum define the type of a [`Chart`] object.
 ///
-/// The main original chart types are supported, see below.
+/// The main original chart types are supported, as well as the newer Treemap
+/// and Sunburst chart types, see below.
 ///
-/// Support for newer Excel chart types such as Treemap, Sunburst, Box and
-/// Whisker, Statistical Histogram, Waterfall, Funnel and Maps is not currently
-/// planned since the underlying structure is substantially different from the
+/// Support for the other newer Excel chart types such as Box and Whisker,
+/// Statistical Histogram, Waterfall, Funnel and Maps is not currently planned
+/// since the underlying structure is substantially different from the
 /// implemented chart types.
 ///
 pub enum ChartType {
@@ -8749,13 +9552,36 @@ pub enum ChartType {
     ScatterSmoothWithMarkers,
 
     /// A Stock chart showing Open-High-Low-Close data. It is also possible to
-    /// show High-Low-Close data.
+    /// show High-Low-Close data. See also [`StockChartBuilder`].
     ///
     /// Note, Volume variants of the Excel stock charts aren't currently
     /// supported but will be in a future release.
     ///
     /// <img src="https://rustxlsxwriter.github.io/images/chart_type_stock.png">
     Stock,
+
+    /// A Treemap chart type. Treemap charts display hierarchical data as a set
+    /// of nested rectangles that are sized according to the series values.
+    ///
+    /// The levels of the hierarchy are taken from the columns of the series
+    /// `categories` range, with the top level in the first column. The
+    /// `categories` range is required for this chart type.
+    ///
+    /// Treemap charts are written in the newer Excel 2016 "chartex" format and
+    /// they only support the chart title, the legend, the series name, the
+    /// series data label visibility options and a single data series. The
+    /// axes and the other chart and series formatting options are ignored.
+    /// They also can't be combined with other charts or added to a
+    /// chartsheet.
+    Treemap,
+
+    /// A Sunburst chart type. Sunburst charts display hierarchical data as a
+    /// set of concentric rings, with the top level of the hierarchy in the
+    /// inner ring.
+    ///
+    /// The data and the supported properties are the same as for a
+    /// [`ChartType::Treemap`] chart.
+    Sunburst,
 }
 
 // -----------------------------------------------------------------------
@@ -8771,12 +9597,13 @@ pub struct ChartTitle {
     pub(crate) range: ChartRange,
     pub(crate) format: ChartFormat,
     pub(crate) font: ChartFont,
-    name: String,
+    pub(crate) name: String,
     hidden: bool,
     is_horizontal: bool,
     ignore_rich_para: bool,
     layout: ChartLayout,
     has_overlay: bool,
+    rich_name: Vec<(ChartFont, String)>,
 }
 
 impl ChartTitle {
@@ -8791,36 +9618,116 @@ impl ChartTitle {
             ignore_rich_para: false,
             layout: ChartLayout::default(),
             has_overlay: false,
+            rich_name: vec![],
         }
     }
 
     /// Add a title for a chart.
     ///
-    /// Set the name (title) for the chart. The name is displayed above the
-    /// chart.
+    /// Set the name (title) for the chart. The name is displayed above the
+    /// chart.
+    ///
+    /// The name can be a simple string, a formula such as `Sheet1!$A$1` or a
+    /// tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The range property which can be one of the following generic
+    ///   types:
+    ///    - A simple string title.
+    ///    - A string with an Excel like range formula such as `"Sheet1!$A$1"`.
+    ///    - A tuple that can be used to create the range programmatically using
+    ///      a sheet name and zero indexed row and column values like:
+    ///      `("Sheet1", 0, 0)` (this gives the same range as the previous
+    ///      string value).
+    ///
+    /// # Examples
+    ///
+    /// A chart example demonstrating setting the chart title.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_title_set_name.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 50)?;
+    /// #     worksheet.write(1, 0, 30)?;
+    /// #     worksheet.write(2, 0, 40)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series using Excel formula syntax to describe the range.
+    ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
+    ///
+    ///     // Set the chart title.
+    ///     chart.title().set_name("This is the chart title");
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img src="https://rustxlsxwriter.github.io/images/chart_title_set_name.png">
+    ///
+    pub fn set_name<T>(&mut self, name: T) -> &mut ChartTitle
+    where
+        T: IntoChartRange,
+    {
+        self.range = name.new_chart_range();
+        self.rich_name.clear();
+
+        // If the name didn't convert to a populated range then it is probably
+        // just a simple string title.
+        if !self.range.has_data() {
+            self.name.clone_from(&self.range.range_string);
+        }
+
+        self
+    }
+
+    /// Add a title with multiple fonts for a chart.
+    ///
+    /// Set the name (title) for the chart as a "rich" string made up of
+    /// segments of text with different fonts. This can be used, for example,
+    /// to combine a heading and a smaller styled subtitle.
     ///
-    /// The name can be a simple string, a formula such as `Sheet1!$A$1` or a
-    /// tuple with a sheet name, row and column such as `('Sheet1', 0, 0)`.
+    /// The segments are passed as an array of `(&ChartFont, &str)` tuples, in
+    /// the same way as [`Worksheet::write_rich_string()`]. A `'\n'` newline
+    /// in the text starts a new line, or paragraph, in the title.
+    ///
+    /// Any font set via [`ChartTitle::set_font()`] is used as the default for
+    /// all of the segments. It is overridden by the properties of the segment
+    /// fonts.
+    ///
+    /// [`Worksheet::write_rich_string()`]:
+    ///     crate::Worksheet::write_rich_string
     ///
     /// # Parameters
     ///
-    /// - `range`: The range property which can be one of the following generic
-    ///   types:
-    ///    - A simple string title.
-    ///    - A string with an Excel like range formula such as `"Sheet1!$A$1"`.
-    ///    - A tuple that can be used to create the range programmatically using
-    ///      a sheet name and zero indexed row and column values like:
-    ///      `("Sheet1", 0, 0)` (this gives the same range as the previous
-    ///      string value).
+    /// - `segments`: An array reference of `(&ChartFont, &str)` tuples.
     ///
     /// # Examples
     ///
-    /// A chart example demonstrating setting the chart title.
+    /// A chart example demonstrating setting a chart title with multiple fonts
+    /// and a subtitle.
     ///
     /// ```
-    /// # // This code is available in examples/doc_chart_title_set_name.rs
+    /// # // This code is available in examples/doc_chart_title_set_rich_name.rs
     /// #
-    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Chart, ChartFont, ChartType, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
@@ -8837,8 +9744,17 @@ impl ChartTitle {
     ///     // Add a data series using Excel formula syntax to describe the range.
     ///     chart.add_series().set_values("Sheet1!$A$1:$A$3");
     ///
-    ///     // Set the chart title.
-    ///     chart.title().set_name("This is the chart title");
+    ///     // Create some fonts for the title segments.
+    ///     let mut heading = ChartFont::new();
+    ///     heading.set_size(16);
+    ///
+    ///     let mut subtitle = ChartFont::new();
+    ///     subtitle.set_size(10).set_italic().set_color("#7F7F7F");
+    ///
+    ///     // Set the chart title with a heading and a subtitle on a new line.
+    ///     chart
+    ///         .title()
+    ///         .set_rich_name(&[(&heading, "Quarterly Sales\n"), (&subtitle, "All regions")]);
     ///
     ///     // Add the chart to the worksheet.
     ///     worksheet.insert_chart(0, 2, &chart)?;
@@ -8850,21 +9766,23 @@ impl ChartTitle {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img src="https://rustxlsxwriter.github.io/images/chart_title_set_name.png">
-    ///
-    pub fn set_name<T>(&mut self, name: T) -> &mut ChartTitle
-    where
-        T: IntoChartRange,
-    {
-        self.range = name.new_chart_range();
+    pub fn set_rich_name(&mut self, segments: &[(&ChartFont, &str)]) -> &mut ChartTitle {
+        self.range = ChartRange::default();
+        self.name = segments.iter().map(|(_, text)| *text).collect();
 
-        // If the name didn't convert to a populated range then it is probably
-        // just a simple string title.
-        if !self.range.has_data() {
-            self.name.clone_from(&self.range.range_string);
-        }
+        self.rich_name = segments
+            .iter()
+            .map(|(font, text)| {
+                let mut font = (*font).clone();
+                font.has_default_bold = true;
+
+                if font.italic || font.is_latin() {
+                    font.has_baseline = true;
+                }
+
+                (font, (*text).to_string())
+            })
+            .collect();
 
         self
     }
@@ -9474,6 +10392,7 @@ pub struct ChartDataLabel {
     pub(crate) is_custom: bool,
     pub(crate) font: Option<ChartFont>,
     pub(crate) num_format: String,
+    pub(crate) value_range: ChartRange,
 }
 
 impl Default for ChartDataLabel {
@@ -9501,6 +10420,7 @@ impl ChartDataLabel {
             is_custom: false,
             font: None,
             num_format: String::new(),
+            value_range: ChartRange::default(),
         }
     }
 
@@ -9694,6 +10614,85 @@ impl ChartDataLabel {
         self
     }
 
+    /// Display the data label text from a range of worksheet cells.
+    ///
+    /// This method is the equivalent of Excel's "Value From Cells" data label
+    /// option. It allows the data labels for a series to display custom text,
+    /// such as names, from a worksheet range instead of, or as well as, the
+    /// plotted values. Each cell in the range is used as the label for the
+    /// corresponding point in the series.
+    ///
+    /// If no other display option is set then the value isn't shown on the
+    /// label. Use [`ChartDataLabel::show_value()`] to show both.
+    ///
+    /// This option only applies to the data labels of a series added via
+    /// [`ChartSeries::set_data_label()`]. It is ignored if used with
+    /// [`ChartSeries::set_custom_data_labels()`]. It requires Excel 2013 or
+    /// later and it is ignored in older versions.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The range of cells that contain the label text. This can be
+    ///   a string like `"Sheet1!$D$2:$D$6"` or a tuple with the sheet name and
+    ///   zero indexed row and column values, see [`IntoChartRange`].
+    ///
+    /// # Examples
+    ///
+    /// An example of adding data labels to a chart series with the label text
+    /// taken from a worksheet range.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_data_labels_set_value_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartDataLabel, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 10)?;
+    /// #     worksheet.write(1, 0, 40)?;
+    /// #     worksheet.write(2, 0, 50)?;
+    /// #     worksheet.write(3, 0, 20)?;
+    /// #     worksheet.write(4, 0, 10)?;
+    /// #     worksheet.write(5, 0, 50)?;
+    /// #
+    /// #     // Add some data for the labels.
+    /// #     worksheet.write(0, 1, "Jan")?;
+    /// #     worksheet.write(1, 1, "Feb")?;
+    /// #     worksheet.write(2, 1, "Mar")?;
+    /// #     worksheet.write(3, 1, "Apr")?;
+    /// #     worksheet.write(4, 1, "May")?;
+    /// #     worksheet.write(5, 1, "Jun")?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///
+    ///     // Add a data series with data labels from a worksheet range.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_data_label(ChartDataLabel::new().set_value_range("Sheet1!$B$1:$B$6"));
+    ///
+    /// #     // Add the chart to the worksheet.
+    /// #     worksheet.insert_chart(0, 2, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_value_range<T>(&mut self, range: T) -> &mut ChartDataLabel
+    where
+        T: IntoChartRange,
+    {
+        self.value_range = range.new_chart_range();
+        self
+    }
+
     /// Set the default position of the data label.
     ///
     /// In Excel the available data label positions vary for different chart
@@ -16818,6 +17817,8 @@ pub struct ChartErrorBars {
     format: ChartFormat,
     pub(crate) plus_range: ChartRange,
     pub(crate) minus_range: ChartRange,
+    plus_values: Vec<f64>,
+    minus_values: Vec<f64>,
 }
 
 impl Default for ChartErrorBars {
@@ -16837,6 +17838,8 @@ impl ChartErrorBars {
             format: ChartFormat::default(),
             plus_range: ChartRange::default(),
             minus_range: ChartRange::default(),
+            plus_values: vec![],
+            minus_values: vec![],
         }
     }
 
@@ -16875,6 +17878,8 @@ impl ChartErrorBars {
             ChartErrorBarsType::Custom(plus, minus) => {
                 self.plus_range = (*plus).clone();
                 self.minus_range = (*minus).clone();
+                self.plus_values.clear();
+                self.minus_values.clear();
             }
             ChartErrorBarsType::StandardError => {}
         }
@@ -16884,6 +17889,76 @@ impl ChartErrorBars {
         self
     }
 
+    /// Set custom error bar values from arrays of numbers.
+    ///
+    /// This method sets the error bar type to custom with the positive and
+    /// negative values taken from literal arrays of numbers rather than from a
+    /// worksheet range. It is the equivalent of entering values like
+    /// `={1,2,3}` in the Excel "Custom Error Bars" dialog. Use
+    /// [`ChartErrorBarsType::Custom`] to set the values from worksheet ranges.
+    ///
+    /// A single value is repeated for each point in the series. Otherwise the
+    /// number of values should match the number of points in the series. If
+    /// either array is empty then no error bar is shown in that direction.
+    ///
+    /// # Parameters
+    ///
+    /// - `plus`: The values for the positive error bars.
+    /// - `minus`: The values for the negative error bars.
+    ///
+    /// # Examples
+    ///
+    /// An example of adding error bars with custom values to a chart data
+    /// series.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_chart_error_bars_set_custom_values.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartErrorBars, ChartType, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some data for the chart.
+    /// #     worksheet.write(0, 0, 11.1)?;
+    /// #     worksheet.write(1, 0, 18.8)?;
+    /// #     worksheet.write(2, 0, 33.2)?;
+    /// #     worksheet.write(3, 0, 37.5)?;
+    /// #     worksheet.write(4, 0, 52.1)?;
+    /// #     worksheet.write(5, 0, 58.9)?;
+    /// #
+    /// #     // Create a new chart.
+    ///     let mut chart = Chart::new(ChartType::Line);
+    ///
+    ///     // Add a data series with custom error bars.
+    ///     chart
+    ///         .add_series()
+    ///         .set_values("Sheet1!$A$1:$A$6")
+    ///         .set_y_error_bars(
+    ///             ChartErrorBars::new()
+    ///                 .set_custom_values(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0], &[2.5]),
+    ///         );
+    ///
+    ///     // Add the chart to the worksheet.
+    ///     worksheet.insert_chart(0, 2, &chart)?;
+    ///
+    /// #     // Save the file.
+    /// #     workbook.save("chart.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_custom_values(&mut self, plus: &[f64], minus: &[f64]) -> &mut ChartErrorBars {
+        self.error_type = ChartErrorBarsType::Custom(ChartRange::default(), ChartRange::default());
+        self.plus_range = ChartRange::default();
+        self.minus_range = ChartRange::default();
+        self.plus_values = plus.to_vec();
+        self.minus_values = minus.to_vec();
+        self
+    }
+
     /// Set the direction of a Chart series error bars.
     ///
     /// The [`ChartErrorBarsDirection`] enum defines the error bar direction for a
@@ -17199,8 +18274,6 @@ impl ChartDataTable {
     ///
     /// `font`: A [`ChartFont`] struct reference to represent the font
     /// properties.
-    ///
-
     ///
     pub fn set_font(mut self, font: &ChartFont) -> ChartDataTable {
         self.font = Some(font.clone());
@@ -17638,8 +18711,8 @@ impl ChartPlotArea {
     /// # Parameters
     ///
     /// - `format`: A [`ChartFormat`] struct reference or a sub struct that will
-    ///    convert into a `ChartFormat` instance. See the docs for
-    ///    [`IntoChartFormat`] for details.
+    ///   convert into a `ChartFormat` instance. See the docs for
+    ///   [`IntoChartFormat`] for details.
     ///
     /// # Examples
     ///
@@ -18008,3 +19081,239 @@ impl ChartLayout {
             || self.height.is_some()
     }
 }
+
+// -----------------------------------------------------------------------
+// StockChartBuilder
+// -----------------------------------------------------------------------
+
+/// The `StockChartBuilder` struct is used to create a configured Stock chart.
+///
+/// Excel Stock charts are made up of several Line chart series with the lines
+/// hidden, some marker formatting and the chart High-Low lines and Up-Down
+/// bars turned on. The `StockChartBuilder` struct takes the date and price
+/// ranges of the data and creates a [`ChartType::Stock`] [`Chart`] with the
+/// same configuration as the default Excel Stock charts:
+///
+/// - High-Low-Close: if an `open` range isn't set. The Close series is shown
+///   with a dash marker.
+/// - Open-High-Low-Close: if an `open` range is set. The chart has Up-Down
+///   bars to show the difference between the open and close prices.
+///
+/// The [`Chart`] returned by [`StockChartBuilder::build()`] can be further
+/// configured, for example to add a title or axis names, before it is inserted
+/// into a worksheet.
+///
+/// Each of the range methods take the same range parameters as
+/// [`ChartSeries::set_values()`].
+///
+/// # Examples
+///
+/// An example of creating a Stock chart with the `StockChartBuilder`.
+///
+/// ```
+/// # // This code is available in examples/doc_stock_chart_builder.rs
+/// #
+/// # use rust_xlsxwriter::{ExcelDateTime, Format, StockChartBuilder, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #     let date_format = Format::new().set_num_format("yyyy-mm-dd");
+/// #
+/// #     // Add some data for the chart.
+/// #     let dates = [
+/// #         ExcelDateTime::parse_from_str("2024-05-01")?,
+/// #         ExcelDateTime::parse_from_str("2024-05-02")?,
+/// #         ExcelDateTime::parse_from_str("2024-05-03")?,
+/// #         ExcelDateTime::parse_from_str("2024-05-04")?,
+/// #         ExcelDateTime::parse_from_str("2024-05-05")?,
+/// #     ];
+/// #     worksheet.write_column_with_format(0, 0, dates, &date_format)?;
+/// #     worksheet.write_column(0, 1, [35.00, 41.53, 43.33, 46.73, 49.50])?;
+/// #     worksheet.write_column(0, 2, [44.12, 45.98, 46.99, 50.40, 54.99])?;
+/// #     worksheet.write_column(0, 3, [32.59, 38.51, 40.02, 45.60, 47.17])?;
+/// #     worksheet.write_column(0, 4, [41.53, 43.33, 46.73, 49.50, 53.29])?;
+/// #     worksheet.set_column_width(0, 11)?;
+/// #
+///     // Create an Open-High-Low-Close Stock chart.
+///     let mut chart = StockChartBuilder::new()
+///         .set_dates("Sheet1!$A$1:$A$5")
+///         .set_open("Sheet1!$B$1:$B$5")
+///         .set_high("Sheet1!$C$1:$C$5")
+///         .set_low("Sheet1!$D$1:$D$5")
+///         .set_close("Sheet1!$E$1:$E$5")
+///         .build()?;
+///
+///     // Add a chart title and hide the legend.
+///     chart.title().set_name("Stock: Open - High - Low - Close");
+///     chart.legend().set_hidden();
+///
+///     // Add the chart to the worksheet.
+///     worksheet.insert_chart(0, 6, &chart)?;
+/// #
+/// #     // Save the file.
+/// #     workbook.save("chart.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Default)]
+pub struct StockChartBuilder {
+    dates: ChartRange,
+    open: ChartRange,
+    high: ChartRange,
+    low: ChartRange,
+    close: ChartRange,
+}
+
+impl StockChartBuilder {
+    /// Create a new `StockChartBuilder` object to create a Stock chart.
+    ///
+    pub fn new() -> StockChartBuilder {
+        StockChartBuilder::default()
+    }
+
+    /// Set the range of the dates for the Stock chart. These are used as the
+    /// categories of each series.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The date range, see [`IntoChartRange`].
+    ///
+    pub fn set_dates<T>(mut self, range: T) -> StockChartBuilder
+    where
+        T: IntoChartRange,
+    {
+        self.dates = range.new_chart_range();
+        self
+    }
+
+    /// Set the range of the open prices for the Stock chart. This is
+    /// optional. If it is set then an Open-High-Low-Close chart is created.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The open price range, see [`IntoChartRange`].
+    ///
+    pub fn set_open<T>(mut self, range: T) -> StockChartBuilder
+    where
+        T: IntoChartRange,
+    {
+        self.open = range.new_chart_range();
+        self
+    }
+
+    /// Set the range of the high prices for the Stock chart.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The high price range, see [`IntoChartRange`].
+    ///
+    pub fn set_high<T>(mut self, range: T) -> StockChartBuilder
+    where
+        T: IntoChartRange,
+    {
+        self.high = range.new_chart_range();
+        self
+    }
+
+    /// Set the range of the low prices for the Stock chart.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The low price range, see [`IntoChartRange`].
+    ///
+    pub fn set_low<T>(mut self, range: T) -> StockChartBuilder
+    where
+        T: IntoChartRange,
+    {
+        self.low = range.new_chart_range();
+        self
+    }
+
+    /// Set the range of the close prices for the Stock chart.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: The close price range, see [`IntoChartRange`].
+    ///
+    pub fn set_close<T>(mut self, range: T) -> StockChartBuilder
+    where
+        T: IntoChartRange,
+    {
+        self.close = range.new_chart_range();
+        self
+    }
+
+    /// Create the Stock chart from the data ranges.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ChartError`] - The dates, high, low or close range
+    ///   hasn't been set.
+    ///
+    pub fn build(&self) -> Result<Chart, XlsxError> {
+        for (range, name) in [
+            (&self.dates, "dates"),
+            (&self.high, "high"),
+            (&self.low, "low"),
+            (&self.close, "close"),
+        ] {
+            if !range.has_data() {
+                return Err(XlsxError::ChartError(format!(
+                    "Stock chart must contain a '{name}' range"
+                )));
+            }
+        }
+
+        let has_open = self.open.has_data();
+        let mut chart = Chart::new(ChartType::Stock);
+
+        let mut ranges = vec![];
+        if has_open {
+            ranges.push(&self.open);
+        }
+        ranges.extend([&self.high, &self.low]);
+
+        // Add the price series with hidden lines and markers.
+        for range in ranges {
+            chart
+                .add_series()
+                .set_categories(&self.dates)
+                .set_values(range)
+                .set_format(ChartLine::new().set_hidden(true))
+                .set_marker(ChartMarker::new().set_none());
+        }
+
+        // Add the close series. The High-Low-Close chart has a close marker.
+        let series = chart
+            .add_series()
+            .set_categories(&self.dates)
+            .set_values(&self.close)
+            .set_format(ChartLine::new().set_hidden(true));
+
+        if has_open {
+            series.set_marker(ChartMarker::new().set_none());
+        } else {
+            series.set_marker(
+                ChartMarker::new()
+                    .set_type(ChartMarkerType::LongDash)
+                    .set_size(10)
+                    .set_format(
+                        ChartFormat::new()
+                            .set_border(ChartLine::new().set_color("#000000"))
+                            .set_solid_fill(ChartSolidFill::new().set_color("#000000")),
+                    ),
+            );
+        }
+
+        chart.set_high_low_lines(true);
+
+        if has_open {
+            chart.set_up_down_bars(true);
+        }
+
+        Ok(chart)
+    }
+}
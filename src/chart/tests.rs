@@ -7,7 +7,10 @@
 #[cfg(test)]
 mod chart_tests {
 
-    use crate::chart::{Chart, ChartRange, ChartSeries, ChartType, XlsxError};
+    use crate::chart::{
+        Chart, ChartDataLabel, ChartErrorBars, ChartFont, ChartLegendPosition, ChartLine,
+        ChartMarkerType, ChartRange, ChartSeries, ChartType, StockChartBuilder, XlsxError,
+    };
     use crate::test_functions::xml_to_vec;
     use crate::ChartRangeCacheDataType;
     use pretty_assertions::assert_eq;
@@ -277,6 +280,356 @@ mod chart_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_assemble_treemap() {
+        let mut categories = ChartRange::new_from_range("Sheet1", 1, 0, 4, 1);
+        categories.set_cache(
+            &[
+                "Asia", "China", "", "India", "Europe", "France", "", "Spain",
+            ],
+            ChartRangeCacheDataType::String,
+        );
+
+        let mut values = ChartRange::new_from_range("Sheet1", 1, 2, 4, 2);
+        values.set_cache(&["30", "20", "15", "10"], ChartRangeCacheDataType::Number);
+
+        let mut chart = Chart::new(ChartType::Treemap);
+        chart
+            .add_series()
+            .set_categories(&categories)
+            .set_values(&values)
+            .set_name("Population");
+
+        chart.title().set_name("Regions");
+        chart.legend().set_position(ChartLegendPosition::Top);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <cx:chartSpace xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex">
+                  <cx:chartData>
+                    <cx:data id="0">
+                      <cx:strDim type="cat">
+                        <cx:f>Sheet1!$A$2:$B$5</cx:f>
+                        <cx:lvl ptCount="4">
+                          <cx:pt idx="0">China</cx:pt>
+                          <cx:pt idx="1">India</cx:pt>
+                          <cx:pt idx="2">France</cx:pt>
+                          <cx:pt idx="3">Spain</cx:pt>
+                        </cx:lvl>
+                        <cx:lvl ptCount="4">
+                          <cx:pt idx="0">Asia</cx:pt>
+                          <cx:pt idx="2">Europe</cx:pt>
+                        </cx:lvl>
+                      </cx:strDim>
+                      <cx:numDim type="size">
+                        <cx:f>Sheet1!$C$2:$C$5</cx:f>
+                        <cx:lvl ptCount="4" formatCode="General">
+                          <cx:pt idx="0">30</cx:pt>
+                          <cx:pt idx="1">20</cx:pt>
+                          <cx:pt idx="2">15</cx:pt>
+                          <cx:pt idx="3">10</cx:pt>
+                        </cx:lvl>
+                      </cx:numDim>
+                    </cx:data>
+                  </cx:chartData>
+                  <cx:chart>
+                    <cx:title pos="t" align="ctr" overlay="0">
+                      <cx:tx>
+                        <cx:txData>
+                          <cx:v>Regions</cx:v>
+                        </cx:txData>
+                      </cx:tx>
+                    </cx:title>
+                    <cx:plotArea>
+                      <cx:plotAreaRegion>
+                        <cx:series layoutId="treemap">
+                          <cx:tx>
+                            <cx:txData>
+                              <cx:v>Population</cx:v>
+                            </cx:txData>
+                          </cx:tx>
+                          <cx:dataLabels pos="inEnd">
+                            <cx:visibility seriesName="0" categoryName="1" value="0"/>
+                          </cx:dataLabels>
+                          <cx:dataId val="0"/>
+                          <cx:layoutPr>
+                            <cx:parentLabelLayout val="overlapping"/>
+                          </cx:layoutPr>
+                        </cx:series>
+                      </cx:plotAreaRegion>
+                    </cx:plotArea>
+                    <cx:legend pos="t" align="ctr" overlay="0"/>
+                  </cx:chart>
+                </cx:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_sunburst() {
+        let mut categories = ChartRange::new_from_range("Sheet1", 1, 0, 2, 0);
+        categories.set_cache(&["Asia", "Europe"], ChartRangeCacheDataType::String);
+
+        let mut values = ChartRange::new_from_range("Sheet1", 1, 1, 2, 1);
+        values.set_cache(&["30", "20"], ChartRangeCacheDataType::Number);
+
+        let mut chart = Chart::new_sunburst();
+        chart
+            .add_series()
+            .set_categories(&categories)
+            .set_values(&values)
+            .set_name(("Sheet1", 0, 1))
+            .set_data_label(ChartDataLabel::new().show_value().show_category_name());
+
+        chart.legend().set_hidden();
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+                <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+                <cx:chartSpace xmlns:a="http://schemas.openxmlformats.org/drawingml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex">
+                  <cx:chartData>
+                    <cx:data id="0">
+                      <cx:strDim type="cat">
+                        <cx:f>Sheet1!$A$2:$A$3</cx:f>
+                        <cx:lvl ptCount="2">
+                          <cx:pt idx="0">Asia</cx:pt>
+                          <cx:pt idx="1">Europe</cx:pt>
+                        </cx:lvl>
+                      </cx:strDim>
+                      <cx:numDim type="size">
+                        <cx:f>Sheet1!$B$2:$B$3</cx:f>
+                        <cx:lvl ptCount="2" formatCode="General">
+                          <cx:pt idx="0">30</cx:pt>
+                          <cx:pt idx="1">20</cx:pt>
+                        </cx:lvl>
+                      </cx:numDim>
+                    </cx:data>
+                  </cx:chartData>
+                  <cx:chart>
+                    <cx:plotArea>
+                      <cx:plotAreaRegion>
+                        <cx:series layoutId="sunburst">
+                          <cx:tx>
+                            <cx:txData>
+                              <cx:f>Sheet1!$B$1</cx:f>
+                              <cx:v></cx:v>
+                            </cx:txData>
+                          </cx:tx>
+                          <cx:dataLabels pos="ctr">
+                            <cx:visibility seriesName="0" categoryName="1" value="1"/>
+                          </cx:dataLabels>
+                          <cx:dataId val="0"/>
+                        </cx:series>
+                      </cx:plotAreaRegion>
+                    </cx:plotArea>
+                  </cx:chart>
+                </cx:chartSpace>
+                "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_chartex_validation() {
+        // Check for Treemap chart without categories.
+        let mut chart = Chart::new(ChartType::Treemap);
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check for Treemap chart with more than one series.
+        let mut chart = Chart::new(ChartType::Treemap);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$3")
+            .set_values("Sheet1!$B$1:$B$3");
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$3")
+            .set_values("Sheet1!$C$1:$C$3");
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        // Check for Sunburst chart combined with another chart.
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+
+        let mut sunburst = Chart::new(ChartType::Sunburst);
+        sunburst
+            .add_series()
+            .set_categories("Sheet1!$A$1:$A$3")
+            .set_values("Sheet1!$B$1:$B$3");
+
+        chart.combine(&sunburst);
+        let result = chart.validate();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn test_error_bars_custom_values() {
+        let mut range = ChartRange::new_from_string("Sheet1!$A$1:$A$3");
+        range.set_cache(&["1", "2", "3"], ChartRangeCacheDataType::Number);
+
+        let mut chart = Chart::new(ChartType::Line);
+        chart
+            .add_series()
+            .set_values(&range)
+            .set_y_error_bars(ChartErrorBars::new().set_custom_values(&[0.5, 1.0, 1.5], &[2.0]));
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(concat!(
+            r#"<c:errValType val="cust"/>"#,
+            r#"<c:plus><c:numLit><c:formatCode>General</c:formatCode><c:ptCount val="3"/>"#,
+            r#"<c:pt idx="0"><c:v>0.5</c:v></c:pt><c:pt idx="1"><c:v>1</c:v></c:pt>"#,
+            r#"<c:pt idx="2"><c:v>1.5</c:v></c:pt></c:numLit></c:plus>"#,
+            r#"<c:minus><c:numLit><c:formatCode>General</c:formatCode><c:ptCount val="1"/>"#,
+            r#"<c:pt idx="0"><c:v>2</c:v></c:pt></c:numLit></c:minus>"#,
+        )));
+    }
+
+    #[test]
+    fn test_title_rich_name() {
+        let mut range = ChartRange::new_from_string("Sheet1!$A$1:$A$3");
+        range.set_cache(&["1", "2", "3"], ChartRangeCacheDataType::Number);
+
+        let mut bold = ChartFont::new();
+        bold.set_bold();
+
+        let mut italic = ChartFont::new();
+        italic.set_italic();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values(&range);
+        chart
+            .title()
+            .set_rich_name(&[(&bold, "Sales\n"), (&italic, "2024"), (&bold, " Q1")]);
+
+        chart.assemble_xml_file();
+
+        let got = chart.writer.read_to_str();
+
+        assert!(got.contains(concat!(
+            r#"<c:title><c:tx><c:rich><a:bodyPr/><a:lstStyle/>"#,
+            r#"<a:p><a:pPr><a:defRPr/></a:pPr>"#,
+            r#"<a:r><a:rPr lang="en-US" b="1"/><a:t>Sales</a:t></a:r></a:p>"#,
+            r#"<a:p><a:pPr><a:defRPr/></a:pPr>"#,
+            r#"<a:r><a:rPr lang="en-US" i="1" baseline="0"/><a:t>2024</a:t></a:r>"#,
+            r#"<a:r><a:rPr lang="en-US" b="1"/><a:t> Q1</a:t></a:r></a:p>"#,
+            r#"</c:rich></c:tx>"#,
+        )));
+    }
+
+    #[test]
+    fn test_from_template() {
+        let mut template = Chart::new(ChartType::Line);
+        template.title().set_name("Template");
+        template
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_name("Sheet1!$A$1")
+            .set_format(ChartLine::new().set_color("#FF0000"))
+            .set_secondary_axis(true);
+
+        let mut line = Chart::new(ChartType::Line);
+        line.add_series().set_values("Sheet1!$C$1:$C$3");
+        template.combine(&line);
+
+        let mut chart = Chart::from_template(&template);
+
+        assert!(chart.series.is_empty());
+        assert!(chart.combined_chart.is_none());
+        assert!(chart.chart_type == ChartType::Line);
+        assert_eq!(chart.title.name, "Template");
+
+        // The first new series uses the template series formatting without
+        // the data ranges. Later series use the default formatting.
+        chart.add_series().set_values("Sheet1!$B$1:$B$3");
+        chart.add_series().set_values("Sheet1!$C$1:$C$3");
+
+        let series = &chart.series[0];
+        assert!(series.value_range == ChartRange::new_from_string("Sheet1!$B$1:$B$3"));
+        assert!(series.title.range == ChartRange::default());
+        assert!(series.format == template.series[0].format);
+        assert!(series.secondary_axis);
+
+        let series = &chart.series[1];
+        assert!(series.format == ChartSeries::new().format);
+        assert!(!series.secondary_axis);
+    }
+
+    #[test]
+    fn test_stock_chart_builder() {
+        // High-Low-Close chart.
+        let chart = StockChartBuilder::new()
+            .set_dates("Sheet1!$A$1:$A$5")
+            .set_high("Sheet1!$B$1:$B$5")
+            .set_low("Sheet1!$C$1:$C$5")
+            .set_close("Sheet1!$D$1:$D$5")
+            .build()
+            .unwrap();
+
+        assert!(chart.chart_type == ChartType::Stock);
+        assert_eq!(chart.series.len(), 3);
+        assert!(chart.has_high_low_lines);
+        assert!(!chart.has_up_down_bars);
+
+        for series in &chart.series {
+            assert_eq!(series.category_range.formula_abs(), "Sheet1!$A$1:$A$5");
+            assert!(series.format.line.as_ref().unwrap().hidden);
+        }
+
+        let marker = chart.series[2].marker.as_ref().unwrap();
+        assert!(marker.marker_type == Some(ChartMarkerType::LongDash));
+        assert_eq!(
+            chart.series[2].value_range.formula_abs(),
+            "Sheet1!$D$1:$D$5"
+        );
+
+        // Open-High-Low-Close chart.
+        let chart = StockChartBuilder::new()
+            .set_dates("Sheet1!$A$1:$A$5")
+            .set_open("Sheet1!$B$1:$B$5")
+            .set_high("Sheet1!$C$1:$C$5")
+            .set_low("Sheet1!$D$1:$D$5")
+            .set_close("Sheet1!$E$1:$E$5")
+            .build()
+            .unwrap();
+
+        assert_eq!(chart.series.len(), 4);
+        assert!(chart.has_high_low_lines);
+        assert!(chart.has_up_down_bars);
+
+        for series in &chart.series {
+            assert!(series.marker.as_ref().unwrap().none);
+        }
+
+        // Missing range.
+        let result = StockChartBuilder::new()
+            .set_dates("Sheet1!$A$1:$A$5")
+            .set_high("Sheet1!$B$1:$B$5")
+            .set_close("Sheet1!$D$1:$D$5")
+            .build();
+
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
     #[test]
     fn test_range_from_string() {
         let range_string = "=Sheet1!$A$1:$A$5";
@@ -102,6 +102,15 @@ impl ContentTypes {
         self.add_override(&part_name, content_type);
     }
 
+    // Add the name of a chartex chart, such as a Treemap, to the ContentTypes
+    // overrides.
+    pub(crate) fn add_chartex_name(&mut self, index: u16) {
+        let content_type = "application/vnd.ms-office.chartex+xml";
+        let part_name = format!("/xl/charts/chartEx{index}.xml");
+
+        self.add_override(&part_name, content_type);
+    }
+
     // Add the name of a table to the ContentTypes overrides.
     pub(crate) fn add_table_name(&mut self, index: u16) {
         let content_type = "application/vnd.openxmlformats-officedocument.spreadsheetml.table+xml";
@@ -155,6 +164,14 @@ impl ContentTypes {
         );
     }
 
+    // Add the featurePropertyBag file to the ContentTypes overrides.
+    pub(crate) fn add_feature_property_bag(&mut self) {
+        self.add_override(
+            "/xl/featurePropertyBag/featurePropertyBag.xml",
+            "application/vnd.ms-excel.featurepropertybag+xml",
+        );
+    }
+
     // Add the custom properties to the ContentTypes overrides.
     pub(crate) fn add_custom_properties(&mut self) {
         self.add_override(
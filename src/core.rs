@@ -56,6 +56,9 @@ impl Core {
         // Write the cp:lastModifiedBy element.
         self.write_cp_last_modified_by();
 
+        // Write the cp:revision element.
+        self.write_cp_revision();
+
         // Write the dcterms:created element.
         self.write_dcterms_created();
 
@@ -68,6 +71,9 @@ impl Core {
         // Write the cp:contentStatus element.
         self.write_cp_content_status();
 
+        // Write the dc:language element.
+        self.write_dc_language();
+
         // Close the coreProperties tag.
         self.writer.xml_end_tag("cp:coreProperties");
     }
@@ -136,6 +142,14 @@ impl Core {
             .xml_data_element_only("cp:lastModifiedBy", &self.properties.author);
     }
 
+    // Write the <cp:revision> element.
+    fn write_cp_revision(&mut self) {
+        if self.properties.revision > 0 {
+            self.writer
+                .xml_data_element_only("cp:revision", &self.properties.revision.to_string());
+        }
+    }
+
     // Write the <dcterms:created> element.
     fn write_dcterms_created(&mut self) {
         let attributes = [("xsi:type", "dcterms:W3CDTF")];
@@ -169,4 +183,12 @@ impl Core {
                 .xml_data_element_only("cp:contentStatus", &self.properties.status);
         }
     }
+
+    // Write the <dc:language> element.
+    fn write_dc_language(&mut self) {
+        if !self.properties.language.is_empty() {
+            self.writer
+                .xml_data_element_only("dc:language", &self.properties.language);
+        }
+    }
 }
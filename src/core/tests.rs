@@ -42,4 +42,40 @@ mod core_tests {
 
         assert_eq!(expected, got);
     }
+
+    #[test]
+    fn test_assemble_language_revision() {
+        let date = ExcelDateTime::from_ymd(2010, 1, 1).unwrap();
+        let properties = DocProperties::new()
+            .set_author("A User")
+            .set_status("Final")
+            .set_language("en-US")
+            .set_revision(3)
+            .set_creation_datetime(&date);
+
+        let mut core = Core::new();
+        core.properties = properties;
+
+        core.assemble_xml_file();
+
+        let got = core.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <cp:coreProperties xmlns:cp="http://schemas.openxmlformats.org/package/2006/metadata/core-properties" xmlns:dc="http://purl.org/dc/elements/1.1/" xmlns:dcterms="http://purl.org/dc/terms/" xmlns:dcmitype="http://purl.org/dc/dcmitype/" xmlns:xsi="http://www.w3.org/2001/XMLSchema-instance">
+              <dc:creator>A User</dc:creator>
+              <cp:lastModifiedBy>A User</cp:lastModifiedBy>
+              <cp:revision>3</cp:revision>
+              <dcterms:created xsi:type="dcterms:W3CDTF">2010-01-01T00:00:00Z</dcterms:created>
+              <dcterms:modified xsi:type="dcterms:W3CDTF">2010-01-01T00:00:00Z</dcterms:modified>
+              <cp:contentStatus>Final</cp:contentStatus>
+              <dc:language>en-US</dc:language>
+            </cp:coreProperties>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
 }
@@ -0,0 +1,410 @@
+// csv - A module for importing CSV/TSV data into a worksheet.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::io::Read;
+
+use crate::{ExcelDateTime, Format, XlsxError};
+
+/// The `CsvOptions` struct is used to configure how delimited text is read by
+/// [`Worksheet::write_csv()`](crate::Worksheet::write_csv).
+///
+/// `CsvOptions` controls the field delimiter and quote character, whether the
+/// fields are converted to typed Excel values, whether ISO 8601 dates are
+/// detected, and how the optional header row and any date cells are
+/// formatted.
+///
+/// The default options are for comma separated data, with `"` as the quote
+/// character, and with type inference and date detection turned on. Tab
+/// separated (TSV) data can be read by changing the delimiter:
+///
+/// ```
+/// # use rust_xlsxwriter::CsvOptions;
+/// #
+/// let options = CsvOptions::new().set_delimiter('\t');
+/// ```
+///
+/// # Type inference
+///
+/// When type inference is on (the default) each unquoted or quoted field is
+/// converted to an Excel type using the following rules:
+///
+/// - Empty fields are skipped and no cell is written.
+/// - `true` and `false`, in any case, are written as booleans.
+/// - Decimal numbers such as `123`, `-1.5` or `1e6` are written as numbers.
+///   Numbers with leading zeros like `00123` are kept as strings to preserve
+///   zip codes and ids.
+/// - If date detection is on then ISO 8601 style dates like `2024-01-31` and
+///   datetimes like `2024-01-31 12:30:00` or `2024-01-31T12:30:00Z` are
+///   written as Excel dates with a date format.
+/// - Everything else is written as a string.
+///
+/// # Examples
+///
+/// The following example demonstrates writing CSV data to a worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_write_csv.rs
+/// #
+/// # use rust_xlsxwriter::{CsvOptions, Format, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Some sample CSV data.
+///     let data = "\
+/// Region,Item,Volume,Date
+/// East,Apple,9000,2024-07-01
+/// West,\"Pear, Green\",8000,2024-07-02
+/// ";
+///
+///     // Read the CSV data with a bold header row.
+///     let options = CsvOptions::new()
+///         .set_header_row(true)
+///         .set_header_format(&Format::new().set_bold());
+///
+///     worksheet.write_csv(0, 0, data.as_bytes(), &options)?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone)]
+pub struct CsvOptions {
+    pub(crate) delimiter: char,
+    pub(crate) quote_char: char,
+    pub(crate) type_inference: bool,
+    pub(crate) date_detection: bool,
+    pub(crate) header_row: bool,
+    pub(crate) header_format: Option<Format>,
+    pub(crate) date_format: Format,
+    pub(crate) datetime_format: Format,
+}
+
+impl Default for CsvOptions {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl CsvOptions {
+    /// Create a new `CsvOptions` object to use with the
+    /// [`Worksheet::write_csv()`](crate::Worksheet::write_csv) method.
+    ///
+    pub fn new() -> CsvOptions {
+        CsvOptions {
+            delimiter: ',',
+            quote_char: '"',
+            type_inference: true,
+            date_detection: true,
+            header_row: false,
+            header_format: None,
+            date_format: Format::new().set_num_format("yyyy-mm-dd"),
+            datetime_format: Format::new().set_num_format("yyyy-mm-dd hh:mm:ss"),
+        }
+    }
+
+    /// Set the field delimiter character.
+    ///
+    /// The default delimiter is a comma. Use `'\t'` for tab separated data or
+    /// `';'` for the semicolon separated data that is common in some locales.
+    ///
+    /// # Parameters
+    ///
+    /// - `delimiter`: The field delimiter character.
+    ///
+    pub fn set_delimiter(mut self, delimiter: char) -> CsvOptions {
+        self.delimiter = delimiter;
+        self
+    }
+
+    /// Set the quote character.
+    ///
+    /// Fields that contain the delimiter, the quote character or newlines can
+    /// be enclosed in quotes. A quote character within a quoted field is
+    /// escaped by doubling it. The default quote character is `"`.
+    ///
+    /// # Parameters
+    ///
+    /// - `quote_char`: The field quote character.
+    ///
+    pub fn set_quote_char(mut self, quote_char: char) -> CsvOptions {
+        self.quote_char = quote_char;
+        self
+    }
+
+    /// Turn on/off the conversion of fields to Excel numbers, booleans and
+    /// dates.
+    ///
+    /// When type inference is turned off all non-empty fields are written as
+    /// strings. See [Type inference](#type-inference) above for the
+    /// conversion rules.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_type_inference(mut self, enable: bool) -> CsvOptions {
+        self.type_inference = enable;
+        self
+    }
+
+    /// Turn on/off the detection of ISO 8601 style dates and datetimes.
+    ///
+    /// Date detection only applies when type inference is turned on.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    pub fn set_date_detection(mut self, enable: bool) -> CsvOptions {
+        self.date_detection = enable;
+        self
+    }
+
+    /// Treat the first record of the data as a header row.
+    ///
+    /// The fields of the header row are always written as strings, with the
+    /// format set by [`CsvOptions::set_header_format()`], if any.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_header_row(mut self, enable: bool) -> CsvOptions {
+        self.header_row = enable;
+        self
+    }
+
+    /// Set the format for the header row.
+    ///
+    /// This only applies when the header row is turned on via
+    /// [`CsvOptions::set_header_row()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for the header cells.
+    ///
+    pub fn set_header_format(mut self, format: &Format) -> CsvOptions {
+        self.header_format = Some(format.clone());
+        self
+    }
+
+    /// Set the format for detected dates.
+    ///
+    /// The default format is `yyyy-mm-dd`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for date cells.
+    ///
+    pub fn set_date_format(mut self, format: &Format) -> CsvOptions {
+        self.date_format = format.clone();
+        self
+    }
+
+    /// Set the format for detected datetimes.
+    ///
+    /// The default format is `yyyy-mm-dd hh:mm:ss`.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for datetime cells.
+    ///
+    pub fn set_datetime_format(mut self, format: &Format) -> CsvOptions {
+        self.datetime_format = format.clone();
+        self
+    }
+
+    // Read and split the delimited text into records of fields.
+    pub(crate) fn read_records<R: Read>(
+        &self,
+        mut reader: R,
+    ) -> Result<Vec<Vec<String>>, XlsxError> {
+        let mut data = String::new();
+        reader.read_to_string(&mut data)?;
+
+        Ok(self.split_records(&data))
+    }
+
+    // Split delimited text into records of fields. Quoted fields can contain
+    // delimiters, escaped quotes and newlines. Both LF and CRLF line endings
+    // are supported.
+    pub(crate) fn split_records(&self, data: &str) -> Vec<Vec<String>> {
+        let mut records = vec![];
+        let mut record = vec![];
+        let mut field = String::new();
+        let mut in_quotes = false;
+        let mut at_field_start = true;
+        let mut chars = data.chars().peekable();
+
+        while let Some(ch) = chars.next() {
+            if in_quotes {
+                if ch == self.quote_char {
+                    if chars.peek() == Some(&self.quote_char) {
+                        field.push(ch);
+                        chars.next();
+                    } else {
+                        in_quotes = false;
+                    }
+                } else {
+                    field.push(ch);
+                }
+            } else if ch == self.quote_char && at_field_start {
+                in_quotes = true;
+                at_field_start = false;
+            } else if ch == self.delimiter {
+                record.push(std::mem::take(&mut field));
+                at_field_start = true;
+            } else if ch == '\n' || ch == '\r' {
+                if ch == '\r' && chars.peek() == Some(&'\n') {
+                    chars.next();
+                }
+                record.push(std::mem::take(&mut field));
+                records.push(std::mem::take(&mut record));
+                at_field_start = true;
+            } else {
+                field.push(ch);
+                at_field_start = false;
+            }
+        }
+
+        // Store any final record that isn't terminated by a newline.
+        if !field.is_empty() || !record.is_empty() || !at_field_start {
+            record.push(field);
+            records.push(record);
+        }
+
+        records
+    }
+
+    // Convert a field string to a typed value.
+    pub(crate) fn convert_field(&self, field: &str) -> CsvValue {
+        if field.is_empty() {
+            return CsvValue::Blank;
+        }
+
+        if !self.type_inference {
+            return CsvValue::String(field.to_string());
+        }
+
+        if field.eq_ignore_ascii_case("true") {
+            return CsvValue::Boolean(true);
+        }
+
+        if field.eq_ignore_ascii_case("false") {
+            return CsvValue::Boolean(false);
+        }
+
+        if is_number_string(field) {
+            if let Ok(number) = field.parse::<f64>() {
+                if number.is_finite() {
+                    return CsvValue::Number(number);
+                }
+            }
+        }
+
+        if self.date_detection {
+            if let Some(has_time) = iso_date_type(field) {
+                if let Ok(datetime) = ExcelDateTime::parse_from_str(field) {
+                    return if has_time {
+                        CsvValue::DateTime(datetime)
+                    } else {
+                        CsvValue::Date(datetime)
+                    };
+                }
+            }
+        }
+
+        CsvValue::String(field.to_string())
+    }
+}
+
+// -----------------------------------------------------------------------
+// Helper enums/structs/functions.
+// -----------------------------------------------------------------------
+
+// A typed value converted from a CSV field.
+pub(crate) enum CsvValue {
+    Blank,
+    Boolean(bool),
+    Number(f64),
+    String(String),
+    Date(ExcelDateTime),
+    DateTime(ExcelDateTime),
+}
+
+// Check that a string only contains the characters of a decimal number and
+// doesn't have leading zeros, as in ids or zip codes. This excludes strings
+// like "inf" and "NaN" that Rust would otherwise parse as numbers.
+fn is_number_string(field: &str) -> bool {
+    let digits = field.trim_start_matches(['-', '+']);
+    let bytes = digits.as_bytes();
+
+    if bytes.is_empty() || !(bytes[0].is_ascii_digit() || bytes[0] == b'.') {
+        return false;
+    }
+
+    if bytes.len() > 1 && bytes[0] == b'0' && bytes[1].is_ascii_digit() {
+        return false;
+    }
+
+    digits
+        .bytes()
+        .all(|b| b.is_ascii_digit() || matches!(b, b'.' | b'e' | b'E' | b'-' | b'+'))
+}
+
+// Check if a string has the shape of an ISO 8601 date, "yyyy-mm-dd", with an
+// optional time part. Returns `Some(true)` if there is a time part.
+fn iso_date_type(field: &str) -> Option<bool> {
+    let bytes = field.as_bytes();
+
+    if bytes.len() < 10 {
+        return None;
+    }
+
+    let is_date = bytes[..10].iter().enumerate().all(|(i, b)| {
+        if i == 4 || i == 7 {
+            *b == b'-'
+        } else {
+            b.is_ascii_digit()
+        }
+    });
+
+    if !is_date {
+        return None;
+    }
+
+    if bytes.len() == 10 {
+        return Some(false);
+    }
+
+    // The time part must look like "Thh:mm[:ss[.sss]][Z]" or " hh:mm...".
+    if !matches!(bytes[10], b'T' | b' ') {
+        return None;
+    }
+
+    let time = field[11..].trim_end_matches('Z');
+    let is_time = time.len() >= 5
+        && time
+            .bytes()
+            .all(|b| b.is_ascii_digit() || b == b':' || b == b'.');
+
+    if is_time {
+        Some(true)
+    } else {
+        None
+    }
+}
@@ -0,0 +1,108 @@
+// CSV unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod csv_tests {
+    use crate::csv::CsvValue;
+    use crate::CsvOptions;
+
+    #[test]
+    fn test_split_records() {
+        let options = CsvOptions::new();
+
+        let tests = vec![
+            ("", vec![]),
+            ("a", vec![vec!["a"]]),
+            ("a,b,c", vec![vec!["a", "b", "c"]]),
+            ("a,b,c\n", vec![vec!["a", "b", "c"]]),
+            ("a,b\r\nc,d\r\n", vec![vec!["a", "b"], vec!["c", "d"]]),
+            ("a,,c", vec![vec!["a", "", "c"]]),
+            ("a,b,", vec![vec!["a", "b", ""]]),
+            ("\"a,b\",c", vec![vec!["a,b", "c"]]),
+            ("\"a\"\"b\",c", vec![vec!["a\"b", "c"]]),
+            ("\"a\nb\",c\nd", vec![vec!["a\nb", "c"], vec!["d"]]),
+            ("\"\",x", vec![vec!["", "x"]]),
+            ("a\n\nb", vec![vec!["a"], vec![""], vec!["b"]]),
+        ];
+
+        for (data, expected) in tests {
+            let records = options.split_records(data);
+            assert_eq!(expected, records, "data: {data:?}");
+        }
+    }
+
+    #[test]
+    fn test_split_records_tsv() {
+        let options = CsvOptions::new().set_delimiter('\t').set_quote_char('\'');
+
+        let records = options.split_records("a,b\t'c\td'\n1\t2\n");
+        assert_eq!(vec![vec!["a,b", "c\td"], vec!["1", "2"]], records);
+    }
+
+    #[test]
+    fn test_convert_field() {
+        let options = CsvOptions::new();
+
+        assert!(matches!(options.convert_field(""), CsvValue::Blank));
+        assert!(matches!(
+            options.convert_field("TRUE"),
+            CsvValue::Boolean(true)
+        ));
+        assert!(matches!(
+            options.convert_field("false"),
+            CsvValue::Boolean(false)
+        ));
+        assert!(matches!(options.convert_field("123"), CsvValue::Number(n) if n == 123.0));
+        assert!(matches!(options.convert_field("-1.5"), CsvValue::Number(n) if n == -1.5));
+        assert!(matches!(options.convert_field("0.25"), CsvValue::Number(n) if n == 0.25));
+        assert!(matches!(options.convert_field("1e3"), CsvValue::Number(n) if n == 1000.0));
+        assert!(matches!(
+            options.convert_field("2024-01-31"),
+            CsvValue::Date(_)
+        ));
+        assert!(matches!(
+            options.convert_field("2024-01-31 12:30:00"),
+            CsvValue::DateTime(_)
+        ));
+        assert!(matches!(
+            options.convert_field("2024-01-31T12:30:00Z"),
+            CsvValue::DateTime(_)
+        ));
+
+        // Values that should stay as strings.
+        for field in [
+            "00123",
+            "inf",
+            "NaN",
+            "1-2",
+            "2024-13-45",
+            "2024-01-31 noon",
+            "abc",
+        ] {
+            assert!(
+                matches!(options.convert_field(field), CsvValue::String(ref s) if s == field),
+                "field: {field:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_convert_field_without_inference() {
+        let options = CsvOptions::new().set_type_inference(false);
+
+        assert!(matches!(options.convert_field(""), CsvValue::Blank));
+
+        for field in ["123", "true", "2024-01-31"] {
+            assert!(matches!(options.convert_field(field), CsvValue::String(ref s) if s == field));
+        }
+
+        let options = CsvOptions::new().set_date_detection(false);
+        assert!(matches!(
+            options.convert_field("2024-01-31"),
+            CsvValue::String(_)
+        ));
+    }
+}
@@ -11,7 +11,10 @@ mod tests;
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
 
 #[cfg(feature = "chrono")]
-use chrono::{Datelike, NaiveDate, NaiveDateTime, NaiveTime};
+use chrono::{DateTime, Datelike, NaiveDate, NaiveDateTime, NaiveTime, TimeZone};
+
+#[cfg(feature = "time")]
+use time::{Date, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
 
 #[cfg(not(all(
     feature = "wasm",
@@ -1328,6 +1331,76 @@ impl ExcelDateTime {
 
         duration.num_milliseconds() as f64 / (24.0 * 60.0 * 60.0 * 1000.0)
     }
+
+    // Convert a chrono::DateTime<Tz> to an Excel serial datetime, either in
+    // the local time of the value or normalized to UTC.
+    #[cfg(feature = "chrono")]
+    pub(crate) fn chrono_tz_datetime_to_excel<Tz: TimeZone>(
+        datetime: &DateTime<Tz>,
+        policy: TimezonePolicy,
+    ) -> f64 {
+        match policy {
+            TimezonePolicy::Local => Self::chrono_datetime_to_excel(&datetime.naive_local()),
+            TimezonePolicy::Utc => Self::chrono_datetime_to_excel(&datetime.naive_utc()),
+        }
+    }
+
+    // Time crate date handling functions.
+
+    // Convert a time::PrimitiveDateTime to an Excel serial datetime.
+    #[cfg(feature = "time")]
+    pub(crate) fn time_datetime_to_excel(datetime: &PrimitiveDateTime) -> f64 {
+        let excel_date = Self::time_date_to_excel(&datetime.date());
+        let excel_time = Self::time_time_to_excel(&datetime.time());
+
+        excel_date + excel_time
+    }
+
+    // Convert a time::Date to an Excel serial date. The Julian day number of
+    // the 1899-12-31 epoch is 2415020.
+    #[cfg(feature = "time")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn time_date_to_excel(date: &Date) -> f64 {
+        let mut excel_date = f64::from(date.to_julian_day() - 2_415_020);
+
+        // For legacy reasons Excel treats 1900 as a leap year. We add an additional
+        // day for dates after the leapday in the 1899 epoch.
+        if excel_date > 59.0 {
+            excel_date += 1.0;
+        }
+
+        excel_date
+    }
+
+    // Convert a time::Time to an Excel time. The time portion of the Excel
+    // datetime is the number of milliseconds divided by the total number of
+    // milliseconds in the day.
+    #[cfg(feature = "time")]
+    #[allow(clippy::trivially_copy_pass_by_ref)]
+    pub(crate) fn time_time_to_excel(time: &Time) -> f64 {
+        let (hour, min, sec, milli) = time.as_hms_milli();
+        let milliseconds = ((u32::from(hour) * 60 + u32::from(min)) * 60 + u32::from(sec)) * 1000
+            + u32::from(milli);
+
+        f64::from(milliseconds) / (24.0 * 60.0 * 60.0 * 1000.0)
+    }
+
+    // Convert a time::OffsetDateTime to an Excel serial datetime, either in
+    // the local time of the value or normalized to UTC.
+    #[cfg(feature = "time")]
+    pub(crate) fn time_offset_datetime_to_excel(
+        datetime: &OffsetDateTime,
+        policy: TimezonePolicy,
+    ) -> f64 {
+        let datetime = match policy {
+            TimezonePolicy::Local => *datetime,
+            TimezonePolicy::Utc => datetime.to_offset(UtcOffset::UTC),
+        };
+
+        let datetime = PrimitiveDateTime::new(datetime.date(), datetime.time());
+
+        Self::time_datetime_to_excel(&datetime)
+    }
 }
 
 impl Default for ExcelDateTime {
@@ -1367,7 +1440,15 @@ enum ExcelDateTimeType {
 /// optional `chrono` feature when adding `rust_xlsxwriter` to your
 /// `Cargo.toml`.
 ///
+/// The [`Date`], [`Time`] and [`PrimitiveDateTime`] types from the [`time`]
+/// crate are also supported via the optional `time` feature.
+///
 /// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+/// [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+/// [`PrimitiveDateTime`]:
+///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
 ///
 pub trait IntoExcelDateTime {
     /// Trait method to convert a date or time into an Excel serial datetime.
@@ -1435,6 +1516,135 @@ impl IntoExcelDateTime for NaiveTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &PrimitiveDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for &Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_time_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for PrimitiveDateTime {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_datetime_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for Date {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_date_to_excel(self)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTime for Time {
+    fn to_excel_serial_date(&self) -> f64 {
+        ExcelDateTime::time_time_to_excel(self)
+    }
+}
+
+/// The `TimezonePolicy` enum defines how a timezone aware datetime is
+/// converted to an Excel datetime.
+///
+/// Excel datetimes don't have any timezone information so timezone aware
+/// values, such as a Chrono `DateTime<Tz>` or a `time` crate
+/// `OffsetDateTime`, need to be converted to a "naive" date and time before
+/// they can be written to a worksheet. This enum is used with
+/// [`Worksheet::write_datetime_with_tz()`](crate::Worksheet::write_datetime_with_tz)
+/// to control that conversion.
+///
+/// For example the datetime `2024-01-01T12:00:00+02:00` is written as
+/// `2024-01-01 12:00:00` with [`TimezonePolicy::Local`] and as `2024-01-01
+/// 10:00:00` with [`TimezonePolicy::Utc`].
+///
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum TimezonePolicy {
+    /// Write the date and time as it would be displayed in the timezone, or
+    /// offset, of the value. This is the default.
+    #[default]
+    Local,
+
+    /// Convert the date and time to UTC before writing it.
+    Utc,
+}
+
+/// Trait to map timezone aware date/time types to an Excel serial datetimes.
+///
+/// This trait is used with
+/// [`Worksheet::write_datetime_with_tz()`](crate::Worksheet::write_datetime_with_tz)
+/// to convert timezone aware dates and times to Excel serial datetimes,
+/// according to a [`TimezonePolicy`].
+///
+/// If the `chrono` feature is enabled the trait is implemented for the
+/// [`Chrono`] [`DateTime<Tz>`] types. If the `time` feature is enabled it is
+/// implemented for the [`time`] crate [`OffsetDateTime`] type.
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+/// [`DateTime<Tz>`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`OffsetDateTime`]:
+///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+///
+pub trait IntoExcelDateTimeTz {
+    /// Trait method to convert a timezone aware date and time into an Excel
+    /// serial datetime.
+    ///
+    fn to_excel_serial_date_tz(&self, policy: TimezonePolicy) -> f64;
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl<Tz: TimeZone> IntoExcelDateTimeTz for &DateTime<Tz> {
+    fn to_excel_serial_date_tz(&self, policy: TimezonePolicy) -> f64 {
+        ExcelDateTime::chrono_tz_datetime_to_excel(self, policy)
+    }
+}
+
+#[cfg(feature = "chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "chrono")))]
+impl<Tz: TimeZone> IntoExcelDateTimeTz for DateTime<Tz> {
+    fn to_excel_serial_date_tz(&self, policy: TimezonePolicy) -> f64 {
+        ExcelDateTime::chrono_tz_datetime_to_excel(self, policy)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTimeTz for &OffsetDateTime {
+    fn to_excel_serial_date_tz(&self, policy: TimezonePolicy) -> f64 {
+        ExcelDateTime::time_offset_datetime_to_excel(self, policy)
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelDateTimeTz for OffsetDateTime {
+    fn to_excel_serial_date_tz(&self, policy: TimezonePolicy) -> f64 {
+        ExcelDateTime::time_offset_datetime_to_excel(self, policy)
+    }
+}
+
 /// Implementation of the `serde::Serialize` trait for `ExcelDateTime`.
 ///
 /// An Excel datetime is a number (see the [`ExcelDateTime`] docs) so it will
@@ -10,7 +10,14 @@ mod datetime_tests {
     #[cfg(feature = "chrono")]
     use chrono::prelude::*;
 
+    #[cfg(feature = "time")]
+    use time::{Month, PrimitiveDateTime};
+
     use crate::{ExcelDateTime, XlsxError};
+
+    #[cfg(any(feature = "chrono", feature = "time"))]
+    use crate::TimezonePolicy;
+
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -2083,4 +2090,140 @@ mod datetime_tests {
             assert!(diff < 0.00000000001);
         }
     }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_dates_and_times() {
+        // Test date and time
+        #[allow(clippy::excessive_precision)]
+        let datetimes = vec![
+            (1899, 12, 31, 0, 0, 0, 0, 0.0),
+            (1982, 8, 25, 0, 15, 20, 213, 30188.010650613425),
+            (2065, 4, 19, 0, 16, 48, 290, 60376.011670023145),
+            (2147, 12, 15, 0, 55, 25, 446, 90565.038488958337),
+            (2230, 8, 10, 1, 2, 46, 891, 120753.04359827546),
+            (2313, 4, 6, 1, 4, 15, 597, 150942.04462496529),
+            (2395, 11, 30, 1, 9, 40, 889, 181130.04838991899),
+            (2478, 7, 25, 1, 11, 32, 560, 211318.04968240741),
+            (2561, 3, 21, 1, 30, 19, 169, 241507.06272186342),
+            (2643, 11, 15, 1, 48, 25, 580, 271695.07529606484),
+            (2726, 7, 12, 2, 3, 31, 919, 301884.08578609955),
+            (9752, 1, 14, 23, 15, 54, 109, 2867899.9693762613),
+            (9834, 9, 10, 23, 17, 12, 632, 2898088.9702850925),
+            (9999, 12, 31, 23, 59, 59, 0, 2958465.999988426),
+        ];
+
+        for test_data in datetimes {
+            let (year, month, day, hour, min, seconds, millis, expected) = test_data;
+            let month = Month::try_from(month).unwrap();
+            let date = time::Date::from_calendar_date(year, month, day).unwrap();
+            let time = time::Time::from_hms_milli(hour, min, seconds, millis).unwrap();
+            let datetime = PrimitiveDateTime::new(date, time);
+            assert_eq!(expected, ExcelDateTime::time_datetime_to_excel(&datetime));
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_dates_only() {
+        // Test date only, including around the Excel 1900 leap year bug.
+        let dates = vec![
+            (1899, 12, 31, 0.0),
+            (1900, 1, 1, 1.0),
+            (1900, 2, 27, 58.0),
+            (1900, 2, 28, 59.0),
+            (1900, 3, 1, 61.0),
+            (1900, 3, 2, 62.0),
+            (1904, 2, 29, 1521.0),
+            (1999, 12, 31, 36525.0),
+            (2000, 1, 1, 36526.0),
+            (2000, 2, 29, 36585.0),
+            (2400, 12, 31, 182988.0),
+            (4000, 3, 1, 767071.0),
+        ];
+
+        for test_data in dates {
+            let (year, month, day, expected) = test_data;
+            let month = Month::try_from(month).unwrap();
+            let date = time::Date::from_calendar_date(year, month, day).unwrap();
+            assert_eq!(expected, ExcelDateTime::time_date_to_excel(&date));
+        }
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_times_only() {
+        // Test time only.
+        #[allow(clippy::excessive_precision)]
+        let times = vec![
+            (0, 0, 0, 0, 0.0),
+            (0, 15, 20, 213, 1.0650613425925924E-2),
+            (12, 0, 0, 0, 0.5),
+            (18, 29, 59, 700, 0.77082986111111118),
+            (23, 59, 59, 999, 0.99999998842592586),
+        ];
+
+        for test_data in times {
+            let (hour, min, seconds, millis, expected) = test_data;
+            let time = time::Time::from_hms_milli(hour, min, seconds, millis).unwrap();
+            let mut diff = ExcelDateTime::time_time_to_excel(&time) - expected;
+            diff = diff.abs();
+            assert!(diff < 0.00000000001);
+        }
+    }
+
+    #[cfg(feature = "chrono")]
+    #[test]
+    fn chrono_timezone_datetimes() {
+        let offset = FixedOffset::east_opt(2 * 3600).unwrap();
+        let datetime = offset.with_ymd_and_hms(2024, 1, 1, 12, 0, 0).unwrap();
+
+        // 2024-01-01 12:00:00 local time.
+        let expected = 45292.5;
+        assert_eq!(
+            expected,
+            ExcelDateTime::chrono_tz_datetime_to_excel(&datetime, TimezonePolicy::Local)
+        );
+
+        // 2024-01-01 10:00:00 UTC.
+        let expected = 45292.0 + 10.0 / 24.0;
+        assert_eq!(
+            expected,
+            ExcelDateTime::chrono_tz_datetime_to_excel(&datetime, TimezonePolicy::Utc)
+        );
+
+        // Check a conversion that crosses a date boundary.
+        let offset = FixedOffset::west_opt(5 * 3600).unwrap();
+        let datetime = offset.with_ymd_and_hms(2024, 1, 1, 21, 0, 0).unwrap();
+
+        // 2024-01-02 02:00:00 UTC.
+        let expected = 45293.0 + 2.0 / 24.0;
+        assert_eq!(
+            expected,
+            ExcelDateTime::chrono_tz_datetime_to_excel(&datetime, TimezonePolicy::Utc)
+        );
+    }
+
+    #[cfg(feature = "time")]
+    #[test]
+    fn time_timezone_datetimes() {
+        let date = time::Date::from_calendar_date(2024, Month::January, 1).unwrap();
+        let time = time::Time::from_hms(12, 0, 0).unwrap();
+        let offset = time::UtcOffset::from_hms(2, 0, 0).unwrap();
+        let datetime = PrimitiveDateTime::new(date, time).assume_offset(offset);
+
+        // 2024-01-01 12:00:00 local time.
+        let expected = 45292.5;
+        assert_eq!(
+            expected,
+            ExcelDateTime::time_offset_datetime_to_excel(&datetime, TimezonePolicy::Local)
+        );
+
+        // 2024-01-01 10:00:00 UTC.
+        let expected = 45292.0 + 10.0 / 24.0;
+        assert_eq!(
+            expected,
+            ExcelDateTime::time_offset_datetime_to_excel(&datetime, TimezonePolicy::Utc)
+        );
+    }
 }
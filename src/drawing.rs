@@ -102,6 +102,7 @@ impl Drawing {
         match drawing_info.drawing_type {
             DrawingType::Image => self.write_pic(index, drawing_info),
             DrawingType::Chart => self.write_graphic_frame(index, drawing_info),
+            DrawingType::ChartEx => self.write_chartex_alternate_content(index, drawing_info),
             DrawingType::Shape => {
                 let shape = self.shapes[self.shape_id].clone();
                 self.shape_id += 1;
@@ -379,7 +380,7 @@ impl Drawing {
         self.write_xfrm();
 
         // Write the a:graphic element.
-        self.write_a_graphic(drawing_info.rel_id);
+        self.write_a_graphic(drawing_info.rel_id, drawing_info.drawing_type);
 
         self.writer.xml_end_tag("xdr:graphicFrame");
     }
@@ -439,11 +440,16 @@ impl Drawing {
     }
 
     // Write the <a:graphic> element.
-    fn write_a_graphic(&mut self, index: u32) {
+    fn write_a_graphic(&mut self, index: u32, drawing_type: DrawingType) {
         self.writer.xml_start_tag_only("a:graphic");
 
-        // Write the a:graphicData element.
-        self.write_a_graphic_data(index);
+        if drawing_type == DrawingType::ChartEx {
+            // Write the a:graphicData element for a chartex chart.
+            self.write_a_graphic_data_chartex(index);
+        } else {
+            // Write the a:graphicData element.
+            self.write_a_graphic_data(index);
+        }
 
         self.writer.xml_end_tag("a:graphic");
     }
@@ -480,6 +486,114 @@ impl Drawing {
         self.writer.xml_empty_tag("c:chart", &attributes);
     }
 
+    // Write the <mc:AlternateContent> element for the newer chartex style
+    // charts such as Treemap. Versions of Excel that don't support these charts
+    // display a fallback text box instead.
+    fn write_chartex_alternate_content(&mut self, index: u32, drawing_info: &DrawingInfo) {
+        let attributes = [(
+            "xmlns:mc",
+            "http://schemas.openxmlformats.org/markup-compatibility/2006",
+        )];
+
+        self.writer
+            .xml_start_tag("mc:AlternateContent", &attributes);
+
+        let attributes = [
+            (
+                "xmlns:cx1",
+                "http://schemas.microsoft.com/office/drawing/2015/9/8/chartex",
+            ),
+            ("Requires", "cx1"),
+        ];
+
+        self.writer.xml_start_tag("mc:Choice", &attributes);
+
+        // Write the xdr:graphicFrame element.
+        self.write_graphic_frame(index, drawing_info);
+
+        self.writer.xml_end_tag("mc:Choice");
+        self.writer.xml_start_tag_only("mc:Fallback");
+
+        // Write the fallback xdr:sp element.
+        self.write_chartex_fallback(drawing_info);
+
+        self.writer.xml_end_tag("mc:Fallback");
+        self.writer.xml_end_tag("mc:AlternateContent");
+    }
+
+    // Write the <a:graphicData> element for a chartex chart.
+    fn write_a_graphic_data_chartex(&mut self, index: u32) {
+        let attributes = [(
+            "uri",
+            "http://schemas.microsoft.com/office/drawing/2014/chartex",
+        )];
+
+        self.writer.xml_start_tag("a:graphicData", &attributes);
+
+        // Write the cx:chart element.
+        let attributes = [
+            (
+                "xmlns:cx",
+                "http://schemas.microsoft.com/office/drawing/2014/chartex".to_string(),
+            ),
+            (
+                "xmlns:r",
+                "http://schemas.openxmlformats.org/officeDocument/2006/relationships".to_string(),
+            ),
+            ("r:id", format!("rId{index}")),
+        ];
+
+        self.writer.xml_empty_tag("cx:chart", &attributes);
+
+        self.writer.xml_end_tag("a:graphicData");
+    }
+
+    // Write the fallback <xdr:sp> element for a chartex chart.
+    fn write_chartex_fallback(&mut self, drawing_info: &DrawingInfo) {
+        let attributes = [("macro", ""), ("textlink", "")];
+
+        self.writer.xml_start_tag("xdr:sp", &attributes);
+
+        // Write the xdr:nvSpPr element.
+        self.writer.xml_start_tag_only("xdr:nvSpPr");
+
+        let attributes = [("id", "0"), ("name", "")];
+        self.writer.xml_empty_tag("xdr:cNvPr", &attributes);
+
+        self.writer.xml_start_tag_only("xdr:cNvSpPr");
+        self.writer
+            .xml_empty_tag("a:spLocks", &[("noTextEdit", "1")]);
+        self.writer.xml_end_tag("xdr:cNvSpPr");
+
+        self.writer.xml_end_tag("xdr:nvSpPr");
+
+        // Write the xdr:spPr element.
+        self.write_sp_pr(drawing_info);
+
+        // Write the xdr:txBody element.
+        self.writer.xml_start_tag_only("xdr:txBody");
+
+        let attributes = [("vertOverflow", "clip"), ("horzOverflow", "clip")];
+        self.writer.xml_empty_tag("a:bodyPr", &attributes);
+        self.writer.xml_empty_tag_only("a:lstStyle");
+
+        self.writer.xml_start_tag_only("a:p");
+        self.writer.xml_start_tag_only("a:r");
+
+        let attributes = [("lang", "en-US"), ("sz", "1100")];
+        self.writer.xml_empty_tag("a:rPr", &attributes);
+        self.writer.xml_data_element_only(
+            "a:t",
+            "This chart isn't available in your version of Excel.",
+        );
+
+        self.writer.xml_end_tag("a:r");
+        self.writer.xml_end_tag("a:p");
+
+        self.writer.xml_end_tag("xdr:txBody");
+        self.writer.xml_end_tag("xdr:sp");
+    }
+
     // Write the <xdr:sp> element.
     fn write_sp(&mut self, index: u32, drawing_info: &DrawingInfo, shape: &Shape) {
         let mut attributes = vec![("macro", String::new())];
@@ -1282,6 +1396,7 @@ pub(crate) struct DrawingInfo {
 #[derive(Clone, Copy, Debug, PartialEq, Eq)]
 pub(crate) enum DrawingType {
     Chart,
+    ChartEx,
     ChartSheet,
     Image,
     Shape,
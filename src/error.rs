@@ -32,12 +32,18 @@ pub enum XlsxError {
     ParameterError(String),
 
     /// Row or column argument exceeds Excel's limits of 1,048,576 rows and
-    /// 16,384 columns for a worksheet.
-    RowColumnLimitError,
+    /// 16,384 columns for a worksheet. The error string contains the
+    /// worksheet name, the zero indexed row and/or column values and, for
+    /// methods other than the cell writing methods, the worksheet method that
+    /// raised the error.
+    RowColumnLimitError(String),
 
     /// First row or column is greater than last row or column in a range
-    /// specification, i.e., the order is reversed.
-    RowColumnOrderError,
+    /// specification, i.e., the order is reversed. The error string contains
+    /// the worksheet name, the zero indexed range values and, for methods
+    /// other than the cell writing methods, the worksheet method that raised
+    /// the error.
+    RowColumnOrderError(String),
 
     /// Worksheet name cannot be blank.
     SheetnameCannotBeBlank(String),
@@ -147,6 +153,11 @@ pub enum XlsxError {
     /// incorrect or missing.
     DataValidationError(String),
 
+    /// Excel only supports 7 levels of outline grouping for rows or columns.
+    /// This error is raised by the worksheet grouping methods if a range is
+    /// nested more than 7 levels deep.
+    MaxGroupLevelExceeded,
+
     /// A general error raised when a VBA name doesn't meet Excel's criteria as
     /// defined by the following rules:
     ///
@@ -198,14 +209,14 @@ impl fmt::Display for XlsxError {
                 write!(f, "Parameter error: '{error}'.")
             }
 
-            XlsxError::RowColumnLimitError => write!(
+            XlsxError::RowColumnLimitError(context) => write!(
                 f,
-                "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384)."
+                "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384): {context}."
             ),
 
-            XlsxError::RowColumnOrderError => write!(
+            XlsxError::RowColumnOrderError(context) => write!(
                 f,
-                "First row or column in range is greater than last row or column."
+                "First row or column in range is greater than last row or column: {context}."
             ),
 
             XlsxError::SheetnameCannotBeBlank(name) => {
@@ -313,6 +324,10 @@ impl fmt::Display for XlsxError {
                 write!(f, "Data validation error: '{error}'.")
             }
 
+            XlsxError::MaxGroupLevelExceeded => {
+                write!(f, "Row or column group exceeds Excel's limit of 7 levels.")
+            }
+
             XlsxError::VbaNameError(error) => {
                 write!(f, "VBA name error: '{error}'.")
             }
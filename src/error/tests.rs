@@ -18,12 +18,12 @@ mod error_tests {
         let name = "ERROR";
 
         assert_eq!(
-            XlsxError::RowColumnLimitError.to_string(),
-            "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384)."
+            XlsxError::RowColumnLimitError(name.to_string()).to_string(),
+            "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384): ERROR."
         );
         assert_eq!(
-            XlsxError::RowColumnOrderError.to_string(),
-            "First row or column in range is greater than last row or column."
+            XlsxError::RowColumnOrderError(name.to_string()).to_string(),
+            "First row or column in range is greater than last row or column: ERROR."
         );
         assert_eq!(
             XlsxError::SheetnameCannotBeBlank(name.to_string()).to_string(),
@@ -78,8 +78,8 @@ mod error_tests {
         assert!(matches!(result, Err(XlsxError::IoError(_))));
 
         assert_eq!(
-            format!("{:?}", XlsxError::RowColumnLimitError),
-            "RowColumnLimitError"
+            format!("{:?}", XlsxError::RowColumnLimitError("ERROR".to_string())),
+            "RowColumnLimitError(\"ERROR\")"
         );
     }
 
@@ -0,0 +1,114 @@
+// FeaturePropertyBag - A module for creating the Excel featurePropertyBag.xml
+// file.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+mod tests;
+
+use crate::xmlwriter::XMLWriter;
+
+pub struct FeaturePropertyBag {
+    pub(crate) writer: XMLWriter,
+}
+
+impl FeaturePropertyBag {
+    // -----------------------------------------------------------------------
+    // Crate public methods.
+    // -----------------------------------------------------------------------
+
+    // Create a new FeaturePropertyBag struct.
+    pub(crate) fn new() -> FeaturePropertyBag {
+        let writer = XMLWriter::new();
+
+        FeaturePropertyBag { writer }
+    }
+
+    // -----------------------------------------------------------------------
+    // XML assembly methods.
+    // -----------------------------------------------------------------------
+
+    // Assemble and write the XML file.
+    pub(crate) fn assemble_xml_file(&mut self) {
+        self.writer.xml_declaration();
+
+        // Write the FeaturePropertyBags element.
+        self.write_feature_property_bags();
+
+        // Write the Checkbox bag element.
+        self.write_checkbox_bag();
+
+        // Write the XFControls bag element.
+        self.write_xf_control_bag();
+
+        // Write the XFComplement bag element.
+        self.write_xf_compliment_bag();
+
+        // Write the XFComplements bag element.
+        self.write_xf_compliments_bag();
+
+        // Close the final tag.
+        self.writer.xml_end_tag("FeaturePropertyBags");
+    }
+
+    // Write the <FeaturePropertyBags> element.
+    fn write_feature_property_bags(&mut self) {
+        let attributes = [(
+            "xmlns",
+            "http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag",
+        )];
+
+        self.writer
+            .xml_start_tag("FeaturePropertyBags", &attributes);
+    }
+
+    // Write the Checkbox <bag> element.
+    fn write_checkbox_bag(&mut self) {
+        let attributes = [("type", "Checkbox")];
+
+        self.writer.xml_empty_tag("bag", &attributes);
+    }
+
+    // Write the XFControls <bag> element.
+    fn write_xf_control_bag(&mut self) {
+        let attributes = [("type", "XFControls")];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.write_bag_id("CellControl", "0");
+        self.writer.xml_end_tag("bag");
+    }
+
+    // Write the XFComplement <bag> element.
+    fn write_xf_compliment_bag(&mut self) {
+        let attributes = [("type", "XFComplement")];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.write_bag_id("XFControls", "1");
+        self.writer.xml_end_tag("bag");
+    }
+
+    // Write the XFComplements <bag> element.
+    fn write_xf_compliments_bag(&mut self) {
+        let attributes = [
+            ("type", "XFComplements"),
+            ("extRef", "XFComplementsMapperExtRef"),
+        ];
+
+        self.writer.xml_start_tag("bag", &attributes);
+        self.writer
+            .xml_start_tag("a", &[("k", "MappedFeaturePropertyBags")]);
+        self.write_bag_id("", "2");
+        self.writer.xml_end_tag("a");
+        self.writer.xml_end_tag("bag");
+    }
+
+    // Write the <bagId> element.
+    fn write_bag_id(&mut self, key: &str, id: &str) {
+        if key.is_empty() {
+            self.writer.xml_data_element_only("bagId", id);
+        } else {
+            self.writer.xml_data_element("bagId", id, &[("k", key)]);
+        }
+    }
+}
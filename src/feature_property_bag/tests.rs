@@ -0,0 +1,45 @@
+// FeaturePropertyBag unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod feature_property_bag_tests {
+
+    use crate::feature_property_bag::FeaturePropertyBag;
+    use crate::test_functions::xml_to_vec;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn test_assemble() {
+        let mut feature_property_bag = FeaturePropertyBag::new();
+
+        feature_property_bag.assemble_xml_file();
+
+        let got = feature_property_bag.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <FeaturePropertyBags xmlns="http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag">
+              <bag type="Checkbox"/>
+              <bag type="XFControls">
+                <bagId k="CellControl">0</bagId>
+              </bag>
+              <bag type="XFComplement">
+                <bagId k="XFControls">1</bagId>
+              </bag>
+              <bag type="XFComplements" extRef="XFComplementsMapperExtRef">
+                <a k="MappedFeaturePropertyBags">
+                  <bagId>2</bagId>
+                </a>
+              </bag>
+            </FeaturePropertyBags>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+}
@@ -401,6 +401,8 @@ pub struct FilterCondition {
     pub(crate) list: Vec<FilterData>,
     pub(crate) custom1: Option<FilterData>,
     pub(crate) custom2: Option<FilterData>,
+    pub(crate) top10: Option<FilterTop10>,
+    pub(crate) dynamic: Option<FilterDynamicType>,
 }
 
 #[allow(clippy::new_without_default)]
@@ -420,6 +422,8 @@ impl FilterCondition {
             list: vec![],
             custom1: None,
             custom2: None,
+            top10: None,
+            dynamic: None,
         }
     }
 
@@ -496,6 +500,8 @@ impl FilterCondition {
         self.list
             .push(value.new_filter_data(FilterCriteria::EqualTo));
         self.is_list_filter = true;
+        self.top10 = None;
+        self.dynamic = None;
         self
     }
 
@@ -624,6 +630,8 @@ impl FilterCondition {
     pub fn add_list_blanks_filter(mut self) -> FilterCondition {
         self.should_match_blanks = true;
         self.is_list_filter = true;
+        self.top10 = None;
+        self.dynamic = None;
         self
     }
 
@@ -709,6 +717,8 @@ impl FilterCondition {
         }
 
         self.is_list_filter = false;
+        self.top10 = None;
+        self.dynamic = None;
         self
     }
 
@@ -724,6 +734,180 @@ impl FilterCondition {
         self.is_list_filter = false;
         self
     }
+
+    /// Add a "Top 10" filter condition.
+    ///
+    /// Add a filter to show the top or bottom `count` items, or `count`
+    /// percent of items, in a numeric column. This is the same as the "Number
+    /// Filters -> Top 10..." option in Excel. Despite the name the number of
+    /// items can be anything in the range 1 to 500.
+    ///
+    /// This filter replaces any list or custom filter conditions.
+    ///
+    /// # Parameters
+    ///
+    /// - `count`: The number of items, or the percentage of items, to show.
+    ///   The range is 1 to 500 for items and 1 to 100 for percentages.
+    /// - `is_percent`: Treat `count` as a percentage of the items.
+    /// - `is_bottom`: Show the bottom, smallest, items instead of the top,
+    ///   largest, items.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting an autofilter to show the
+    /// top 3 values in a column.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_filter_column8.rs
+    /// #
+    /// # use rust_xlsxwriter::{FilterCondition, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet with some sample data to filter.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_string(0, 0, "Region")?;
+    /// #     worksheet.write_string(1, 0, "East")?;
+    /// #     worksheet.write_string(2, 0, "West")?;
+    /// #     worksheet.write_string(3, 0, "East")?;
+    /// #     worksheet.write_string(4, 0, "North")?;
+    /// #     worksheet.write_string(5, 0, "South")?;
+    /// #     worksheet.write_string(6, 0, "West")?;
+    /// #
+    /// #     worksheet.write_string(0, 1, "Sales")?;
+    /// #     worksheet.write_number(1, 1, 3000)?;
+    /// #     worksheet.write_number(2, 1, 8000)?;
+    /// #     worksheet.write_number(3, 1, 5000)?;
+    /// #     worksheet.write_number(4, 1, 4000)?;
+    /// #     worksheet.write_number(5, 1, 7000)?;
+    /// #     worksheet.write_number(6, 1, 9000)?;
+    /// #
+    /// #     // Set the autofilter.
+    /// #     worksheet.autofilter(0, 0, 6, 1)?;
+    /// #
+    ///     // Set a filter condition to show the top 3 sales values.
+    ///     let filter_condition = FilterCondition::new().add_top10_filter(3, false, false);
+    ///     worksheet.filter_column(1, &filter_condition)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_top10_filter(
+        mut self,
+        count: u16,
+        is_percent: bool,
+        is_bottom: bool,
+    ) -> FilterCondition {
+        let max = if is_percent { 100 } else { 500 };
+
+        if !(1..=max).contains(&count) {
+            eprintln!("Top 10 filter count {count} must be in the range 1 to {max}.");
+            return self;
+        }
+
+        self.top10 = Some(FilterTop10 {
+            count,
+            is_percent,
+            is_bottom,
+        });
+        self.dynamic = None;
+        self.is_list_filter = false;
+        self
+    }
+
+    /// Add a filter condition to show values above the column average.
+    ///
+    /// This is the same as the "Number Filters -> Above Average" option in
+    /// Excel. It replaces any list or custom filter conditions.
+    ///
+    pub fn add_above_average_filter(mut self) -> FilterCondition {
+        self.dynamic = Some(FilterDynamicType::AboveAverage);
+        self.top10 = None;
+        self.is_list_filter = false;
+        self
+    }
+
+    /// Add a filter condition to show values below the column average.
+    ///
+    /// This is the same as the "Number Filters -> Below Average" option in
+    /// Excel. It replaces any list or custom filter conditions.
+    ///
+    pub fn add_below_average_filter(mut self) -> FilterCondition {
+        self.dynamic = Some(FilterDynamicType::BelowAverage);
+        self.top10 = None;
+        self.is_list_filter = false;
+        self
+    }
+
+    /// Add a dynamic filter condition.
+    ///
+    /// Add a filter that Excel evaluates relative to the column data or to the
+    /// current date, such as "Today", "This Week" or "Year to Date". These are
+    /// the options in the "Date Filters" menu in Excel. See
+    /// [`FilterDynamicType`] for the supported types.
+    ///
+    /// This filter replaces any list or custom filter conditions.
+    ///
+    /// Note, rows that don't match the date based filters aren't hidden
+    /// automatically since the result depends on the date when the file is
+    /// opened. The filter is applied when the user reapplies it in Excel. If
+    /// required you can hide the rows with
+    /// [`Worksheet::set_row_hidden()`](crate::Worksheet::set_row_hidden).
+    ///
+    /// # Parameters
+    ///
+    /// - `dynamic_type`: A [`FilterDynamicType`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting an autofilter with a
+    /// dynamic filter to show dates in the current month.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_filter_column9.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     ExcelDateTime, FilterCondition, FilterDynamicType, Format, Workbook, XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let date_format = Format::new().set_num_format("yyyy-mm-dd");
+    /// #
+    /// #     // Add a worksheet with some sample data to filter.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.set_column_width(0, 12)?;
+    /// #     worksheet.write_string(0, 0, "Date")?;
+    /// #
+    /// #     for (row, day) in (1..=6).zip([1, 9, 18, 27, 36, 45]) {
+    /// #         let date = ExcelDateTime::from_serial_datetime(45658 + day)?;
+    /// #         worksheet.write_datetime_with_format(row, 0, &date, &date_format)?;
+    /// #     }
+    /// #
+    /// #     // Set the autofilter.
+    /// #     worksheet.autofilter(0, 0, 6, 0)?;
+    /// #
+    ///     // Set a filter condition to show dates in the current month.
+    ///     let filter_condition =
+    ///         FilterCondition::new().add_dynamic_filter(FilterDynamicType::ThisMonth);
+    ///     worksheet.filter_column(0, &filter_condition)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_dynamic_filter(mut self, dynamic_type: FilterDynamicType) -> FilterCondition {
+        self.dynamic = Some(dynamic_type);
+        self.top10 = None;
+        self.is_list_filter = false;
+        self
+    }
 }
 
 /// The `FilterCriteria` enum defines logical filter criteria used in an
@@ -796,6 +980,179 @@ impl FilterCriteria {
     }
 }
 
+// Struct to represent a "Top 10" filter condition.
+#[derive(Clone, Copy)]
+pub(crate) struct FilterTop10 {
+    pub(crate) count: u16,
+    pub(crate) is_percent: bool,
+    pub(crate) is_bottom: bool,
+}
+
+/// The `FilterDynamicType` enum defines the Excel dynamic filter types.
+///
+/// These are used with the [`FilterCondition`]
+/// [`add_dynamic_filter()`](FilterCondition::add_dynamic_filter) method to
+/// create filters that are evaluated by Excel relative to the column data or
+/// to the current date. They correspond to the options in the "Date Filters"
+/// menu, and the "Above/Below Average" number filters, in Excel.
+///
+/// Excel weeks start on Sunday.
+///
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum FilterDynamicType {
+    /// Show numbers above the average of the column.
+    AboveAverage,
+
+    /// Show numbers below the average of the column.
+    BelowAverage,
+
+    /// Show dates for yesterday.
+    Yesterday,
+
+    /// Show dates for today.
+    Today,
+
+    /// Show dates for tomorrow.
+    Tomorrow,
+
+    /// Show dates in the previous week.
+    LastWeek,
+
+    /// Show dates in the current week.
+    ThisWeek,
+
+    /// Show dates in the next week.
+    NextWeek,
+
+    /// Show dates in the previous month.
+    LastMonth,
+
+    /// Show dates in the current month.
+    ThisMonth,
+
+    /// Show dates in the next month.
+    NextMonth,
+
+    /// Show dates in the previous quarter.
+    LastQuarter,
+
+    /// Show dates in the current quarter.
+    ThisQuarter,
+
+    /// Show dates in the next quarter.
+    NextQuarter,
+
+    /// Show dates in the previous year.
+    LastYear,
+
+    /// Show dates in the current year.
+    ThisYear,
+
+    /// Show dates in the next year.
+    NextYear,
+
+    /// Show dates from the start of the current year to today.
+    YearToDate,
+
+    /// Show dates in quarter 1 of any year.
+    Quarter1,
+
+    /// Show dates in quarter 2 of any year.
+    Quarter2,
+
+    /// Show dates in quarter 3 of any year.
+    Quarter3,
+
+    /// Show dates in quarter 4 of any year.
+    Quarter4,
+
+    /// Show dates in January of any year.
+    January,
+
+    /// Show dates in February of any year.
+    February,
+
+    /// Show dates in March of any year.
+    March,
+
+    /// Show dates in April of any year.
+    April,
+
+    /// Show dates in May of any year.
+    May,
+
+    /// Show dates in June of any year.
+    June,
+
+    /// Show dates in July of any year.
+    July,
+
+    /// Show dates in August of any year.
+    August,
+
+    /// Show dates in September of any year.
+    September,
+
+    /// Show dates in October of any year.
+    October,
+
+    /// Show dates in November of any year.
+    November,
+
+    /// Show dates in December of any year.
+    December,
+}
+
+impl FilterDynamicType {
+    pub(crate) fn as_str(self) -> &'static str {
+        match self {
+            FilterDynamicType::AboveAverage => "aboveAverage",
+            FilterDynamicType::BelowAverage => "belowAverage",
+            FilterDynamicType::Yesterday => "yesterday",
+            FilterDynamicType::Today => "today",
+            FilterDynamicType::Tomorrow => "tomorrow",
+            FilterDynamicType::LastWeek => "lastWeek",
+            FilterDynamicType::ThisWeek => "thisWeek",
+            FilterDynamicType::NextWeek => "nextWeek",
+            FilterDynamicType::LastMonth => "lastMonth",
+            FilterDynamicType::ThisMonth => "thisMonth",
+            FilterDynamicType::NextMonth => "nextMonth",
+            FilterDynamicType::LastQuarter => "lastQuarter",
+            FilterDynamicType::ThisQuarter => "thisQuarter",
+            FilterDynamicType::NextQuarter => "nextQuarter",
+            FilterDynamicType::LastYear => "lastYear",
+            FilterDynamicType::ThisYear => "thisYear",
+            FilterDynamicType::NextYear => "nextYear",
+            FilterDynamicType::YearToDate => "yearToDate",
+            FilterDynamicType::Quarter1 => "Q1",
+            FilterDynamicType::Quarter2 => "Q2",
+            FilterDynamicType::Quarter3 => "Q3",
+            FilterDynamicType::Quarter4 => "Q4",
+            FilterDynamicType::January => "M1",
+            FilterDynamicType::February => "M2",
+            FilterDynamicType::March => "M3",
+            FilterDynamicType::April => "M4",
+            FilterDynamicType::May => "M5",
+            FilterDynamicType::June => "M6",
+            FilterDynamicType::July => "M7",
+            FilterDynamicType::August => "M8",
+            FilterDynamicType::September => "M9",
+            FilterDynamicType::October => "M10",
+            FilterDynamicType::November => "M11",
+            FilterDynamicType::December => "M12",
+        }
+    }
+
+    // Check if the filter is relative to the column average, rather than a
+    // date.
+    pub(crate) fn is_average(self) -> bool {
+        matches!(
+            self,
+            FilterDynamicType::AboveAverage | FilterDynamicType::BelowAverage
+        )
+    }
+}
+
 /// The FilterData struct represents data types used in Excel's filters.
 ///
 /// The FilterData struct is a simple data type to allow a generic mapping
@@ -0,0 +1,201 @@
+// font_metrics - A module for registering the character widths of custom
+// fonts.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+mod tests;
+
+use std::collections::HashMap;
+
+/// The `FontMetrics` struct is used to register the character widths of a
+/// custom font with [`Workbook::register_font()`](crate::Workbook::register_font).
+///
+/// Excel doesn't store the rendered width of strings in a file so
+/// `rust_xlsxwriter` estimates it, for [`Worksheet::autofit()`] and for the
+/// conversion of column widths between character units and pixels, using a
+/// table of character widths for the default Excel font, Calibri 11. This
+/// gives poor results for corporate or other non-standard fonts that have
+/// different proportions.
+///
+/// `FontMetrics` allows you to supply a table of per-character pixel widths for
+/// a font at a given size. Any cell with a [`Format`](crate::Format) that uses
+/// the font name is then measured with the registered widths, scaled to the
+/// font size of the format. Characters that aren't in the table use a default
+/// width.
+///
+/// The font family and character set can also be registered. These are written
+/// in the font declarations of the formats that use the font so that Excel can
+/// match the font correctly on machines where it is installed.
+///
+/// [`Worksheet::autofit()`]: crate::Worksheet::autofit
+///
+/// # Examples
+///
+/// The following example demonstrates registering the character widths of a
+/// custom font so that `autofit()` gives a better column width.
+///
+/// ```
+/// # // This code is available in examples/doc_workbook_register_font.rs
+/// #
+/// # use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+///     let mut workbook = Workbook::new();
+///
+///     // Register the widths of a wide corporate font at 11pt.
+///     let metrics = FontMetrics::new("Corporate Sans", 11.0)
+///         .set_char_widths(('0'..='9').map(|digit| (digit, 9)))
+///         .set_char_widths(('a'..='z').map(|letter| (letter, 10)))
+///         .set_char_widths(('A'..='Z').map(|letter| (letter, 12)))
+///         .set_char_width(' ', 4)
+///         .set_font_family(1);
+///
+///     workbook.register_font(&metrics);
+///
+///     let format = Format::new().set_font_name("Corporate Sans");
+///
+///     let worksheet = workbook.add_worksheet();
+///     worksheet.write_with_format(0, 0, "Quarterly Revenue", &format)?;
+///     worksheet.write_with_format(1, 0, 12345.5, &format)?;
+///
+///     // Autofit the columns using the registered font widths.
+///     worksheet.autofit();
+/// #
+/// #     workbook.save("workbook.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, PartialEq)]
+pub struct FontMetrics {
+    pub(crate) name: String,
+    pub(crate) size: f64,
+    pub(crate) char_widths: HashMap<char, u16>,
+    pub(crate) default_width: u16,
+    pub(crate) family: Option<u8>,
+    pub(crate) charset: Option<u8>,
+}
+
+impl FontMetrics {
+    /// Create a new `FontMetrics` object for a font name and size.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The font name as used in [`Format::set_font_name()`].
+    /// - `size`: The font size, in points, that the character widths were
+    ///   measured at. Values less than or equal to 0.0 are set to 11.0.
+    ///
+    /// [`Format::set_font_name()`]: crate::Format::set_font_name
+    ///
+    pub fn new(name: impl Into<String>, size: f64) -> FontMetrics {
+        FontMetrics {
+            name: name.into(),
+            size: if size > 0.0 { size } else { 11.0 },
+            char_widths: HashMap::new(),
+            default_width: 8,
+            family: None,
+            charset: None,
+        }
+    }
+
+    /// Set the pixel width of a character.
+    ///
+    /// # Parameters
+    ///
+    /// - `character`: The character to set the width for.
+    /// - `width`: The width of the character in pixels, at the font size of
+    ///   the metrics.
+    ///
+    pub fn set_char_width(mut self, character: char, width: u16) -> FontMetrics {
+        self.char_widths.insert(character, width);
+        self
+    }
+
+    /// Set the pixel widths of several characters.
+    ///
+    /// # Parameters
+    ///
+    /// - `widths`: An iterator of `(character, width)` pairs, with the widths
+    ///   in pixels at the font size of the metrics.
+    ///
+    pub fn set_char_widths(mut self, widths: impl IntoIterator<Item = (char, u16)>) -> FontMetrics {
+        self.char_widths.extend(widths);
+        self
+    }
+
+    /// Set the pixel width of characters that aren't in the width table.
+    ///
+    /// The default is 8 pixels, the same as the width used for unknown
+    /// characters in the default Calibri table.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The default character width in pixels, at the font size of
+    ///   the metrics.
+    ///
+    pub fn set_default_char_width(mut self, width: u16) -> FontMetrics {
+        self.default_width = width;
+        self
+    }
+
+    /// Set the font family to declare for the font.
+    ///
+    /// This is the same as [`Format::set_font_family()`] and is applied to
+    /// all formats that use the font. The common values are 1 for serif fonts
+    /// and 2 for sans serif fonts.
+    ///
+    /// [`Format::set_font_family()`]: crate::Format::set_font_family
+    ///
+    /// # Parameters
+    ///
+    /// - `font_family`: The font family property.
+    ///
+    pub fn set_font_family(mut self, font_family: u8) -> FontMetrics {
+        self.family = Some(font_family);
+        self
+    }
+
+    /// Set the font character set to declare for the font.
+    ///
+    /// This is the same as [`Format::set_font_charset()`] and is applied to
+    /// all formats that use the font.
+    ///
+    /// [`Format::set_font_charset()`]: crate::Format::set_font_charset
+    ///
+    /// # Parameters
+    ///
+    /// - `font_charset`: The font character set property.
+    ///
+    pub fn set_font_charset(mut self, font_charset: u8) -> FontMetrics {
+        self.charset = Some(font_charset);
+        self
+    }
+
+    // Get the pixel width of a string at a given font size.
+    pub(crate) fn string_width(&self, string: &str, size: f64) -> u16 {
+        let width: u32 = string
+            .chars()
+            .map(|char| u32::from(self.char_width(char)))
+            .sum();
+
+        (f64::from(width) * size / self.size).round() as u16
+    }
+
+    // Get the maximum pixel width of the digits 0-9 at a given font size. This
+    // is the unit that Excel uses for column widths.
+    pub(crate) fn max_digit_width(&self, size: f64) -> f64 {
+        let width = ('0'..='9').map(|digit| self.char_width(digit)).max();
+
+        f64::from(width.unwrap_or(self.default_width)) * size / self.size
+    }
+
+    // Get the pixel width of a character at the font size of the metrics.
+    fn char_width(&self, char: char) -> u16 {
+        *self.char_widths.get(&char).unwrap_or(&self.default_width)
+    }
+}
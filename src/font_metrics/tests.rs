@@ -0,0 +1,28 @@
+// FontMetrics unit tests.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#[cfg(test)]
+mod font_metrics_tests {
+    use crate::FontMetrics;
+
+    #[test]
+    fn test_string_width() {
+        let metrics = FontMetrics::new("Wide", 10.0)
+            .set_char_widths([('a', 10), ('b', 12)])
+            .set_char_width('0', 8)
+            .set_default_char_width(6);
+
+        assert_eq!(0, metrics.string_width("", 10.0));
+        assert_eq!(22, metrics.string_width("ab", 10.0));
+        assert_eq!(28, metrics.string_width("abc", 10.0));
+        assert_eq!(44, metrics.string_width("ab", 20.0));
+        assert_eq!(11, metrics.string_width("a", 11.0));
+
+        // The digits 1-9 use the default width.
+        assert_eq!(8.0, metrics.max_digit_width(10.0));
+        assert_eq!(16.0, metrics.max_digit_width(20.0));
+    }
+}
@@ -449,6 +449,10 @@ pub struct Format {
     // Non-UI properties.
     pub(crate) quote_prefix: bool,
     pub(crate) is_dxf_format: bool,
+    pub(crate) checkbox: bool,
+
+    // Marks the implicit url format, see Workbook::set_hyperlink_format().
+    pub(crate) is_default_hyperlink: bool,
 }
 
 impl Hash for Format {
@@ -463,6 +467,8 @@ impl Hash for Format {
         self.hidden.hash(state);
         self.locked.hash(state);
         self.quote_prefix.hash(state);
+        self.checkbox.hash(state);
+        self.is_default_hyperlink.hash(state);
     }
 }
 
@@ -477,6 +483,8 @@ impl PartialEq for Format {
             && self.hidden == other.hidden
             && self.locked == other.locked
             && self.quote_prefix == other.quote_prefix
+            && self.checkbox == other.checkbox
+            && self.is_default_hyperlink == other.is_default_hyperlink
     }
 }
 
@@ -529,6 +537,8 @@ impl Format {
             num_format_index: 0,
             quote_prefix: false,
             is_dxf_format: false,
+            checkbox: false,
+            is_default_hyperlink: false,
         }
     }
 
@@ -602,6 +612,13 @@ impl Format {
         self.hidden || !self.locked
     }
 
+    // Set the checkbox property for the cell format. This is set internally by
+    // Worksheet::insert_checkbox().
+    pub(crate) fn set_checkbox(mut self) -> Format {
+        self.checkbox = true;
+        self
+    }
+
     // Check if the format is in the default/unmodified condition.
     pub(crate) fn is_default(&self) -> bool {
         static DEFAULT_STATE: OnceLock<Format> = OnceLock::new();
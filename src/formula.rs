@@ -10,6 +10,11 @@ mod tests;
 
 use std::{collections::HashMap, sync::OnceLock};
 
+#[cfg(feature = "serde")]
+use crate::serializer::SERIALIZED_FORMULA_NAME;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
+
 /// The `Formula` struct is used to define a worksheet formula.
 ///
 /// The `Formula` struct creates a formula type that can be used to write
@@ -1191,3 +1196,23 @@ impl From<&Formula> for Formula {
         (*value).clone()
     }
 }
+
+/// Implementation of the `serde::Serialize` trait for `Formula`.
+///
+/// This allows `Formula` struct fields to be serialized to a worksheet as
+/// formula cells via [`Worksheet::serialize()`](crate::Worksheet::serialize).
+/// Other Serde serializers will see the formula as a tuple of the formula
+/// string and the formula result.
+///
+#[cfg(feature = "serde")]
+impl Serialize for Formula {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(
+            SERIALIZED_FORMULA_NAME,
+            &(&self.formula_string, &*self.result),
+        )
+    }
+}
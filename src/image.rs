@@ -75,6 +75,7 @@ pub struct Image {
     pub(crate) data: Vec<u8>,
     pub(crate) drawing_type: DrawingType,
     pub(crate) url: Option<Url>,
+    pub(crate) header_options: HeaderImageOptions,
 }
 
 impl Image {
@@ -267,6 +268,7 @@ impl Image {
             data: buffer.to_vec(),
             drawing_type: DrawingType::Image,
             url: None,
+            header_options: HeaderImageOptions::default(),
         };
 
         Self::process_image(&mut image)?;
@@ -847,20 +849,46 @@ impl Image {
             text: self.vml_name(),
             header_position: self.vml_position(),
             is_scaled: self.is_scaled(),
+            image_crop: self.vml_crop(),
+            image_color_mode: self.header_options.color_mode,
             ..Default::default()
         }
     }
 
     // Get the image width as used by header/footer VML.
     fn vml_width(&self) -> f64 {
-        // Scale the image dimension relative to 96dpi.
-        self.width * 96.0 / self.width_dpi * self.scale_width
+        let options = &self.header_options;
+        let scale = options.scale_width.unwrap_or(self.scale_width);
+
+        // Scale the image dimension relative to 96dpi, less any cropping.
+        let width = self.width * 96.0 / self.width_dpi;
+        (width - (f64::from(options.crop_left) + f64::from(options.crop_right))) * scale
     }
 
     // Get the image height as used by header/footer VML.
     fn vml_height(&self) -> f64 {
-        // Scale the image dimension relative to 96dpi.
-        self.height * 96.0 / self.height_dpi * self.scale_height
+        let options = &self.header_options;
+        let scale = options.scale_height.unwrap_or(self.scale_height);
+
+        // Scale the image dimension relative to 96dpi, less any cropping.
+        let height = self.height * 96.0 / self.height_dpi;
+        (height - (f64::from(options.crop_top) + f64::from(options.crop_bottom))) * scale
+    }
+
+    // Get the header/footer image cropping, in pixels, as VML fixed point
+    // fractions of the image size, in the order top, bottom, left, right.
+    fn vml_crop(&self) -> [u32; 4] {
+        let options = &self.header_options;
+        let width = self.width * 96.0 / self.width_dpi;
+        let height = self.height * 96.0 / self.height_dpi;
+        let fraction = |crop: u32, size: f64| (f64::from(crop) / size * 65536.0).round() as u32;
+
+        [
+            fraction(options.crop_top, height),
+            fraction(options.crop_bottom, height),
+            fraction(options.crop_left, width),
+            fraction(options.crop_right, width),
+        ]
     }
 
     // Get the image short name as used by header/footer VML.
@@ -870,7 +898,11 @@ impl Image {
 
     // Check if the image scale has changed. Mainly used by header/footer VML.
     pub(crate) fn is_scaled(&self) -> bool {
-        self.scale_height != 1.0 || self.scale_width != 1.0
+        let options = &self.header_options;
+        let scale_width = options.scale_width.unwrap_or(self.scale_width);
+        let scale_height = options.scale_height.unwrap_or(self.scale_height);
+
+        scale_height != 1.0 || scale_width != 1.0
     }
 
     // Get the image position string as used by header/footer VML.
@@ -1165,6 +1197,184 @@ pub enum HeaderImagePosition {
     Right,
 }
 
+/// The `HeaderImageOptions` struct is used to set the scaling, cropping and
+/// color mode of a header or footer image.
+///
+/// Header and footer images are inserted with the size and scale of the
+/// [`Image`] and with their original colors by
+/// [`Worksheet::set_header_image()`](crate::Worksheet::set_header_image) and
+/// [`Worksheet::set_footer_image()`](crate::Worksheet::set_footer_image). The
+/// `HeaderImageOptions` struct can be used with the
+/// [`Worksheet::set_header_image_with_options()`](crate::Worksheet::set_header_image_with_options)
+/// and
+/// [`Worksheet::set_footer_image_with_options()`](crate::Worksheet::set_footer_image_with_options)
+/// methods to change these properties for the image in each
+/// [`HeaderImagePosition`]. This equates to the following options in the
+/// Excel "Format Picture" dialog for header/footer images:
+///
+/// - Size: Scale height and width.
+/// - Picture: Crop from left, top, right and bottom.
+/// - Picture: Image control color.
+///
+/// # Examples
+///
+/// The following example demonstrates adding a washed out, or watermark, image
+/// to a worksheet header.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_set_header_image_with_options.rs
+/// #
+/// # use rust_xlsxwriter::{
+/// #     HeaderImageColorMode, HeaderImageOptions, HeaderImagePosition, Image, Workbook, XlsxError,
+/// # };
+/// #
+/// # fn main() -> Result<(), XlsxError> {
+/// #     let mut workbook = Workbook::new();
+/// #     let worksheet = workbook.add_worksheet();
+/// #
+///     let image = Image::new("examples/rust_logo.png")?;
+///
+///     // Scale the image and wash it out so it can be used as a watermark.
+///     let options = HeaderImageOptions::new()
+///         .set_scale_width(2.0)
+///         .set_scale_height(2.0)
+///         .set_color_mode(HeaderImageColorMode::Washout);
+///
+///     // Insert the watermark image in the header.
+///     worksheet.set_header("&C&[Picture]");
+///     worksheet.set_header_image_with_options(&image, HeaderImagePosition::Center, &options)?;
+/// #
+/// #     // Set Page View mode so the watermark is visible.
+/// #     worksheet.set_view_page_layout();
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct HeaderImageOptions {
+    pub(crate) scale_width: Option<f64>,
+    pub(crate) scale_height: Option<f64>,
+    pub(crate) crop_left: u32,
+    pub(crate) crop_top: u32,
+    pub(crate) crop_right: u32,
+    pub(crate) crop_bottom: u32,
+    pub(crate) color_mode: HeaderImageColorMode,
+}
+
+impl HeaderImageOptions {
+    /// Create a new `HeaderImageOptions` object to use with the
+    /// [`Worksheet::set_header_image_with_options()`](crate::Worksheet::set_header_image_with_options)
+    /// and
+    /// [`Worksheet::set_footer_image_with_options()`](crate::Worksheet::set_footer_image_with_options)
+    /// methods.
+    ///
+    pub fn new() -> HeaderImageOptions {
+        HeaderImageOptions::default()
+    }
+
+    /// Set the height scale for the header/footer image.
+    ///
+    /// Set the height scale for the image relative to 1.0 (i.e. 100%). This
+    /// overrides any scale set with [`Image::set_scale_height()`]. Values less
+    /// than or equal to 0.0 are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: The scale ratio.
+    ///
+    pub fn set_scale_height(mut self, scale: f64) -> HeaderImageOptions {
+        if scale > 0.0 {
+            self.scale_height = Some(scale);
+        }
+
+        self
+    }
+
+    /// Set the width scale for the header/footer image.
+    ///
+    /// Set the width scale for the image relative to 1.0 (i.e. 100%). This
+    /// overrides any scale set with [`Image::set_scale_width()`]. Values less
+    /// than or equal to 0.0 are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `scale`: The scale ratio.
+    ///
+    pub fn set_scale_width(mut self, scale: f64) -> HeaderImageOptions {
+        if scale > 0.0 {
+            self.scale_width = Some(scale);
+        }
+
+        self
+    }
+
+    /// Crop the edges of the header/footer image.
+    ///
+    /// The crop amounts are in pixels relative to the native size of the image
+    /// at 96 DPI and are applied before any scaling. The total cropping in
+    /// each direction must be less than the image width or height. This is
+    /// checked when the image is added to the header or footer.
+    ///
+    /// # Parameters
+    ///
+    /// - `left`: The number of pixels to crop from the left edge.
+    /// - `top`: The number of pixels to crop from the top edge.
+    /// - `right`: The number of pixels to crop from the right edge.
+    /// - `bottom`: The number of pixels to crop from the bottom edge.
+    ///
+    pub fn set_crop(mut self, left: u32, top: u32, right: u32, bottom: u32) -> HeaderImageOptions {
+        self.crop_left = left;
+        self.crop_top = top;
+        self.crop_right = right;
+        self.crop_bottom = bottom;
+
+        self
+    }
+
+    /// Set the color mode of the header/footer image.
+    ///
+    /// See [`HeaderImageColorMode`] for the available options. The
+    /// [`HeaderImageColorMode::Washout`] mode is commonly used for watermark
+    /// images.
+    ///
+    /// # Parameters
+    ///
+    /// - `color_mode`: A [`HeaderImageColorMode`] enum value.
+    ///
+    pub fn set_color_mode(mut self, color_mode: HeaderImageColorMode) -> HeaderImageOptions {
+        self.color_mode = color_mode;
+
+        self
+    }
+}
+
+/// The `HeaderImageColorMode` enum defines the color mode of a header or
+/// footer image.
+///
+/// This equates to the "Image control: Color" option in the Excel "Format
+/// Picture" dialog for header/footer images. Used with
+/// [`HeaderImageOptions::set_color_mode()`].
+///
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum HeaderImageColorMode {
+    /// Display the image in its original colors. This is the default.
+    #[default]
+    Automatic,
+
+    /// Display the image in shades of gray.
+    Grayscale,
+
+    /// Display the image in black and white only.
+    BlackAndWhite,
+
+    /// Display the image with reduced contrast and increased brightness so
+    /// that it can be used as a watermark behind the cell data.
+    Washout,
+}
+
 #[derive(Clone, Debug)]
 pub(crate) enum XlsxImageType {
     Unknown,
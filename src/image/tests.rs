@@ -9,7 +9,7 @@ mod image_tests {
 
     use crate::XlsxError;
 
-    use crate::Image;
+    use crate::{HeaderImageColorMode, HeaderImageOptions, HeaderImagePosition, Image, Worksheet};
 
     #[test]
     fn test_images() {
@@ -93,4 +93,43 @@ mod image_tests {
         let image = Image::new(filename);
         assert!(matches!(image, Err(XlsxError::ImageDimensionError)));
     }
+
+    #[test]
+    fn header_image_options() {
+        let image = Image::new("tests/input/images/red.png").unwrap();
+        let mut worksheet = Worksheet::new();
+        worksheet.set_header("&L&[Picture]");
+
+        let options = HeaderImageOptions::new()
+            .set_scale_width(2.0)
+            .set_crop(8, 0, 8, 4)
+            .set_color_mode(HeaderImageColorMode::Grayscale);
+
+        worksheet
+            .set_header_image_with_options(&image, HeaderImagePosition::Left, &options)
+            .unwrap();
+
+        let vml_info = worksheet.header_footer_images[0]
+            .as_ref()
+            .unwrap()
+            .vml_info();
+        assert_eq!(32.0, vml_info.width);
+        assert_eq!(28.0, vml_info.height);
+        assert_eq!([0, 8192, 16384, 16384], vml_info.image_crop);
+        assert_eq!(HeaderImageColorMode::Grayscale, vml_info.image_color_mode);
+        assert!(vml_info.is_scaled);
+
+        // Cropping the whole image is an error, including for cropping values
+        // that would overflow when added.
+        for (left, top, right, bottom) in [(16, 0, 16, 0), (u32::MAX, 0, 1, 0), (0, u32::MAX, 0, 1)]
+        {
+            let options = HeaderImageOptions::new().set_crop(left, top, right, bottom);
+            let result = worksheet.set_header_image_with_options(
+                &image,
+                HeaderImagePosition::Left,
+                &options,
+            );
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
 }
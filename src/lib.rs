@@ -60,7 +60,7 @@
 //! - [`Worksheet`]: The main spreadsheet canvas for writing data and objects to
 //!   a worksheet.
 //! - [`Working with Worksheets`](crate::worksheet): A higher level introduction
-//!    to creating and working with worksheets.
+//!   to creating and working with worksheets.
 //! </p>
 //!
 //! <p>
@@ -176,32 +176,92 @@
 //! - `default`: Includes all the standard functionality. This has a dependency
 //!   on the `zip` crate only.
 //! - `serde`: Adds supports for Serde serialization. This is off by default.
+//! - `serde_json`: Adds support for writing arrays of `serde_json::Value`
+//!   objects to a worksheet. This is off by default.
+//! - `calamine`: Adds support for loading cell data read by `calamine` from
+//!   an existing workbook into a worksheet. This is off by default.
 //! - `chrono`: Adds supports for Chrono date/time types to the API. This is off
 //!   by default.
+//! - `time`: Adds supports for `time` crate date/time types to the API. This
+//!   is off by default.
 //! - `zlib`: Adds a dependency on zlib and a C compiler. This includes the same
 //!   features as `default` but is 1.5x faster for large files.
+//! - `ndarray`: Adds support for writing `ndarray` 2D arrays to a worksheet.
+//!   This is off by default.
+//! - `num-bigint`: Adds support for writing `num_bigint::BigInt` values. This
+//!   is off by default.
 //! - `polars`: Add support for mapping between `PolarsError` and
 //!   `rust_xlsxwriter::XlsxError` to make code that handles both types of error
 //!   easier to write.
 //! - `wasm`: Adds a dependency on `js-sys` and `wasm-bindgen` to allow
-//!   compilation for wasm/JavaScript targets.
+//!   compilation for wasm/JavaScript targets. See [WASM
+//!   support](#wasm-support) below.
+//! - `rust_decimal`: Adds support for writing `rust_decimal::Decimal`
+//!   values. This is off by default.
 //! - `ryu`: Adds a dependency on `ryu`. This speeds up writing numeric
 //!   worksheet cells for large data files. It gives a performance boost above
 //!   300,000 numeric cells and can be up to 30% faster than the default number
 //!   formatting for 5,000,000 numeric cells.
 //!
+//! ## WASM support
+//!
+//! The `rust_xlsxwriter` crate can be compiled for the
+//! `wasm32-unknown-unknown` target, with the `wasm` feature enabled, to
+//! generate xlsx files client side in a browser or other JavaScript runtime.
+//! The `wasm` feature uses `js-sys` to get the current time for the file
+//! metadata and converts [`XlsxError`] into a `wasm_bindgen::JsValue` so
+//! that errors can be propagated to JavaScript with `?`.
+//!
+//! The `wasm32-unknown-unknown` target doesn't have a filesystem so the
+//! methods that read or write files will return an error. Instead, use the
+//! following buffer based methods:
+//!
+//! | Filesystem method                             | WASM equivalent                                             |
+//! | :-------------------------------------------- | :---------------------------------------------------------- |
+//! | [`Workbook::save()`]                          | [`Workbook::save_to_buffer()`], [`Workbook::save_to_writer()`] |
+//! | [`Image::new()`]                              | [`Image::new_from_buffer()`]                                |
+//! | [`Workbook::add_vba_project()`]               | [`Workbook::add_vba_project_from_buffer()`]                 |
+//! | [`Workbook::add_vba_project_with_signature()`] | [`Workbook::add_vba_project_with_signature_from_buffer()`] |
+//!
+//! Worksheet data is generated and zipped in memory and no temporary files
+//! are used. Worksheets are assembled on a single thread for WASM targets.
+//!
+//! The `Vec<u8>` returned by [`Workbook::save_to_buffer()`] is converted to a
+//! JavaScript `Uint8Array` by `wasm-bindgen` and can be used to create a
+//! `Blob` for download:
+//!
+//! ```ignore
+//! use rust_xlsxwriter::Workbook;
+//! use wasm_bindgen::prelude::*;
+//!
+//! #[wasm_bindgen]
+//! pub fn create_xlsx(name: &str) -> Result<Vec<u8>, JsValue> {
+//!     let mut workbook = Workbook::new();
+//!     let worksheet = workbook.add_worksheet();
+//!
+//!     worksheet.write(0, 0, name)?;
+//!
+//!     Ok(workbook.save_to_buffer()?)
+//! }
+//! ```
+//!
+mod accessibility;
 mod app;
 mod button;
+mod cell_ref;
 mod color;
 mod comment;
 mod content_types;
 mod core;
+mod csv;
 mod custom;
 mod data_validation;
 mod datetime;
 mod drawing;
 mod error;
+mod feature_property_bag;
 mod filter;
+mod font_metrics;
 mod format;
 mod formula;
 mod image;
@@ -215,6 +275,7 @@ mod rich_value;
 mod rich_value_rel;
 mod rich_value_structure;
 mod rich_value_types;
+mod row_source;
 mod shape;
 mod shared_strings;
 mod shared_strings_table;
@@ -244,18 +305,23 @@ pub mod worksheet;
 mod test_functions;
 
 // Re-export the public APIs.
+pub use accessibility::*;
 pub use button::*;
+pub use cell_ref::*;
 pub use color::*;
+pub use csv::*;
 pub use data_validation::*;
 pub use datetime::*;
 pub use error::*;
 pub use filter::*;
+pub use font_metrics::*;
 pub use format::*;
 pub use formula::*;
 pub use image::*;
 pub use note::*;
 pub use properties::*;
 pub use protection::*;
+pub use row_source::*;
 pub use shape::*;
 pub use table::*;
 pub use url::*;
@@ -54,6 +54,7 @@ use crate::content_types::ContentTypes;
 use crate::core::Core;
 use crate::custom::Custom;
 use crate::error::XlsxError;
+use crate::feature_property_bag::FeaturePropertyBag;
 use crate::metadata::Metadata;
 use crate::relationship::Relationship;
 use crate::rich_value::RichValue;
@@ -74,6 +75,7 @@ pub struct Packager<W: Write + Seek> {
     zip: ZipWriter<W>,
     zip_options: SimpleFileOptions,
     zip_options_for_binary_files: SimpleFileOptions,
+    strict_ooxml: bool,
 }
 
 impl<W: Write + Seek + Send> Packager<W> {
@@ -98,6 +100,7 @@ impl<W: Write + Seek + Send> Packager<W> {
             zip,
             zip_options,
             zip_options_for_binary_files,
+            strict_ooxml: false,
         }
     }
 
@@ -107,6 +110,8 @@ impl<W: Write + Seek + Send> Packager<W> {
         workbook: &mut Workbook,
         options: &PackagerOptions,
     ) -> Result<(), XlsxError> {
+        self.strict_ooxml = options.strict_ooxml;
+
         // Write the sub-component files.
         self.write_content_types_file(options)?;
         self.write_root_rels_file(options)?;
@@ -121,6 +126,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             worksheet.update_string_table_ids(&mut string_table);
         }
 
+        for worksheet in &mut workbook.worksheets {
+            worksheet.writer.strict_ooxml = self.strict_ooxml;
+        }
+
         // Assemble, but don't write, the worksheet files in parallel. These are
         // generally the largest files and the threading can help performance if
         // there are multiple large worksheets.
@@ -206,6 +215,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             self.write_rich_value_files(workbook, options)?;
         }
 
+        if options.has_checkboxes {
+            self.write_feature_property_bag_file()?;
+        }
+
         // Close the zip file.
         self.zip.finish()?;
 
@@ -257,6 +270,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_chart_name(i + 1);
         }
 
+        for i in 0..options.num_chartex_charts {
+            content_types.add_chartex_name(i + 1);
+        }
+
         for i in 0..options.num_tables {
             content_types.add_table_name(i + 1);
         }
@@ -277,6 +294,10 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_rich_value();
         }
 
+        if options.has_checkboxes {
+            content_types.add_feature_property_bag();
+        }
+
         if options.has_vml {
             content_types.add_default(
                 "vml",
@@ -306,9 +327,19 @@ impl<W: Write + Seek + Send> Packager<W> {
             content_types.add_default("bin", "application/vnd.ms-office.vbaProject");
         }
 
+        // Add any user defined content types.
+        for (extension, content_type) in &options.content_type_defaults {
+            content_types.add_default(extension, content_type);
+        }
+
+        for (part_name, content_type) in &options.content_type_overrides {
+            content_types.add_override(part_name, content_type);
+        }
+
         self.zip
             .start_file("[Content_Types].xml", self.zip_options)?;
 
+        content_types.writer.strict_ooxml = self.strict_ooxml;
         content_types.assemble_xml_file();
         self.zip.write_all(content_types.writer.xmlfile.get_ref())?;
 
@@ -327,8 +358,14 @@ impl<W: Write + Seek + Send> Packager<W> {
             rels.add_document_relationship("custom-properties", "docProps/custom.xml", "");
         }
 
+        // Add any user defined package relationships.
+        for (rel_type, target) in &options.package_relationships {
+            rels.add_custom_relationship(rel_type, target);
+        }
+
         self.zip.start_file("_rels/.rels", self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -405,9 +442,19 @@ impl<W: Write + Seek + Send> Packager<W> {
             );
         }
 
+        if options.has_checkboxes {
+            rels.add_office_relationship(
+                "2022/11",
+                "FeaturePropertyBag",
+                "featurePropertyBag/featurePropertyBag.xml",
+                "",
+            );
+        }
+
         self.zip
             .start_file("xl/_rels/workbook.xml.rels", self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -468,6 +515,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -490,6 +538,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -505,13 +554,23 @@ impl<W: Write + Seek + Send> Packager<W> {
         let mut rels = Relationship::new();
 
         for relationship in relationships {
-            rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+            if relationship.0 == "chartEx" {
+                rels.add_office_relationship(
+                    "2014",
+                    &relationship.0,
+                    &relationship.1,
+                    &relationship.2,
+                );
+            } else {
+                rels.add_document_relationship(&relationship.0, &relationship.1, &relationship.2);
+            }
         }
 
         let filename = format!("xl/drawings/_rels/drawing{index}.xml.rels");
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -534,6 +593,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -547,17 +607,16 @@ impl<W: Write + Seek + Send> Packager<W> {
     ) -> Result<(), XlsxError> {
         let mut rels = Relationship::new();
 
-        let mut index = 1;
-        for image in &workbook.embedded_images {
+        for (index, image) in (1..).zip(&workbook.embedded_images) {
             let target = format!("../media/image{index}.{}", image.image_type.extension());
             rels.add_document_relationship("image", &target, "");
-            index += 1;
         }
 
         let filename = "xl/richData/_rels/richValueRel.xml.rels";
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -574,6 +633,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file(filename, self.zip_options)?;
 
+        rels.writer.strict_ooxml = self.strict_ooxml;
         rels.assemble_xml_file();
         self.zip.write_all(rels.writer.xmlfile.get_ref())?;
 
@@ -584,6 +644,7 @@ impl<W: Write + Seek + Send> Packager<W> {
     pub(crate) fn write_workbook_file(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         self.zip.start_file("xl/workbook.xml", self.zip_options)?;
 
+        workbook.writer.strict_ooxml = self.strict_ooxml;
         workbook.assemble_xml_file();
         self.zip.write_all(workbook.writer.xmlfile.get_ref())?;
 
@@ -600,6 +661,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/sharedStrings.xml", self.zip_options)?;
 
+        shared_strings.writer.strict_ooxml = self.strict_ooxml;
         shared_strings.assemble_xml_file(string_table);
         self.zip
             .write_all(shared_strings.writer.xmlfile.get_ref())?;
@@ -623,6 +685,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file("xl/styles.xml", self.zip_options)?;
 
+        styles.writer.strict_ooxml = self.strict_ooxml;
         styles.assemble_xml_file();
         self.zip.write_all(styles.writer.xmlfile.get_ref())?;
 
@@ -636,6 +699,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/theme/theme1.xml", self.zip_options)?;
 
+        theme.writer.strict_ooxml = self.strict_ooxml;
         theme.assemble_xml_file();
         self.zip.write_all(theme.writer.xmlfile.get_ref())?;
 
@@ -649,6 +713,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file("docProps/core.xml", self.zip_options)?;
 
+        core.writer.strict_ooxml = self.strict_ooxml;
         core.assemble_xml_file();
         self.zip.write_all(core.writer.xmlfile.get_ref())?;
 
@@ -667,6 +732,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("docProps/custom.xml", self.zip_options)?;
 
+        custom.writer.strict_ooxml = self.strict_ooxml;
         custom.assemble_xml_file();
         self.zip.write_all(custom.writer.xmlfile.get_ref())?;
 
@@ -719,12 +785,30 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file("docProps/app.xml", self.zip_options)?;
 
+        app.writer.strict_ooxml = self.strict_ooxml;
         app.assemble_xml_file();
         self.zip.write_all(app.writer.xmlfile.get_ref())?;
 
         Ok(())
     }
 
+    // Write the featurePropertyBag.xml file.
+    fn write_feature_property_bag_file(&mut self) -> Result<(), XlsxError> {
+        let mut feature_property_bag = FeaturePropertyBag::new();
+
+        self.zip.start_file(
+            "xl/featurePropertyBag/featurePropertyBag.xml",
+            self.zip_options,
+        )?;
+
+        feature_property_bag.writer.strict_ooxml = self.strict_ooxml;
+        feature_property_bag.assemble_xml_file();
+        self.zip
+            .write_all(feature_property_bag.writer.xmlfile.get_ref())?;
+
+        Ok(())
+    }
+
     // Write the metadata.xml file.
     fn write_metadata_file(&mut self, options: &PackagerOptions) -> Result<(), XlsxError> {
         let mut metadata = Metadata::new();
@@ -734,6 +818,7 @@ impl<W: Write + Seek + Send> Packager<W> {
 
         self.zip.start_file("xl/metadata.xml", self.zip_options)?;
 
+        metadata.writer.strict_ooxml = self.strict_ooxml;
         metadata.assemble_xml_file();
         self.zip.write_all(metadata.writer.xmlfile.get_ref())?;
 
@@ -761,6 +846,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/richData/rdrichvalue.xml", self.zip_options)?;
 
+        rich_value.writer.strict_ooxml = self.strict_ooxml;
         rich_value.assemble_xml_file();
         self.zip.write_all(rich_value.writer.xmlfile.get_ref())?;
 
@@ -774,6 +860,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/richData/rdRichValueTypes.xml", self.zip_options)?;
 
+        rich_value_types.writer.strict_ooxml = self.strict_ooxml;
         rich_value_types.assemble_xml_file();
         self.zip
             .write_all(rich_value_types.writer.xmlfile.get_ref())?;
@@ -793,6 +880,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/richData/rdrichvaluestructure.xml", self.zip_options)?;
 
+        rich_value_structure.writer.strict_ooxml = self.strict_ooxml;
         rich_value_structure.assemble_xml_file();
         self.zip
             .write_all(rich_value_structure.writer.xmlfile.get_ref())?;
@@ -808,6 +896,7 @@ impl<W: Write + Seek + Send> Packager<W> {
         self.zip
             .start_file("xl/richData/richValueRel.xml", self.zip_options)?;
 
+        rich_value_rel.writer.strict_ooxml = self.strict_ooxml;
         rich_value_rel.assemble_xml_file();
         self.zip
             .write_all(rich_value_rel.writer.xmlfile.get_ref())?;
@@ -823,6 +912,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                 let filename = format!("xl/drawings/drawing{index}.xml");
                 self.zip.start_file(filename, self.zip_options)?;
 
+                worksheet.drawing.writer.strict_ooxml = self.strict_ooxml;
                 worksheet.drawing.assemble_xml_file();
                 self.zip
                     .write_all(worksheet.drawing.writer.xmlfile.get_ref())?;
@@ -845,6 +935,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                 comment.notes = worksheet.notes.clone();
                 comment.note_authors = worksheet.note_authors.keys().cloned().collect();
 
+                comment.writer.strict_ooxml = self.strict_ooxml;
                 comment.assemble_xml_file();
 
                 self.zip.write_all(comment.writer.xmlfile.get_ref())?;
@@ -872,6 +963,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                     vml.data_id.clone_from(&worksheet.vml_data_id);
 
                     vml.shape_id = worksheet.vml_shape_id;
+                    vml.writer.strict_ooxml = self.strict_ooxml;
                     vml.assemble_xml_file();
 
                     self.zip.write_all(vml.writer.xmlfile.get_ref())?;
@@ -890,6 +982,7 @@ impl<W: Write + Seek + Send> Packager<W> {
                     vml.shape_id = 1024 * header_data_id;
                     header_data_id += 1;
 
+                    vml.writer.strict_ooxml = self.strict_ooxml;
                     vml.assemble_xml_file();
 
                     self.zip.write_all(vml.writer.xmlfile.get_ref())?;
@@ -954,14 +1047,22 @@ impl<W: Write + Seek + Send> Packager<W> {
     // Write the chart files.
     fn write_chart_files(&mut self, workbook: &mut Workbook) -> Result<(), XlsxError> {
         let mut index = 1;
+        let mut chartex_index = 1;
 
         for worksheet in &mut workbook.worksheets {
             for chart in worksheet.charts.values_mut() {
-                let filename = format!("xl/charts/chart{index}.xml");
+                let filename = if chart.is_chartex() {
+                    chartex_index += 1;
+                    format!("xl/charts/chartEx{}.xml", chartex_index - 1)
+                } else {
+                    index += 1;
+                    format!("xl/charts/chart{}.xml", index - 1)
+                };
+
                 self.zip.start_file(filename, self.zip_options)?;
+                chart.writer.strict_ooxml = self.strict_ooxml;
                 chart.assemble_xml_file();
                 self.zip.write_all(chart.writer.xmlfile.get_ref())?;
-                index += 1;
             }
         }
 
@@ -976,6 +1077,7 @@ impl<W: Write + Seek + Send> Packager<W> {
             for table in &mut worksheet.tables {
                 let filename = format!("xl/tables/table{index}.xml");
                 self.zip.start_file(filename, self.zip_options)?;
+                table.writer.strict_ooxml = self.strict_ooxml;
                 table.assemble_xml_file();
                 self.zip.write_all(table.writer.xmlfile.get_ref())?;
                 index += 1;
@@ -1018,12 +1120,17 @@ impl<W: Write + Seek + Send> Packager<W> {
     }
 }
 
+// -----------------------------------------------------------------------
+// Helper enums/structs/functions.
+// -----------------------------------------------------------------------
+
 // Internal struct to pass options to the Packager struct.
 pub(crate) struct PackagerOptions {
     pub(crate) has_sst_table: bool,
     pub(crate) has_metadata: bool,
     pub(crate) has_dynamic_functions: bool,
     pub(crate) has_embedded_images: bool,
+    pub(crate) has_checkboxes: bool,
     pub(crate) has_vml: bool,
     pub(crate) is_xlsm_file: bool,
     pub(crate) has_vba_signature: bool,
@@ -1031,6 +1138,7 @@ pub(crate) struct PackagerOptions {
     pub(crate) num_chartsheets: u16,
     pub(crate) num_drawings: u16,
     pub(crate) num_charts: u16,
+    pub(crate) num_chartex_charts: u16,
     pub(crate) num_tables: u16,
     pub(crate) num_comments: u16,
     pub(crate) doc_security: u8,
@@ -1040,6 +1148,10 @@ pub(crate) struct PackagerOptions {
     pub(crate) properties: DocProperties,
     pub(crate) num_embedded_images: u32,
     pub(crate) has_embedded_image_descriptions: bool,
+    pub(crate) strict_ooxml: bool,
+    pub(crate) content_type_defaults: Vec<(String, String)>,
+    pub(crate) content_type_overrides: Vec<(String, String)>,
+    pub(crate) package_relationships: Vec<(String, String)>,
 }
 
 impl PackagerOptions {
@@ -1050,6 +1162,7 @@ impl PackagerOptions {
             has_metadata: false,
             has_dynamic_functions: false,
             has_embedded_images: false,
+            has_checkboxes: false,
             has_vml: false,
             is_xlsm_file: false,
             has_vba_signature: false,
@@ -1057,6 +1170,7 @@ impl PackagerOptions {
             num_chartsheets: 0,
             num_drawings: 0,
             num_charts: 0,
+            num_chartex_charts: 0,
             num_tables: 0,
             num_comments: 0,
             doc_security: 0,
@@ -1066,6 +1180,10 @@ impl PackagerOptions {
             properties: DocProperties::new(),
             num_embedded_images: 0,
             has_embedded_image_descriptions: false,
+            strict_ooxml: false,
+            content_type_defaults: vec![],
+            content_type_overrides: vec![],
+            package_relationships: vec![],
         }
     }
 }
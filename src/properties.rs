@@ -353,6 +353,12 @@ pub struct DocProperties {
     pub(crate) category: String,
     pub(crate) keywords: String,
     pub(crate) hyperlink_base: String,
+    pub(crate) language: String,
+    pub(crate) revision: u32,
+    pub(crate) doc_security: u8,
+    pub(crate) scale_crop: bool,
+    pub(crate) shared_doc: bool,
+    pub(crate) hyperlinks_changed: bool,
     pub(crate) creation_time: String,
     pub(crate) custom_properties: Vec<CustomProperty>,
 }
@@ -377,6 +383,12 @@ impl DocProperties {
             category: String::new(),
             keywords: String::new(),
             hyperlink_base: String::new(),
+            language: String::new(),
+            revision: 0,
+            doc_security: 0,
+            scale_crop: false,
+            shared_doc: false,
+            hyperlinks_changed: false,
             creation_time: ExcelDateTime::utc_now(),
             custom_properties: vec![],
         }
@@ -533,6 +545,145 @@ impl DocProperties {
         self
     }
 
+    /// Set the Language field of the document properties.
+    ///
+    /// Set the `dc:language` field of the core document properties. This
+    /// should be an IETF language tag such as `"en-US"` or `"de-DE"`. Excel
+    /// doesn't display this field but it is read by document management and
+    /// accessibility tools.
+    ///
+    /// # Parameters
+    ///
+    /// - `language`: The language tag string property.
+    ///
+    pub fn set_language(mut self, language: impl Into<String>) -> DocProperties {
+        self.language = language.into();
+
+        self
+    }
+
+    /// Set the Revision field of the document properties.
+    ///
+    /// Set the `cp:revision` field of the core document properties. This is
+    /// the revision number of the document. It isn't written if it is 0,
+    /// which is the default.
+    ///
+    /// # Parameters
+    ///
+    /// - `revision`: The revision number property.
+    ///
+    pub fn set_revision(mut self, revision: u32) -> DocProperties {
+        self.revision = revision;
+
+        self
+    }
+
+    /// Set the DocSecurity field of the extended document properties.
+    ///
+    /// Set the `DocSecurity` field of the `app.xml` extended properties. This
+    /// is a bit field with the following values, which can be combined:
+    ///
+    /// - `0`: No document security. The default.
+    /// - `1`: The document is password protected.
+    /// - `2`: The document is recommended to be opened as read-only.
+    /// - `4`: The document is enforced to be opened as read-only.
+    /// - `8`: The document is locked for annotation.
+    ///
+    /// This property is informational and is read by compliance scanners and
+    /// document management systems. It doesn't add any protection to the file
+    /// in Excel. To recommend that a file is opened as read-only use
+    /// [`Workbook::read_only_recommended()`](crate::Workbook::read_only_recommended),
+    /// which also sets the `2` bit of this field.
+    ///
+    /// # Parameters
+    ///
+    /// - `doc_security`: The document security bit field.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the language, revision and
+    /// extended document properties that are read by document management
+    /// systems.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_properties_extended.rs
+    /// #
+    /// # use rust_xlsxwriter::{DocProperties, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let properties = DocProperties::new()
+    ///         .set_title("Quarterly Report")
+    ///         .set_language("en-US")
+    ///         .set_revision(4)
+    ///         .set_doc_security(8)
+    ///         .set_shared_doc(true);
+    ///
+    ///     workbook.set_properties(&properties);
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write(0, 0, "Hello")?;
+    /// #
+    /// #     workbook.save("properties.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_doc_security(mut self, doc_security: u8) -> DocProperties {
+        self.doc_security = doc_security;
+
+        self
+    }
+
+    /// Set the ScaleCrop field of the extended document properties.
+    ///
+    /// Set the `ScaleCrop` field of the `app.xml` extended properties which
+    /// indicates how the document thumbnail is displayed: scaled, if `true`,
+    /// or cropped, if `false`.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_scale_crop(mut self, enable: bool) -> DocProperties {
+        self.scale_crop = enable;
+
+        self
+    }
+
+    /// Set the SharedDoc field of the extended document properties.
+    ///
+    /// Set the `SharedDoc` field of the `app.xml` extended properties to
+    /// indicate that the document is shared between multiple producers.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_shared_doc(mut self, enable: bool) -> DocProperties {
+        self.shared_doc = enable;
+
+        self
+    }
+
+    /// Set the HyperlinksChanged field of the extended document properties.
+    ///
+    /// Set the `HyperlinksChanged` field of the `app.xml` extended properties
+    /// to indicate that one or more hyperlinks in the document were updated
+    /// exclusively by the producer and should be updated by the consumer.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    pub fn set_hyperlinks_changed(mut self, enable: bool) -> DocProperties {
+        self.hyperlinks_changed = enable;
+
+        self
+    }
+
     /// Set the create date/time for the document.
     ///
     /// Excel sets a date and time for every new document in UTC. The
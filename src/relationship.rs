@@ -74,6 +74,13 @@ impl Relationship {
         ));
     }
 
+    // Add a user defined relationship, with a full relationship type, to xlsx
+    // .rels xml files.
+    pub(crate) fn add_custom_relationship(&mut self, rel_type: &str, target: &str) {
+        self.relationships
+            .push((rel_type.to_string(), target.to_string(), String::new()));
+    }
+
     // -----------------------------------------------------------------------
     // XML assembly methods.
     // -----------------------------------------------------------------------
@@ -0,0 +1,269 @@
+// row_source - A module for writing rows of data from external sources, such
+// as database query results.
+//
+// SPDX-License-Identifier: MIT OR Apache-2.0
+//
+// Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
+
+#![warn(missing_docs)]
+
+use crate::{ColNum, ExcelDateTime, Format, IntoExcelData, RowNum, Worksheet, XlsxError};
+
+/// The `ExcelRowSource` trait is used to stream rows of data from an external
+/// source to a worksheet via [`Worksheet::write_rows_from()`].
+///
+/// The trait is intended for adapters around sources of tabular data such as
+/// database query results, where the column names and types are only known at
+/// runtime. An implementation supplies the column headers and then returns a
+/// row of typed [`ExcelCellValue`] values each time
+/// [`ExcelRowSource::next_row()`] is called, until the data is exhausted.
+///
+/// # Examples
+///
+/// The following is an example of an adapter for rows returned from a `sqlx`
+/// SQLite query. The column types are mapped to the nearest Excel type.
+///
+/// ```ignore
+/// use rust_xlsxwriter::{ExcelCellValue, ExcelRowSource, Workbook, XlsxError};
+/// use sqlx::sqlite::SqliteRow;
+/// use sqlx::{Column, Row, TypeInfo, ValueRef};
+///
+/// struct SqliteRowSource {
+///     headers: Vec<String>,
+///     rows: std::vec::IntoIter<SqliteRow>,
+/// }
+///
+/// impl SqliteRowSource {
+///     fn new(rows: Vec<SqliteRow>) -> SqliteRowSource {
+///         let headers = match rows.first() {
+///             Some(row) => row.columns().iter().map(|c| c.name().to_string()).collect(),
+///             None => vec![],
+///         };
+///
+///         SqliteRowSource {
+///             headers,
+///             rows: rows.into_iter(),
+///         }
+///     }
+/// }
+///
+/// impl ExcelRowSource for SqliteRowSource {
+///     fn headers(&self) -> Vec<String> {
+///         self.headers.clone()
+///     }
+///
+///     fn next_row(&mut self) -> Result<Option<Vec<ExcelCellValue>>, XlsxError> {
+///         let Some(row) = self.rows.next() else {
+///             return Ok(None);
+///         };
+///
+///         let to_error = |e: sqlx::Error| XlsxError::CustomError(e.to_string());
+///         let mut values = vec![];
+///
+///         for index in 0..row.len() {
+///             let raw = row.try_get_raw(index).map_err(to_error)?;
+///             if raw.is_null() {
+///                 values.push(ExcelCellValue::Blank);
+///                 continue;
+///             }
+///
+///             let value = match raw.type_info().name() {
+///                 "INTEGER" | "REAL" | "NUMERIC" => {
+///                     ExcelCellValue::Number(row.try_get(index).map_err(to_error)?)
+///                 }
+///                 "BOOLEAN" => ExcelCellValue::Boolean(row.try_get(index).map_err(to_error)?),
+///                 _ => ExcelCellValue::String(row.try_get(index).map_err(to_error)?),
+///             };
+///
+///             values.push(value);
+///         }
+///
+///         Ok(Some(values))
+///     }
+/// }
+///
+/// #[tokio::main]
+/// async fn main() -> Result<(), Box<dyn std::error::Error>> {
+///     let pool = sqlx::SqlitePool::connect("sqlite:sales.db").await?;
+///     let rows = sqlx::query("SELECT * FROM sales").fetch_all(&pool).await?;
+///
+///     let mut workbook = Workbook::new();
+///     let worksheet = workbook.add_worksheet();
+///
+///     worksheet.write_rows_from(0, 0, &mut SqliteRowSource::new(rows))?;
+///
+///     workbook.save("sales.xlsx")?;
+///
+///     Ok(())
+/// }
+/// ```
+///
+pub trait ExcelRowSource {
+    /// Return the column headers for the data.
+    ///
+    /// The headers are written as strings in the first row. If the returned
+    /// vector is empty then no header row is written.
+    ///
+    fn headers(&self) -> Vec<String>;
+
+    /// Return the next row of data, or `None` when there are no more rows.
+    ///
+    /// # Errors
+    ///
+    /// Implementations can return any [`XlsxError`] to stop writing the rows.
+    /// Errors from the underlying data source can be mapped to
+    /// [`XlsxError::CustomError`].
+    ///
+    fn next_row(&mut self) -> Result<Option<Vec<ExcelCellValue>>, XlsxError>;
+}
+
+/// The `ExcelCellValue` enum represents a typed cell value returned by an
+/// [`ExcelRowSource`].
+///
+/// Values can be created directly or converted from the equivalent Rust types
+/// via [`From`]. A `None` option is converted to [`ExcelCellValue::Blank`].
+///
+/// This type only represents input values. Data that has been written to a
+/// worksheet is read back as a [`CellValue`](crate::CellValue), which
+/// represents the stored cell data. See the [`CellValue`](crate::CellValue)
+/// docs for the differences.
+///
+/// ```
+/// # use rust_xlsxwriter::ExcelCellValue;
+/// #
+/// let values: Vec<ExcelCellValue> = vec![
+///     "Apple".into(),
+///     1.25.into(),
+///     true.into(),
+///     None::<f64>.into(),
+/// ];
+/// ```
+///
+#[derive(Clone)]
+pub enum ExcelCellValue {
+    /// An empty cell. No data is written.
+    Blank,
+
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A number value.
+    Number(f64),
+
+    /// A string value.
+    String(String),
+
+    /// A date value. When written without a user format it is displayed with
+    /// the format `yyyy-mm-dd`.
+    Date(ExcelDateTime),
+
+    /// A datetime value. When written without a user format it is displayed
+    /// with the format `yyyy-mm-dd hh:mm:ss`.
+    DateTime(ExcelDateTime),
+}
+
+macro_rules! cell_value_from_number_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for ExcelCellValue {
+            fn from(value: $t) -> ExcelCellValue {
+                ExcelCellValue::Number(value.into())
+            }
+        }
+    )*)
+}
+cell_value_from_number_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+
+// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
+// is a loss of precision, as with `Worksheet::write()`.
+macro_rules! cell_value_from_large_number_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for ExcelCellValue {
+            fn from(value: $t) -> ExcelCellValue {
+                #[allow(clippy::cast_precision_loss)]
+                ExcelCellValue::Number(value as f64)
+            }
+        }
+    )*)
+}
+cell_value_from_large_number_impl!(u64 i64);
+
+impl From<bool> for ExcelCellValue {
+    fn from(value: bool) -> ExcelCellValue {
+        ExcelCellValue::Boolean(value)
+    }
+}
+
+impl From<&str> for ExcelCellValue {
+    fn from(value: &str) -> ExcelCellValue {
+        ExcelCellValue::String(value.to_string())
+    }
+}
+
+impl From<String> for ExcelCellValue {
+    fn from(value: String) -> ExcelCellValue {
+        ExcelCellValue::String(value)
+    }
+}
+
+impl From<ExcelDateTime> for ExcelCellValue {
+    fn from(value: ExcelDateTime) -> ExcelCellValue {
+        ExcelCellValue::DateTime(value)
+    }
+}
+
+impl<T: Into<ExcelCellValue>> From<Option<T>> for ExcelCellValue {
+    fn from(value: Option<T>) -> ExcelCellValue {
+        match value {
+            Some(value) => value.into(),
+            None => ExcelCellValue::Blank,
+        }
+    }
+}
+
+impl IntoExcelData for ExcelCellValue {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        match self {
+            ExcelCellValue::Blank => Ok(worksheet),
+            ExcelCellValue::Boolean(value) => worksheet.write_boolean(row, col, value),
+            ExcelCellValue::Number(value) => worksheet.write_number(row, col, value),
+            ExcelCellValue::String(value) => worksheet.write_string(row, col, value),
+            ExcelCellValue::Date(value) => {
+                let format = Format::new().set_num_format("yyyy-mm-dd");
+                worksheet.write_datetime_with_format(row, col, &value, &format)
+            }
+            ExcelCellValue::DateTime(value) => {
+                let format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+                worksheet.write_datetime_with_format(row, col, &value, &format)
+            }
+        }
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        match self {
+            ExcelCellValue::Blank => worksheet.write_blank(row, col, format),
+            ExcelCellValue::Boolean(value) => {
+                worksheet.write_boolean_with_format(row, col, value, format)
+            }
+            ExcelCellValue::Number(value) => {
+                worksheet.write_number_with_format(row, col, value, format)
+            }
+            ExcelCellValue::String(value) => {
+                worksheet.write_string_with_format(row, col, value, format)
+            }
+            ExcelCellValue::Date(value) | ExcelCellValue::DateTime(value) => {
+                worksheet.write_datetime_with_format(row, col, &value, format)
+            }
+        }
+    }
+}
@@ -27,6 +27,10 @@
 //! - [Skipping fields when serializing](#skipping-fields-when-serializing)
 //! - [Setting serialization formatting](#setting-serialization-formatting)
 //! - [Serializing dates and times](#serializing-dates-and-times)
+//! - [Serializing decimal numbers](#serializing-decimal-numbers)
+//! - [Serializing formulas and hyperlinks](#serializing-formulas-and-hyperlinks)
+//! - [Serializing maps](#serializing-maps)
+//!   - [Serializing flattened structs](#serializing-flattened-structs)
 //! - [Controlling Excel output via `XlsxSerialize` and struct
 //!   attributes](#controlling-excel-output-via-xlsxserialize-and-struct-attributes)
 //!   - [Container `xlsx` attributes](#container-xlsx-attributes)
@@ -49,8 +53,8 @@
 //! Serialization with `rust_xlsxwriter` needs to take into consideration
 //! that the target output is a 2D grid of cells into which the data can be
 //! serialized. As such the focus is on serializing data types that map to this
-//! 2D grid such as structs or compound collections of structs such as vectors
-//! or tuples and it (currently) ignores compound types like maps.
+//! 2D grid such as structs, maps, or compound collections of structs such as
+//! vectors or tuples.
 //!
 //! The image below shows the basic scheme for mapping a struct to a worksheet:
 //! fields are mapped to a header and values are mapped to sequential cells
@@ -126,6 +130,13 @@
 //!
 //! <img src="https://rustxlsxwriter.github.io/images/serialize_intro2.png">
 //!
+//! Data that is generated or read lazily doesn't need to be collected into a
+//! vector first. It can be serialized item by item from an iterator using
+//! [`Worksheet::serialize_iter()`](crate::Worksheet::serialize_iter). Data
+//! can also be split across several worksheets, one per key value such as a
+//! region or month, using
+//! [`Workbook::serialize_partitioned()`](crate::Workbook::serialize_partitioned).
+//!
 //!
 //!
 //!
@@ -919,6 +930,10 @@
 //! Examples of the first three methods are shown below. Examples of
 //! `XlsxSerialize` attributes are shown in a subsequent section below.
 //!
+//! Conditional formats can also be added to the serialized data of a field,
+//! without having to calculate the cell range, using
+//! [`CustomSerializeField::set_conditional_format()`].
+//!
 //! ### Examples of formatting
 //!
 //! The following example demonstrates serializing instances of a Serde derived
@@ -1158,6 +1173,12 @@
 //!   - [`NaiveDateTime`]
 //!   - [`NaiveDate`]
 //!   - [`NaiveTime`]
+//! - [`Chrono`] timezone aware [`DateTime`] types, like `DateTime<Utc>`, via a
+//!   helper function (see below).
+//! - [`time`] crate types, via a helper function (see below):
+//!   - [`PrimitiveDateTime`]
+//!   - [`Date`]
+//!   - [`Time`]
 //!
 //! [`ExcelDateTime`]: crate::ExcelDateTime
 //! [`Chrono`]: https://docs.rs/chrono/latest/chrono
@@ -1167,6 +1188,12 @@
 //!     https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
 //! [`NaiveDateTime`]:
 //!     https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
+//! [`DateTime`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+//! [`time`]: https://docs.rs/time/latest/time
+//! [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+//! [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+//! [`PrimitiveDateTime`]:
+//!     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
 //!
 //! The [`ExcelDateTime`] type is serialized automatically since it implements
 //! the [`Serialize`] trait. The [`Chrono`] types also implements [`Serialize`]
@@ -1175,6 +1202,10 @@
 //! [`Utility::serialize_chrono_naive_to_excel()`](crate::utility::serialize_chrono_naive_to_excel())
 //! (as shown in the example below) or
 //! [`Utility::serialize_chrono_option_naive_to_excel()`](crate::utility::serialize_chrono_option_naive_to_excel()).
+//! The `time` crate types can be serialized in the same way with
+//! [`Utility::serialize_time_to_excel()`](crate::utility::serialize_time_to_excel())
+//! or
+//! [`Utility::serialize_time_option_to_excel()`](crate::utility::serialize_time_option_to_excel()).
 //!
 //! Excel datetimes also need a number format to display them as a date/time
 //! since they are stored  as `f64` values. See [Datetimes in
@@ -1183,6 +1214,11 @@
 //!
 //! Note, Excel doesn't use timezones or try to convert or encode timezone
 //! information in any way so they aren't supported by `rust_xlsxwriter`.
+//! Timezone aware [`DateTime`] values can be serialized with
+//! [`Utility::serialize_chrono_datetime_to_excel()`](crate::utility::serialize_chrono_datetime_to_excel())
+//! or
+//! [`Utility::serialize_chrono_option_datetime_to_excel()`](crate::utility::serialize_chrono_option_datetime_to_excel())
+//! which strip the timezone and write the local date and time of the value.
 //!
 //! ### Examples of serializing dates
 //!
@@ -1348,6 +1384,288 @@
 //!
 //!
 //!
+//! ## Serializing decimal numbers
+//!
+//! The [`rust_decimal`] `Decimal` type can be serialized as an Excel number
+//! using the
+//! [`Utility::serialize_decimal_to_excel()`](crate::utility::serialize_decimal_to_excel())
+//! or
+//! [`Utility::serialize_decimal_option_to_excel()`](crate::utility::serialize_decimal_option_to_excel())
+//! helper functions. These require the `rust_decimal` feature.
+//!
+//! Excel stores numbers as `f64` values so each `Decimal` is converted to the
+//! nearest `f64`. This is exact for values with up to 15 significant digits,
+//! which covers most financial data. Values with more digits are rounded, as
+//! they would be in Excel. The number of displayed decimal places is set by
+//! the cell number format, such as `"0.00"`, and not by the scale of the
+//! `Decimal`.
+//!
+//! [`rust_decimal`]: https://docs.rs/rust_decimal/latest/rust_decimal
+//!
+//! ```ignore
+//! # // This code is available in examples/doc_worksheet_serialize_decimal.rs
+//! #
+//! use rust_decimal::Decimal;
+//! use rust_xlsxwriter::utility::serialize_decimal_to_excel;
+//! use serde::Serialize;
+//!
+//! fn main() {
+//!     #[derive(Serialize)]
+//!     struct Invoice {
+//!         item: String,
+//!
+//!         #[serde(serialize_with = "serialize_decimal_to_excel")]
+//!         price: Decimal,
+//!     }
+//! }
+//! ```
+//!
+//!
+//!
+//!
+//!
+//!
+//! ## Serializing formulas and hyperlinks
+//!
+//! Struct fields of type [`Formula`] and [`Url`] are serialized as worksheet
+//! formula and hyperlink cells, in the same way as
+//! [`Worksheet::write_formula()`] and [`Worksheet::write_url()`]. Any
+//! formula result or url display text and tool tip are also written. The
+//! types can also be used in `Option` fields.
+//!
+//! ```
+//! # // This code is available in examples/doc_worksheet_serialize_formulas.rs
+//! #
+//! # use rust_xlsxwriter::{Formula, Url, Workbook, XlsxError};
+//! # use serde::Serialize;
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     let mut workbook = Workbook::new();
+//! #
+//! #     // Add a worksheet to the workbook.
+//! #     let worksheet = workbook.add_worksheet();
+//! #
+//!     // Create a serializable struct with formula and url fields.
+//!     #[derive(Serialize)]
+//!     #[serde(rename_all = "PascalCase")]
+//!     struct Produce {
+//!         fruit: &'static str,
+//!         cost: f64,
+//!         quantity: u32,
+//!         total: Formula,
+//!         supplier: Url,
+//!     }
+//!
+//!     // Create some data instances.
+//!     let items = [
+//!         Produce {
+//!             fruit: "Peach",
+//!             cost: 1.05,
+//!             quantity: 20,
+//!             total: Formula::new("=B2*C2"),
+//!             supplier: Url::new("https://www.example.com/peach").set_text("Peach Co."),
+//!         },
+//!         Produce {
+//!             fruit: "Plum",
+//!             cost: 0.15,
+//!             quantity: 50,
+//!             total: Formula::new("=B3*C3"),
+//!             supplier: Url::new("https://www.example.com/plum").set_text("Plum Co."),
+//!         },
+//!     ];
+//!
+//!     // Set up the start location and headers of the data to be serialized.
+//!     worksheet.serialize_headers(0, 0, &items[0])?;
+//!
+//!     // Serialize the data.
+//!     worksheet.serialize(&items)?;
+//! #
+//! #     // Save the file.
+//! #     workbook.save("serialize.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//! Note, since a `Formula` or `Url` needs to be serialized from an instance
+//! of the struct you will need to use [`Worksheet::serialize_headers()`], or
+//! one of the related methods, to set up the headers.
+//!
+//!
+//!
+//!
+//! ## Serializing maps
+//!
+//! Maps with string keys, such as `HashMap<String, T>` or `BTreeMap<String,
+//! T>`, can also be serialized. There are two ways that maps are mapped to the
+//! worksheet.
+//!
+//! If the values of the map are simple types then the map is treated like a
+//! struct: the keys are the field names and are used as the headers, and the
+//! values are written in the row below the headers. The headers are set up by
+//! passing an instance of the map to [`Worksheet::serialize_headers()`] or one
+//! of the other `serialize_headers*()` methods. Maps don't have a type name so
+//! the headers are stored under the name `"Map"`, which can be used with
+//! methods like [`Worksheet::get_serialize_dimensions()`]. Since the headers
+//! are taken from the keys of the map instance it is best to use a map with a
+//! consistent key order, like a `BTreeMap`, rather than a `HashMap`.
+//!
+//! If the values of the map are structs then each struct is written as a row,
+//! in the same way as a vector of structs. In this case the headers are set up
+//! for the struct type and the keys can optionally be written to a row label
+//! column before the struct fields via
+//! [`SerializeFieldOptions::set_map_key_header()`].
+//!
+//! ```
+//! # // This code is available in examples/doc_worksheet_serialize_maps.rs
+//! #
+//! # use std::collections::BTreeMap;
+//! #
+//! # use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+//! # use serde::{Deserialize, Serialize};
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     let mut workbook = Workbook::new();
+//! #
+//! #     // Add a worksheet to the workbook.
+//! #     let worksheet = workbook.add_worksheet();
+//! #
+//!     // Add a simple format for the headers.
+//!     let format = Format::new().set_bold();
+//!
+//!     // Create some maps where the keys are used as the headers.
+//!     let mut sales1 = BTreeMap::new();
+//!     sales1.insert("East", 1000);
+//!     sales1.insert("North", 3000);
+//!     sales1.insert("West", 2000);
+//!
+//!     let mut sales2 = BTreeMap::new();
+//!     sales2.insert("East", 1200);
+//!     sales2.insert("North", 3300);
+//!     sales2.insert("West", 2400);
+//!
+//!     // Set up the start location and headers of the data to be serialized.
+//!     worksheet.serialize_headers_with_format(0, 0, &sales1, &format)?;
+//!
+//!     // Serialize the data.
+//!     worksheet.serialize(&vec![sales1, sales2])?;
+//!
+//!     // Create a map of structs where the keys are used as row labels.
+//!     #[derive(Deserialize, Serialize)]
+//!     #[serde(rename_all = "PascalCase")]
+//!     struct Produce {
+//!         cost: f64,
+//!         stock: u32,
+//!     }
+//!
+//!     let mut produce = BTreeMap::new();
+//!     produce.insert("Peach", Produce { cost: 1.05, stock: 20 });
+//!     produce.insert("Pear", Produce { cost: 0.75, stock: 35 });
+//!     produce.insert("Plum", Produce { cost: 0.15, stock: 40 });
+//!
+//!     // Set the header for the column of map keys.
+//!     let header_options = SerializeFieldOptions::new()
+//!         .set_header_format(&format)
+//!         .set_map_key_header("Fruit");
+//!
+//!     worksheet.deserialize_headers_with_options::<Produce>(0, 4, &header_options)?;
+//!
+//!     // Serialize the data.
+//!     worksheet.serialize(&produce)?;
+//! #
+//! #     // Save the file.
+//! #     workbook.save("serialize.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//!
+//!
+//!
+//!
+//! ### Serializing flattened structs
+//!
+//! Structs with sub-structs that use the Serde `#[serde(flatten)]` attribute
+//! are serialized by Serde as maps. The fields of the flattened sub-struct
+//! are written in the same header row, and in the same data rows, as the
+//! fields of the parent struct. Since Serde doesn't pass the struct name for
+//! flattened structs the headers are stored under the name of the Rust type of
+//! the parent struct, `"Produce"` in the example below. The headers must be
+//! set up from an instance of the struct via
+//! [`Worksheet::serialize_headers()`] or one of the other
+//! `serialize_headers*()` methods since Serde doesn't support flattened
+//! fields in the deserialization methods used by
+//! [`Worksheet::deserialize_headers()`].
+//!
+//! ```
+//! # // This code is available in examples/doc_worksheet_serialize_flatten.rs
+//! #
+//! # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+//! # use serde::Serialize;
+//! #
+//! # fn main() -> Result<(), XlsxError> {
+//! #     let mut workbook = Workbook::new();
+//! #
+//! #     // Add a worksheet to the workbook.
+//! #     let worksheet = workbook.add_worksheet();
+//! #
+//!     // Add a simple format for the headers.
+//!     let format = Format::new().set_bold();
+//!
+//!     // Create a serializable struct with a flattened sub-struct.
+//!     #[derive(Serialize)]
+//!     #[serde(rename_all = "PascalCase")]
+//!     struct Price {
+//!         cost: f64,
+//!         discount: f64,
+//!     }
+//!
+//!     #[derive(Serialize)]
+//!     #[serde(rename_all = "PascalCase")]
+//!     struct Produce {
+//!         fruit: &'static str,
+//!         #[serde(flatten)]
+//!         price: Price,
+//!         in_stock: bool,
+//!     }
+//!
+//!     // Create some data instances.
+//!     let items = [
+//!         Produce {
+//!             fruit: "Peach",
+//!             price: Price {
+//!                 cost: 1.05,
+//!                 discount: 0.1,
+//!             },
+//!             in_stock: true,
+//!         },
+//!         Produce {
+//!             fruit: "Plum",
+//!             price: Price {
+//!                 cost: 0.15,
+//!                 discount: 0.0,
+//!             },
+//!             in_stock: false,
+//!         },
+//!     ];
+//!
+//!     // Set up the start location and headers of the data to be serialized.
+//!     worksheet.serialize_headers_with_format(0, 0, &items[0], &format)?;
+//!
+//!     // Serialize the data.
+//!     worksheet.serialize(&items)?;
+//! #
+//! #     // Save the file.
+//! #     workbook.save("serialize.xlsx")?;
+//! #
+//! #     Ok(())
+//! # }
+//! ```
+//!
+//!
+//!
+//!
 //! ## Controlling Excel output via `XlsxSerialize` and struct attributes
 //!
 //! In the sections above we saw how to use [Serde
@@ -1928,13 +2246,12 @@
 //! serializing data to Excel via `rust_xlsxwriter` it is best to consider what
 //! that data will look like while designing your serialization.
 //!
-//! Another limitation is that currently you can only serialize structs or
-//! struct values in compound containers such as vectors. Not all of the
+//! Another limitation is that currently you can only serialize structs, maps,
+//! or struct values in compound containers such as vectors. Not all of the
 //! supported types in the [Serde data model] make sense in the context of
 //! Excel. In upcoming releases I will try to add support for additional types
 //! where it makes sense. If you have a valid use case please open a GitHub
-//! issue to discuss it with an example data structure. Also Serde sub-struct
-//! flattening is not currently supported.
+//! issue to discuss it with an example data structure.
 //!
 //! [Serde data model]: https://serde.rs/data-model.html
 //!
@@ -1954,10 +2271,14 @@
 //!
 #![warn(missing_docs)]
 
-use std::collections::HashMap;
+use std::collections::{HashMap, HashSet};
 use std::sync::Arc;
 
-use crate::{ColNum, Format, RowNum, Table, TableStyle, Worksheet, XlsxError};
+use crate::worksheet::ROW_MAX;
+use crate::{
+    ColNum, ConditionalFormat, ExcelCellValue, Format, Formula, RowNum, Table, TableStyle, Url,
+    Worksheet, XlsxError,
+};
 use serde::de::Visitor;
 use serde::{ser, Deserialize, Deserializer, Serialize};
 
@@ -1970,6 +2291,19 @@ pub(crate) struct TableData(
     pub(crate) Table,
 );
 
+// Convenience tuple struct for conditional format data used for serialization
+// formatting.
+pub(crate) struct ConditionalFormatData(
+    pub(crate) RowNum,
+    pub(crate) ColNum,
+    pub(crate) RowNum,
+    pub(crate) ColNum,
+    pub(crate) Box<dyn ConditionalFormat + Send>,
+);
+
+// The row, column and value format of the next serialized cell.
+pub(crate) type CellState = (RowNum, ColNum, Arc<Option<Format>>);
+
 // -----------------------------------------------------------------------
 // SerializerState, a struct to maintain row/column state and other metadata
 // between serialized writes. This avoids passing around cell location
@@ -1979,8 +2313,40 @@ pub(crate) struct SerializerState {
     pub(crate) structs: HashMap<String, SerializationHeaderConfig>,
     pub(crate) current_struct: String,
     pub(crate) current_field: String,
+    pub(crate) depth: usize,
+    pub(crate) maps: Vec<SerializedMap>,
+    pub(crate) map_key: Option<String>,
+    pub(crate) type_name: &'static str,
+    pub(crate) field_prefix: Option<String>,
+    pub(crate) group_depth: usize,
+    pub(crate) sequence: Option<(String, usize)>,
+}
+
+// The type name used to store the headers of a map, such as a `HashMap` or
+// `BTreeMap`, since maps don't have a struct name.
+pub(crate) const SERIALIZED_MAP_NAME: &str = "Map";
+
+// The state of a map that is serialized as a worksheet row. This is also used
+// for structs with `#[serde(flatten)]` fields since Serde serializes them as
+// maps.
+pub(crate) struct SerializedMap {
+    // The name that the headers of the map/struct are stored under.
+    pub(crate) struct_name: String,
+
+    // The serialization depth of the map keys.
+    pub(crate) depth: usize,
+
+    // A row has been added for the map but no data has been written to it. It
+    // is used by the first map value that is serialized as a row of the same
+    // type, such as in a map of maps.
+    pub(crate) has_unused_row: bool,
 }
 
+// The newtype struct names used by the `Serialize` implementations of `Formula`
+// and `Url` so that they can be written as formula and hyperlink cells.
+pub(crate) const SERIALIZED_FORMULA_NAME: &str = "$rust_xlsxwriter::Formula";
+pub(crate) const SERIALIZED_URL_NAME: &str = "$rust_xlsxwriter::Url";
+
 impl SerializerState {
     // Create a new SerializerState struct.
     pub(crate) fn new() -> SerializerState {
@@ -1988,26 +2354,46 @@ impl SerializerState {
             structs: HashMap::new(),
             current_struct: String::new(),
             current_field: String::new(),
+            depth: 0,
+            maps: vec![],
+            map_key: None,
+            type_name: "",
+            field_prefix: None,
+            group_depth: 0,
+            sequence: None,
         }
     }
 
     // Check if the current struct/field have been selected to be serialized by
     // the user. If it has then return the row value for the next `write()` call.
-    pub(crate) fn current_state(&mut self) -> Result<(RowNum, ColNum, Arc<Option<Format>>), ()> {
+    // Returns an error if the data has exceeded Excel's row limit.
+    pub(crate) fn current_state(&mut self) -> Result<Option<CellState>, XlsxError> {
         let Some(header_config) = self.structs.get_mut(&self.current_struct) else {
-            return Err(());
+            return Ok(None);
         };
 
         let Some(field) = header_config.fields.get_mut(&self.current_field) else {
-            return Err(());
+            return Ok(None);
         };
 
         // Set the "current" cell values used to write the serialized data.
         let row = header_config.max_row - 1;
         let col = field.col;
-        let value_format = Arc::clone(&field.value_format);
 
-        Ok((row, col, value_format))
+        if row >= ROW_MAX {
+            return Err(XlsxError::RowColumnLimitError(format!(
+                "serialized struct '{}', field '{}', row {row}, in Worksheet::serialize()",
+                self.current_struct, self.current_field
+            )));
+        }
+
+        let value_format = if field.value_format.is_some() {
+            Arc::clone(&field.value_format)
+        } else {
+            header_config.row_format(row)
+        };
+
+        Ok(Some((row, col, value_format)))
     }
 
     // Store the name and max row of the current struct being serialized.
@@ -2023,6 +2409,96 @@ impl SerializerState {
         header_config.max_row += 1;
     }
 
+    // Get the value to write for `None` values in the current field.
+    pub(crate) fn current_none_value(&self) -> SerializeNoneValue {
+        self.structs
+            .get(&self.current_struct)
+            .and_then(|header_config| header_config.fields.get(&self.current_field))
+            .map_or(SerializeNoneValue::Blank, |field| field.none_value.clone())
+    }
+
+    // Check if the current field is a nested struct that is serialized as part
+    // of the parent struct, under a group header.
+    pub(crate) fn is_group_field(&self) -> bool {
+        self.field_prefix.is_none()
+            && self.depth > self.map_depth()
+            && self
+                .structs
+                .get(&self.current_struct)
+                .is_some_and(|header_config| header_config.groups.contains(&self.current_field))
+    }
+
+    // Check if the current field is a sequence, such as a `Vec` or array, that
+    // is serialized across several columns.
+    pub(crate) fn is_sequence_field(&self) -> bool {
+        self.sequence.is_none()
+            && self.depth > self.map_depth()
+            && self
+                .structs
+                .get(&self.current_struct)
+                .is_some_and(|header_config| header_config.sequences.contains(&self.current_field))
+    }
+
+    // Set the current field to the column field, "field_1", "field_2", etc.,
+    // of the next element of a sequence field.
+    pub(crate) fn next_sequence_element(&mut self) {
+        if let Some((field_name, index)) = &mut self.sequence {
+            *index += 1;
+            self.current_field = format!("{field_name}_{index}");
+        }
+    }
+
+    // Get the value transform function, if any, for the current field.
+    pub(crate) fn current_value_transform(&self) -> Option<SerializeValueTransform> {
+        self.structs
+            .get(&self.current_struct)
+            .and_then(|header_config| header_config.fields.get(&self.current_field))
+            .and_then(|field| field.value_transform.clone())
+    }
+
+    // Get the enum variant format of the current struct being serialized.
+    pub(crate) fn current_enum_format(&self) -> SerializeEnumFormat {
+        self.structs
+            .get(&self.current_struct)
+            .map_or(SerializeEnumFormat::InnerValue, |header_config| {
+                header_config.enum_format
+            })
+    }
+
+    // Get the depth of the keys of the innermost map that is serialized as a
+    // row, or 0 if there isn't one.
+    pub(crate) fn map_depth(&self) -> usize {
+        self.maps.last().map_or(0, |map| map.depth)
+    }
+
+    // Get the name that the headers of a serialized map are stored under.
+    // Structs with `#[serde(flatten)]` fields are serialized by Serde as maps
+    // without a name so they are matched by the Rust type of the serialized
+    // data instead.
+    pub(crate) fn map_struct_name(&self, is_flattened: bool) -> String {
+        if is_flattened {
+            for (struct_name, header_config) in &self.structs {
+                if let Some(type_path) = &header_config.type_path {
+                    if contains_type_path(self.type_name, type_path) {
+                        return struct_name.clone();
+                    }
+                }
+            }
+        }
+
+        SERIALIZED_MAP_NAME.to_string()
+    }
+
+    // Get the cell location and format to write a map key when a map of
+    // structs is serialized with a row label column.
+    pub(crate) fn map_key_location(&self) -> Option<(RowNum, ColNum, Arc<Option<Format>>)> {
+        let header_config = self.structs.get(&self.current_struct)?;
+        let col = header_config.map_key_col?;
+        let row = header_config.max_row - 1;
+
+        Some((row, col, header_config.row_format(row)))
+    }
+
     // Get dimensions of a serialization area. This is the internal function for
     // worksheet.get_serialize_dimensions().
     pub(crate) fn get_dimensions(
@@ -2082,6 +2558,17 @@ impl SerializerState {
 
         tables
     }
+
+    // Get all/any conditional formats defined for serialized fields.
+    pub(crate) fn get_conditional_formats(&mut self) -> Vec<ConditionalFormatData> {
+        let mut conditional_formats = vec![];
+
+        for header_config in self.structs.values_mut() {
+            conditional_formats.append(&mut header_config.get_conditional_formats());
+        }
+
+        conditional_formats
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -2094,10 +2581,27 @@ pub(crate) struct SerializationHeaderConfig {
     pub(crate) min_col: ColNum,
     pub(crate) max_row: RowNum,
     pub(crate) max_col: ColNum,
+    pub(crate) map_key_col: Option<ColNum>,
+    pub(crate) enum_format: SerializeEnumFormat,
+    pub(crate) first_data_row: RowNum,
+    pub(crate) row_formats: Vec<Arc<Option<Format>>>,
     pub(crate) table: Option<Table>,
+    pub(crate) groups: HashSet<String>,
+    pub(crate) sequences: HashSet<String>,
+    pub(crate) type_path: Option<String>,
 }
 
 impl SerializationHeaderConfig {
+    // Get the banded row format, if any, for a serialized data row.
+    pub(crate) fn row_format(&self, row: RowNum) -> Arc<Option<Format>> {
+        if self.row_formats.is_empty() {
+            return Arc::new(None);
+        }
+
+        let index = row.saturating_sub(self.first_data_row) as usize % self.row_formats.len();
+        Arc::clone(&self.row_formats[index])
+    }
+
     // Get table object and dimensions for the a serialization area.
     pub(crate) fn get_table(&mut self) -> Option<TableData> {
         let table = self.table.take();
@@ -2113,6 +2617,34 @@ impl SerializationHeaderConfig {
             None => None,
         }
     }
+
+    // Get the conditional formats for the fields, applied to the serialized
+    // data range of the field column. The conditional formats are removed so
+    // that they are only applied once.
+    pub(crate) fn get_conditional_formats(&mut self) -> Vec<ConditionalFormatData> {
+        let mut conditional_formats = vec![];
+
+        for field in self.fields.values_mut() {
+            let field_formats = std::mem::take(&mut field.conditional_formats);
+
+            // Ignore skipped fields and fields without any serialized data.
+            if field.skip || self.max_row <= self.first_data_row {
+                continue;
+            }
+
+            for conditional_format in field_formats {
+                conditional_formats.push(ConditionalFormatData(
+                    self.first_data_row,
+                    field.col,
+                    self.max_row - 1,
+                    field.col,
+                    conditional_format.0,
+                ));
+            }
+        }
+
+        conditional_formats
+    }
 }
 
 // -----------------------------------------------------------------------
@@ -2218,11 +2750,17 @@ impl SerializationHeaderConfig {
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 pub struct SerializeFieldOptions {
     pub(crate) struct_name: String,
+    pub(crate) type_path: Option<String>,
     pub(crate) header_format: Option<Format>,
     pub(crate) has_headers: bool,
     pub(crate) custom_headers: Vec<CustomSerializeField>,
     pub(crate) use_custom_headers_only: bool,
+    pub(crate) map_key_header: Option<String>,
+    pub(crate) enum_format: SerializeEnumFormat,
+    pub(crate) row_formats: Vec<Format>,
     pub(crate) table: Option<Table>,
+    pub(crate) group_headers: bool,
+    pub(crate) expand_sequences: bool,
 }
 
 impl Default for SerializeFieldOptions {
@@ -2245,11 +2783,17 @@ impl SerializeFieldOptions {
     pub fn new() -> SerializeFieldOptions {
         SerializeFieldOptions {
             struct_name: String::new(),
+            type_path: None,
             header_format: None,
             has_headers: true,
             custom_headers: vec![],
             use_custom_headers_only: false,
+            map_key_header: None,
+            enum_format: SerializeEnumFormat::InnerValue,
+            row_formats: vec![],
             table: None,
+            group_headers: false,
+            expand_sequences: false,
         }
     }
 
@@ -2415,30 +2959,32 @@ impl SerializeFieldOptions {
         self
     }
 
-    /// Set custom properties for serialized fields.
+    /// Set the header for a row label column of map keys.
     ///
-    /// This method allows customization of of the serialization output of
-    /// individual fields. It allows you to rename field headers, set
-    /// formatting for serialized values, set the column width and other properties.
+    /// When a map of structs, such as a `BTreeMap<String, T>`, is serialized
+    /// the values are written as rows in the same way as a vector of structs.
+    /// This option adds a column before the struct fields, with the given
+    /// header, where the map key of each row is written as a row label.
     ///
-    /// See [`CustomSerializeField`] for more details.
+    /// See [Serializing maps](crate::serializer#serializing-maps) for more
+    /// information.
     ///
     /// # Parameters
     ///
-    /// - `custom_headers`: An array of [`CustomSerializeField`] values.
+    /// - `header`: The header string for the map key column.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates serializing instances of a Serde
-    /// derived data structure to a worksheet with custom headers and cell
-    /// formatting.
+    /// The following example demonstrates serializing maps to a worksheet. The
+    /// keys of the first map are used as the headers and the keys of the second
+    /// map are written to a row label column.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers_custom.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_maps.rs
     /// #
-    /// # use rust_xlsxwriter::{
-    /// #     CustomSerializeField, Format, FormatBorder, SerializeFieldOptions, Workbook, XlsxError,
-    /// # };
+    /// # use std::collections::BTreeMap;
+    /// #
+    /// # use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
     /// # use serde::{Deserialize, Serialize};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
@@ -2447,25 +2993,200 @@ impl SerializeFieldOptions {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Add some formats to use with the serialization data.
-    /// #     let header_format = Format::new()
-    /// #         .set_bold()
-    /// #         .set_border(FormatBorder::Thin)
-    /// #         .set_background_color("C6EFCE");
-    /// #
-    /// #     let currency_format = Format::new().set_num_format("$0.00");
-    /// #
-    /// #     // Create a serializable struct.
+    ///     // Add a simple format for the headers.
+    ///     let format = Format::new().set_bold();
+    ///
+    ///     // Create some maps where the keys are used as the headers.
+    ///     let mut sales1 = BTreeMap::new();
+    ///     sales1.insert("East", 1000);
+    ///     sales1.insert("North", 3000);
+    ///     sales1.insert("West", 2000);
+    ///
+    ///     let mut sales2 = BTreeMap::new();
+    ///     sales2.insert("East", 1200);
+    ///     sales2.insert("North", 3300);
+    ///     sales2.insert("West", 2400);
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.serialize_headers_with_format(0, 0, &sales1, &format)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&vec![sales1, sales2])?;
+    ///
+    ///     // Create a map of structs where the keys are used as row labels.
     ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
     ///     struct Produce {
-    ///         fruit: &'static str,
     ///         cost: f64,
+    ///         stock: u32,
     ///     }
     ///
-    ///     // Create some data instances.
-    ///     let item1 = Produce {
-    ///         fruit: "Peach",
-    ///         cost: 1.05,
+    ///     let mut produce = BTreeMap::new();
+    ///     produce.insert("Peach", Produce { cost: 1.05, stock: 20 });
+    ///     produce.insert("Pear", Produce { cost: 0.75, stock: 35 });
+    ///     produce.insert("Plum", Produce { cost: 0.15, stock: 40 });
+    ///
+    ///     // Set the header for the column of map keys.
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(&format)
+    ///         .set_map_key_header("Fruit");
+    ///
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 4, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&produce)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_map_key_header(mut self, header: impl Into<String>) -> SerializeFieldOptions {
+        self.map_key_header = Some(header.into());
+        self
+    }
+
+    /// Set alternating formats for the serialized rows.
+    ///
+    /// The formats are applied in turn to each serialized record, across all
+    /// the fields of the struct, and then repeated. This can be used to add
+    /// banded or "zebra striped" rows to the serialized data. Fields that have
+    /// a value format set via [`CustomSerializeField::set_value_format()`] use
+    /// that format instead of the row format.
+    ///
+    /// # Parameters
+    ///
+    /// - `formats`: A slice of [`Format`] objects to apply to the rows in
+    ///   turn.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing data with alternating row
+    /// formats.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_row_formats.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.05,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.15,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Pear",
+    ///             cost: 0.75,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Apple",
+    ///             cost: 0.50,
+    ///         },
+    ///     ];
+    ///
+    ///     // Set up alternating row formats.
+    ///     let row_formats = [
+    ///         Format::new().set_background_color("DCE6F1"),
+    ///         Format::new().set_background_color("FFFFFF"),
+    ///     ];
+    ///
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(Format::new().set_bold())
+    ///         .set_row_formats(&row_formats);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_row_formats(mut self, formats: &[Format]) -> SerializeFieldOptions {
+        self.row_formats = formats.to_vec();
+        self
+    }
+
+    /// Set custom properties for serialized fields.
+    ///
+    /// This method allows customization of of the serialization output of
+    /// individual fields. It allows you to rename field headers, set
+    /// formatting for serialized values, set the column width and other properties.
+    ///
+    /// See [`CustomSerializeField`] for more details.
+    ///
+    /// # Parameters
+    ///
+    /// - `custom_headers`: An array of [`CustomSerializeField`] values.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing instances of a Serde
+    /// derived data structure to a worksheet with custom headers and cell
+    /// formatting.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_custom.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, Format, FormatBorder, SerializeFieldOptions, Workbook, XlsxError,
+    /// # };
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add some formats to use with the serialization data.
+    /// #     let header_format = Format::new()
+    /// #         .set_bold()
+    /// #         .set_border(FormatBorder::Thin)
+    /// #         .set_background_color("C6EFCE");
+    /// #
+    /// #     let currency_format = Format::new().set_num_format("$0.00");
+    /// #
+    /// #     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
     ///     };
     ///
     ///     let item2 = Produce {
@@ -2908,6 +3629,315 @@ impl SerializeFieldOptions {
         self.struct_name = name.into();
         self
     }
+
+    /// Set the format used to serialize enum variants that contain a value.
+    ///
+    /// Unit enum variants, like `Status::Active`, are always serialized as
+    /// the variant name string. Newtype enum variants, like `Amount::Usd(9.5)`,
+    /// are serialized as their inner value by default. This option allows them
+    /// to be serialized as the variant name instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: A [`SerializeEnumFormat`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing structs that contain enum
+    /// fields to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_enums.rs
+    /// #
+    /// # use rust_xlsxwriter::{SerializeEnumFormat, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create some serializable enums and a struct that contains them.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     enum Status {
+    ///         Pending,
+    ///         Shipped,
+    ///     }
+    ///
+    ///     #[derive(Deserialize, Serialize)]
+    ///     enum Payment {
+    ///         Card(f64),
+    ///         Cash(f64),
+    ///     }
+    ///
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Order {
+    ///         id: u32,
+    ///         status: Status,
+    ///         payment: Payment,
+    ///     }
+    ///
+    ///     let orders = [
+    ///         Order {
+    ///             id: 1001,
+    ///             status: Status::Shipped,
+    ///             payment: Payment::Card(12.5),
+    ///         },
+    ///         Order {
+    ///             id: 1002,
+    ///             status: Status::Pending,
+    ///             payment: Payment::Cash(7.25),
+    ///         },
+    ///     ];
+    ///
+    ///     // Serialize the newtype variants as their inner value (the default).
+    ///     worksheet.deserialize_headers::<Order>(0, 0)?;
+    ///     worksheet.serialize(&orders)?;
+    ///
+    ///     // Serialize the newtype variants as the variant name.
+    ///     let header_options =
+    ///         SerializeFieldOptions::new().set_enum_format(SerializeEnumFormat::VariantName);
+    ///
+    ///     worksheet.deserialize_headers_with_options::<Order>(0, 4, &header_options)?;
+    ///     worksheet.serialize(&orders)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_enum_format(mut self, format: SerializeEnumFormat) -> SerializeFieldOptions {
+        self.enum_format = format;
+        self
+    }
+
+    /// Write merged group headers for the fields of nested structs.
+    ///
+    /// By default a nested struct field is serialized as a single column. With
+    /// this option turned on the fields of the nested struct are serialized to
+    /// their own columns with a merged group header, the name of the nested
+    /// struct field, above the field headers. Fields that aren't nested structs
+    /// get an empty, formatted, cell in the group header row.
+    ///
+    /// The fields of a nested struct can be customized with
+    /// [`CustomSerializeField`] using a dotted `"group.field"` name. The group
+    /// header itself can be renamed or formatted using the group name, for
+    /// example `CustomSerializeField::new("group").rename("Group")`. If the
+    /// group field is skipped then all of the group columns are skipped.
+    ///
+    /// The nested field names aren't available at compile time so this option
+    /// requires an instance of the data and
+    /// [`Worksheet::serialize_headers_with_options()`](crate::Worksheet::serialize_headers_with_options).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing a struct with nested
+    /// structs to a worksheet with merged group headers.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_group_headers.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, Format, FormatAlign, FormatBorder, SerializeFieldOptions,
+    /// #     Workbook, XlsxError,
+    /// # };
+    /// # use serde::Serialize;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add some formats to use with the serialization data.
+    ///     let header_format = Format::new()
+    ///         .set_bold()
+    ///         .set_align(FormatAlign::Center)
+    ///         .set_border(FormatBorder::Thin);
+    ///
+    ///     // Create some serializable nested structs.
+    ///     #[derive(Serialize)]
+    ///     struct Dimensions {
+    ///         width: f64,
+    ///         height: f64,
+    ///     }
+    ///
+    ///     #[derive(Serialize)]
+    ///     struct Product {
+    ///         name: &'static str,
+    ///         size: Dimensions,
+    ///     }
+    ///
+    ///     let items = [
+    ///         Product {
+    ///             name: "Box",
+    ///             size: Dimensions {
+    ///                 width: 10.0,
+    ///                 height: 5.0,
+    ///             },
+    ///         },
+    ///         Product {
+    ///             name: "Crate",
+    ///             size: Dimensions {
+    ///                 width: 40.0,
+    ///                 height: 25.0,
+    ///             },
+    ///         },
+    ///     ];
+    ///
+    ///     // Set the serialization options, including the nested field names.
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(&header_format)
+    ///         .set_group_headers(true)
+    ///         .set_custom_headers(&[
+    ///             CustomSerializeField::new("size").rename("Dimensions"),
+    ///             CustomSerializeField::new("size.width").rename("Width"),
+    ///             CustomSerializeField::new("size.height").rename("Height"),
+    ///         ]);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers_with_options(0, 0, &items[0], &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_group_headers(mut self, enable: bool) -> SerializeFieldOptions {
+        self.group_headers = enable;
+        self
+    }
+
+    /// Serialize sequence fields across several columns.
+    ///
+    /// By default the values of a struct field that is a sequence, such as a
+    /// `Vec<f64>` or a fixed-size array, are all written to the single column
+    /// of the field. With this option turned on each element of the sequence
+    /// is serialized to its own adjacent column. The column headers are the
+    /// field name with a 1-based index suffix such as `sales_1`, `sales_2`,
+    /// etc.
+    ///
+    /// The number of columns is taken from the length of the sequence in the
+    /// instance that is used to set up the headers. Shorter sequences in
+    /// subsequent data leave the remaining cells blank and any additional
+    /// elements in longer sequences are ignored.
+    ///
+    /// The element columns can be customized with [`CustomSerializeField`]
+    /// using the suffixed name, for example `"sales_1"`. Options set for the
+    /// sequence field name, such as a rename, format or skip, are applied to
+    /// all of the element columns. A renamed sequence field is used as the
+    /// base of the suffixed header names.
+    ///
+    /// The length of the sequence isn't available at compile time so this
+    /// option requires an instance of the data and
+    /// [`Worksheet::serialize_headers_with_options()`](crate::Worksheet::serialize_headers_with_options).
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing a struct with a vector
+    /// field across several columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_sequences.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError,
+    /// # };
+    /// # use serde::Serialize;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct with a sequence field.
+    ///     #[derive(Serialize)]
+    ///     struct Region {
+    ///         name: &'static str,
+    ///         sales: Vec<f64>,
+    ///     }
+    ///
+    ///     let regions = [
+    ///         Region {
+    ///             name: "North",
+    ///             sales: vec![1200.0, 1350.0, 1100.0, 1500.0],
+    ///         },
+    ///         Region {
+    ///             name: "South",
+    ///             sales: vec![980.0, 1020.0, 1250.0, 1190.0],
+    ///         },
+    ///     ];
+    ///
+    ///     // Serialize the sales to a column for each quarter.
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_header_format(Format::new().set_bold())
+    ///         .set_expand_sequences(true)
+    ///         .set_custom_headers(&[CustomSerializeField::new("sales")
+    ///             .rename("Q")
+    ///             .set_value_format(Format::new().set_num_format("#,##0"))]);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.serialize_headers_with_options(0, 0, &regions[0], &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&regions)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_expand_sequences(mut self, enable: bool) -> SerializeFieldOptions {
+        self.expand_sequences = enable;
+        self
+    }
+}
+
+// -----------------------------------------------------------------------
+// SerializeEnumFormat.
+// -----------------------------------------------------------------------
+
+/// The `SerializeEnumFormat` enum defines how newtype enum variants are
+/// serialized.
+///
+/// It is used with [`SerializeFieldOptions::set_enum_format()`]. Unit enum
+/// variants don't contain a value and are always serialized as the variant
+/// name.
+///
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub enum SerializeEnumFormat {
+    /// Serialize newtype variants, like `Amount::Usd(9.5)`, as the inner
+    /// value, `9.5`. This is the default.
+    #[default]
+    InnerValue,
+
+    /// Serialize newtype variants, like `Amount::Usd(9.5)`, as the variant
+    /// name, `Usd`.
+    VariantName,
 }
 
 // -----------------------------------------------------------------------
@@ -3024,6 +4054,11 @@ pub struct CustomSerializeField {
     pub(crate) col: ColNum,
     pub(crate) width: Option<f64>,
     pub(crate) pixel_width: Option<u16>,
+    pub(crate) none_value: SerializeNoneValue,
+    pub(crate) value_transform: Option<SerializeValueTransform>,
+    pub(crate) group_header: Option<(String, Option<Format>)>,
+    pub(crate) conditional_formats: Vec<SerializeConditionalFormat>,
+    pub(crate) sequence_name: Option<String>,
 }
 
 impl CustomSerializeField {
@@ -3054,6 +4089,11 @@ impl CustomSerializeField {
             col: 0,
             width: None,
             pixel_width: None,
+            none_value: SerializeNoneValue::Blank,
+            value_transform: None,
+            group_header: None,
+            conditional_formats: vec![],
+            sequence_name: None,
         }
     }
 
@@ -3351,11 +4391,236 @@ impl CustomSerializeField {
     ///
     ///     // Set the serialization location and headers.
     ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
-    /// #
-    /// #     // Serialize the data.
-    /// #     worksheet.serialize(&item1)?;
-    /// #     worksheet.serialize(&item2)?;
-    /// #     worksheet.serialize(&item3)?;
+    /// #
+    /// #     // Serialize the data.
+    /// #     worksheet.serialize(&item1)?;
+    /// #     worksheet.serialize(&item2)?;
+    /// #     worksheet.serialize(&item3)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_format3.png">
+    ///
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn set_value_format(mut self, format: impl Into<Format>) -> CustomSerializeField {
+        self.value_format = Arc::new(Some(format.into()));
+        self
+    }
+
+    /// Skip a field when serializing.
+    ///
+    /// When serializing a struct you may not want all of the fields to be
+    /// serialized. For example the struct may contain internal fields that
+    /// aren't of interest to the end user. There are several ways to skip
+    /// fields:
+    ///
+    /// 1. Using the Serde [field attributes] `#[serde(skip)]`. This is the
+    ///    simplest and best method.
+    /// 2. Explicitly omitting the field when setting up custom serialization
+    ///    headers This method is useful when you can't add any additional
+    ///    attributes on the struct.
+    /// 3. Marking the field as skippable via custom headers and this `skip()`
+    ///    method. This is only required in a few edge cases where the previous
+    ///    methods won't work.
+    ///
+    /// [field attributes]: https://serde.rs/field-attrs.html
+    ///
+    /// See [Skipping fields when
+    /// serializing](crate::serializer#skipping-fields-when-serializing) for
+    /// more details.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates skipping fields during serialization
+    /// by explicitly skipping them via custom headers.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_headers_skip3.rs
+    /// #
+    /// # use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///         in_stock: bool,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let item1 = Produce {
+    ///         fruit: "Peach",
+    ///         cost: 1.05,
+    ///         in_stock: true,
+    ///     };
+    ///
+    ///     let item2 = Produce {
+    ///         fruit: "Plum",
+    ///         cost: 0.15,
+    ///         in_stock: true,
+    ///     };
+    ///
+    ///     let item3 = Produce {
+    ///         fruit: "Pear",
+    ///         cost: 0.75,
+    ///         in_stock: false,
+    ///     };
+    ///
+    ///     // We only need to set a custom header for the field we want to skip.
+    ///     let header_options = SerializeFieldOptions::new()
+    ///         .set_custom_headers(&[CustomSerializeField::new("in_stock").skip(true)]);
+    ///
+    ///     // Set the serialization location and custom headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&item1)?;
+    ///     worksheet.serialize(&item2)?;
+    ///     worksheet.serialize(&item3)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_skip1.png">
+    ///
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn skip(mut self, enable: bool) -> CustomSerializeField {
+        self.skip = enable;
+        self
+    }
+
+    /// Set the width for the column corresponding to a serialize header/field.
+    ///
+    /// The `set_column_width()` method is used to change the default width of a
+    /// worksheet column in character units.
+    ///
+    /// This a a wrapper around the [`Worksheet::set_column_width()`] method
+    /// with the advantage that it doesn't require you to keep track of the
+    /// actual column number to use it.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The row width in character units.
+    ///
+    pub fn set_column_width(mut self, width: impl Into<f64>) -> CustomSerializeField {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// Set the width for the column corresponding to a serialize header/field.
+    ///
+    /// The `set_column_width_pixels()` method is used to change the default
+    /// width of a worksheet column in pixel units.
+    ///
+    /// This a a wrapper around the [`Worksheet::set_column_width_pixels()`]
+    /// method with the advantage that it doesn't require you to keep track of
+    /// the actual column number to use it.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The row width in character units.
+    ///
+    pub fn set_column_width_pixels(mut self, width: u16) -> CustomSerializeField {
+        self.pixel_width = Some(width);
+        self
+    }
+
+    /// Set the value written for `None` and other empty values.
+    ///
+    /// By default `Option` fields with a `None` value are written as a blank
+    /// cell, which is only written to the worksheet if the field has a value
+    /// format. This method allows you to write a default literal value, like
+    /// `"N/A"` or `0`, or to skip the cell entirely so that reports can
+    /// distinguish missing data.
+    ///
+    /// The value is written with the field value format, if any.
+    ///
+    /// # Parameters
+    ///
+    /// - `value`: A [`SerializeNoneValue`] enum value, or a string or number
+    ///   that can be converted into one.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing `None` values with a
+    /// default literal value.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_none_value.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, SerializeFieldOptions, SerializeNoneValue, Workbook, XlsxError,
+    /// # };
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct with optional fields.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: Option<f64>,
+    ///         supplier: Option<&'static str>,
+    ///     }
+    ///
+    ///     // Create some data instances.
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: Some(1.05),
+    ///             supplier: None,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: None,
+    ///             supplier: Some("Orchard Co."),
+    ///         },
+    ///     ];
+    ///
+    ///     // Write "N/A" for a missing cost and skip a missing supplier.
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("Cost").set_none_value("N/A"),
+    ///         CustomSerializeField::new("Supplier").set_none_value(SerializeNoneValue::Skip),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
     /// #
     /// #     // Save the file.
     /// #     workbook.save("serialize.xlsx")?;
@@ -3364,53 +4629,43 @@ impl CustomSerializeField {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_format3.png">
-    ///
-    ///
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn set_value_format(mut self, format: impl Into<Format>) -> CustomSerializeField {
-        self.value_format = Arc::new(Some(format.into()));
+    pub fn set_none_value(mut self, value: impl Into<SerializeNoneValue>) -> CustomSerializeField {
+        self.none_value = value.into();
         self
     }
 
-    /// Skip a field when serializing.
-    ///
-    /// When serializing a struct you may not want all of the fields to be
-    /// serialized. For example the struct may contain internal fields that
-    /// aren't of interest to the end user. There are several ways to skip
-    /// fields:
-    ///
-    /// 1. Using the Serde [field attributes] `#[serde(skip)]`. This is the
-    ///    simplest and best method.
-    /// 2. Explicitly omitting the field when setting up custom serialization
-    ///    headers This method is useful when you can't add any additional
-    ///    attributes on the struct.
-    /// 3. Marking the field as skippable via custom headers and this `skip()`
-    ///    method. This is only required in a few edge cases where the previous
-    ///    methods won't work.
+    /// Set a function to transform the field values before they are written.
     ///
-    /// [field attributes]: https://serde.rs/field-attrs.html
+    /// By default the serialized values of a field are written to the
+    /// worksheet as they are. This method allows you to set a closure that
+    /// maps each value to a different value before it is written, for example
+    /// to scale an amount in cents to dollars or to map booleans to "Yes" and
+    /// "No". This avoids duplicating a struct just to change the presentation
+    /// of the data in Excel.
     ///
-    /// See [Skipping fields when
-    /// serializing](crate::serializer#skipping-fields-when-serializing) for
-    /// more details.
+    /// The closure takes and returns an [`ExcelCellValue`]. Serialized
+    /// numbers, booleans and strings are passed as the equivalent
+    /// [`ExcelCellValue`] variant and blank or `None` values are passed as
+    /// [`ExcelCellValue::Blank`]. Dates and times are serialized as numbers.
+    /// The returned value is written with the field value format, if any.
     ///
     /// # Parameters
     ///
-    /// - `enable`: Turn the property on/off. It is off by default.
+    /// - `transform`: A closure that takes an [`ExcelCellValue`] and returns
+    ///   the [`ExcelCellValue`] to write.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates skipping fields during serialization
-    /// by explicitly skipping them via custom headers.
+    /// The following example demonstrates transforming field values when
+    /// serializing.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_serialize_headers_skip3.rs
+    /// # // This code is available in examples/doc_worksheet_serialize_value_transform.rs
     /// #
-    /// # use rust_xlsxwriter::{CustomSerializeField, SerializeFieldOptions, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{
+    /// #     CustomSerializeField, ExcelCellValue, Format, SerializeFieldOptions, Workbook,
+    /// #     XlsxError,
+    /// # };
     /// # use serde::{Deserialize, Serialize};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
@@ -3419,44 +4674,52 @@ impl CustomSerializeField {
     /// #     // Add a worksheet to the workbook.
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     // Create a serializable struct.
+    ///     // Create a serializable struct.
     ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
     ///     struct Produce {
     ///         fruit: &'static str,
-    ///         cost: f64,
+    ///         cents: u32,
     ///         in_stock: bool,
     ///     }
     ///
     ///     // Create some data instances.
-    ///     let item1 = Produce {
-    ///         fruit: "Peach",
-    ///         cost: 1.05,
-    ///         in_stock: true,
-    ///     };
-    ///
-    ///     let item2 = Produce {
-    ///         fruit: "Plum",
-    ///         cost: 0.15,
-    ///         in_stock: true,
-    ///     };
-    ///
-    ///     let item3 = Produce {
-    ///         fruit: "Pear",
-    ///         cost: 0.75,
-    ///         in_stock: false,
-    ///     };
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cents: 105,
+    ///             in_stock: true,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cents: 99,
+    ///             in_stock: false,
+    ///         },
+    ///     ];
     ///
-    ///     // We only need to set a custom header for the field we want to skip.
-    ///     let header_options = SerializeFieldOptions::new()
-    ///         .set_custom_headers(&[CustomSerializeField::new("in_stock").skip(true)]);
+    ///     // Convert the cents to dollars and the booleans to "Yes"/"No".
+    ///     let currency_format = Format::new().set_num_format("$0.00");
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("Cents")
+    ///             .rename("Cost")
+    ///             .set_value_format(&currency_format)
+    ///             .set_value_transform(|value| match value {
+    ///                 ExcelCellValue::Number(cents) => ExcelCellValue::Number(cents / 100.0),
+    ///                 value => value,
+    ///             }),
+    ///         CustomSerializeField::new("InStock").set_value_transform(|value| match value {
+    ///             ExcelCellValue::Boolean(true) => "Yes".into(),
+    ///             ExcelCellValue::Boolean(false) => "No".into(),
+    ///             value => value,
+    ///         }),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
     ///
-    ///     // Set the serialization location and custom headers.
+    ///     // Set the serialization location and headers.
     ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
     ///
     ///     // Serialize the data.
-    ///     worksheet.serialize(&item1)?;
-    ///     worksheet.serialize(&item2)?;
-    ///     worksheet.serialize(&item3)?;
+    ///     worksheet.serialize(&items)?;
     /// #
     /// #     // Save the file.
     /// #     workbook.save("serialize.xlsx")?;
@@ -3465,60 +4728,203 @@ impl CustomSerializeField {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_serialize_headers_skip1.png">
-    ///
-    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
-    pub fn skip(mut self, enable: bool) -> CustomSerializeField {
-        self.skip = enable;
+    pub fn set_value_transform(
+        mut self,
+        transform: impl Fn(ExcelCellValue) -> ExcelCellValue + Send + Sync + 'static,
+    ) -> CustomSerializeField {
+        self.value_transform = Some(Arc::new(transform));
         self
     }
 
-    /// Set the width for the column corresponding to a serialize header/field.
+    /// Add a conditional format to the serialized data of a field.
     ///
-    /// The `set_column_width()` method is used to change the default width of a
-    /// worksheet column in character units.
+    /// Conditional formats are usually added to a worksheet with
+    /// [`Worksheet::add_conditional_format()`] which requires the cell range of
+    /// the data. For serialized data the range isn't known until all of the
+    /// data has been written. This method stores the conditional format with
+    /// the field and applies it to the data cells of the field column, not
+    /// including the header, when the workbook is saved.
     ///
-    /// This a a wrapper around the [`Worksheet::set_column_width()`] method
-    /// with the advantage that it doesn't require you to keep track of the
-    /// actual column number to use it.
+    /// The method can be called more than once to add several conditional
+    /// formats to the same field. Conditional formats aren't applied to
+    /// skipped fields or to fields without any serialized data.
     ///
     /// # Parameters
     ///
-    /// - `width`: The row width in character units.
+    /// - `conditional_format`: A conditional format instance that implements
+    ///   the [`ConditionalFormat`] trait, such as
+    ///   [`ConditionalFormatCell`](crate::ConditionalFormatCell) or
+    ///   [`ConditionalFormatDataBar`](crate::ConditionalFormatDataBar).
     ///
-    pub fn set_column_width(mut self, width: impl Into<f64>) -> CustomSerializeField {
-        self.width = Some(width.into());
-        self
-    }
-
-    /// Set the width for the column corresponding to a serialize header/field.
+    /// # Examples
     ///
-    /// The `set_column_width_pixels()` method is used to change the default
-    /// width of a worksheet column in pixel units.
+    /// The following example demonstrates adding conditional formats to
+    /// serialized fields.
     ///
-    /// This a a wrapper around the [`Worksheet::set_column_width_pixels()`]
-    /// method with the advantage that it doesn't require you to keep track of
-    /// the actual column number to use it.
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_conditional_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     ConditionalFormatCell, ConditionalFormatCellRule, ConditionalFormatDataBar,
+    /// #     CustomSerializeField, Format, SerializeFieldOptions, Workbook, XlsxError,
+    /// # };
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Produce {
+    ///         fruit: &'static str,
+    ///         cost: f64,
+    ///         stock: u32,
+    ///     }
     ///
-    /// # Parameters
+    ///     // Create some data instances.
+    ///     let items = [
+    ///         Produce {
+    ///             fruit: "Peach",
+    ///             cost: 1.05,
+    ///             stock: 120,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Plum",
+    ///             cost: 0.15,
+    ///             stock: 35,
+    ///         },
+    ///         Produce {
+    ///             fruit: "Pear",
+    ///             cost: 0.75,
+    ///             stock: 80,
+    ///         },
+    ///     ];
     ///
-    /// - `width`: The row width in character units.
+    ///     // Highlight costs above 0.50 and add data bars to the stock values.
+    ///     let red_format = Format::new()
+    ///         .set_font_color("9C0006")
+    ///         .set_background_color("FFC7CE");
     ///
-    pub fn set_column_width_pixels(mut self, width: u16) -> CustomSerializeField {
-        self.pixel_width = Some(width);
+    ///     let cost_format = ConditionalFormatCell::new()
+    ///         .set_rule(ConditionalFormatCellRule::GreaterThan(0.5))
+    ///         .set_format(red_format);
+    ///
+    ///     let custom_headers = [
+    ///         CustomSerializeField::new("Cost").set_conditional_format(&cost_format),
+    ///         CustomSerializeField::new("Stock")
+    ///             .set_conditional_format(&ConditionalFormatDataBar::new()),
+    ///     ];
+    ///     let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+    ///
+    ///     // Set the serialization location and headers.
+    ///     worksheet.deserialize_headers_with_options::<Produce>(0, 0, &header_options)?;
+    ///
+    ///     // Serialize the data.
+    ///     worksheet.serialize(&items)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// [`Worksheet::add_conditional_format()`]:
+    ///     crate::Worksheet::add_conditional_format
+    ///
+    pub fn set_conditional_format<T>(mut self, conditional_format: &T) -> CustomSerializeField
+    where
+        T: ConditionalFormat + Send,
+    {
+        self.conditional_formats
+            .push(SerializeConditionalFormat(conditional_format.box_clone()));
         self
     }
 }
 
+// A closure to transform serialized field values before they are written.
+pub(crate) type SerializeValueTransform =
+    Arc<dyn Fn(ExcelCellValue) -> ExcelCellValue + Send + Sync>;
+
+// A wrapper for a conditional format trait object so that it can be cloned
+// with the field options.
+pub(crate) struct SerializeConditionalFormat(pub(crate) Box<dyn ConditionalFormat + Send>);
+
+impl Clone for SerializeConditionalFormat {
+    fn clone(&self) -> Self {
+        SerializeConditionalFormat(self.0.box_clone())
+    }
+}
+
+// -----------------------------------------------------------------------
+// SerializeNoneValue.
+// -----------------------------------------------------------------------
+
+/// The `SerializeNoneValue` enum defines how `None` and other empty values are
+/// serialized.
+///
+/// It is used with [`CustomSerializeField::set_none_value()`]. Strings and
+/// numbers can be converted directly into the `String` and `Number` variants:
+///
+/// ```
+/// # use rust_xlsxwriter::{CustomSerializeField, SerializeNoneValue};
+/// #
+/// let field1 = CustomSerializeField::new("Cost").set_none_value("N/A");
+/// let field2 = CustomSerializeField::new("Stock").set_none_value(0);
+/// let field3 = CustomSerializeField::new("Notes").set_none_value(SerializeNoneValue::Skip);
+/// ```
+///
+#[derive(Clone, Debug, Default, PartialEq)]
+#[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+pub enum SerializeNoneValue {
+    /// Write a blank cell. Blank cells are only written to the worksheet if
+    /// the field has a value format. This is the default.
+    #[default]
+    Blank,
+
+    /// Don't write anything to the cell, even if the field has a value format.
+    Skip,
+
+    /// Write a string value, such as `"N/A"`.
+    String(String),
+
+    /// Write a number value, such as `0`.
+    Number(f64),
+}
+
+impl From<&str> for SerializeNoneValue {
+    fn from(value: &str) -> SerializeNoneValue {
+        SerializeNoneValue::String(value.to_string())
+    }
+}
+
+impl From<String> for SerializeNoneValue {
+    fn from(value: String) -> SerializeNoneValue {
+        SerializeNoneValue::String(value)
+    }
+}
+
+macro_rules! none_value_from_number_impl {
+    ($($t:ty)*) => ($(
+        impl From<$t> for SerializeNoneValue {
+            fn from(value: $t) -> SerializeNoneValue {
+                SerializeNoneValue::Number(value.into())
+            }
+        }
+    )*)
+}
+none_value_from_number_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+
 // -----------------------------------------------------------------------
 // Worksheet Serializer. This is the implementation of the Serializer trait to
 // serialized a serde derived struct to an Excel worksheet.
 // -----------------------------------------------------------------------
 #[allow(unused_variables)]
-impl<'a> ser::Serializer for &'a mut Worksheet {
+impl ser::Serializer for &mut Worksheet {
     #[doc(hidden)]
     type Ok = ();
     #[doc(hidden)]
@@ -3625,11 +5031,17 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
     // Empty/None/Null values in Excel are ignored unless the cell has
     // formatting in which case they are handled as a "blank" cell. For all of
     // these cases we write an empty string and the worksheet writer methods
-    // will handle it correctly based on context.
+    // will handle it correctly based on context. The user can also specify a
+    // default value to write instead or skip the cell.
 
     #[doc(hidden)]
     fn serialize_none(self) -> Result<(), XlsxError> {
-        self.serialize_str("")
+        match self.serializer_state.current_none_value() {
+            SerializeNoneValue::Blank => self.serialize_str(""),
+            SerializeNoneValue::Skip => Ok(()),
+            SerializeNoneValue::String(value) => self.serialize_str(&value),
+            SerializeNoneValue::Number(value) => self.serialize_f64(value),
+        }
     }
 
     #[doc(hidden)]
@@ -3642,7 +5054,7 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         self.serialize_none()
     }
 
-    // Excel doesn't have an equivalent for the structure so we ignore it.
+    // Unit enum variants are serialized as the variant name.
     #[doc(hidden)]
     fn serialize_unit_variant(
         self,
@@ -3650,19 +5062,31 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         _variant_index: u32,
         variant: &'static str,
     ) -> Result<(), XlsxError> {
-        Ok(())
+        self.serialize_str(variant)
     }
 
-    // Try to handle this as a single value.
+    // Try to handle this as a single value. The `Formula` and `Url` types are
+    // serialized as newtype structs with the string parts of the type.
     #[doc(hidden)]
-    fn serialize_newtype_struct<T>(self, _name: &'static str, value: &T) -> Result<(), XlsxError>
+    fn serialize_newtype_struct<T>(self, name: &'static str, value: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(self)
+        match name {
+            SERIALIZED_FORMULA_NAME => {
+                let [formula, result] = serialized_strings(value)?;
+                self.write_serialized_cell(Formula::new(formula).set_result(result))
+            }
+            SERIALIZED_URL_NAME => {
+                let [link, text, tip] = serialized_strings(value)?;
+                self.write_serialized_cell(Url::new(link).set_text(text).set_tip(tip))
+            }
+            _ => value.serialize(self),
+        }
     }
 
-    // Excel doesn't have an equivalent for the structure so we ignore it.
+    // Newtype enum variants are serialized as the inner value or the variant
+    // name, depending on the user options.
     #[doc(hidden)]
     fn serialize_newtype_variant<T>(
         self,
@@ -3674,29 +5098,63 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(&mut *self)?;
-        Ok(())
+        match self.serializer_state.current_enum_format() {
+            SerializeEnumFormat::InnerValue => value.serialize(&mut *self),
+            SerializeEnumFormat::VariantName => self.serialize_str(variant),
+        }
     }
 
     // Compound types.
     //
-    // The only compound types we map into the Excel data model are structs.
+    // The compound types we map into the Excel data model are structs and
+    // maps.
 
     // Structs are the main primary data type used to map data structures into
     // Excel.
     fn serialize_struct(
         self,
         name: &'static str,
-        len: usize,
+        _len: usize,
     ) -> Result<Self::SerializeStruct, XlsxError> {
+        // A nested struct in a struct with group headers is serialized to the
+        // same row as the parent struct, with the parent field name as a
+        // prefix for the nested field names.
+        if self.serializer_state.is_group_field() {
+            let state = &mut self.serializer_state;
+            state.depth += 1;
+            state.group_depth = state.depth;
+            state.field_prefix = Some(format!("{}.", state.current_field));
+
+            return Ok(self);
+        }
+
         // Store the struct type name to check against user defined structs.
         self.serializer_state.set_current_struct(name);
+        self.serializer_state.depth += 1;
+
+        // If the struct is a value in a map of structs then write the map key
+        // to the row label column, if there is one.
+        if let Some(key) = self.serializer_state.map_key.take() {
+            if let Some((row, col, format)) = self.serializer_state.map_key_location() {
+                match &*format {
+                    Some(format) => self.write_with_format(row, col, key, format)?,
+                    None => self.write(row, col, key)?,
+                };
+            }
+        }
 
-        self.serialize_map(Some(len))
+        Ok(self)
     }
 
     #[doc(hidden)]
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, XlsxError> {
+        // A sequence field can be serialized across several columns, with one
+        // column for each element.
+        if self.serializer_state.is_sequence_field() {
+            let field_name = self.serializer_state.current_field.clone();
+            self.serializer_state.sequence = Some((field_name, 0));
+        }
+
         Ok(self)
     }
 
@@ -3729,9 +5187,55 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
         Ok(self)
     }
 
-    // The field/values of structs are treated as a map.
+    // Top level maps are treated like a struct where the keys are the field
+    // names. Structs with `#[serde(flatten)]` fields are also serialized by
+    // Serde as maps, of unknown length, so they are handled in the same way.
     #[doc(hidden)]
-    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+        let state = &mut self.serializer_state;
+        let is_flattened = len.is_none();
+
+        if state.depth == 0 {
+            let struct_name = state.map_struct_name(is_flattened);
+            state.set_current_struct(&struct_name);
+            state.maps.push(SerializedMap {
+                struct_name,
+                depth: 1,
+                has_unused_row: true,
+            });
+        } else if state.maps.len() == 1 && state.map_depth() == state.depth {
+            // A map value in a top level map, such as a flattened struct in a
+            // map of structs, is serialized as a row. The first row may have
+            // already been added by the parent map.
+            if let Some(key) = state.map_key.take() {
+                let struct_name = state.map_struct_name(is_flattened);
+                let parent = &mut state.maps[0];
+                let reuse_row = parent.has_unused_row && parent.struct_name == struct_name;
+                parent.has_unused_row = false;
+
+                if reuse_row {
+                    state.current_struct.clone_from(&struct_name);
+                } else {
+                    state.set_current_struct(&struct_name);
+                }
+
+                state.maps.push(SerializedMap {
+                    struct_name,
+                    depth: state.depth + 1,
+                    has_unused_row: false,
+                });
+
+                if let Some((row, col, format)) = state.map_key_location() {
+                    match &*format {
+                        Some(format) => self.write_with_format(row, col, key, format)?,
+                        None => self.write(row, col, key)?,
+                    };
+                }
+            }
+        }
+
+        self.serializer_state.depth += 1;
+
         Ok(self)
     }
 
@@ -3754,7 +5258,7 @@ impl<'a> ser::Serializer for &'a mut Worksheet {
 
 // Structs are the main sequence type used by `rust_xlsxwriter`.
 #[doc(hidden)]
-impl<'a> ser::SerializeStruct for &'a mut Worksheet {
+impl ser::SerializeStruct for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3763,19 +5267,28 @@ impl<'a> ser::SerializeStruct for &'a mut Worksheet {
         T: ?Sized + Serialize,
     {
         // Store field name to allow us to map to the correct header/column.
-        self.serializer_state.current_field = key.to_string();
+        self.serializer_state.current_field = match &self.serializer_state.field_prefix {
+            Some(prefix) => format!("{prefix}{key}"),
+            None => key.to_string(),
+        };
 
         value.serialize(&mut **self)
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        let state = &mut self.serializer_state;
+        if state.field_prefix.is_some() && state.depth == state.group_depth {
+            state.field_prefix = None;
+        }
+
+        state.depth -= 1;
         Ok(())
     }
 }
 
 // We also serialize sequences to map vectors/arrays to Excel.
 #[doc(hidden)]
-impl<'a> ser::SerializeSeq for &'a mut Worksheet {
+impl ser::SerializeSeq for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3784,17 +5297,19 @@ impl<'a> ser::SerializeSeq for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
+        self.serializer_state.next_sequence_element();
         value.serialize(&mut **self)
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        self.serializer_state.sequence = None;
         Ok(())
     }
 }
 
 // Serialize tuple sequences.
 #[doc(hidden)]
-impl<'a> ser::SerializeTuple for &'a mut Worksheet {
+impl ser::SerializeTuple for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3802,17 +5317,19 @@ impl<'a> ser::SerializeTuple for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
+        self.serializer_state.next_sequence_element();
         value.serialize(&mut **self)
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        self.serializer_state.sequence = None;
         Ok(())
     }
 }
 
 // Serialize tuple struct sequences.
 #[doc(hidden)]
-impl<'a> ser::SerializeTupleStruct for &'a mut Worksheet {
+impl ser::SerializeTupleStruct for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3830,7 +5347,7 @@ impl<'a> ser::SerializeTupleStruct for &'a mut Worksheet {
 
 // Serialize tuple variant sequences.
 #[doc(hidden)]
-impl<'a> ser::SerializeTupleVariant for &'a mut Worksheet {
+impl ser::SerializeTupleVariant for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3846,9 +5363,11 @@ impl<'a> ser::SerializeTupleVariant for &'a mut Worksheet {
     }
 }
 
-// Serialize tuple map sequences.
+// Serialize maps. The keys of a top level map are treated like struct field
+// names and are matched against the map headers. They are also stored so they
+// can be written to a row label column if the values are structs.
 #[doc(hidden)]
-impl<'a> ser::SerializeMap for &'a mut Worksheet {
+impl ser::SerializeMap for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3856,24 +5375,51 @@ impl<'a> ser::SerializeMap for &'a mut Worksheet {
     where
         T: ?Sized + Serialize,
     {
-        key.serialize(&mut **self)
+        let state = &self.serializer_state;
+        if state.depth != state.map_depth() {
+            return key.serialize(&mut **self);
+        }
+
+        // Serialize the key to a string. Non-string keys are ignored.
+        let mut headers = SerializerHeader::default();
+        key.serialize(&mut headers)?;
+        let key = headers.field_names.pop().unwrap_or_default();
+
+        let state = &mut self.serializer_state;
+        if let Some(map) = state.maps.last() {
+            state.current_struct.clone_from(&map.struct_name);
+        }
+        state.current_field.clone_from(&key);
+        state.map_key = Some(key);
+
+        Ok(())
     }
 
     fn serialize_value<T>(&mut self, value: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        value.serialize(&mut **self)
+        let result = value.serialize(&mut **self);
+        self.serializer_state.map_key = None;
+
+        result
     }
 
     fn end(self) -> Result<(), XlsxError> {
+        let state = &mut self.serializer_state;
+
+        if state.depth == state.map_depth() {
+            state.maps.pop();
+        }
+        state.depth -= 1;
+
         Ok(())
     }
 }
 
 // Serialize struct variant sequences.
 #[doc(hidden)]
-impl<'a> ser::SerializeStructVariant for &'a mut Worksheet {
+impl ser::SerializeStructVariant for &mut Worksheet {
     type Ok = ();
     type Error = XlsxError;
 
@@ -3894,9 +5440,53 @@ impl<'a> ser::SerializeStructVariant for &'a mut Worksheet {
 // SerializerHeader. A struct used to store header/field name during
 // serialization of the headers.
 // -----------------------------------------------------------------------
+#[derive(Default)]
 pub(crate) struct SerializerHeader {
     pub(crate) struct_name: String,
+    pub(crate) type_path: Option<String>,
+    pub(crate) is_flattened: bool,
     pub(crate) field_names: Vec<String>,
+    pub(crate) capture_groups: bool,
+    pub(crate) group_fields: Vec<(String, Vec<String>)>,
+    pub(crate) capture_sequences: bool,
+    pub(crate) sequence_fields: Vec<(String, usize)>,
+    pub(crate) sequence_len: Option<usize>,
+}
+
+impl SerializerHeader {
+    // Serialize a data structure to capture its type name and field names.
+    // Structs with `#[serde(flatten)]` fields don't have a Serde type name so
+    // their headers are stored under the name of the Rust type instead.
+    pub(crate) fn capture<T>(&mut self, data_structure: &T) -> Result<(), XlsxError>
+    where
+        T: ?Sized + Serialize,
+    {
+        data_structure.serialize(&mut *self)?;
+
+        if self.is_flattened {
+            let type_name = std::any::type_name::<T>().trim_start_matches('&');
+            let type_path = type_name.split('<').next().unwrap_or(type_name);
+            let struct_name = type_path.rsplit("::").next().unwrap_or(type_path);
+
+            self.struct_name = struct_name.to_string();
+            self.type_path = Some(type_path.to_string());
+        }
+
+        Ok(())
+    }
+}
+
+// Check if a Rust type name, such as `Vec<crate::Item>`, contains a type path,
+// such as `crate::Item`, as a complete path.
+fn contains_type_path(type_name: &str, type_path: &str) -> bool {
+    let is_path_char = |c: char| c.is_alphanumeric() || c == '_' || c == ':';
+
+    type_name.match_indices(type_path).any(|(index, _)| {
+        let before = type_name[..index].chars().next_back();
+        let after = type_name[index + type_path.len()..].chars().next();
+
+        !before.is_some_and(is_path_char) && !after.is_some_and(is_path_char)
+    })
 }
 
 // -----------------------------------------------------------------------
@@ -3904,7 +5494,7 @@ pub(crate) struct SerializerHeader {
 // trait to capture the headers/field names only.
 // -----------------------------------------------------------------------
 #[allow(unused_variables)]
-impl<'a> ser::Serializer for &'a mut SerializerHeader {
+impl ser::Serializer for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
     type SerializeSeq = Self;
@@ -4034,6 +5624,7 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
     }
 
     fn serialize_seq(self, _len: Option<usize>) -> Result<Self::SerializeSeq, XlsxError> {
+        self.sequence_len = Some(0);
         Ok(self)
     }
 
@@ -4060,7 +5651,14 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
         Ok(self)
     }
 
-    fn serialize_map(self, _len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+    // Maps don't have a type name so we use a default name for the headers.
+    // Structs with `#[serde(flatten)]` fields are serialized by Serde as maps
+    // of unknown length and are renamed later from their Rust type.
+    fn serialize_map(self, len: Option<usize>) -> Result<Self::SerializeMap, XlsxError> {
+        if self.struct_name.is_empty() {
+            self.struct_name = SERIALIZED_MAP_NAME.to_string();
+            self.is_flattened = len.is_none();
+        }
         Ok(self)
     }
 
@@ -4077,16 +5675,36 @@ impl<'a> ser::Serializer for &'a mut SerializerHeader {
 }
 
 // We are only interested in Struct fields. Other compound types are ignored.
-impl<'a> ser::SerializeStruct for &'a mut SerializerHeader {
+impl ser::SerializeStruct for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
-    fn serialize_field<T>(&mut self, key: &'static str, _value: &T) -> Result<(), XlsxError>
+    fn serialize_field<T>(&mut self, key: &'static str, value: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        // Serialize the key/field name but ignore the values.
-        key.serialize(&mut **self)
+        // Serialize the key/field name but ignore the values, apart from
+        // capturing the fields of nested structs for group headers and the
+        // length of sequences for sequence columns.
+        key.serialize(&mut **self)?;
+
+        if self.capture_groups || self.capture_sequences {
+            let mut nested = SerializerHeader::default();
+            if value.serialize(&mut nested).is_ok() {
+                if nested.struct_name.is_empty() {
+                    // Capture the number of elements in sequences of values.
+                    let len = nested.sequence_len.unwrap_or_default();
+                    if self.capture_sequences && len > 0 {
+                        self.sequence_fields.push((key.to_string(), len));
+                    }
+                } else if self.capture_groups && !nested.field_names.is_empty() {
+                    self.group_fields
+                        .push((key.to_string(), nested.field_names));
+                }
+            }
+        }
+
+        Ok(())
     }
 
     fn end(self) -> Result<(), XlsxError> {
@@ -4094,7 +5712,7 @@ impl<'a> ser::SerializeStruct for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeSeq for &'a mut SerializerHeader {
+impl ser::SerializeSeq for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
@@ -4102,6 +5720,10 @@ impl<'a> ser::SerializeSeq for &'a mut SerializerHeader {
     where
         T: ?Sized + Serialize,
     {
+        if let Some(len) = &mut self.sequence_len {
+            *len += 1;
+        }
+
         Ok(())
     }
 
@@ -4110,7 +5732,7 @@ impl<'a> ser::SerializeSeq for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeTuple for &'a mut SerializerHeader {
+impl ser::SerializeTuple for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
@@ -4118,6 +5740,10 @@ impl<'a> ser::SerializeTuple for &'a mut SerializerHeader {
     where
         T: ?Sized + Serialize,
     {
+        if let Some(len) = &mut self.sequence_len {
+            *len += 1;
+        }
+
         value.serialize(&mut **self)
     }
 
@@ -4126,7 +5752,7 @@ impl<'a> ser::SerializeTuple for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeTupleStruct for &'a mut SerializerHeader {
+impl ser::SerializeTupleStruct for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
@@ -4142,7 +5768,7 @@ impl<'a> ser::SerializeTupleStruct for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeTupleVariant for &'a mut SerializerHeader {
+impl ser::SerializeTupleVariant for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
@@ -4158,15 +5784,16 @@ impl<'a> ser::SerializeTupleVariant for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeMap for &'a mut SerializerHeader {
+// Map keys are treated like struct field names. The values are ignored.
+impl ser::SerializeMap for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
-    fn serialize_key<T>(&mut self, _key: &T) -> Result<(), XlsxError>
+    fn serialize_key<T>(&mut self, key: &T) -> Result<(), XlsxError>
     where
         T: ?Sized + Serialize,
     {
-        Ok(())
+        key.serialize(&mut **self)
     }
 
     fn serialize_value<T>(&mut self, _value: &T) -> Result<(), XlsxError>
@@ -4181,7 +5808,7 @@ impl<'a> ser::SerializeMap for &'a mut SerializerHeader {
     }
 }
 
-impl<'a> ser::SerializeStructVariant for &'a mut SerializerHeader {
+impl ser::SerializeStructVariant for &mut SerializerHeader {
     type Ok = ();
     type Error = XlsxError;
 
@@ -4237,6 +5864,20 @@ impl<'de, 'a> Deserializer<'de> for DeSerializerHeader<'a> {
     }
 }
 
+// Get the string parts of a serialized `Formula` or `Url` tuple.
+fn serialized_strings<T, const N: usize>(value: &T) -> Result<[String; N], XlsxError>
+where
+    T: ?Sized + Serialize,
+{
+    let mut headers = SerializerHeader::default();
+    value.serialize(&mut headers)?;
+
+    headers
+        .field_names
+        .try_into()
+        .map_err(|_| XlsxError::SerdeError("Unexpected serialized data".to_string()))
+}
+
 pub(crate) fn deserialize_headers<'de, T>() -> SerializerHeader
 where
     T: Deserialize<'de>,
@@ -4257,6 +5898,7 @@ where
     SerializerHeader {
         struct_name,
         field_names,
+        ..Default::default()
     }
 }
 
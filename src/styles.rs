@@ -596,7 +596,7 @@ impl<'a> Styles<'a> {
             attributes.push(("applyProtection", "1".to_string()));
         }
 
-        if has_alignment || has_protection {
+        if has_alignment || has_protection || xf_format.checkbox {
             self.writer.xml_start_tag("xf", &attributes);
 
             if has_alignment {
@@ -609,12 +609,35 @@ impl<'a> Styles<'a> {
                 self.write_protection(xf_format);
             }
 
+            if xf_format.checkbox {
+                // Write the checkbox extension element.
+                self.write_xf_format_extensions();
+            }
+
             self.writer.xml_end_tag("xf");
         } else {
             self.writer.xml_empty_tag("xf", &attributes);
         }
     }
 
+    // Write the <extLst> element for the checkbox cell control feature property.
+    fn write_xf_format_extensions(&mut self) {
+        let attributes = [
+            ("uri", "{C7286773-470A-42A8-94C5-96B5CB345126}"),
+            (
+                "xmlns:xfpb",
+                "http://schemas.microsoft.com/office/spreadsheetml/2022/featurepropertybag",
+            ),
+        ];
+
+        self.writer.xml_start_tag_only("extLst");
+        self.writer.xml_start_tag("ext", &attributes);
+        self.writer
+            .xml_empty_tag("xfpb:xfComplement", &[("i", "0")]);
+        self.writer.xml_end_tag("ext");
+        self.writer.xml_end_tag("extLst");
+    }
+
     // Write the <protection> element.
     fn write_protection(&mut self, xf_format: &Format) {
         let mut attributes = vec![];
@@ -74,6 +74,48 @@ mod table_tests {
         assert!(matches!(result, Err(XlsxError::TableError(_))));
     }
 
+    #[test]
+    fn test_write_table_range() {
+        let mut worksheet = Worksheet::new();
+        let data = [[1, 2], [3, 4], [5, 6]];
+
+        // The range includes the header row, the data and the total row.
+        let table = Table::new().set_total_row(true);
+        worksheet
+            .write_table(1, 1, ["Foo", "Bar", "Baz"], data, &table)
+            .unwrap();
+
+        assert_eq!(1, worksheet.tables[0].cell_range.first_row);
+        assert_eq!(1, worksheet.tables[0].cell_range.first_col);
+        assert_eq!(5, worksheet.tables[0].cell_range.last_row);
+        assert_eq!(3, worksheet.tables[0].cell_range.last_col);
+        assert_eq!("Baz", worksheet.tables[0].columns[2].name);
+
+        // No header row and a blank data row.
+        let table = Table::new().set_header_row(false);
+        let data: [[i32; 0]; 0] = [];
+        worksheet
+            .write_table(10, 0, ["Foo", "Bar"], data, &table)
+            .unwrap();
+
+        assert_eq!(10, worksheet.tables[1].cell_range.first_row);
+        assert_eq!(10, worksheet.tables[1].cell_range.last_row);
+        assert_eq!(1, worksheet.tables[1].cell_range.last_col);
+
+        // No headers or data.
+        let headers: [&str; 0] = [];
+        let result = worksheet.write_table(20, 0, headers, data, &Table::new());
+        assert!(matches!(result, Err(XlsxError::TableError(_))));
+
+        // Table range beyond the worksheet limits.
+        let result = worksheet.write_table(1_048_575, 0, ["Foo"], [[1]], &Table::new());
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        // Overlapping tables.
+        let result = worksheet.write_table(2, 2, ["Foo"], [[1]], &Table::new());
+        assert!(matches!(result, Err(XlsxError::TableRangeOverlaps(_, _))));
+    }
+
     #[test]
     fn test_assemble1() {
         let mut table = Table::new();
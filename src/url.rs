@@ -6,7 +6,12 @@
 
 #![warn(missing_docs)]
 
-use crate::{XlsxError, MAX_PARAMETER_LEN};
+use crate::{Format, XlsxError, MAX_PARAMETER_LEN};
+
+#[cfg(feature = "serde")]
+use crate::serializer::SERIALIZED_URL_NAME;
+#[cfg(feature = "serde")]
+use serde::{Serialize, Serializer};
 
 const MAX_URL_LEN: usize = 2_080;
 
@@ -138,6 +143,10 @@ const MAX_URL_LEN: usize = 2_080;
 /// style escapes. In which case it is assumed that the URL was escaped
 /// correctly by the user and will by passed directly to Excel.
 ///
+/// The file and internal link types can also be created with the typed
+/// [`Url::file()`], [`Url::internal()`] and [`Url::defined_name()`]
+/// constructors, which add the URI prefix.
+///
 /// Excel has a limit of around 2080 characters in the url string. Urls beyond
 /// this limit will raise an error when written.
 ///
@@ -151,6 +160,7 @@ pub struct Url {
     pub(crate) rel_display: bool,
     pub(crate) link_type: HyperlinkType,
     pub(crate) rel_id: u32,
+    pub(crate) internal_target: Option<String>,
 }
 
 impl Url {
@@ -172,6 +182,106 @@ impl Url {
             rel_id: 0,                         // Relationship id.
             tool_tip: String::new(),           // The mouseover tool tip.
             link_type: HyperlinkType::Unknown, // Url, file, internal.
+            internal_target: None,             // Target checked when the file is saved.
+        }
+    }
+
+    /// Create a new Url struct for a link to a location in the workbook.
+    ///
+    /// This is a typed alternative to the `internal:` pseudo-uri prefix
+    /// described above. The location can be a cell or a range of cells,
+    /// optionally with a worksheet name, like `Sheet2!A1` or `'Sales
+    /// Data'!A1:B10`. Worksheet names that contain spaces or non alphanumeric
+    /// characters should be single quoted.
+    ///
+    /// When the url is written to a worksheet the location is validated, and
+    /// checked when the workbook is saved, in the same way as the target of
+    /// [`Worksheet::write_url_internal()`](crate::Worksheet::write_url_internal).
+    ///
+    /// # Parameters
+    ///
+    /// `location` - The cell or range location in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating the different types of
+    /// url links with the typed constructors.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_url_internal.rs
+    /// #
+    /// # use rust_xlsxwriter::{Url, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Link to a range in another worksheet.
+    ///     worksheet.write(0, 0, Url::internal("Sheet2!A1:B10"))?;
+    ///
+    ///     // Link to a defined name, with a tool tip.
+    ///     worksheet.write(1, 0, Url::defined_name("Totals").set_tip("Go to totals"))?;
+    ///
+    ///     // Link to a cell in another file.
+    ///     worksheet.write(
+    ///         2,
+    ///         0,
+    ///         Url::file("Budget.xlsx#Sheet1!A1").set_text("Budget"),
+    ///     )?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, 123)?;
+    ///
+    ///     workbook.define_name("Totals", "=Sheet2!$A$1")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn internal(location: impl Into<String>) -> Url {
+        let location = location.into();
+        let mut url = Url::new(format!("internal:{location}"));
+        url.internal_target = Some(location);
+        url
+    }
+
+    /// Create a new Url struct for a link to a defined name in the workbook.
+    ///
+    /// Excel allows hyperlinks to target a workbook level defined name, such
+    /// as one created with
+    /// [`Workbook::define_name()`](crate::Workbook::define_name), instead of
+    /// a cell reference. See [`Url::internal()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// `name` - The defined name to link to.
+    ///
+    pub fn defined_name(name: impl Into<String>) -> Url {
+        Url::internal(name)
+    }
+
+    /// Create a new Url struct for a link to a local or network file.
+    ///
+    /// This is a typed alternative to the `file://` URI prefix described
+    /// above. The path can be relative, like `Book2.xlsx` or
+    /// `..\Sales\Book2.xlsx`, or absolute, like `C:\Temp\Book1.xlsx` or
+    /// `\\share\Book1.xlsx`. For links to other Excel files the path can
+    /// include a sheet and cell reference after a `#` anchor, like
+    /// `Book2.xlsx#Sheet1!A1`. See [`Url::internal()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// `path` - The file path, with an optional `#` anchor.
+    ///
+    pub fn file(path: impl Into<String>) -> Url {
+        let path = path.into();
+
+        if path.starts_with("file://") {
+            Url::new(path)
+        } else {
+            Url::new(format!("file:///{path}"))
         }
     }
 
@@ -445,6 +555,60 @@ impl From<&Url> for Url {
     }
 }
 
+/// Implementation of the `serde::Serialize` trait for `Url`.
+///
+/// This allows `Url` struct fields to be serialized to a worksheet as
+/// hyperlink cells via [`Worksheet::serialize()`](crate::Worksheet::serialize).
+/// Other Serde serializers will see the url as a tuple of the link, the display
+/// text and the tool tip.
+///
+#[cfg(feature = "serde")]
+impl Serialize for Url {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        serializer.serialize_newtype_struct(
+            SERIALIZED_URL_NAME,
+            &(&self.url_link, &self.user_text, &self.tool_tip),
+        )
+    }
+}
+
+// -----------------------------------------------------------------------
+// HyperlinkStyle enum.
+// -----------------------------------------------------------------------
+
+/// The `HyperlinkStyle` enum defines the format that is applied to urls that
+/// are written without an explicit format.
+///
+/// It is used with
+/// [`Worksheet::set_hyperlink_style()`](crate::Worksheet::set_hyperlink_style)
+/// to control the format used by
+/// [`Worksheet::write_url()`](crate::Worksheet::write_url),
+/// [`Worksheet::write_url_with_text()`](crate::Worksheet::write_url_with_text)
+/// and the other url write methods when a [`Format`] isn't passed as a
+/// parameter.
+///
+#[allow(clippy::large_enum_variant)]
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub enum HyperlinkStyle {
+    /// Use the Excel "Hyperlink" cell style with a blue underlined font. This
+    /// is the default. The style can be changed for all worksheets in a
+    /// workbook with
+    /// [`Workbook::set_hyperlink_format()`](crate::Workbook::set_hyperlink_format).
+    #[default]
+    Default,
+
+    /// Use a user defined [`Format`] for the url cells.
+    Custom(Format),
+
+    /// Keep the existing format of the cell, or the row or column format if
+    /// the cell hasn't been formatted, like Excel does when a hyperlink is
+    /// inserted into a formatted cell.
+    Inherit,
+}
+
 // -----------------------------------------------------------------------
 // HyperlinkType enum.
 // -----------------------------------------------------------------------
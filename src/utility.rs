@@ -7,8 +7,8 @@
 //! Utility functions for `rust_xlsxwriter`.
 //!
 //! The `rust_xlsxwriter` library provides a number of utility functions for
-//! dealing with cell ranges, Chrono Serde serialization, and other helper
-//! method.
+//! dealing with cell ranges, Chrono, `time` and `Decimal` Serde
+//! serialization, and other helper method.
 //!
 //!
 //! # Examples:
@@ -31,9 +31,16 @@ mod tests;
 use crate::IntoExcelDateTime;
 use crate::COL_MAX;
 use crate::ROW_MAX;
+#[cfg(all(feature = "serde", feature = "chrono"))]
+use chrono::{DateTime, TimeZone};
+#[cfg(all(feature = "serde", feature = "rust_decimal"))]
+use rust_decimal::Decimal;
 #[cfg(feature = "serde")]
 use serde::Serializer;
 
+use std::iter::Peekable;
+use std::str::Chars;
+
 use crate::worksheet::ColNum;
 use crate::worksheet::RowNum;
 use crate::XlsxError;
@@ -234,6 +241,183 @@ pub fn cell_range_absolute(
     }
 }
 
+/// Convert an `A1` style cell reference to zero indexed row and column
+/// numbers.
+///
+/// Utility function to convert an `A1` style cell reference, such as a
+/// reference read from a configuration file, to zero indexed row and column
+/// numbers. Absolute references like `$A$1` are also supported. This is the
+/// inverse of [`row_col_to_cell()`].
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't a valid cell reference
+///   or it exceeds Excel's worksheet limits.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::cell_to_row_col;
+///
+/// assert_eq!(cell_to_row_col("A1").unwrap(), (0, 0));
+/// assert_eq!(cell_to_row_col("B3").unwrap(), (2, 1));
+/// assert_eq!(cell_to_row_col("$AA$10").unwrap(), (9, 26));
+/// assert!(cell_to_row_col("1A").is_err());
+/// ```
+///
+pub fn cell_to_row_col(cell: &str) -> Result<(RowNum, ColNum), XlsxError> {
+    let error = || XlsxError::ParameterError(format!("Invalid cell reference '{cell}'"));
+
+    let reference = cell.replace('$', "").to_ascii_uppercase();
+    let (column, row) = split_cell_reference(&reference);
+
+    // The column should be 1-3 letters and the row should be all digits.
+    if column.is_empty()
+        || column.len() > 3
+        || !column.chars().all(|c| c.is_ascii_uppercase())
+        || !row.chars().all(|c| c.is_ascii_digit())
+    {
+        return Err(error());
+    }
+
+    let row = row.parse::<RowNum>().map_err(|_| error())?;
+    if row == 0 || row > ROW_MAX {
+        return Err(error());
+    }
+
+    // Check the column range before converting it to avoid an overflow.
+    if column.len() == 3 && column.as_str() > "XFD" {
+        return Err(error());
+    }
+
+    Ok((row - 1, column_name_to_number(&column)))
+}
+
+/// Convert an `A1:B2` style range reference to zero indexed row and column
+/// numbers.
+///
+/// Utility function to convert an `A1:B2` style range reference to a tuple of
+/// zero indexed `(first_row, first_col, last_row, last_col)` numbers.
+/// Absolute references like `$A$1:$B$2` and single cell ranges like `A1` are
+/// also supported. This is the inverse of [`cell_range()`].
+///
+/// Note, the range shouldn't include a worksheet name.
+///
+/// # Errors
+///
+/// - [`XlsxError::ParameterError`] - The string isn't a valid range reference
+///   or it exceeds Excel's worksheet limits.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::cell_range_to_row_col;
+///
+/// assert_eq!(cell_range_to_row_col("A1:A10").unwrap(), (0, 0, 9, 0));
+/// assert_eq!(cell_range_to_row_col("$C$2:$C$9").unwrap(), (1, 2, 8, 2));
+/// assert_eq!(cell_range_to_row_col("B2").unwrap(), (1, 1, 1, 1));
+/// ```
+///
+pub fn cell_range_to_row_col(range: &str) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError> {
+    let (first_cell, last_cell) = match range.split_once(':') {
+        Some((first_cell, last_cell)) => (first_cell, last_cell),
+        None => (range, range),
+    };
+
+    let (first_row, first_col) = cell_to_row_col(first_cell).map_err(|_| {
+        XlsxError::ParameterError(format!("Invalid cell range reference '{range}'"))
+    })?;
+    let (last_row, last_col) = cell_to_row_col(last_cell).map_err(|_| {
+        XlsxError::ParameterError(format!("Invalid cell range reference '{range}'"))
+    })?;
+
+    Ok((first_row, first_col, last_row, last_col))
+}
+
+/// Create a structured reference to a table column in the same row.
+///
+/// Utility function to create an Excel "this row" structured reference like
+/// `Table1[@[Column Name]]` from a table name and a column header name. This
+/// can be used to build formulas for table calculated columns with
+/// [`TableColumn::set_formula()`](crate::TableColumn::set_formula) without
+/// hand writing the reference syntax. The formula is applied to every data
+/// row in the table when it is added to the worksheet.
+///
+/// The table name is the name set with
+/// [`Table::set_name()`](crate::Table::set_name) or the default name, `Table1`,
+/// `Table2`, etc., in the order that tables are added to the workbook.
+///
+/// The special characters `[`, `]`, `#` and `'` in the column name are escaped
+/// as required by Excel.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::structured_reference;
+///
+/// assert_eq!(structured_reference("Table1", "Price"), "Table1[@[Price]]");
+/// assert_eq!(structured_reference("Sales", "Unit Price"), "Sales[@[Unit Price]]");
+/// assert_eq!(structured_reference("Sales", "Item #"), "Sales[@[Item '#]]");
+///
+/// let formula = format!(
+///     "{}*{}",
+///     structured_reference("Sales", "Qty"),
+///     structured_reference("Sales", "Price")
+/// );
+/// assert_eq!(formula, "Sales[@[Qty]]*Sales[@[Price]]");
+/// ```
+///
+pub fn structured_reference(table_name: &str, column_name: &str) -> String {
+    format!(
+        "{table_name}[@[{}]]",
+        escape_structured_reference(column_name)
+    )
+}
+
+/// Create a structured reference to a range of table columns in the same row.
+///
+/// Utility function to create an Excel "this row" structured reference like
+/// `Table1[@[Quarter 1]:[Quarter 4]]` for a contiguous range of table
+/// columns. See [`structured_reference()`] for details.
+///
+/// # Examples:
+///
+/// ```
+/// use rust_xlsxwriter::structured_reference_range;
+///
+/// let formula = format!(
+///     "SUM({})",
+///     structured_reference_range("Table1", "Quarter 1", "Quarter 4")
+/// );
+/// assert_eq!(formula, "SUM(Table1[@[Quarter 1]:[Quarter 4]])");
+/// ```
+///
+pub fn structured_reference_range(
+    table_name: &str,
+    first_column_name: &str,
+    last_column_name: &str,
+) -> String {
+    format!(
+        "{table_name}[@[{}]:[{}]]",
+        escape_structured_reference(first_column_name),
+        escape_structured_reference(last_column_name)
+    )
+}
+
+// Escape the special characters in a structured reference column name.
+fn escape_structured_reference(column_name: &str) -> String {
+    let mut escaped = String::with_capacity(column_name.len());
+
+    for ch in column_name.chars() {
+        if matches!(ch, '[' | ']' | '#' | '\'') {
+            escaped.push('\'');
+        }
+        escaped.push(ch);
+    }
+
+    escaped
+}
+
 /// Serialize a Chrono naive date/time to an Excel value.
 ///
 /// This is a helper function for serializing [`Chrono`] naive date/time fields
@@ -373,6 +557,458 @@ where
     }
 }
 
+/// Serialize a Chrono timezone aware datetime to an Excel value.
+///
+/// This is a helper function for serializing [`Chrono`] [`DateTime`] fields,
+/// such as `DateTime<Utc>` or `DateTime<FixedOffset>`, using
+/// [Serde](https://serde.rs). By default Chrono serializes these types as
+/// RFC 3339 strings which aren't recognized as dates by Excel.
+///
+/// Excel datetimes don't have timezone information so the timezone is
+/// stripped from the value and the local date and time, as it would be
+/// displayed in the timezone of the value, is written. For example
+/// `2024-01-01T12:00:00+02:00` is written as `2024-01-01 12:00:00`. If you
+/// need the datetimes to be normalized to UTC you should use
+/// [`serialize_chrono_datetime_utc_to_excel()`] instead.
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+/// [`DateTime`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+///
+/// `Option<T>` Chrono types can be handled with
+/// [`serialize_chrono_option_datetime_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a Chrono `DateTime<Utc>` value with a
+/// helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime6.rs
+/// #
+/// use chrono::{DateTime, Utc};
+/// use rust_xlsxwriter::utility::serialize_chrono_datetime_to_excel;
+/// use serde::Serialize;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Order {
+///         order_id: u32,
+///
+///         #[serde(serialize_with = "serialize_chrono_datetime_to_excel")]
+///         created: DateTime<Utc>,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "chrono"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "chrono"))))]
+pub fn serialize_chrono_datetime_to_excel<S, Tz>(
+    datetime: &DateTime<Tz>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Tz: TimeZone,
+{
+    serializer.serialize_f64(datetime.naive_local().to_excel_serial_date())
+}
+
+/// Serialize an `Option` Chrono timezone aware datetime to an Excel value.
+///
+/// This is a helper function for serializing [`Chrono`] `Option<DateTime>`
+/// fields, such as `Option<DateTime<Utc>>`, using [Serde](https://serde.rs).
+/// A `None` value is written as a blank cell with the same cell format as
+/// other values of the field type.
+///
+/// The timezone is stripped from the value in the same way as
+/// [`serialize_chrono_datetime_to_excel()`].
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with an Option Chrono `DateTime` value
+/// with a helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime7.rs
+/// #
+/// use chrono::{DateTime, FixedOffset};
+/// use rust_xlsxwriter::utility::serialize_chrono_option_datetime_to_excel;
+/// use serde::Serialize;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Order {
+///         order_id: u32,
+///
+///         #[serde(serialize_with = "serialize_chrono_option_datetime_to_excel")]
+///         shipped: Option<DateTime<FixedOffset>>,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "chrono"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "chrono"))))]
+pub fn serialize_chrono_option_datetime_to_excel<S, Tz>(
+    datetime: &Option<DateTime<Tz>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Tz: TimeZone,
+{
+    match datetime {
+        Some(datetime) => serialize_chrono_datetime_to_excel(datetime, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a Chrono timezone aware datetime to an Excel value in UTC.
+///
+/// This is a helper function for serializing [`Chrono`] [`DateTime`] fields
+/// using [Serde](https://serde.rs). It is similar to
+/// [`serialize_chrono_datetime_to_excel()`] except that the datetime is
+/// converted to UTC before it is written. For example
+/// `2024-01-01T12:00:00+02:00` is written as `2024-01-01 10:00:00`. This is
+/// the Serde equivalent of
+/// [`Worksheet::write_datetime_with_tz()`](crate::Worksheet::write_datetime_with_tz)
+/// with [`TimezonePolicy::Utc`](crate::TimezonePolicy::Utc).
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+/// [`DateTime`]: https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+///
+/// `Option<T>` Chrono types can be handled with
+/// [`serialize_chrono_option_datetime_utc_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a Chrono `DateTime<FixedOffset>`
+/// value that is normalized to UTC with a helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime10.rs
+/// #
+/// use chrono::{DateTime, FixedOffset};
+/// use rust_xlsxwriter::utility::serialize_chrono_datetime_utc_to_excel;
+/// use serde::Serialize;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Order {
+///         order_id: u32,
+///
+///         #[serde(serialize_with = "serialize_chrono_datetime_utc_to_excel")]
+///         created: DateTime<FixedOffset>,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "chrono"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "chrono"))))]
+pub fn serialize_chrono_datetime_utc_to_excel<S, Tz>(
+    datetime: &DateTime<Tz>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Tz: TimeZone,
+{
+    serializer.serialize_f64(datetime.naive_utc().to_excel_serial_date())
+}
+
+/// Serialize an `Option` Chrono timezone aware datetime to an Excel value in
+/// UTC.
+///
+/// This is a helper function for serializing [`Chrono`] `Option<DateTime>`
+/// fields using [Serde](https://serde.rs). A `None` value is written as a
+/// blank cell with the same cell format as other values of the field type.
+///
+/// The datetime is converted to UTC in the same way as
+/// [`serialize_chrono_datetime_utc_to_excel()`].
+///
+/// [`Chrono`]: https://docs.rs/chrono/latest/chrono
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+#[cfg(all(feature = "serde", feature = "chrono"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "chrono"))))]
+pub fn serialize_chrono_option_datetime_utc_to_excel<S, Tz>(
+    datetime: &Option<DateTime<Tz>>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    Tz: TimeZone,
+{
+    match datetime {
+        Some(datetime) => serialize_chrono_datetime_utc_to_excel(datetime, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a `time` crate date/time to an Excel value.
+///
+/// This is a helper function for serializing [`time`] crate date/time fields
+/// using [Serde](https://serde.rs). It is the equivalent of
+/// [`serialize_chrono_naive_to_excel()`] for the `time` crate.
+///
+/// The function works for the following types:
+///   - [`PrimitiveDateTime`]
+///   - [`Date`]
+///   - [`Time`]
+///
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+/// [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+/// [`PrimitiveDateTime`]:
+///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+///
+/// `Option<T>` `time` types can be handled with
+/// [`serialize_time_option_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a `time` crate date value with a
+/// helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime8.rs
+/// #
+/// use rust_xlsxwriter::utility::serialize_time_to_excel;
+/// use serde::Serialize;
+/// use time::Date;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Student {
+///         full_name: String,
+///
+///         #[serde(serialize_with = "serialize_time_to_excel")]
+///         birth_date: Date,
+///
+///         id_number: u32,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "time"))))]
+pub fn serialize_time_to_excel<S>(
+    datetime: impl IntoExcelDateTime,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    serializer.serialize_f64(datetime.to_excel_serial_date())
+}
+
+/// Serialize an `Option` `time` crate date/time to an Excel value.
+///
+/// This is a helper function for serializing `Option` [`time`] crate
+/// date/time fields using [Serde](https://serde.rs). A `None` value is written
+/// as a blank cell with the same cell format as other values of the field
+/// type.
+///
+/// The function works for the following `Option<T>` where T is:
+///   - [`PrimitiveDateTime`]
+///   - [`Date`]
+///   - [`Time`]
+///
+/// [`time`]: https://docs.rs/time/latest/time
+/// [`Date`]: https://docs.rs/time/latest/time/struct.Date.html
+/// [`Time`]: https://docs.rs/time/latest/time/struct.Time.html
+/// [`PrimitiveDateTime`]:
+///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+///
+/// Non `Option<T>` `time` types can be handled with
+/// [`serialize_time_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with an Option `time` crate date value
+/// with a helper function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_datetime9.rs
+/// #
+/// use rust_xlsxwriter::utility::serialize_time_option_to_excel;
+/// use serde::Serialize;
+/// use time::Date;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Student {
+///         full_name: String,
+///
+///         #[serde(serialize_with = "serialize_time_option_to_excel")]
+///         birth_date: Option<Date>,
+///
+///         id_number: u32,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "time"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "time"))))]
+pub fn serialize_time_option_to_excel<S>(
+    datetime: &Option<impl IntoExcelDateTime>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match datetime {
+        Some(datetime) => serializer.serialize_f64(datetime.to_excel_serial_date()),
+        None => serializer.serialize_none(),
+    }
+}
+
+/// Serialize a `rust_decimal::Decimal` value to an Excel number.
+///
+/// This is a helper function for serializing [`Decimal`] fields using
+/// [Serde](https://serde.rs). The `Decimal` type doesn't implement
+/// `Serialize` unless its `serde` feature is enabled and even then it
+/// serializes to a string by default, which would be written to Excel as
+/// text.
+///
+/// Excel stores all numbers as IEEE 754 `f64` values so the `Decimal` is
+/// converted to the nearest `f64`. Values with more than 15 significant digits
+/// lose precision in this conversion, in the same way as they would if they
+/// were entered in Excel. If the exact value is more important than being
+/// able to use it in calculations then serialize it to a string instead.
+///
+/// The number of decimal places displayed in Excel is controlled by the cell
+/// number format and not by the scale of the `Decimal`. To display values
+/// like `1.50` with a fixed number of decimal places set a value format such
+/// as `"0.00"` on the field via
+/// [`CustomSerializeField::set_value_format()`](crate::CustomSerializeField::set_value_format).
+///
+/// [`Decimal`]: https://docs.rs/rust_decimal/latest/rust_decimal/struct.Decimal.html
+///
+/// `Option<Decimal>` values can be handled with
+/// [`serialize_decimal_option_to_excel()`].
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+/// # Examples
+///
+/// Example of a serializable struct with a `Decimal` value with a helper
+/// function.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_serialize_decimal.rs
+/// #
+/// use rust_decimal::Decimal;
+/// use rust_xlsxwriter::utility::serialize_decimal_to_excel;
+/// use serde::Serialize;
+///
+/// fn main() {
+///     #[allow(dead_code)]
+///     #[derive(Serialize)]
+///     struct Invoice {
+///         item: String,
+///
+///         #[serde(serialize_with = "serialize_decimal_to_excel")]
+///         price: Decimal,
+///     }
+/// }
+/// ```
+///
+#[cfg(all(feature = "serde", feature = "rust_decimal"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "rust_decimal"))))]
+pub fn serialize_decimal_to_excel<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    let number = f64::try_from(*decimal).map_err(serde::ser::Error::custom)?;
+    serializer.serialize_f64(number)
+}
+
+/// Serialize an `Option` `rust_decimal::Decimal` value to an Excel number.
+///
+/// This is a helper function for serializing `Option` [`Decimal`] fields
+/// using [Serde](https://serde.rs). A `None` value is written as a blank cell
+/// with the same cell format as other values of the field type.
+///
+/// The `Decimal` values are converted to `f64` in the same way as
+/// [`serialize_decimal_to_excel()`].
+///
+/// [`Decimal`]: https://docs.rs/rust_decimal/latest/rust_decimal/struct.Decimal.html
+///
+/// See [Working with Serde](crate::serializer#working-with-serde) for more
+/// information about serialization with `rust_xlsxwriter`.
+///
+/// # Errors
+///
+/// - [`XlsxError::SerdeError`] - A wrapped serialization error.
+///
+#[cfg(all(feature = "serde", feature = "rust_decimal"))]
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "rust_decimal"))))]
+pub fn serialize_decimal_option_to_excel<S>(
+    decimal: &Option<Decimal>,
+    serializer: S,
+) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    match decimal {
+        Some(decimal) => serialize_decimal_to_excel(decimal, serializer),
+        None => serializer.serialize_none(),
+    }
+}
+
 // Convert zero indexed row and col cell references to a non-absolute chart
 // "Sheet1!A1:B1" style range string.
 pub(crate) fn chart_range(
@@ -645,6 +1281,73 @@ pub(crate) fn is_valid_range(range: &str) -> bool {
     true
 }
 
+// Check if the location part of an internal link target, i.e., the part after
+// "Sheet1!", is a cell based reference like "A1", "A1:B3", "A:C" or "1:3"
+// rather than a worksheet scoped defined name. Names like "Sales2024" have
+// more than 3 column letters so they can't be a cell reference.
+pub(crate) fn is_cell_link_reference(reference: &str) -> bool {
+    if reference.is_empty() || reference.contains(':') {
+        return true;
+    }
+
+    let reference = reference.replace('$', "");
+    let (column, row) = split_cell_reference(&reference);
+
+    !column.is_empty()
+        && column.len() <= 3
+        && column.chars().all(|c| c.is_ascii_uppercase())
+        && row.chars().all(|c| c.is_ascii_digit())
+}
+
+// Check that the cell based location of an internal link target is a valid
+// cell, cell range, whole column range like "A:C" or whole row range like
+// "1:3".
+pub(crate) fn validate_cell_link_reference(reference: &str) -> Result<(), XlsxError> {
+    let error = || XlsxError::ParameterError(format!("Invalid cell reference '{reference}'"));
+
+    let parts: Vec<String> = reference
+        .split(':')
+        .map(|part| part.replace('$', "").to_ascii_uppercase())
+        .collect();
+
+    if parts.len() > 2 {
+        return Err(error());
+    }
+
+    if parts.len() == 2 && !parts[0].is_empty() && !parts[1].is_empty() {
+        // Whole column ranges like "A:C".
+        if parts
+            .iter()
+            .all(|part| part.chars().all(|c| c.is_ascii_uppercase()))
+        {
+            let is_valid = parts
+                .iter()
+                .all(|part| part.len() < 3 || (part.len() == 3 && part.as_str() <= "XFD"));
+
+            return if is_valid { Ok(()) } else { Err(error()) };
+        }
+
+        // Whole row ranges like "1:3".
+        if parts
+            .iter()
+            .all(|part| part.chars().all(|c| c.is_ascii_digit()))
+        {
+            let is_valid = parts.iter().all(|part| {
+                part.parse::<RowNum>()
+                    .is_ok_and(|row| row > 0 && row <= ROW_MAX)
+            });
+
+            return if is_valid { Ok(()) } else { Err(error()) };
+        }
+    }
+
+    for part in &parts {
+        cell_to_row_col(part).map_err(|_| error())?;
+    }
+
+    Ok(())
+}
+
 /// Check that a worksheet name is valid in Excel.
 ///
 /// This function checks if an worksheet name is valid according to the Excel
@@ -840,6 +1543,224 @@ pub(crate) fn formula_to_string(formula: &str) -> String {
     formula
 }
 
+// Shift the relative cell references in a formula by a row and column offset,
+// in the same way as Excel does when a formula is copied to another cell. This
+// includes whole column and whole row ranges like `A:C` and `1:3`. Absolute
+// row and column parts, marked with `$`, aren't changed. References that are
+// shifted outside the worksheet are replaced with `#REF!`. Strings, quoted
+// sheet names and structured table references are left unchanged.
+pub(crate) fn shift_formula_references(formula: &str, row_offset: i64, col_offset: i64) -> String {
+    let mut shifted = String::with_capacity(formula.len());
+    let mut chars = formula.chars().peekable();
+
+    while let Some(char) = chars.next() {
+        match char {
+            '"' | '\'' => {
+                // Copy strings and quoted sheet names. Escaped, doubled,
+                // quotes are handled as two adjacent quoted strings.
+                shifted.push(char);
+                for next in chars.by_ref() {
+                    shifted.push(next);
+                    if next == char {
+                        break;
+                    }
+                }
+            }
+            '[' => {
+                // Copy structured references like `Table1[[#This Row],[A]]`.
+                let mut depth = 1;
+                shifted.push(char);
+                for next in chars.by_ref() {
+                    shifted.push(next);
+                    match next {
+                        '[' => depth += 1,
+                        ']' => depth -= 1,
+                        _ => {}
+                    }
+                    if depth == 0 {
+                        break;
+                    }
+                }
+            }
+            _ if is_reference_char(char) => {
+                let mut token = String::from(char);
+                token.push_str(&next_reference_token(&mut chars));
+
+                // Tokens followed by these characters are function names,
+                // sheet names or table names rather than cell references.
+                if matches!(chars.peek(), Some('(' | '!' | '[')) {
+                    shifted.push_str(&token);
+                    continue;
+                }
+
+                // Check for whole column or whole row ranges like `A:C` or
+                // `1:3`, which aren't cell references on their own.
+                if chars.peek() == Some(&':') {
+                    let mut lookahead = chars.clone();
+                    lookahead.next();
+                    let last = next_reference_token(&mut lookahead);
+
+                    if let Some(range) =
+                        shift_column_or_row_range(&token, &last, row_offset, col_offset)
+                    {
+                        shifted.push_str(&range);
+                        chars = lookahead;
+                        continue;
+                    }
+                }
+
+                match shift_cell_reference(&token, row_offset, col_offset) {
+                    Some(reference) => shifted.push_str(&reference),
+                    None => shifted.push_str(&token),
+                }
+            }
+            _ => shifted.push(char),
+        }
+    }
+
+    shifted
+}
+
+// Check if a character can be part of a cell reference, or of a name token.
+fn is_reference_char(char: char) -> bool {
+    char.is_alphanumeric() || matches!(char, '$' | '_' | '.' | '\\')
+}
+
+// Get the rest of a cell reference or name token from a formula.
+fn next_reference_token(chars: &mut Peekable<Chars>) -> String {
+    let mut token = String::new();
+
+    while let Some(&next) = chars.peek() {
+        if !is_reference_char(next) {
+            break;
+        }
+        token.push(next);
+        chars.next();
+    }
+
+    token
+}
+
+// Shift a whole column range like `A:C` or a whole row range like `1:3` by a
+// row and column offset. Returns `None` if the tokens aren't a column or row
+// range.
+fn shift_column_or_row_range(
+    first: &str,
+    last: &str,
+    row_offset: i64,
+    col_offset: i64,
+) -> Option<String> {
+    for (is_column, offset) in [(true, col_offset), (false, row_offset)] {
+        let first = shift_range_part(first, offset, is_column);
+        let last = shift_range_part(last, offset, is_column);
+
+        if let (Some(first), Some(last)) = (first, last) {
+            return match (first, last) {
+                (Some(first), Some(last)) => Some(format!("{first}:{last}")),
+                _ => Some("#REF!".to_string()),
+            };
+        }
+    }
+
+    None
+}
+
+// Shift one part of a whole column or whole row range, like `A` or `$3`.
+// Returns `None` if the token isn't a column/row, and `Some(None)` if it is
+// shifted outside the worksheet.
+fn shift_range_part(token: &str, offset: i64, is_column: bool) -> Option<Option<String>> {
+    let (absolute, name) = match token.strip_prefix('$') {
+        Some(name) => (true, name),
+        None => (false, token),
+    };
+
+    let (mut number, max) = if is_column {
+        if name.is_empty() || name.len() > 3 || !name.bytes().all(|b| b.is_ascii_alphabetic()) {
+            return None;
+        }
+        let col = column_name_to_number(&name.to_ascii_uppercase());
+        (i64::from(col), i64::from(COL_MAX))
+    } else {
+        if name.is_empty() || name.starts_with('0') || !name.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+        (name.parse::<i64>().ok()? - 1, i64::from(ROW_MAX))
+    };
+
+    if number >= max {
+        return None;
+    }
+
+    if !absolute {
+        number += offset;
+    }
+
+    if number < 0 || number >= max {
+        return Some(None);
+    }
+
+    let name = if is_column {
+        column_number_to_name(number as ColNum)
+    } else {
+        (number + 1).to_string()
+    };
+
+    Some(Some(format!("{}{name}", if absolute { "$" } else { "" })))
+}
+
+// Shift an `A1` style cell reference, with optional `$` absolute markers, by a
+// row and column offset. Returns `None` if the token isn't a cell reference.
+fn shift_cell_reference(token: &str, row_offset: i64, col_offset: i64) -> Option<String> {
+    let (col_absolute, token) = match token.strip_prefix('$') {
+        Some(token) => (true, token),
+        None => (false, token),
+    };
+
+    let letters = token.find(|c: char| !c.is_ascii_alphabetic())?;
+    let (col_name, token) = token.split_at(letters);
+
+    let (row_absolute, row_string) = match token.strip_prefix('$') {
+        Some(token) => (true, token),
+        None => (false, token),
+    };
+
+    if col_name.is_empty()
+        || col_name.len() > 3
+        || row_string.is_empty()
+        || row_string.starts_with('0')
+        || !row_string.bytes().all(|b| b.is_ascii_digit())
+    {
+        return None;
+    }
+
+    let mut col = i64::from(column_name_to_number(&col_name.to_ascii_uppercase()));
+    let mut row = row_string.parse::<i64>().ok()? - 1;
+
+    if col >= i64::from(COL_MAX) || row >= i64::from(ROW_MAX) {
+        return None;
+    }
+
+    if !col_absolute {
+        col += col_offset;
+    }
+
+    if !row_absolute {
+        row += row_offset;
+    }
+
+    if col < 0 || col >= i64::from(COL_MAX) || row < 0 || row >= i64::from(ROW_MAX) {
+        return Some("#REF!".to_string());
+    }
+
+    Some(format!(
+        "{}{}{}{}",
+        if col_absolute { "$" } else { "" },
+        column_number_to_name(col as ColNum),
+        if row_absolute { "$" } else { "" },
+        row + 1
+    ))
+}
+
 // Trait to convert bool to XML "0" or "1".
 pub(crate) trait ToXmlBoolean {
     fn to_xml_bool(self) -> String;
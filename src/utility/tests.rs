@@ -10,6 +10,69 @@ mod utility_tests {
     use crate::{utility, XlsxError};
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn test_cell_to_row_col() {
+        let tests = vec![
+            ("A1", (0, 0)),
+            ("a1", (0, 0)),
+            ("$A$1", (0, 0)),
+            ("B3", (2, 1)),
+            ("Z1", (0, 25)),
+            ("AA10", (9, 26)),
+            ("$XFD$1048576", (1_048_575, 16_383)),
+        ];
+
+        for (cell, expected) in tests {
+            assert_eq!(expected, utility::cell_to_row_col(cell).unwrap());
+        }
+
+        let errors = vec![
+            "",
+            "A",
+            "1",
+            "1A",
+            "A1B",
+            "A0",
+            "XFE1",
+            "AAAA1",
+            "A1048577",
+            "A-1",
+            "Sheet1!A1",
+        ];
+
+        for cell in errors {
+            let result = utility::cell_to_row_col(cell);
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "{cell}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_cell_range_to_row_col() {
+        let tests = vec![
+            ("A1", (0, 0, 0, 0)),
+            ("A1:A10", (0, 0, 9, 0)),
+            ("$C$2:$C$9", (1, 2, 8, 2)),
+            ("A1:E4", (0, 0, 3, 4)),
+        ];
+
+        for (range, expected) in tests {
+            assert_eq!(expected, utility::cell_range_to_row_col(range).unwrap());
+        }
+
+        let errors = vec!["", "A1:", ":B2", "A1:B2:C3", "A:B"];
+
+        for range in errors {
+            let result = utility::cell_range_to_row_col(range);
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "{range}"
+            );
+        }
+    }
+
     #[test]
     fn test_hash_password() {
         let tests = vec![
@@ -516,6 +579,29 @@ mod utility_tests {
         assert!(matches!(result, Err(XlsxError::VbaNameError(_))));
     }
 
+    #[test]
+    fn test_structured_reference() {
+        let tests = vec![
+            ("Price", "Table1[@[Price]]"),
+            ("Unit Price", "Table1[@[Unit Price]]"),
+            ("Item #", "Table1[@[Item '#]]"),
+            ("[Note]", "Table1[@['[Note']]]"),
+            ("Owner's", "Table1[@[Owner''s]]"),
+        ];
+
+        for (column_name, expected) in tests {
+            assert_eq!(
+                expected,
+                utility::structured_reference("Table1", column_name)
+            );
+        }
+
+        assert_eq!(
+            "Table1[@[Q1 '#]:[Q4]]",
+            utility::structured_reference_range("Table1", "Q1 #", "Q4")
+        );
+    }
+
     #[test]
     fn check_is_valid_range() {
         assert_eq!(true, utility::is_valid_range("A1"));
@@ -526,4 +612,34 @@ mod utility_tests {
         assert_eq!(false, utility::is_valid_range("a1"));
         assert_eq!(false, utility::is_valid_range("1:3"));
     }
+
+    #[test]
+    fn test_shift_formula_references() {
+        let tests = vec![
+            ("A1", 1, 1, "B2"),
+            ("SUM(A1:B2)", 2, 0, "SUM(A3:B4)"),
+            ("$A$1+A$1+$A1", 1, 1, "$A$1+B$1+$A2"),
+            ("LOG10(A1)", 0, 1, "LOG10(B1)"),
+            ("Sheet1!A1+'Sheet 2'!B2", 1, 0, "Sheet1!A2+'Sheet 2'!B3"),
+            ("\"A1\"&A1", 1, 0, "\"A1\"&A2"),
+            ("Table1[A1]+A1", 0, 1, "Table1[A1]+B1"),
+            ("1E5+TRUE", 1, 1, "1E5+TRUE"),
+            ("A1+B2", -1, 0, "#REF!+B1"),
+            ("XFD1", 0, 1, "#REF!"),
+            ("_xlfn.STDEV.S(A1:A3)", 1, 0, "_xlfn.STDEV.S(A2:A4)"),
+            ("SUM(A:C)", 5, 1, "SUM(B:D)"),
+            ("SUM($A:C)+SUM($B:$B)", 0, 2, "SUM($A:E)+SUM($B:$B)"),
+            ("SUM(1:3)", 2, 5, "SUM(3:5)"),
+            ("SUM($1:3)+SUM($2:$2)", 1, 0, "SUM($1:4)+SUM($2:$2)"),
+            ("Sheet1!A:A+Sheet1!2:2", 1, 1, "Sheet1!B:B+Sheet1!3:3"),
+            ("SUM(A:A)", 0, -1, "SUM(#REF!)"),
+            ("SUM(1048576:1048576)", 1, 0, "SUM(#REF!)"),
+            ("SUM(A1:B2)+A:B", 1, 1, "SUM(B2:C3)+B:C"),
+        ];
+
+        for (formula, row_offset, col_offset, expected) in tests {
+            let got = utility::shift_formula_references(formula, row_offset, col_offset);
+            assert_eq!(expected, got);
+        }
+    }
 }
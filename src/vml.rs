@@ -6,7 +6,7 @@
 
 mod tests;
 
-use crate::{drawing::DrawingInfo, xmlwriter::XMLWriter, ColNum, RowNum};
+use crate::{drawing::DrawingInfo, xmlwriter::XMLWriter, ColNum, HeaderImageColorMode, RowNum};
 
 pub struct Vml {
     pub(crate) comments: Vec<VmlInfo>,
@@ -428,11 +428,34 @@ impl Vml {
 
     // Write the <v:imagedata> element.
     fn write_imagedata(&mut self, vml_info: &VmlInfo) {
-        let attributes = [
+        let mut attributes = vec![
             ("o:relid", format!("rId{}", vml_info.rel_id)),
             ("o:title", vml_info.text.to_string()),
         ];
 
+        // Add any image cropping, as fixed point fractions of the image size.
+        let crop_names = ["croptop", "cropbottom", "cropleft", "cropright"];
+        for (name, crop) in crop_names.into_iter().zip(vml_info.image_crop) {
+            if crop > 0 {
+                attributes.push((name, format!("{crop}f")));
+            }
+        }
+
+        match vml_info.image_color_mode {
+            HeaderImageColorMode::Automatic => {}
+            HeaderImageColorMode::Grayscale => {
+                attributes.push(("grayscale", "t".to_string()));
+            }
+            HeaderImageColorMode::BlackAndWhite => {
+                attributes.push(("grayscale", "t".to_string()));
+                attributes.push(("bilevel", "t".to_string()));
+            }
+            HeaderImageColorMode::Washout => {
+                attributes.push(("gain", "19661f".to_string()));
+                attributes.push(("blacklevel", "22938f".to_string()));
+            }
+        }
+
         self.writer.xml_empty_tag("v:imagedata", &attributes);
     }
 
@@ -656,6 +679,8 @@ pub(crate) struct VmlInfo {
     pub(crate) drawing_info: DrawingInfo,
     pub(crate) is_visible: bool,
     pub(crate) fill_color: String,
+    pub(crate) image_crop: [u32; 4],
+    pub(crate) image_color_mode: HeaderImageColorMode,
 }
 
 impl Default for VmlInfo {
@@ -674,6 +699,8 @@ impl Default for VmlInfo {
             drawing_info: DrawingInfo::default(),
             is_visible: false,
             fill_color: String::new(),
+            image_crop: [0; 4],
+            image_color_mode: HeaderImageColorMode::Automatic,
         }
     }
 }
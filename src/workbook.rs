@@ -229,9 +229,16 @@ use crate::packager::Packager;
 use crate::packager::PackagerOptions;
 use crate::worksheet::Worksheet;
 use crate::xmlwriter::XMLWriter;
+
+#[cfg(feature = "serde")]
+use crate::SerializeFieldOptions;
+#[cfg(feature = "serde")]
+use serde::Serialize;
+
 use crate::{
-    utility, Border, Chart, ChartRange, ChartRangeCacheData, ColNum, Color, DefinedName,
-    DefinedNameType, DocProperties, Fill, Font, FormatPattern, Image, RowNum, Visible,
+    utility, AccessibilityIssue, AccessibilityIssueType, Border, Chart, ChartRange,
+    ChartRangeCacheData, ColNum, Color, DefinedName, DefinedNameType, DocProperties, Fill, Font,
+    FontMetrics, FormatPattern, Image, IntoRangeRef, RangeRef, RowNum, Table, Visible,
     NUM_IMAGE_FORMATS,
 };
 
@@ -331,6 +338,15 @@ pub struct Workbook {
     read_only_mode: u8,
     num_worksheets: u16,
     num_chartsheets: u16,
+    strict_ooxml: bool,
+    hyperlink_format: Option<Format>,
+    font_metrics: HashMap<String, FontMetrics>,
+    default_format: Format,
+    default_dimensions: Option<(u32, u32)>,
+    right_to_left: bool,
+    content_type_defaults: Vec<(String, String)>,
+    content_type_overrides: Vec<(String, String)>,
+    package_relationships: Vec<(String, String)>,
 }
 
 impl Default for Workbook {
@@ -408,6 +424,15 @@ impl Workbook {
             has_comments: false,
             num_worksheets: 0,
             num_chartsheets: 0,
+            strict_ooxml: false,
+            hyperlink_format: None,
+            font_metrics: HashMap::new(),
+            default_format: Format::default(),
+            default_dimensions: None,
+            right_to_left: false,
+            content_type_defaults: vec![],
+            content_type_overrides: vec![],
+            package_relationships: vec![],
         };
 
         // Initialize the workbook with the same function used to reset it.
@@ -476,6 +501,11 @@ impl Workbook {
 
         let mut worksheet = Worksheet::new();
         worksheet.set_name(&name).unwrap();
+        worksheet.font_metrics.clone_from(&self.font_metrics);
+        worksheet.set_right_to_left(self.right_to_left);
+        if let Some((row_height, col_width)) = self.default_dimensions {
+            worksheet.set_default_dimensions(row_height, col_width);
+        }
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -558,6 +588,10 @@ impl Workbook {
 
         let mut worksheet = Worksheet::new_chartsheet();
         worksheet.set_name(&name).unwrap();
+        worksheet.font_metrics.clone_from(&self.font_metrics);
+        if let Some((row_height, col_width)) = self.default_dimensions {
+            worksheet.set_default_dimensions(row_height, col_width);
+        }
 
         self.worksheets.push(worksheet);
         let worksheet = self.worksheets.last_mut().unwrap();
@@ -829,6 +863,315 @@ impl Workbook {
         &self.worksheets
     }
 
+    /// Get the names of the tables in the workbook.
+    ///
+    /// Get the names of all the worksheet tables that have been added to the
+    /// workbook, in worksheet order and then in the order that they were added
+    /// to each worksheet. Tables that don't have a user defined name, set via
+    /// [`Table::set_name()`](crate::Table::set_name), are given the same
+    /// default `Table1`, `Table2`, etc., name that will be used when the file
+    /// is saved.
+    ///
+    /// The names can be used with [`Workbook::table_range()`] to get the
+    /// range of a table or to build structured reference formulas.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the names of the tables in a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_table_names.rs
+    /// #
+    /// # use rust_xlsxwriter::{Table, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add some tables to the worksheets.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.add_table(0, 0, 4, 3, &Table::new())?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.add_table(0, 0, 4, 3, &Table::new().set_name("Sales"))?;
+    ///     worksheet.add_table(6, 0, 9, 3, &Table::new())?;
+    ///
+    ///     // Get the table names.
+    ///     assert_eq!(workbook.table_names(), vec!["Table1", "Sales", "Table3"]);
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn table_names(&self) -> Vec<String> {
+        self.named_tables().map(|(_, name, _)| name).collect()
+    }
+
+    /// Get the worksheet name and cell range of a table in the workbook.
+    ///
+    /// Get the worksheet name and range of a table, including the header and
+    /// total rows, from the table name. This can be used to build defined
+    /// names, chart ranges or formulas that refer to the table without
+    /// tracking the table coordinates separately. The name lookup is case
+    /// insensitive, like Excel.
+    ///
+    /// Tables that don't have a user defined name can be looked up with the
+    /// default `Table1`, `Table2`, etc., name returned by
+    /// [`Workbook::table_names()`], even before the workbook is saved.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the table.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the range of a table with a
+    /// default name and using it to create a defined name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_table_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{Table, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add some tables to the worksheets.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.add_table(0, 0, 4, 3, &Table::new())?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.add_table(2, 1, 6, 4, &Table::new())?;
+    ///
+    ///     // Get the range of the second table and use it to create a defined name.
+    ///     let (sheet_name, range) = workbook.table_range("Table2").unwrap();
+    ///     let formula = range.to_sheet_range(&sheet_name);
+    ///     assert_eq!(formula, "Sheet2!$B$3:$E$7");
+    ///
+    ///     workbook.define_name("SalesData", &format!("={formula}"))?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn table_range(&self, name: &str) -> Option<(String, RangeRef)> {
+        let name = name.to_lowercase();
+
+        self.named_tables()
+            .find(|(_, table_name, _)| table_name.to_lowercase() == name)
+            .map(|(worksheet, _, table)| {
+                (
+                    worksheet.name(),
+                    RangeRef::new(
+                        table.cell_range.first_row,
+                        table.cell_range.first_col,
+                        table.cell_range.last_row,
+                        table.cell_range.last_col,
+                    ),
+                )
+            })
+    }
+
+    /// Serialize data to separate worksheets based on a key value.
+    ///
+    /// This method serializes each item of an iterator to a worksheet named
+    /// after a key value, such as a region or month, that is returned by
+    /// `key_fn` for the item. Worksheets that don't exist are added to the
+    /// workbook, in the order that the keys are first seen, and the
+    /// serialization headers are written in the first row, starting at cell
+    /// `A1`.
+    ///
+    /// If a worksheet with the key name already exists it is used instead. If
+    /// the serialization headers for the data type have already been set up
+    /// in the worksheet, for example to position the data or via a previous
+    /// call to this method, then the data is added below the existing data.
+    /// Otherwise the headers are written from cell `A1`.
+    ///
+    /// See the [`serializer`](crate::serializer) module for more information
+    /// on serialization. To customize the headers see
+    /// [`Workbook::serialize_partitioned_with_options()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An iterator, or anything that can be converted into an
+    ///   iterator, of items that implement the [`serde::Serialize`] trait.
+    /// - `key_fn`: A function or closure that returns the worksheet name for
+    ///   an item.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::SheetnameCannotBeBlank`] - Worksheet name cannot be
+    ///   blank.
+    /// - [`XlsxError::SheetnameLengthExceeded`] - Worksheet name exceeds
+    ///   Excel's limit of 31 characters.
+    /// - [`XlsxError::SheetnameContainsInvalidCharacter`] - Worksheet name
+    ///   cannot contain invalid characters: `[ ] : * ? / \`
+    /// - [`XlsxError::SheetnameStartsOrEndsWithApostrophe`] - Worksheet name
+    ///   cannot start or end with an apostrophe.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing data to separate
+    /// worksheets based on the value of a field.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_serialize_partitioned.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde::Serialize;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Create a serializable struct.
+    ///     #[derive(Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Sale {
+    ///         region: &'static str,
+    ///         item: &'static str,
+    ///         volume: u32,
+    ///     }
+    ///
+    ///     let sales = [
+    ///         Sale {
+    ///             region: "East",
+    ///             item: "Apple",
+    ///             volume: 9000,
+    ///         },
+    ///         Sale {
+    ///             region: "West",
+    ///             item: "Pear",
+    ///             volume: 8000,
+    ///         },
+    ///         Sale {
+    ///             region: "East",
+    ///             item: "Grape",
+    ///             volume: 6000,
+    ///         },
+    ///     ];
+    ///
+    ///     // Serialize the data to a worksheet for each region.
+    ///     workbook.serialize_partitioned(&sales, |sale| sale.region)?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_partitioned<I, K, F>(
+        &mut self,
+        data: I,
+        key_fn: F,
+    ) -> Result<&mut Workbook, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+        F: Fn(&I::Item) -> K,
+        K: Into<String>,
+    {
+        self.serialize_partitioned_with_options(data, key_fn, &SerializeFieldOptions::new())
+    }
+
+    /// Serialize data to separate worksheets based on a key value, with header
+    /// options.
+    ///
+    /// This method is similar to [`Workbook::serialize_partitioned()`] except
+    /// that the headers written to new worksheets can be configured via
+    /// [`SerializeFieldOptions`] in the same way as
+    /// [`Worksheet::serialize_headers_with_options()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An iterator, or anything that can be converted into an
+    ///   iterator, of items that implement the [`serde::Serialize`] trait.
+    /// - `key_fn`: A function or closure that returns the worksheet name for
+    ///   an item.
+    /// - `header_options`: A [`SerializeFieldOptions`] instance.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::SheetnameCannotBeBlank`] - Worksheet name cannot be
+    ///   blank.
+    /// - [`XlsxError::SheetnameLengthExceeded`] - Worksheet name exceeds
+    ///   Excel's limit of 31 characters.
+    /// - [`XlsxError::SheetnameContainsInvalidCharacter`] - Worksheet name
+    ///   cannot contain invalid characters: `[ ] : * ? / \`
+    /// - [`XlsxError::SheetnameStartsOrEndsWithApostrophe`] - Worksheet name
+    ///   cannot start or end with an apostrophe.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_partitioned_with_options<I, K, F>(
+        &mut self,
+        data: I,
+        key_fn: F,
+        header_options: &SerializeFieldOptions,
+    ) -> Result<&mut Workbook, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+        F: Fn(&I::Item) -> K,
+        K: Into<String>,
+    {
+        let mut partitions: HashMap<String, usize> = HashMap::new();
+
+        for data_structure in data {
+            let sheetname: String = key_fn(&data_structure).into();
+
+            // Excel worksheet names are case insensitive.
+            let key = sheetname.to_lowercase();
+
+            let index = match partitions.get(&key) {
+                Some(index) => *index,
+                None => {
+                    let position = self
+                        .worksheets
+                        .iter()
+                        .position(|worksheet| worksheet.name.to_lowercase() == key);
+
+                    let index = match position {
+                        Some(index) => index,
+                        None => {
+                            // Validate the name before the worksheet is added.
+                            let mut worksheet = Worksheet::new();
+                            worksheet.set_name(&sheetname)?;
+                            self.push_worksheet(worksheet);
+                            self.worksheets.len() - 1
+                        }
+                    };
+
+                    let worksheet = &mut self.worksheets[index];
+                    if !worksheet.has_serialization_headers(&data_structure)? {
+                        worksheet.serialize_headers_with_options(
+                            0,
+                            0,
+                            &data_structure,
+                            header_options,
+                        )?;
+                    }
+
+                    partitions.insert(key, index);
+                    index
+                }
+            };
+
+            self.worksheets[index].serialize(&data_structure)?;
+        }
+
+        Ok(self)
+    }
+
     /// Add a worksheet object to a workbook.
     ///
     /// Add a worksheet created directly using `Workbook::new()` to a workbook.
@@ -891,6 +1234,23 @@ impl Workbook {
             worksheet.set_name(&name).unwrap();
         }
 
+        for (name, metrics) in &self.font_metrics {
+            worksheet
+                .font_metrics
+                .entry(name.clone())
+                .or_insert_with(|| metrics.clone());
+        }
+
+        if let Some((row_height, col_width)) = self.default_dimensions {
+            worksheet.set_default_dimensions(row_height, col_width);
+        }
+
+        // Only turn on the workbook default so that an explicit worksheet
+        // setting isn't overwritten.
+        if self.right_to_left {
+            worksheet.set_right_to_left(true);
+        }
+
         self.worksheets.push(worksheet);
     }
 
@@ -1133,6 +1493,9 @@ impl Workbook {
     /// formulas](https://support.microsoft.com/en-us/office/define-and-use-names-in-formulas-4d0f13ac-53b7-422e-afd2-abd7ff379c64)
     /// and subsections.
     ///
+    /// To define a name for a worksheet range from a [`RangeRef`] or a
+    /// `(row, col)` range see [`Workbook::define_name_range()`].
+    ///
     /// # Parameters
     ///
     /// - `name`: The variable name to define.
@@ -1255,6 +1618,70 @@ impl Workbook {
         Ok(self)
     }
 
+    /// Create a defined name for a worksheet range.
+    ///
+    /// This is a variant of [`Workbook::define_name()`] that creates a defined
+    /// name for a range of cells in a worksheet from a [`RangeRef`], or any
+    /// other type that implements [`IntoRangeRef`], rather than from a
+    /// formula string. The worksheet name is quoted, if required, and the
+    /// range is stored as an absolute reference like `Sheet1!$A$1:$B$2`.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The variable name to define. As with `define_name()` a local
+    ///   name can be created with a `Sheet1!Name` style prefix.
+    /// - `sheet_name`: The name of the worksheet that the range refers to.
+    /// - `range`: A [`RangeRef`], a `(first_row, first_col, last_row,
+    ///   last_col)` tuple or an `A1:B2` style range string.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The name isn't a valid Excel name,
+    ///   see [`Workbook::define_name()`], or the range isn't valid or exceeds
+    ///   Excel's worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating defined names for
+    /// worksheet ranges.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_define_name_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{RangeRef, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet().set_name("Sales Data")?;
+    /// #     worksheet.write_column(0, 0, [10, 20, 30])?;
+    /// #     worksheet.write_column(0, 1, [40, 50, 60])?;
+    /// #
+    ///     // Define names from a range string and a RangeRef.
+    ///     workbook.define_name_range("Sales", "Sales Data", "A1:A3")?;
+    ///     workbook.define_name_range("Costs", "Sales Data", RangeRef::new(0, 1, 2, 1))?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("defined_name.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn define_name_range(
+        &mut self,
+        name: impl Into<String>,
+        sheet_name: &str,
+        range: impl IntoRangeRef,
+    ) -> Result<&mut Workbook, XlsxError> {
+        let range = range.to_range_ref()?;
+        range.validate()?;
+
+        self.define_name(name, &range.to_sheet_range(sheet_name))
+    }
+
     /// Set the Excel document metadata properties.
     ///
     /// Set various Excel document metadata properties such as Author or
@@ -1344,6 +1771,88 @@ impl Workbook {
         self
     }
 
+    /// Check the workbook for common accessibility issues.
+    ///
+    /// The `accessibility_report()` method checks the workbook for issues that
+    /// can make it difficult to use with screen readers or for users with
+    /// visual impairments. This can be used to help meet accessibility
+    /// policies for published workbooks. The following issues are reported:
+    ///
+    /// - Images, charts and shapes without alternative text that aren't
+    ///   marked as decorative.
+    /// - Merged cells, which are often used for layout.
+    /// - A missing document title, see [`DocProperties::set_title()`].
+    /// - Worksheets with default names like `Sheet1`.
+    /// - Cell formats where the font and fill colors have a contrast ratio
+    ///   below the WCAG minimum of 4.5:1. Only the first cell that uses each
+    ///   format is reported. Theme colors aren't checked.
+    ///
+    /// The report is a list of [`AccessibilityIssue`] items that can be
+    /// examined or displayed. An empty list means that no issues were found.
+    /// The workbook isn't modified.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates checking a workbook for
+    /// accessibility issues before saving it.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_accessibility_report.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add a merged range and some low contrast text.
+    ///     let format = Format::new()
+    ///         .set_font_color(Color::Silver)
+    ///         .set_background_color(Color::White);
+    ///
+    ///     worksheet.merge_range(0, 0, 0, 3, "Sales report", &Format::new())?;
+    ///     worksheet.write_string_with_format(1, 0, "Draft", &format)?;
+    ///
+    ///     // Check the workbook for accessibility issues.
+    ///     for issue in workbook.accessibility_report() {
+    ///         println!("{issue}");
+    ///     }
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output:
+    ///
+    /// ```text
+    /// Workbook: Document doesn't have a title.
+    /// Sheet1: Worksheet has a default name instead of a descriptive name.
+    /// Sheet1!A1:D1: Merged cells can make the worksheet difficult to navigate.
+    /// Sheet1!A2: Font and fill colors have a low contrast ratio of 1.82:1.
+    /// ```
+    ///
+    pub fn accessibility_report(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = vec![];
+
+        if self.properties.title.is_empty() {
+            issues.push(AccessibilityIssue::new(
+                AccessibilityIssueType::MissingTitle,
+                None,
+                None,
+                "Document doesn't have a title.",
+            ));
+        }
+
+        for worksheet in &self.worksheets {
+            issues.extend(worksheet.accessibility_issues());
+        }
+
+        issues
+    }
+
     /// Add a vba macro file to the workbook.
     ///
     /// The `add_vba_project()` method can be used to add macros or functions to
@@ -1455,14 +1964,63 @@ impl Workbook {
         let mut data = vec![];
         reader.read_to_end(&mut data)?;
 
-        self.vba_project = data;
-        self.is_xlsm_file = true;
+        Ok(self.store_vba_project(data))
+    }
 
-        if self.vba_codename.is_none() {
-            self.vba_codename = Some("ThisWorkbook".to_string());
+    /// Add a vba macro file to the workbook from a buffer.
+    ///
+    /// This method is similar to [`Workbook::add_vba_project()`] except the
+    /// data for the `vbaProject.bin` file is read from a buffer instead of a
+    /// file path. This is useful for environments that don't have a
+    /// filesystem, such as WASM, or if the VBA project is embedded in the
+    /// application with [`include_bytes!()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `project`: A buffer containing the data of a `vbaProject.bin` file.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The buffer is empty.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a vba project to an xlsm
+    /// file from a buffer.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_vba_project_from_buffer.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Embed the VBA project in the application.
+    ///     let project = include_bytes!("../examples/vbaProject.bin");
+    ///
+    ///     workbook.add_vba_project_from_buffer(project)?;
+    /// #
+    /// #     let _worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Note the `.xlsm` extension.
+    /// #     workbook.save("macros.xlsm")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_vba_project_from_buffer(
+        &mut self,
+        project: &[u8],
+    ) -> Result<&mut Workbook, XlsxError> {
+        if project.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "VBA project buffer cannot be empty".to_string(),
+            ));
         }
 
-        Ok(self)
+        Ok(self.store_vba_project(project.to_vec()))
     }
 
     /// Add a signed vba macro file to the workbook.
@@ -1544,6 +2102,41 @@ impl Workbook {
         Ok(self)
     }
 
+    /// Add a signed vba macro file to the workbook from buffers.
+    ///
+    /// This method is similar to
+    /// [`Workbook::add_vba_project_with_signature()`] except the data for the
+    /// `vbaProject.bin` and `vbaProjectSignature.bin` files is read from
+    /// buffers instead of file paths. See
+    /// [`Workbook::add_vba_project_from_buffer()`] above.
+    ///
+    /// # Parameters
+    ///
+    /// - `project`: A buffer containing the data of a `vbaProject.bin` file.
+    /// - `signature`: A buffer containing the data of a
+    ///   `vbaProjectSignature.bin` file.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - One of the buffers is empty.
+    ///
+    pub fn add_vba_project_with_signature_from_buffer(
+        &mut self,
+        project: &[u8],
+        signature: &[u8],
+    ) -> Result<&mut Workbook, XlsxError> {
+        if signature.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "VBA signature buffer cannot be empty".to_string(),
+            ));
+        }
+
+        self.add_vba_project_from_buffer(project)?;
+        self.vba_signature = signature.to_vec();
+
+        Ok(self)
+    }
+
     /// Set the workbook name used in VBA macros.
     ///
     /// This method can be used to set the VBA name for the workbook. This is
@@ -1621,20 +2214,504 @@ impl Workbook {
         self
     }
 
-    // -----------------------------------------------------------------------
-    // Internal function/methods.
-    // -----------------------------------------------------------------------
-
-    // Reset workbook between saves.
-    fn reset(&mut self) {
-        self.writer.reset();
-
-        self.xf_indices = HashMap::from([(Format::default(), 0)]);
-        self.xf_formats = vec![Format::default()];
-        self.dxf_indices = HashMap::new();
-        self.dxf_formats = vec![];
-        self.font_count = 0;
-        self.fill_count = 0;
+    /// Register the character widths of a custom font.
+    ///
+    /// The `register_font()` method registers a [`FontMetrics`] table of
+    /// character widths for a non-standard font, such as a corporate font.
+    /// The widths are used by [`Worksheet::autofit()`] for cells with a
+    /// [`Format`] that uses the font, instead of the default Calibri 11
+    /// widths. If the metrics are for "Calibri" they are also used to convert
+    /// column widths between character units and pixels.
+    ///
+    /// Any font family or character set in the metrics is added to the font
+    /// declarations of formats that use the font when the workbook is saved.
+    ///
+    /// The font applies to all worksheets in the workbook, including worksheets
+    /// that are added after it is registered. It should be registered before
+    /// [`Worksheet::autofit()`] is called. Registering a font with the same
+    /// name replaces the previous metrics.
+    ///
+    /// # Parameters
+    ///
+    /// - `metrics`: The [`FontMetrics`] for the font.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates registering the character widths of
+    /// a custom font so that `autofit()` gives a better column width.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_register_font.rs
+    /// #
+    /// # use rust_xlsxwriter::{FontMetrics, Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Register the widths of a wide corporate font at 11pt.
+    ///     let metrics = FontMetrics::new("Corporate Sans", 11.0)
+    ///         .set_char_widths(('0'..='9').map(|digit| (digit, 9)))
+    ///         .set_char_widths(('a'..='z').map(|letter| (letter, 10)))
+    ///         .set_char_widths(('A'..='Z').map(|letter| (letter, 12)))
+    ///         .set_char_width(' ', 4)
+    ///         .set_font_family(1);
+    ///
+    ///     workbook.register_font(&metrics);
+    ///
+    ///     let format = Format::new().set_font_name("Corporate Sans");
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_with_format(0, 0, "Quarterly Revenue", &format)?;
+    ///     worksheet.write_with_format(1, 0, 12345.5, &format)?;
+    ///
+    ///     // Autofit the columns using the registered font widths.
+    ///     worksheet.autofit();
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn register_font(&mut self, metrics: &FontMetrics) -> &mut Workbook {
+        self.font_metrics
+            .insert(metrics.name.clone(), metrics.clone());
+
+        for worksheet in &mut self.worksheets {
+            worksheet
+                .font_metrics
+                .insert(metrics.name.clone(), metrics.clone());
+        }
+
+        self
+    }
+
+    /// Set the default format for urls in all the worksheets of the workbook.
+    ///
+    /// Urls that are written with [`Worksheet::write_url()`] and similar
+    /// methods, without an explicit format, are displayed with the Excel
+    /// "Hyperlink" cell style which is a blue underlined font. This method
+    /// replaces that style with a user defined [`Format`] in all of the
+    /// worksheets in the workbook, including worksheets that are added after
+    /// it is called. This avoids having to pass the same format with every url
+    /// write call.
+    ///
+    /// The format is applied when the workbook is saved. It doesn't apply to
+    /// urls written with an explicit format or to worksheets that use a
+    /// custom or inherited [`HyperlinkStyle`](crate::HyperlinkStyle) via
+    /// [`Worksheet::set_hyperlink_style()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The [`Format`] property for url cells.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a workbook wide format for
+    /// urls.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_hyperlink_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, FormatUnderline, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Display all urls with a dark green double underlined font.
+    ///     let format = Format::new()
+    ///         .set_font_color(Color::RGB(0x006100))
+    ///         .set_underline(FormatUnderline::Double);
+    ///
+    ///     workbook.set_hyperlink_format(&format);
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_url(0, 0, "https://www.rust-lang.org")?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write_url(0, 0, "https://crates.io")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_hyperlink_format(&mut self, format: &Format) -> &mut Workbook {
+        self.hyperlink_format = Some(format.clone());
+        self
+    }
+
+    /// Set the default format for all the cells in the workbook.
+    ///
+    /// Excel uses a default Calibri 11 font for all cells that don't have an
+    /// explicit format. The `set_default_format()` method changes the
+    /// workbook default format, and in particular the default font, so that
+    /// the entire workbook is displayed in another font such as a corporate
+    /// font.
+    ///
+    /// The default format is also used as the base font for other formats in
+    /// the workbook. Formats that don't set a font name, or a font size, get
+    /// the font name, or font size, of the default format. For example a
+    /// format created with `Format::new().set_bold()` will be displayed in the
+    /// bold version of the default font.
+    ///
+    /// Excel calculates the default row height and column width from the
+    /// default font. Since `rust_xlsxwriter` doesn't have access to the font
+    /// metrics these must be supplied in pixels. The simplest way to find
+    /// them is to set the default font in Excel and then check the row height
+    /// and column width, in pixels, of an empty cell. For reference the
+    /// values for the standard Calibri 11 font are 20 and 64 pixels.
+    ///
+    /// The row height is used as the default height of worksheet rows and the
+    /// column width is used to convert column widths between character units
+    /// and pixels, and to position images and charts.
+    ///
+    /// This method must be called before any worksheets are added to the
+    /// workbook.
+    ///
+    /// # Parameters
+    ///
+    /// - `format`: The default [`Format`] for the workbook.
+    /// - `row_height`: The default row height for the format, in pixels.
+    /// - `col_width`: The default column width for the format, in pixels.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The method was called after
+    ///   worksheets were added to the workbook or the row height or column
+    ///   width is zero.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates changing the default font for a
+    /// workbook.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_default_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Use Aptos Narrow 11 as the default font. The default row height
+    ///     // and column width for this font are 20 and 64 pixels.
+    ///     let format = Format::new().set_font_name("Aptos Narrow");
+    ///     workbook.set_default_format(&format, 20, 64)?;
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     // This format will also use the default font.
+    ///     let bold = Format::new().set_bold();
+    ///     worksheet.write_with_format(1, 0, "World", &bold)?;
+    /// #
+    /// #     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_default_format(
+        &mut self,
+        format: &Format,
+        row_height: u32,
+        col_width: u32,
+    ) -> Result<&mut Workbook, XlsxError> {
+        if !self.worksheets.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "Workbook::set_default_format() must be called before adding worksheets"
+                    .to_string(),
+            ));
+        }
+
+        if row_height == 0 || col_width == 0 {
+            return Err(XlsxError::ParameterError(
+                "Default row height and column width must be greater than 0".to_string(),
+            ));
+        }
+
+        self.default_format = format.clone();
+        self.default_dimensions = Some((row_height, col_width));
+
+        Ok(self)
+    }
+
+    /// Display all new worksheets in the workbook from right to left.
+    ///
+    /// The `set_right_to_left()` method sets the default direction for
+    /// worksheets that are subsequently added to the workbook. It is
+    /// equivalent to calling [`Worksheet::set_right_to_left()`] on each new
+    /// worksheet and is useful when creating Arabic, Hebrew or other
+    /// workbooks where right-to-left is the expected direction.
+    ///
+    /// The setting applies to worksheets created with
+    /// [`Workbook::add_worksheet()`] and to worksheets added with
+    /// [`Workbook::push_worksheet()`]. Individual worksheets can be changed
+    /// back to left-to-right with [`Worksheet::set_right_to_left()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the default direction of
+    /// the worksheets in a workbook to right-to-left.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_right_to_left.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Set the default direction before adding the worksheets.
+    ///     workbook.set_right_to_left(true);
+    ///
+    ///     // Both worksheets are displayed from right to left.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///     worksheet1.write(0, 0, "نص عربي / English text")?;
+    ///
+    ///     let worksheet2 = workbook.add_worksheet();
+    ///     worksheet2.write(0, 0, "نص عربي / English text")?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_right_to_left(&mut self, enable: bool) -> &mut Workbook {
+        self.right_to_left = enable;
+        self
+    }
+
+    /// Write the file using the Strict Office Open XML conformance class.
+    ///
+    /// By default `rust_xlsxwriter`, like Excel, writes xlsx files that
+    /// conform to the "Transitional" variant of the Office Open XML (ECMA-376
+    /// / ISO/IEC 29500) standard. Some document management and archiving
+    /// systems require files that validate against the "Strict" variant of the
+    /// standard. This is equivalent to saving a file in Excel as "Strict Open
+    /// XML Spreadsheet".
+    ///
+    /// When this option is turned on the Strict namespaces and relationship
+    /// types are used in the xml files of the xlsx package and the workbook is
+    /// marked as `conformance="strict"`. Excel can open and edit Strict files.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is off by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates creating a workbook that conforms to
+    /// the Strict Office Open XML standard.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_set_strict_ooxml.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     workbook.set_strict_ooxml(true);
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_strict_ooxml(&mut self, enable: bool) -> &mut Workbook {
+        self.strict_ooxml = enable;
+        self
+    }
+
+    /// Add a default content type for a file extension in the xlsx package.
+    ///
+    /// The `[Content_Types].xml` file in an xlsx package maps the parts of the
+    /// package to their content types. This method is an escape hatch for
+    /// applications that post-process the saved file to add their own parts
+    /// and need to register the content type for a file extension that
+    /// `rust_xlsxwriter` doesn't know about.
+    ///
+    /// # Parameters
+    ///
+    /// - `extension`: The file extension, without a leading `.`.
+    /// - `content_type`: The MIME content type for the extension.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The extension or content type is
+    ///   blank, or the extension starts with `.`.
+    ///
+    pub fn add_content_type_default(
+        &mut self,
+        extension: &str,
+        content_type: &str,
+    ) -> Result<&mut Workbook, XlsxError> {
+        if extension.is_empty() || extension.starts_with('.') || content_type.is_empty() {
+            let error = format!(
+                "Invalid content type default: extension = '{extension}', content type = '{content_type}'"
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        self.content_type_defaults
+            .push((extension.to_string(), content_type.to_string()));
+
+        Ok(self)
+    }
+
+    /// Add a content type override for a part in the xlsx package.
+    ///
+    /// This method is an escape hatch for applications that post-process the
+    /// saved file to add their own parts, such as custom xml data, and need to
+    /// register the content type of the part in the `[Content_Types].xml`
+    /// file. See also [`Workbook::add_package_relationship()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `part_name`: The absolute name of the part in the package, such as
+    ///   `/customXml/item1.xml`.
+    /// - `content_type`: The MIME content type for the part.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The part name doesn't start with `/`
+    ///   or the content type is blank.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates registering the content type and
+    /// relationship for a part that is added to the file after it is saved.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_workbook_add_content_type_override.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     let _worksheet = workbook.add_worksheet();
+    ///
+    ///     workbook.add_content_type_override("/docProps/thumbnail.jpeg", "image/jpeg")?;
+    ///
+    ///     workbook.add_package_relationship(
+    ///         "http://schemas.openxmlformats.org/package/2006/relationships/metadata/thumbnail",
+    ///         "docProps/thumbnail.jpeg",
+    ///     )?;
+    ///
+    ///     workbook.save("workbook.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn add_content_type_override(
+        &mut self,
+        part_name: &str,
+        content_type: &str,
+    ) -> Result<&mut Workbook, XlsxError> {
+        if !part_name.starts_with('/') || content_type.is_empty() {
+            let error = format!(
+                "Invalid content type override: part name = '{part_name}', content type = '{content_type}'"
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        self.content_type_overrides
+            .push((part_name.to_string(), content_type.to_string()));
+
+        Ok(self)
+    }
+
+    /// Add a relationship to the package level `_rels/.rels` file.
+    ///
+    /// This method is an escape hatch for applications that post-process the
+    /// saved file to add their own parts and need to link them from the
+    /// package relationships. See [`Workbook::add_content_type_override()`]
+    /// for an example.
+    ///
+    /// If the workbook is saved with [`Workbook::set_strict_ooxml()`] then
+    /// Transitional relationship types are converted to their Strict
+    /// equivalents.
+    ///
+    /// # Parameters
+    ///
+    /// - `rel_type`: The full relationship type URI.
+    /// - `target`: The target part of the relationship, relative to the root
+    ///   of the package.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - The relationship type or target is
+    ///   blank.
+    ///
+    pub fn add_package_relationship(
+        &mut self,
+        rel_type: &str,
+        target: &str,
+    ) -> Result<&mut Workbook, XlsxError> {
+        if rel_type.is_empty() || target.is_empty() {
+            let error =
+                format!("Invalid package relationship: type = '{rel_type}', target = '{target}'");
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        self.package_relationships
+            .push((rel_type.to_string(), target.to_string()));
+
+        Ok(self)
+    }
+
+    // -----------------------------------------------------------------------
+    // Internal function/methods.
+    // -----------------------------------------------------------------------
+
+    // Apply the workbook default format to a worksheet format. An unformatted
+    // cell gets the default format and any other format that uses the
+    // standard Calibri 11 font gets the default font name and/or size.
+    fn apply_default_format(default_format: &Format, format: &mut Format) {
+        if *format == Format::default() {
+            format.clone_from(default_format);
+            return;
+        }
+
+        let standard_font = Font::default();
+        let default_font = &default_format.font;
+
+        if format.font.name == standard_font.name && format.font.scheme == standard_font.scheme {
+            format.font.name.clone_from(&default_font.name);
+            format.font.scheme.clone_from(&default_font.scheme);
+            format.font.family = default_font.family;
+            format.font.charset = default_font.charset;
+        }
+
+        if format.font.size == standard_font.size {
+            format.font.size.clone_from(&default_font.size);
+        }
+    }
+
+    // Reset workbook between saves.
+    fn reset(&mut self) {
+        self.writer.reset();
+
+        self.xf_indices = HashMap::from([(self.default_format.clone(), 0)]);
+        self.xf_formats = vec![self.default_format.clone()];
+        self.dxf_indices = HashMap::new();
+        self.dxf_formats = vec![];
+        self.font_count = 0;
+        self.fill_count = 0;
         self.border_count = 0;
         self.num_formats = vec![];
 
@@ -1643,6 +2720,18 @@ impl Workbook {
         }
     }
 
+    // Store the vbaProject.bin data and mark the workbook as an xlsm file.
+    fn store_vba_project(&mut self, project: Vec<u8>) -> &mut Workbook {
+        self.vba_project = project;
+        self.is_xlsm_file = true;
+
+        if self.vba_codename.is_none() {
+            self.vba_codename = Some("ThisWorkbook".to_string());
+        }
+
+        self
+    }
+
     // Internal function to prepare the workbook and other component files for
     // writing to the xlsx file.
     #[allow(clippy::similar_names)]
@@ -1657,18 +2746,6 @@ impl Workbook {
         // Ensure one sheet is active/selected.
         self.set_active_worksheets();
 
-        // Check for the use of hyperlink style in the worksheets and if so add
-        // a hyperlink style to the global formats.
-        for worksheet in &self.worksheets {
-            if worksheet.has_hyperlink_style {
-                let format = Format::new().set_hyperlink();
-                self.xf_indices.insert(format.clone(), 1);
-                self.xf_formats.push(format);
-                self.has_hyperlink_style = true;
-                break;
-            }
-        }
-
         // Check for duplicate sheet names, which aren't allowed by Excel.
         let mut unique_worksheet_names = HashSet::new();
         for worksheet in &self.worksheets {
@@ -1690,6 +2767,9 @@ impl Workbook {
             }
         }
 
+        // Check that internal links point to existing worksheets or names.
+        self.check_internal_links()?;
+
         // Write any Tables associated with serialization areas.
         #[cfg(feature = "serde")]
         for worksheet in &mut self.worksheets {
@@ -1704,12 +2784,62 @@ impl Workbook {
         let mut worksheet_xf_formats: Vec<Vec<Format>> = vec![];
         let mut worksheet_dxf_formats: Vec<Vec<Format>> = vec![];
         for worksheet in &self.worksheets {
-            let formats = worksheet.xf_formats.clone();
+            let mut formats = worksheet.xf_formats.clone();
+
+            // Replace the implicit url format with the user workbook format, if
+            // any. Otherwise it is the same as a user hyperlink format.
+            for format in &mut formats {
+                if format.is_default_hyperlink {
+                    match &self.hyperlink_format {
+                        Some(hyperlink_format) => format.clone_from(hyperlink_format),
+                        None => format.is_default_hyperlink = false,
+                    }
+                }
+            }
+
+            // Add the font declarations of any registered fonts.
+            for format in &mut formats {
+                if let Some(metrics) = self.font_metrics.get(&format.font.name) {
+                    if let Some(family) = metrics.family {
+                        format.font.family = family;
+                    }
+                    if let Some(charset) = metrics.charset {
+                        format.font.charset = charset;
+                    }
+                }
+            }
+
+            // Apply the workbook default format, if any.
+            if self.default_dimensions.is_some() {
+                for format in &mut formats {
+                    Self::apply_default_format(&self.default_format, format);
+                }
+            }
+
             worksheet_xf_formats.push(formats);
             let formats = worksheet.dxf_formats.clone();
             worksheet_dxf_formats.push(formats);
         }
 
+        // Check for the use of hyperlink style in the worksheets and if so add
+        // a hyperlink style to the global formats. This is checked after the
+        // workbook hyperlink format, if any, has replaced the default format.
+        let has_hyperlink_style = worksheet_xf_formats
+            .iter()
+            .chain(&worksheet_dxf_formats)
+            .flatten()
+            .any(|format| format.font.is_hyperlink);
+
+        if has_hyperlink_style {
+            let mut format = Format::new().set_hyperlink();
+            if self.default_dimensions.is_some() {
+                Self::apply_default_format(&self.default_format, &mut format);
+            }
+            self.xf_indices.insert(format.clone(), 1);
+            self.xf_formats.push(format);
+            self.has_hyperlink_style = true;
+        }
+
         let mut worksheet_xf_indices: Vec<Vec<u32>> = vec![];
         for formats in &worksheet_xf_formats {
             let mut indices = vec![];
@@ -1760,6 +2890,9 @@ impl Workbook {
         // Prepare the worksheet VML elements such as buttons and header images.
         self.prepare_vml();
 
+        // Resolve chart series ranges that refer to worksheet table columns.
+        self.prepare_chart_table_ranges()?;
+
         // Fill the chart data caches from worksheet data.
         self.prepare_chart_cache_data()?;
 
@@ -1780,6 +2913,84 @@ impl Workbook {
         Ok(())
     }
 
+    // Check that the targets of internal links written with
+    // write_url_internal(), or with Url::internal(), refer to an existing
+    // worksheet or defined name. The names can be global, or scoped to a
+    // worksheet like "Sheet1!Name". Excel treats both worksheet names and
+    // defined names as case insensitive.
+    fn check_internal_links(&self) -> Result<(), XlsxError> {
+        let sheet_names: HashSet<String> = self
+            .worksheets
+            .iter()
+            .map(|worksheet| worksheet.name.to_lowercase())
+            .collect();
+
+        let global_names: HashSet<String> = self
+            .user_defined_names
+            .iter()
+            .filter(|defined_name| matches!(defined_name.name_type, DefinedNameType::Global))
+            .map(|defined_name| defined_name.name.to_lowercase())
+            .collect();
+
+        let local_names: HashSet<(String, String)> = self
+            .user_defined_names
+            .iter()
+            .filter(|defined_name| matches!(defined_name.name_type, DefinedNameType::Local))
+            .map(|defined_name| {
+                (
+                    utility::unquote_sheetname(&defined_name.quoted_sheet_name).to_lowercase(),
+                    defined_name.name.to_lowercase(),
+                )
+            })
+            .collect();
+
+        for worksheet in &self.worksheets {
+            for ((row, col), target) in &worksheet.internal_link_targets {
+                let location = format!(
+                    "'{}'!{}",
+                    worksheet.name,
+                    utility::row_col_to_cell(*row, *col)
+                );
+
+                let (sheet_name, reference) = match target.rsplit_once('!') {
+                    Some((sheet_name, reference)) => {
+                        let sheet_name = utility::unquote_sheetname(sheet_name).to_lowercase();
+
+                        if !sheet_names.contains(&sheet_name) {
+                            let error = format!(
+                                "Unknown worksheet in internal link '{target}' at {location}"
+                            );
+                            return Err(XlsxError::UnknownWorksheetNameOrIndex(error));
+                        }
+
+                        if utility::is_cell_link_reference(reference) {
+                            continue;
+                        }
+
+                        (sheet_name, reference.to_lowercase())
+                    }
+                    None => {
+                        let name = target.to_lowercase();
+
+                        if global_names.contains(&name) {
+                            continue;
+                        }
+
+                        (worksheet.name.to_lowercase(), name)
+                    }
+                };
+
+                if !local_names.contains(&(sheet_name, reference)) {
+                    let error =
+                        format!("Unknown defined name in internal link '{target}' at {location}");
+                    return Err(XlsxError::ParameterError(error));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
     // Iterates through the worksheets and find which is the user defined Active
     // sheet. If none has been set then default to the first sheet, like Excel.
     fn set_active_worksheets(&mut self) {
@@ -1866,6 +3077,7 @@ impl Workbook {
     // Convert the images in the workbooks into drawing files and rel links.
     fn prepare_drawings(&mut self) {
         let mut chart_id = 1;
+        let mut chartex_id = 1;
         let mut drawing_id = 1;
         let mut shape_id = 1;
         let mut image_id = self.embedded_images.len() as u32;
@@ -1884,8 +3096,8 @@ impl Workbook {
             }
 
             if !worksheet.charts.is_empty() {
-                worksheet.prepare_worksheet_charts(chart_id, drawing_id);
-                chart_id += worksheet.charts.len() as u32;
+                (chart_id, chartex_id) =
+                    worksheet.prepare_worksheet_charts(chart_id, chartex_id, drawing_id);
             }
 
             if !worksheet.shapes.is_empty() {
@@ -1992,6 +3204,113 @@ impl Workbook {
         Ok(())
     }
 
+    // Get the tables in the workbook along with their worksheet and the name
+    // that they will have in the saved file. Tables without a user defined
+    // name are given a default name from their index in the workbook, in the
+    // same way as Worksheet::prepare_worksheet_tables().
+    fn named_tables(&self) -> impl Iterator<Item = (&Worksheet, String, &Table)> {
+        self.worksheets
+            .iter()
+            .flat_map(|worksheet| worksheet.tables.iter().map(move |table| (worksheet, table)))
+            .enumerate()
+            .map(|(index, (worksheet, table))| {
+                let name = if table.name.is_empty() {
+                    format!("Table{}", index + 1)
+                } else {
+                    table.name.clone()
+                };
+
+                (worksheet, name, table)
+            })
+    }
+
+    // Convert chart series table column references into worksheet ranges.
+    fn prepare_chart_table_ranges(&mut self) -> Result<(), XlsxError> {
+        let mut table_columns: HashMap<(String, String), (ChartRange, Option<ChartRange>)> =
+            HashMap::new();
+
+        for (worksheet, table_name, table) in self.named_tables() {
+            for (offset, column) in table.columns.iter().enumerate() {
+                let col = table.cell_range.first_col + offset as ColNum;
+                let first_row = table.cell_range.first_row;
+
+                let data_range = ChartRange::new_from_range(
+                    &worksheet.name,
+                    table.first_data_row(),
+                    col,
+                    table.last_data_row(),
+                    col,
+                );
+
+                let header_range = if table.show_header_row {
+                    Some(ChartRange::new_from_range(
+                        &worksheet.name,
+                        first_row,
+                        col,
+                        first_row,
+                        col,
+                    ))
+                } else {
+                    None
+                };
+
+                table_columns.insert(
+                    (table_name.to_lowercase(), column.name.to_lowercase()),
+                    (data_range, header_range),
+                );
+            }
+        }
+
+        for worksheet in &mut self.worksheets {
+            for chart in worksheet.charts.values_mut() {
+                Self::update_chart_table_ranges(chart, &table_columns)?;
+
+                if let Some(chart) = &mut chart.combined_chart {
+                    Self::update_chart_table_ranges(chart, &table_columns)?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    // Set the chart series ranges from the table column lookup table.
+    fn update_chart_table_ranges(
+        chart: &mut Chart,
+        table_columns: &HashMap<(String, String), (ChartRange, Option<ChartRange>)>,
+    ) -> Result<(), XlsxError> {
+        let lookup = |(table_name, column_name): &(String, String)| {
+            table_columns
+                .get(&(table_name.to_lowercase(), column_name.to_lowercase()))
+                .ok_or_else(|| {
+                    XlsxError::ChartError(format!(
+                        "Unknown table or column name in chart series range: '{table_name}[{column_name}]'"
+                    ))
+                })
+        };
+
+        for series in &mut chart.series {
+            if let Some(table_column) = &series.value_table {
+                let (data_range, header_range) = lookup(table_column)?;
+                series.value_range = data_range.clone();
+
+                // Use the column header as the default series name.
+                if let Some(header_range) = header_range {
+                    if series.title.name.is_empty() && !series.title.range.has_data() {
+                        series.set_name(header_range);
+                    }
+                }
+            }
+
+            if let Some(table_column) = &series.category_table {
+                let (data_range, _) = lookup(table_column)?;
+                series.category_range = data_range.clone();
+            }
+        }
+
+        Ok(())
+    }
+
     // Insert all the various chart ranges into the lookup range cache.
     fn insert_chart_ranges_to_cache(
         chart: &Chart,
@@ -2010,6 +3329,10 @@ impl Workbook {
                 Self::insert_to_chart_cache(&data_label.title.range, chart_caches);
             }
 
+            if let Some(data_label) = &series.data_label {
+                Self::insert_to_chart_cache(&data_label.value_range, chart_caches);
+            }
+
             if let Some(error_bars) = &series.y_error_bars {
                 Self::insert_to_chart_cache(&error_bars.plus_range, chart_caches);
                 Self::insert_to_chart_cache(&error_bars.minus_range, chart_caches);
@@ -2042,6 +3365,10 @@ impl Workbook {
                 }
             }
 
+            if let Some(data_label) = &mut series.data_label {
+                Self::update_range_cache(&mut data_label.value_range, chart_caches);
+            }
+
             if let Some(error_bars) = &mut series.y_error_bars {
                 Self::update_range_cache(&mut error_bars.plus_range, chart_caches);
                 Self::update_range_cache(&mut error_bars.minus_range, chart_caches);
@@ -2273,6 +3600,16 @@ impl Workbook {
         mut package_options: PackagerOptions,
     ) -> Result<PackagerOptions, XlsxError> {
         package_options.doc_security = self.read_only_mode;
+        package_options.strict_ooxml = self.strict_ooxml;
+        package_options
+            .content_type_defaults
+            .clone_from(&self.content_type_defaults);
+        package_options
+            .content_type_overrides
+            .clone_from(&self.content_type_overrides);
+        package_options
+            .package_relationships
+            .clone_from(&self.package_relationships);
         package_options.num_embedded_images = self.embedded_images.len() as u32;
 
         let mut defined_names = self.user_defined_names.clone();
@@ -2319,6 +3656,10 @@ impl Workbook {
                 }
             }
 
+            if worksheet.has_checkboxes {
+                package_options.has_checkboxes = true;
+            }
+
             if worksheet.has_vml || worksheet.has_header_footer_images() {
                 package_options.has_vml = true;
             }
@@ -2327,8 +3668,12 @@ impl Workbook {
                 package_options.num_drawings += 1;
             }
 
-            if !worksheet.charts.is_empty() {
-                package_options.num_charts += worksheet.charts.len() as u16;
+            for chart in worksheet.charts.values() {
+                if chart.is_chartex() {
+                    package_options.num_chartex_charts += 1;
+                } else {
+                    package_options.num_charts += 1;
+                }
             }
 
             if !worksheet.tables.is_empty() {
@@ -2449,7 +3794,11 @@ impl Workbook {
         let xmlns = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
         let xmlns_r = "http://schemas.openxmlformats.org/officeDocument/2006/relationships";
 
-        let attributes = [("xmlns", xmlns), ("xmlns:r", xmlns_r)];
+        let mut attributes = vec![("xmlns", xmlns), ("xmlns:r", xmlns_r)];
+
+        if self.strict_ooxml {
+            attributes.push(("conformance", "strict"));
+        }
 
         self.writer.xml_start_tag("workbook", &attributes);
     }
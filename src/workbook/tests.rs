@@ -8,7 +8,7 @@
 mod workbook_tests {
 
     use crate::{test_functions::xml_to_vec, XlsxError};
-    use crate::{Table, Workbook};
+    use crate::{RangeRef, Table, Url, Workbook, Worksheet};
     use pretty_assertions::assert_eq;
 
     #[test]
@@ -59,6 +59,36 @@ mod workbook_tests {
         }
     }
 
+    #[test]
+    fn define_name_range() {
+        let mut workbook = Workbook::default();
+
+        workbook
+            .define_name_range("Sales", "Sales Data", "A1:B3")
+            .unwrap();
+        workbook
+            .define_name_range("Sheet1!Costs", "Sheet1", RangeRef::new(0, 0, 9, 0))
+            .unwrap();
+
+        assert_eq!(
+            workbook.user_defined_names[0].range,
+            "'Sales Data'!$A$1:$B$3"
+        );
+        assert_eq!(workbook.user_defined_names[1].range, "Sheet1!$A$1:$A$10");
+
+        // Unvalidated ranges are checked.
+        let ranges = [
+            RangeRef::new(0, 0, 1_048_576, 0),
+            RangeRef::new(0, 0, 0, 16_384),
+            RangeRef::new(2, 0, 1, 0),
+        ];
+
+        for range in ranges {
+            let result = workbook.define_name_range("Foo", "Sheet1", range);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
+
     #[test]
     fn duplicate_worksheets() {
         let mut workbook = Workbook::default();
@@ -97,4 +127,572 @@ mod workbook_tests {
 
         assert!(matches!(result, Err(XlsxError::TableNameReused(_))));
     }
+
+    #[test]
+    fn table_names_and_ranges() {
+        use crate::RangeRef;
+
+        let mut workbook = Workbook::new();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.add_table(0, 0, 4, 3, &Table::new()).unwrap();
+        worksheet
+            .add_table(6, 0, 9, 3, &Table::new().set_name("Sales"))
+            .unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.add_table(2, 1, 5, 2, &Table::new()).unwrap();
+
+        assert_eq!(workbook.table_names(), vec!["Table1", "Sales", "Table3"]);
+
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        assert_eq!(
+            worksheet.table_range("sales"),
+            Some(RangeRef::new(6, 0, 9, 3))
+        );
+
+        // Default names can be looked up at the workbook level before saving.
+        for name in workbook.table_names() {
+            assert!(workbook.table_range(&name).is_some());
+        }
+        assert_eq!(
+            workbook.table_range("table3"),
+            Some(("Sheet2".to_string(), RangeRef::new(2, 1, 5, 2)))
+        );
+        assert_eq!(workbook.table_range("Table4"), None);
+
+        // Default names are assigned to the worksheet when the file is saved.
+        workbook.save_to_buffer().unwrap();
+
+        assert_eq!(workbook.table_names(), vec!["Table1", "Sales", "Table3"]);
+
+        let worksheet = workbook.worksheet_from_index(1).unwrap();
+        assert_eq!(
+            worksheet.table_range("Table3"),
+            Some(RangeRef::new(2, 1, 5, 2))
+        );
+        assert_eq!(worksheet.table_range("Sales"), None);
+    }
+
+    #[test]
+    fn strict_ooxml() {
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "Hello").unwrap();
+
+        workbook.set_strict_ooxml(true);
+        workbook
+            .add_content_type_override("/customXml/item1.xml", "application/xml")
+            .unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        for index in 0..zip.len() {
+            let mut file = zip.by_index(index).unwrap();
+            let mut xml = String::new();
+            file.read_to_string(&mut xml).unwrap();
+
+            assert!(
+                !xml.contains("http://schemas.openxmlformats.org/spreadsheetml")
+                    && !xml.contains("http://schemas.openxmlformats.org/officeDocument"),
+                "Transitional namespace in {}",
+                file.name()
+            );
+
+            match file.name() {
+                "xl/workbook.xml" => assert!(xml.contains(r#"conformance="strict""#)),
+                "[Content_Types].xml" => assert!(xml.contains(
+                    r#"<Override PartName="/customXml/item1.xml" ContentType="application/xml"/>"#
+                )),
+                "_rels/.rels" => assert!(xml.contains(
+                    "http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties"
+                )),
+                _ => {}
+            }
+        }
+    }
+
+    #[test]
+    fn default_format() {
+        use crate::Format;
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+
+        let format = Format::new().set_font_name("Arial").set_font_size(12);
+        workbook.set_default_format(&format, 21, 72).unwrap();
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_column_width_pixels(0, 93).unwrap();
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet
+            .write_with_format(1, 0, "World", &Format::new().set_bold())
+            .unwrap();
+
+        let result = workbook.set_default_format(&format, 21, 72);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut xml = String::new();
+        zip.by_name("xl/styles.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains(
+            r#"<fonts count="2"><font><sz val="12"/><color theme="1"/><name val="Arial"/><family val="2"/></font><font><b/><sz val="12"/><color theme="1"/><name val="Arial"/><family val="2"/></font></fonts>"#
+        ));
+
+        let mut xml = String::new();
+        zip.by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains(r#"<sheetFormatPr defaultRowHeight="15.75"/>"#));
+        assert!(xml.contains(r#"<col min="1" max="1" width="11.625" customWidth="1"/>"#));
+    }
+
+    #[test]
+    fn default_right_to_left() {
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        workbook.set_right_to_left(true);
+
+        workbook.add_worksheet();
+        workbook.add_worksheet().set_right_to_left(false);
+        workbook.push_worksheet(Worksheet::new());
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        for (sheet, expected) in [(1, true), (2, false), (3, true)] {
+            let mut xml = String::new();
+            zip.by_name(&format!("xl/worksheets/sheet{sheet}.xml"))
+                .unwrap()
+                .read_to_string(&mut xml)
+                .unwrap();
+
+            assert_eq!(expected, xml.contains(r#"rightToLeft="1""#));
+        }
+    }
+
+    #[test]
+    fn chartex_charts() {
+        use crate::{Chart, ChartType};
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        worksheet
+            .write_column(0, 0, ["Asia", "", "Europe"])
+            .unwrap();
+        worksheet
+            .write_column(0, 1, ["China", "India", "France"])
+            .unwrap();
+        worksheet.write_column(0, 2, [30, 20, 15]).unwrap();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values("Sheet1!$C$1:$C$3");
+        worksheet.insert_chart(4, 0, &chart).unwrap();
+
+        let mut chart = Chart::new(ChartType::Treemap);
+        chart
+            .add_series()
+            .set_categories("Sheet1!$A$1:$B$3")
+            .set_values("Sheet1!$C$1:$C$3");
+        worksheet.insert_chart(4, 8, &chart).unwrap();
+
+        // Chartex charts aren't supported in chartsheets.
+        let mut chartsheet = Worksheet::new_chartsheet();
+        let result = chartsheet.insert_chart(0, 0, &chart);
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut read_file = |name: &str| {
+            let mut xml = String::new();
+            zip.by_name(name).unwrap().read_to_string(&mut xml).unwrap();
+            xml
+        };
+
+        let xml = read_file("[Content_Types].xml");
+        assert!(xml.contains(r#"<Override PartName="/xl/charts/chart1.xml" ContentType="application/vnd.openxmlformats-officedocument.drawingml.chart+xml"/>"#));
+        assert!(xml.contains(r#"<Override PartName="/xl/charts/chartEx1.xml" ContentType="application/vnd.ms-office.chartex+xml"/>"#));
+
+        let xml = read_file("xl/drawings/_rels/drawing1.xml.rels");
+        assert!(xml.contains(r#"<Relationship Id="rId1" Type="http://schemas.openxmlformats.org/officeDocument/2006/relationships/chart" Target="../charts/chart1.xml"/>"#));
+        assert!(xml.contains(r#"<Relationship Id="rId2" Type="http://schemas.microsoft.com/office/2014/relationships/chartEx" Target="../charts/chartEx1.xml"/>"#));
+
+        let xml = read_file("xl/drawings/drawing1.xml");
+        assert!(xml.contains(r#"<mc:Choice xmlns:cx1="http://schemas.microsoft.com/office/drawing/2015/9/8/chartex" Requires="cx1">"#));
+        assert!(xml.contains(r#"<cx:chart xmlns:cx="http://schemas.microsoft.com/office/drawing/2014/chartex" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships" r:id="rId2"/>"#));
+
+        let xml = read_file("xl/charts/chartEx1.xml");
+        assert!(xml.contains(r#"<cx:lvl ptCount="3"><cx:pt idx="0">China</cx:pt><cx:pt idx="1">India</cx:pt><cx:pt idx="2">France</cx:pt></cx:lvl><cx:lvl ptCount="3"><cx:pt idx="0">Asia</cx:pt><cx:pt idx="2">Europe</cx:pt></cx:lvl>"#));
+
+        let xml = read_file("xl/charts/chart1.xml");
+        assert!(xml.contains("<c:barChart>"));
+    }
+
+    #[test]
+    fn chart_table_ranges() {
+        use crate::{Chart, ChartType, Table, TableColumn};
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart
+            .add_series()
+            .set_categories_from_table("sales", "MONTH")
+            .set_values_from_table("Sales", "Revenue");
+        chart
+            .add_series()
+            .set_name("Other")
+            .set_values_from_table("Table2", "Column2");
+        worksheet.insert_chart(0, 0, &chart).unwrap();
+
+        let worksheet = workbook.add_worksheet().set_name("Data").unwrap();
+
+        worksheet.write_column(1, 0, ["Jan", "Feb", "Mar"]).unwrap();
+        worksheet.write_column(1, 1, [10, 20, 30]).unwrap();
+
+        let columns = vec![
+            TableColumn::new().set_header("Month"),
+            TableColumn::new().set_header("Revenue"),
+        ];
+        let table = Table::new().set_name("Sales").set_columns(&columns);
+        worksheet.add_table(0, 0, 3, 1, &table).unwrap();
+
+        let table = Table::new().set_header_row(false).set_total_row(true);
+        worksheet.add_table(0, 4, 4, 5, &table).unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut xml = String::new();
+        zip.by_name("xl/charts/chart1.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains("<c:tx><c:strRef><c:f>Data!$B$1</c:f>"));
+        assert!(xml.contains("<c:cat><c:strRef><c:f>Data!$A$2:$A$4</c:f>"));
+        assert!(xml.contains("<c:val><c:numRef><c:f>Data!$B$2:$B$4</c:f>"));
+        assert!(xml.contains("<c:tx><c:v>Other</c:v></c:tx>"));
+        assert!(xml.contains("<c:val><c:numRef><c:f>Data!$F$1:$F$4</c:f>"));
+
+        // Check for an unknown table column.
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        let mut chart = Chart::new(ChartType::Column);
+        chart.add_series().set_values_from_table("Sales", "Profit");
+        worksheet.insert_chart(0, 0, &chart).unwrap();
+
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ChartError(_))));
+    }
+
+    #[test]
+    fn chart_data_label_value_range() {
+        use crate::{Chart, ChartDataLabel, ChartType};
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        worksheet.write_column(0, 0, [10, 40, 50]).unwrap();
+        worksheet.write_column(0, 1, ["Jan", "Feb", "Mar"]).unwrap();
+
+        let mut chart = Chart::new(ChartType::Line);
+        chart
+            .add_series()
+            .set_values("Sheet1!$A$1:$A$3")
+            .set_smooth(true)
+            .set_data_label(ChartDataLabel::new().set_value_range("Sheet1!$B$1:$B$3"));
+        worksheet.insert_chart(4, 0, &chart).unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut xml = String::new();
+        zip.by_name("xl/charts/chart1.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains(r#"<c:dLbls><c:extLst><c:ext uri="{CE6537A1-D6FC-4f65-9D91-7224C49458BB}" xmlns:c15="http://schemas.microsoft.com/office/drawing/2012/chart"><c15:showDataLabelsRange val="1"/></c:ext></c:extLst></c:dLbls>"#));
+        assert!(xml.contains(r#"<c:smooth val="1"/><c:extLst><c:ext uri="{02D57815-91ED-43cb-92C2-25804820EDAC}" xmlns:c15="http://schemas.microsoft.com/office/drawing/2012/chart"><c15:datalabelsRange><c15:f>Sheet1!$B$1:$B$3</c15:f><c15:dlblRangeCache><c:ptCount val="3"/><c:pt idx="0"><c:v>Jan</c:v></c:pt><c:pt idx="1"><c:v>Feb</c:v></c:pt><c:pt idx="2"><c:v>Mar</c:v></c:pt></c15:dlblRangeCache></c15:datalabelsRange></c:ext></c:extLst></c:ser>"#));
+    }
+
+    #[test]
+    fn strict_ooxml_user_data() {
+        use std::io::{Cursor, Read};
+
+        let namespace = "http://schemas.openxmlformats.org/spreadsheetml/2006/main";
+
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, namespace).unwrap();
+
+        workbook.set_strict_ooxml(true);
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut xml = String::new();
+        zip.by_name("xl/sharedStrings.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains(r#"xmlns="http://purl.oclc.org/ooxml/spreadsheetml/main""#));
+        assert!(xml.contains(&format!("<t>{namespace}</t>")));
+    }
+
+    #[test]
+    fn hyperlink_format() {
+        use crate::{Format, HyperlinkStyle};
+        use std::io::{Cursor, Read};
+
+        let mut workbook = Workbook::new();
+        workbook.set_hyperlink_format(&Format::new().set_bold());
+
+        let worksheet = workbook.add_worksheet();
+
+        // The implicit url format is replaced but an equal user format isn't.
+        worksheet
+            .write_url(0, 0, "https://www.rust-lang.org")
+            .unwrap();
+        worksheet
+            .write_string_with_format(1, 0, "Text", &Format::new().set_hyperlink())
+            .unwrap();
+
+        // An inherited url format falls back to the row format.
+        worksheet
+            .set_row_format(2, &Format::new().set_italic())
+            .unwrap();
+        worksheet.set_hyperlink_style(HyperlinkStyle::Inherit);
+        worksheet.write_url(2, 0, "https://crates.io").unwrap();
+
+        let buffer = workbook.save_to_buffer().unwrap();
+        let mut zip = zip::ZipArchive::new(Cursor::new(buffer)).unwrap();
+
+        let mut xml = String::new();
+        zip.by_name("xl/worksheets/sheet1.xml")
+            .unwrap()
+            .read_to_string(&mut xml)
+            .unwrap();
+
+        assert!(xml.contains(r#"<c r="A1" s="2" t="s">"#));
+        assert!(xml.contains(r#"<c r="A2" s="1" t="s">"#));
+        assert!(xml.contains(r#"<row r="3" spans="1:1" s="3" customFormat="1">"#));
+        assert!(xml.contains(r#"<c r="A3" s="3" t="s">"#));
+    }
+
+    #[test]
+    fn content_type_errors() {
+        let mut workbook = Workbook::new();
+
+        let result = workbook.add_content_type_override("customXml/item1.xml", "application/xml");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_content_type_default(".foo", "application/foo");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = workbook.add_package_relationship("", "foo.xml");
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    fn internal_link_targets() {
+        let mut workbook = Workbook::new();
+        let worksheet = workbook.add_worksheet();
+
+        worksheet.write_url_internal(0, 0, "Sheet2!A1", "").unwrap();
+        worksheet
+            .write_url_internal(1, 0, "'data sheet'!A1:B5", "Data")
+            .unwrap();
+        worksheet
+            .write_url_internal(2, 0, "sales", "Sales")
+            .unwrap();
+        worksheet
+            .write_url_internal(5, 0, "Sheet2!A:C", "")
+            .unwrap();
+        worksheet
+            .write_url_internal(6, 0, "Sheet2!$1:$3", "")
+            .unwrap();
+        worksheet
+            .write_url_internal(7, 0, "Sheet2!local", "")
+            .unwrap();
+        worksheet.write_url_internal(8, 0, "Here", "").unwrap();
+        worksheet
+            .write_url_internal(9, 0, "Sheet2!Sales2024", "")
+            .unwrap();
+
+        for target in [
+            "",
+            "Sheet2!",
+            "Sheet2!A0",
+            "Sheet2!XFE1",
+            "Sheet2!A1:B",
+            "Sheet2!A:3",
+            "Sheet2!A:XFE",
+            "Sheet2!0:1",
+            "Sheet2!1:1048577",
+        ] {
+            let result = worksheet.write_url_internal(3, 0, target, "");
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "target: {target}"
+            );
+        }
+
+        workbook.add_worksheet();
+        workbook.add_worksheet().set_name("Data Sheet").unwrap();
+        workbook.define_name("Sales", "=Sheet2!$A$1").unwrap();
+        workbook
+            .define_name("Sheet2!Local", "=Sheet2!$A$2")
+            .unwrap();
+        workbook.define_name("Sheet1!Here", "=Sheet1!$A$2").unwrap();
+        workbook
+            .define_name("Sheet2!Sales2024", "=Sheet2!$A$3")
+            .unwrap();
+
+        assert!(workbook.save_to_buffer().is_ok());
+
+        // Links to a missing worksheet are errors.
+        for target in ["Sheet4!A1", "'Sales Data'!A1", "Sheet4!Local"] {
+            let worksheet = workbook.worksheet_from_index(0).unwrap();
+            worksheet.write_url_internal(4, 0, target, "").unwrap();
+
+            let result = workbook.save_to_buffer();
+            assert!(
+                matches!(result, Err(XlsxError::UnknownWorksheetNameOrIndex(_))),
+                "target: {target}"
+            );
+        }
+
+        // Links to a missing name, or to a name in the scope of another
+        // worksheet, are errors.
+        for target in ["Local", "Unknown", "Sheet2!Unknown", "Sheet2!Here"] {
+            let worksheet = workbook.worksheet_from_index(0).unwrap();
+            worksheet.write_url_internal(4, 0, target, "").unwrap();
+
+            let result = workbook.save_to_buffer();
+            assert!(
+                matches!(result, Err(XlsxError::ParameterError(_))),
+                "target: {target}"
+            );
+        }
+
+        // Links written with the typed Url constructors are checked in the
+        // same way.
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        let result = worksheet.write_url(4, 0, Url::internal("Sheet2!A0"));
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet
+            .write_url(4, 0, Url::internal("Sheet2!B1"))
+            .unwrap();
+        assert!(workbook.save_to_buffer().is_ok());
+
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.write(4, 0, Url::internal("Sheet4!A1")).unwrap();
+        let result = workbook.save_to_buffer();
+        assert!(matches!(
+            result,
+            Err(XlsxError::UnknownWorksheetNameOrIndex(_))
+        ));
+
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet.write(4, 0, Url::defined_name("Unknown")).unwrap();
+        let result = workbook.save_to_buffer();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        // Overwriting the link with a standard url removes the check.
+        let worksheet = workbook.worksheet_from_index(0).unwrap();
+        worksheet
+            .write_url(4, 0, "https://www.rust-lang.org")
+            .unwrap();
+        assert!(workbook.save_to_buffer().is_ok());
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_partitioned() {
+        use serde::Serialize;
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            region: &'static str,
+            volume: u32,
+        }
+
+        let data = [
+            MyStruct {
+                region: "East",
+                volume: 1,
+            },
+            MyStruct {
+                region: "West",
+                volume: 2,
+            },
+            MyStruct {
+                region: "East",
+                volume: 3,
+            },
+        ];
+
+        let mut workbook = Workbook::new();
+        workbook.add_worksheet().set_name("West").unwrap();
+
+        workbook
+            .serialize_partitioned(&data, |item| item.region)
+            .unwrap();
+
+        // A second call adds the data below the existing data.
+        workbook
+            .serialize_partitioned(&data[..1], |item| item.region)
+            .unwrap();
+
+        let names: Vec<String> = workbook.worksheets().iter().map(|ws| ws.name()).collect();
+        assert_eq!(names, vec!["West", "East"]);
+
+        let worksheet = workbook.worksheet_from_name("East").unwrap();
+        let dimensions = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!(dimensions, (0, 0, 3, 1));
+
+        let worksheet = workbook.worksheet_from_name("West").unwrap();
+        let dimensions = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!(dimensions, (0, 0, 1, 1));
+
+        // Worksheet names are matched case insensitively.
+        workbook
+            .serialize_partitioned(&data[..1], |_| "EAST")
+            .unwrap();
+
+        let worksheet = workbook.worksheet_from_name("East").unwrap();
+        let dimensions = worksheet.get_serialize_dimensions("MyStruct").unwrap();
+        assert_eq!(dimensions, (0, 0, 4, 1));
+
+        // Worksheet names are validated without adding a worksheet.
+        let result = workbook.serialize_partitioned(&data, |_| "Sheet[1]");
+        assert!(matches!(
+            result,
+            Err(XlsxError::SheetnameContainsInvalidCharacter(_))
+        ));
+        assert_eq!(workbook.worksheets().len(), 2);
+    }
 }
@@ -1245,22 +1245,37 @@ use std::borrow::Cow;
 use std::cmp;
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, HashMap, HashSet};
+use std::fmt::Display;
 use std::io::Write;
 use std::mem;
+use std::num::{
+    NonZeroI16, NonZeroI32, NonZeroI64, NonZeroI8, NonZeroU16, NonZeroU32, NonZeroU64, NonZeroU8,
+};
 use std::sync::Arc;
 
 #[cfg(feature = "chrono")]
 use chrono::{NaiveDate, NaiveDateTime, NaiveTime};
 
+#[cfg(feature = "time")]
+use time::{Date, PrimitiveDateTime, Time};
+
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigInt;
+
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
 #[cfg(feature = "serde")]
 use crate::{
-    deserialize_headers, serializer::SerializerState, CustomSerializeField,
-    SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader, TableData, XlsxSerialize,
+    deserialize_headers, serializer::ConditionalFormatData, serializer::SerializerState, ChartType,
+    CustomSerializeField, SerializationHeaderConfig, SerializeFieldOptions, SerializerHeader,
+    TableData, XlsxSerialize,
 };
 
+use crate::accessibility::{format_contrast_ratio, MIN_CONTRAST_RATIO};
 use crate::drawing::{Drawing, DrawingCoordinates, DrawingInfo, DrawingObject, DrawingType};
 use crate::error::XlsxError;
 use crate::format::Format;
@@ -1270,11 +1285,14 @@ use crate::styles::Styles;
 use crate::vml::VmlInfo;
 use crate::xmlwriter::{XMLWriter, XML_WRITE_ERROR};
 use crate::{
-    utility, Button, Chart, ChartEmptyCells, ChartRangeCacheData, ChartRangeCacheDataType, Color,
-    ConditionalFormat, DataValidation, DataValidationErrorStyle, DataValidationRuleInternal,
-    DataValidationType, ExcelDateTime, FilterCondition, FilterCriteria, FilterData, FilterDataType,
-    HeaderImagePosition, HyperlinkType, Image, IntoExcelDateTime, Note, ObjectMovement,
-    ProtectionOptions, Shape, Sparkline, SparklineType, Table, TableFunction, Url,
+    utility, AccessibilityIssue, AccessibilityIssueType, Button, Chart, ChartEmptyCells,
+    ChartRangeCacheData, ChartRangeCacheDataType, Color, ConditionalFormat, CsvOptions, CsvValue,
+    DataValidation, DataValidationErrorStyle, DataValidationRuleInternal, DataValidationType,
+    ExcelDateTime, ExcelRowSource, FilterCondition, FilterCriteria, FilterData, FilterDataType,
+    FilterDynamicType, FilterTop10, FontMetrics, HeaderImageOptions, HeaderImagePosition,
+    HyperlinkStyle, HyperlinkType, Image, IntoCellRef, IntoExcelDateTime, IntoExcelDateTimeTz,
+    IntoRangeRef, Note, ObjectMovement, ProtectionOptions, RangeRef, Shape, Sparkline,
+    SparklineType, Table, TableColumn, TableFunction, TimezonePolicy, Url,
 };
 
 /// Integer type to represent a zero indexed row number. Excel's limit for rows
@@ -1294,6 +1312,7 @@ pub(crate) const DEFAULT_ROW_HEIGHT_PIXELS: u32 = 20;
 const DEFAULT_COL_WIDTH: f64 = 8.43;
 const DEFAULT_ROW_HEIGHT: f64 = 15.0;
 const MAX_STRING_LEN: usize = 32_767;
+const MAX_OUTLINE_LEVEL: u8 = 7;
 const COLUMN_LETTERS: &str = "ABCDEFGHIJKLMNOPQRSTUVWXYZ";
 
 /// The `Worksheet` struct represents an Excel worksheet. It handles operations
@@ -1375,6 +1394,7 @@ pub struct Worksheet {
     pub(crate) first_sheet: bool,
     pub(crate) uses_string_table: bool,
     pub(crate) has_dynamic_arrays: bool,
+    pub(crate) has_checkboxes: bool,
     pub(crate) print_area_defined_name: DefinedName,
     pub(crate) repeat_row_cols_defined_name: DefinedName,
     pub(crate) autofilter_defined_name: DefinedName,
@@ -1382,7 +1402,6 @@ pub struct Worksheet {
     pub(crate) xf_formats: Vec<Format>,
     pub(crate) dxf_formats: Vec<Format>,
     pub(crate) has_vml: bool,
-    pub(crate) has_hyperlink_style: bool,
     pub(crate) images: BTreeMap<(RowNum, ColNum), Image>,
     pub(crate) buttons_vml_info: Vec<VmlInfo>,
     pub(crate) comments_vml_info: Vec<VmlInfo>,
@@ -1396,6 +1415,7 @@ pub struct Worksheet {
     pub(crate) tables: Vec<Table>,
     pub(crate) has_embedded_image_descriptions: bool,
     pub(crate) embedded_images: Vec<Image>,
+    pub(crate) font_metrics: HashMap<String, FontMetrics>,
     pub(crate) global_embedded_image_indices: Vec<u32>,
     pub(crate) vba_codename: Option<String>,
     pub(crate) note_authors: BTreeMap<String, usize>,
@@ -1416,6 +1436,7 @@ pub struct Worksheet {
     data_table: BTreeMap<RowNum, BTreeMap<ColNum, CellType>>,
     merged_ranges: Vec<CellRange>,
     merged_cells: HashMap<(RowNum, ColNum), usize>,
+    ignored_errors: BTreeMap<IgnoreError, Vec<String>>,
     table_ranges: Vec<CellRange>,
     table_cells: HashMap<(RowNum, ColNum), usize>,
     col_names: HashMap<ColNum, String>,
@@ -1429,9 +1450,14 @@ pub struct Worksheet {
     page_setup_changed: bool,
     tab_color: Color,
     fit_to_page: bool,
+    outline_symbols_below: bool,
+    outline_symbols_right: bool,
+    outline_auto_style: bool,
+    outline_visible: bool,
     fit_width: u16,
     fit_height: u16,
     paper_size: u8,
+    paper_dimensions: (f64, f64),
     default_page_order: bool,
     right_to_left: bool,
     is_portrait: bool,
@@ -1442,6 +1468,7 @@ pub struct Worksheet {
     center_horizontally: bool,
     center_vertically: bool,
     screen_gridlines: bool,
+    screen_headings: bool,
     print_gridlines: bool,
     print_black_and_white: bool,
     print_draft: bool,
@@ -1461,6 +1488,8 @@ pub struct Worksheet {
     default_result: Box<str>,
     panes: Panes,
     hyperlinks: BTreeMap<(RowNum, ColNum), Url>,
+    pub(crate) internal_link_targets: BTreeMap<(RowNum, ColNum), String>,
+    hyperlink_style: HyperlinkStyle,
     rel_count: u32,
     protection_on: bool,
     protection_hash: u16,
@@ -1484,7 +1513,16 @@ pub struct Worksheet {
     embedded_image_ids: HashMap<String, u32>,
     show_all_notes: bool,
     user_default_row_height: f64,
+    default_row_height: f64,
+    default_col_width: f64,
+    default_col_width_pixels: u32,
+    default_max_digit_width: Option<f64>,
+    cell_padding: f64,
     hide_unused_rows: bool,
+    first_unused_hidden_row: Option<RowNum>,
+
+    #[cfg(feature = "num-bigint")]
+    bigint_precision: BigIntPrecision,
 
     #[cfg(feature = "serde")]
     pub(crate) serializer_state: SerializerState,
@@ -1575,7 +1613,10 @@ impl Worksheet {
 
         let panes = Panes {
             freeze_cell: (0, 0),
+            split_pixels: (0, 0),
             top_cell: (0, 0),
+            active_pane: None,
+            selections: HashMap::new(),
         };
 
         Worksheet {
@@ -1589,6 +1630,7 @@ impl Worksheet {
             uses_string_table: false,
             has_vml: false,
             has_dynamic_arrays: false,
+            has_checkboxes: false,
             print_area_defined_name: DefinedName::new(),
             repeat_row_cols_defined_name: DefinedName::new(),
             autofilter_defined_name: DefinedName::new(),
@@ -1598,6 +1640,7 @@ impl Worksheet {
             dimensions,
             merged_ranges: vec![],
             merged_cells: HashMap::new(),
+            ignored_errors: BTreeMap::new(),
             tables: vec![],
             table_ranges: vec![],
             table_cells: HashMap::new(),
@@ -1612,9 +1655,14 @@ impl Worksheet {
             page_setup_changed: false,
             fit_to_page: false,
             tab_color: Color::Default,
+            outline_symbols_below: true,
+            outline_symbols_right: true,
+            outline_auto_style: false,
+            outline_visible: true,
             fit_width: 1,
             fit_height: 1,
             paper_size: 0,
+            paper_dimensions: (0.0, 0.0),
             default_page_order: true,
             right_to_left: false,
             is_portrait: true,
@@ -1625,6 +1673,7 @@ impl Worksheet {
             center_horizontally: false,
             center_vertically: false,
             screen_gridlines: true,
+            screen_headings: true,
             print_gridlines: false,
             print_black_and_white: false,
             print_draft: false,
@@ -1643,8 +1692,9 @@ impl Worksheet {
             first_page_number: 0,
             default_result: Box::from("0"),
             panes,
-            has_hyperlink_style: false,
             hyperlinks: BTreeMap::new(),
+            internal_link_targets: BTreeMap::new(),
+            hyperlink_style: HyperlinkStyle::Default,
             images: BTreeMap::new(),
             shapes: BTreeMap::new(),
             drawing: Drawing::new(),
@@ -1674,6 +1724,7 @@ impl Worksheet {
             use_x14_extensions: false,
             has_x14_conditional_formats: false,
             embedded_images: vec![],
+            font_metrics: HashMap::new(),
             embedded_image_ids: HashMap::new(),
             global_embedded_image_indices: vec![],
             has_embedded_image_descriptions: false,
@@ -1685,7 +1736,13 @@ impl Worksheet {
             vml_data_id: String::new(),
             vml_shape_id: 0,
             user_default_row_height: DEFAULT_ROW_HEIGHT,
+            default_row_height: DEFAULT_ROW_HEIGHT,
+            default_col_width: DEFAULT_COL_WIDTH,
+            default_col_width_pixels: DEFAULT_COL_WIDTH_PIXELS,
+            default_max_digit_width: None,
+            cell_padding: 5.0,
             hide_unused_rows: false,
+            first_unused_hidden_row: None,
 
             // These collections need to be reset on resave.
             comment_relationships: vec![],
@@ -1698,6 +1755,9 @@ impl Worksheet {
             vml_drawing_relationships: vec![],
             is_chartsheet: false,
 
+            #[cfg(feature = "num-bigint")]
+            bigint_precision: BigIntPrecision::default(),
+
             #[cfg(feature = "serde")]
             serializer_state: SerializerState::new(),
         }
@@ -1901,10 +1961,13 @@ impl Worksheet {
     /// - Numbers that convert [`Into`] [`f64`]. Also, u64 and i64 are supported
     ///   with loss of precision outside Excel's integer range of +/-
     ///   999,999,999,999,999 (15 digits).
+    /// - The [`NonZero`](std::num) integer types such as [`NonZeroU32`].
     /// - [`bool`]
     /// - [`ExcelDateTime`].
     /// - [`Formula`].
     /// - [`Url`].
+    /// - [`AsString<T>`]: Any type that implements [`Display`]
+    ///   wrapped in [`AsString`] is written as a string.
     /// - [`Option<T>`]: If `T` is a supported type then the [`Some<T>`] value
     ///   is written. The [`None`] value is ignored.
     /// - [`Result<T, E>`]: If `T` and `E` are supported types then the `T` or
@@ -1916,6 +1979,28 @@ impl Worksheet {
     /// - [`chrono::NaiveDate`].
     /// - [`chrono::NaiveTime`].
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `rust_decimal` feature is enabled you can write
+    /// `rust_decimal::Decimal` values. These are stored as f64 values by Excel
+    /// so there is a loss of precision for values with more than 15
+    /// significant digits.
+    ///
+    /// If the `num-bigint` feature is enabled you can write
+    /// `num_bigint::BigInt` values. Values within Excel's integer range of +/-
+    /// 999,999,999,999,999 (15 digits) are written as numbers. Values outside
+    /// this range are written as strings by default to avoid a silent loss of
+    /// precision, see `Worksheet::set_bigint_precision()`.
+    ///
     /// [`Chrono`]: https://docs.rs/chrono/latest/chrono/index.html
     /// [`chrono::NaiveDate`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDate.html
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
@@ -1947,6 +2032,68 @@ impl Worksheet {
         data.write(self, row, col)
     }
 
+    /// Write generic data to a cell using an `A1` style cell reference.
+    ///
+    /// This method is the same as [`Worksheet::write()`] except that the cell
+    /// is specified as an `A1` or `$A$1` style string. This is useful when
+    /// the cell location comes from user configuration or other text input.
+    /// See [`cell_to_row_col()`](crate::utility::cell_to_row_col) for details
+    /// of the supported syntax.
+    ///
+    /// # Parameters
+    ///
+    /// - `cell`: An `A1` style cell reference string, or any other type that
+    ///   implements [`IntoCellRef`] such as a
+    ///   [`CellRef`](crate::CellRef).
+    /// - `data`: An type that implements the  [`IntoExcelData`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Invalid cell reference.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing data to a worksheet using
+    /// `A1` style cell references.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_a1.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     // Write data using A1 style cell references.
+    ///     worksheet.write_with_format_a1("A1", "Total", &bold)?;
+    ///     worksheet.write_a1("B1", 1234)?;
+    ///
+    ///     // Set the print area using an A1 style range.
+    ///     worksheet.set_print_area_a1("A1:B1")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_a1(
+        &mut self,
+        cell: impl IntoCellRef,
+        data: impl IntoExcelData,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let cell = cell.to_cell_ref()?;
+
+        self.write(cell.row(), cell.col(), data)
+    }
+
     /// Write formatted generic data to a cell.
     ///
     /// The `write_with_format()` method writes formatted data that implements
@@ -1957,10 +2104,13 @@ impl Worksheet {
     /// - Numbers that convert [`Into`] [`f64`]. Also, u64 and i64 are supported
     ///   with loss of precision outside Excel's integer range of +/-
     ///   999,999,999,999,999 (15 digits).
+    /// - The [`NonZero`](std::num) integer types such as [`NonZeroU32`].
     /// - [`bool`]
     /// - [`ExcelDateTime`].
     /// - [`Formula`].
     /// - [`Url`].
+    /// - [`AsString<T>`]: Any type that implements [`Display`]
+    ///   wrapped in [`AsString`] is written as a string.
     /// - [`Option<T>`]: If `T` is a supported type then [`Some<T>`] is written
     ///   as a formatted value or [`None`] is written as a formatted blank cell.
     /// - [`Result<T, E>`]: If `T` and `E` are supported types then the `T` or
@@ -1972,6 +2122,28 @@ impl Worksheet {
     /// - [`chrono::NaiveDate`].
     /// - [`chrono::NaiveTime`].
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
+    /// If the `rust_decimal` feature is enabled you can write
+    /// `rust_decimal::Decimal` values. These are stored as f64 values by Excel
+    /// so there is a loss of precision for values with more than 15
+    /// significant digits.
+    ///
+    /// If the `num-bigint` feature is enabled you can write
+    /// `num_bigint::BigInt` values. Values within Excel's integer range of +/-
+    /// 999,999,999,999,999 (15 digits) are written as numbers. Values outside
+    /// this range are written as strings by default to avoid a silent loss of
+    /// precision, see `Worksheet::set_bigint_precision()`.
+    ///
     /// [`Chrono`]: https://docs.rs/chrono/latest/chrono/index.html
     /// [`chrono::NaiveDate`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDate.html
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
@@ -2007,6 +2179,40 @@ impl Worksheet {
         data.write_with_format(self, row, col, format)
     }
 
+    /// Write formatted generic data to a cell using an `A1` style cell
+    /// reference.
+    ///
+    /// This method is the same as [`Worksheet::write_with_format()`] except
+    /// that the cell is specified as an `A1` or `$A$1` style string. See
+    /// [`Worksheet::write_a1()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `cell`: An `A1` style cell reference string, or any other type that
+    ///   implements [`IntoCellRef`] such as a
+    ///   [`CellRef`](crate::CellRef).
+    /// - `data`: An type that implements the  [`IntoExcelData`] trait.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Invalid cell reference.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    pub fn write_with_format_a1(
+        &mut self,
+        cell: impl IntoCellRef,
+        data: impl IntoExcelData,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let cell = cell.to_cell_ref()?;
+
+        self.write_with_format(cell.row(), cell.col(), data, format)
+    }
+
     /// Write an array like data structure as a row of data to a worksheet.
     ///
     /// Write an array of data horizontally rightwards starting from the initial
@@ -2135,10 +2341,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write(row, col, item)?;
-            col += 1;
         }
 
         Ok(self)
@@ -2179,10 +2383,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write_with_format(row, col, item, format)?;
-            col += 1;
         }
 
         Ok(self)
@@ -2259,10 +2461,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
+        for (row, item) in (row..).zip(data) {
             self.write(row, col, item)?;
-            row += 1;
         }
 
         Ok(self)
@@ -2303,10 +2503,8 @@ impl Worksheet {
         I: IntoIterator,
         I::Item: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
+        for (row, item) in (row..).zip(data) {
             self.write_with_format(row, col, item, format)?;
-            row += 1;
         }
 
         Ok(self)
@@ -2388,10 +2586,8 @@ impl Worksheet {
         I::Item: IntoIterator<Item = II>,
         II: IntoExcelData,
     {
-        let mut row = row;
-        for item in data {
+        for (row, item) in (row..).zip(data) {
             self.write_row(row, col, item)?;
-            row += 1;
         }
 
         Ok(self)
@@ -2473,203 +2669,205 @@ impl Worksheet {
         I::Item: IntoIterator<Item = II>,
         II: IntoExcelData,
     {
-        let mut col = col;
-        for item in data {
+        for (col, item) in (col..).zip(data) {
             self.write_column(row, col, item)?;
-            col += 1;
         }
 
         Ok(self)
     }
 
-    /// Write an unformatted number to a cell.
-    ///
-    /// Write an unformatted number to a worksheet cell. To write a formatted
-    /// number see the
-    /// [`Worksheet::write_number_with_format()`]
-    /// method below.
-    ///
-    /// All numerical values in Excel are stored as [IEEE 754] Doubles which are
-    /// the equivalent of rust's [`f64`] type. This method will accept any rust
-    /// type that will convert [`Into`] a f64. These include i8, u8, i16, u16,
-    /// i32, u32 and f32 but not i64 or u64, see below.
-    ///
-    /// IEEE 754 Doubles and f64 have around 15 digits of precision. Anything
-    /// beyond that cannot be stored as a number by Excel without a loss of
-    /// precision and may need to be stored as a string instead.
-    ///
-    /// [IEEE 754]: https://en.wikipedia.org/wiki/IEEE_754
+    /// Set how `BigInt` values outside Excel's integer range are written.
     ///
-    /// For i64/u64 you can cast the numbers `as f64` which will allow you to
-    /// store the number with a loss of precision outside Excel's integer range
-    /// of +/- 999,999,999,999,999 (15 digits).
+    /// Excel stores numbers as f64 values so integers outside the range +/-
+    /// 999,999,999,999,999 (15 digits) can't be stored without a loss of
+    /// precision. By default `num_bigint::BigInt` values outside this range
+    /// are written as strings. This method can be used to write them as
+    /// numbers, with a loss of precision, or to return an error instead. See
+    /// [`BigIntPrecision`] for the options.
     ///
-    /// Excel doesn't have handling for NaN or INF floating point numbers.
-    /// These will be stored as the strings "Nan", "INF", and "-INF" strings
-    /// instead.
+    /// This method requires the `num-bigint` feature to be enabled.
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
-    /// - `number`: The number to write to the cell.
-    ///
-    /// # Errors
-    ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - `precision`: A [`BigIntPrecision`] enum value.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates writing unformatted numbers to an
-    /// Excel worksheet. Any numeric type that will convert [`Into`] f64 can be
-    /// transferred to Excel.
+    /// The following example demonstrates writing large `BigInt` values to a
+    /// worksheet as numbers, with a loss of precision.
     ///
     /// ```
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # // This code is available in examples/doc_worksheet_set_bigint_precision.rs
+    /// #
+    /// # use num_bigint::BigInt;
+    /// # use rust_xlsxwriter::{BigIntPrecision, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
-    ///
-    ///     // Add a worksheet to the workbook.
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
     ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     // Write some different Rust number types to a worksheet.
-    ///     // Note, u64 isn't supported by Excel.
-    ///     worksheet.write_number(0, 0, 1_u8)?;
-    ///     worksheet.write_number(1, 0, 2_i16)?;
-    ///     worksheet.write_number(2, 0, 3_u32)?;
-    ///     worksheet.write_number(3, 0, 4_f32)?;
-    ///     worksheet.write_number(4, 0, 5_f64)?;
+    ///     // A value with more than 15 digits.
+    ///     let number = BigInt::from(1_234_567_890_123_456_789_i64);
     ///
-    ///     // Write some numbers with implicit types.
-    ///     worksheet.write_number(5, 0, 1234)?;
-    ///     worksheet.write_number(6, 0, 1234.5)?;
+    ///     // The default is to write the value as a string.
+    ///     worksheet.write(0, 0, &number)?;
     ///
-    ///     // Note Excel normally ignores trailing decimal zeros
-    ///     // when the number is unformatted.
-    ///     worksheet.write_number(7, 0, 1234.50000)?;
+    ///     // Write the value as a number, with a loss of precision.
+    ///     worksheet.set_bigint_precision(BigIntPrecision::Number);
+    ///     worksheet.write(1, 0, &number)?;
     /// #
-    /// #     workbook.save("numbers.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_number.png">
-    ///
-    pub fn write_number(
-        &mut self,
-        row: RowNum,
-        col: ColNum,
-        number: impl Into<f64>,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Store the cell data.
-        self.store_number(row, col, number, None)
+    #[cfg(feature = "num-bigint")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+    pub fn set_bigint_precision(&mut self, precision: BigIntPrecision) -> &mut Worksheet {
+        self.bigint_precision = precision;
+        self
     }
 
-    /// Write a formatted number to a worksheet cell.
-    ///
-    /// Write a number with formatting to a worksheet cell. The format is set
-    /// via a [`Format`] struct which can control the numerical formatting of
-    /// the number, for example as a currency or a percentage value, or the
-    /// visual format, such as bold and italic text.
-    ///
-    /// All numerical values in Excel are stored as [IEEE 754] Doubles which are
-    /// the equivalent of rust's [`f64`] type. This method will accept any rust
-    /// type that will convert [`Into`] a f64. These include i8, u8, i16, u16,
-    /// i32, u32 and f32 but not i64 or u64, see below.
+    /// Write an `ndarray` 2D array to a worksheet.
     ///
-    /// IEEE 754 Doubles and f64 have around 15 digits of precision. Anything
-    /// beyond that cannot be stored as a number by Excel without a loss of
-    /// precision and may need to be stored as a string instead.
+    /// Write the elements of an [`ndarray`] 2D array, such as an
+    /// [`ndarray::Array2`] or an [`ndarray::ArrayView2`], to a worksheet
+    /// starting from the initial `row, col` cell. The rows and columns of the
+    /// array map to the rows and columns of the worksheet.
     ///
-    /// [IEEE 754]: https://en.wikipedia.org/wiki/IEEE_754
+    /// The element type of the array can be any type that implements
+    /// [`IntoExcelData`], such as `f64`, `i32`, `bool` or `String`.
     ///
-    /// For i64/u64 you can cast the numbers `as f64` which will allow you to
-    /// store the number with a loss of precision outside Excel's integer range
-    /// of +/- 999,999,999,999,999 (15 digits).
+    /// This method requires the `ndarray` feature to be enabled.
     ///
-    /// Excel doesn't have handling for NaN or INF floating point numbers. These
-    /// will be stored as the strings "Nan", "INF", and "-INF" strings instead.
+    /// [`ndarray`]: https://docs.rs/ndarray/latest/ndarray
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `number`: The number to write to the cell.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `data`: A reference to an `ndarray` 2D array.
     ///
     /// # Errors
     ///
     /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
     ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting different formatting for
-    /// numbers in an Excel worksheet.
+    /// The following example demonstrates writing an `ndarray` 2D array to a
+    /// worksheet.
     ///
     /// ```
-    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # // This code is available in examples/doc_worksheet_write_array2.rs
+    /// #
+    /// # use ndarray::array;
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
-    ///     let mut workbook = Workbook::new();
-    ///
-    ///     // Create some formats to use with the numbers below.
-    ///     let number_format = Format::new().set_num_format("#,##0.00");
-    ///     let currency_format = Format::new().set_num_format("€#,##0.00");
-    ///     let percentage_format = Format::new().set_num_format("0.0%");
-    ///     let bold_italic_format = Format::new().set_bold().set_italic();
-    ///
-    ///     // Add a worksheet to the workbook.
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
     ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     worksheet.write_number_with_format(0, 0, 1234.5, &number_format)?;
-    ///     worksheet.write_number_with_format(1, 0, 1234.5, &currency_format)?;
-    ///     worksheet.write_number_with_format(2, 0, 0.3300, &percentage_format)?;
-    ///     worksheet.write_number_with_format(3, 0, 1234.5, &bold_italic_format)?;
+    ///     // Some matrix data to write.
+    ///     let data = array![[1.0, 0.5, 0.25], [0.5, 1.0, 0.75], [0.25, 0.75, 1.0]];
+    ///
+    ///     // Write the matrix to the worksheet.
+    ///     worksheet.write_array2(0, 0, &data)?;
     /// #
-    /// #     workbook.save("numbers.xlsx")?;
+    /// #     workbook.save("worksheet.xlsx")?;
     /// #
     /// #     Ok(())
     /// # }
     /// ```
     ///
-    /// Output file:
+    #[cfg(feature = "ndarray")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn write_array2<S, T>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: &ndarray::ArrayBase<S, ndarray::Ix2>,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: IntoExcelData + Clone,
+    {
+        for (row, data_row) in (row..).zip(data.rows()) {
+            self.write_row(row, col, data_row.iter().cloned())?;
+        }
+
+        Ok(self)
+    }
+
+    /// Write an `ndarray` 2D array to a worksheet, with formatting.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_number_with_format.png">
+    /// This method is similar to [`Worksheet::write_array2()`] except you can
+    /// also specify a format for the data.
     ///
+    /// This method requires the `ndarray` feature to be enabled.
     ///
-    pub fn write_number_with_format(
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `data`: A reference to an `ndarray` 2D array.
+    /// - `format`: The [`Format`] property for the data.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    #[cfg(feature = "ndarray")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "ndarray")))]
+    pub fn write_array2_with_format<S, T>(
         &mut self,
         row: RowNum,
         col: ColNum,
-        number: impl Into<f64>,
+        data: &ndarray::ArrayBase<S, ndarray::Ix2>,
         format: &Format,
-    ) -> Result<&mut Worksheet, XlsxError> {
-        // Store the cell data.
-        self.store_number(row, col, number.into(), Some(format))
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        S: ndarray::Data<Elem = T>,
+        T: IntoExcelData + Clone,
+    {
+        for (row, data_row) in (row..).zip(data.rows()) {
+            self.write_row_with_format(row, col, data_row.iter().cloned(), format)?;
+        }
+
+        Ok(self)
     }
 
-    /// Write an unformatted string to a worksheet cell.
+    /// Write CSV or other delimited text data to a worksheet.
     ///
-    /// Write an unformatted string to a worksheet cell. To write a formatted
-    /// string see the [`Worksheet::write_string_with_format()`] method below.
+    /// Read comma, tab or other delimiter separated text from a reader and
+    /// write it to the worksheet starting from the initial `row, col` cell.
+    /// Each record in the data is written as a row and each field is written
+    /// as a typed cell: a number, boolean, date or string, depending on the
+    /// contents of the field. Empty fields are skipped.
     ///
-    /// Excel only supports UTF-8 text in the xlsx file format. Any Rust UTF-8
-    /// encoded string can be written with this method. The maximum string size
-    /// supported by Excel is 32,767 characters.
+    /// The delimiter, quoting, type inference, date detection and the header
+    /// row are controlled via a [`CsvOptions`] struct. See the [`CsvOptions`]
+    /// docs for the rules used to infer the cell types.
     ///
     /// # Parameters
     ///
     /// - `row`: The zero indexed row number.
     /// - `col`: The zero indexed column number.
-    /// - `string`: The string to write to the cell.
+    /// - `reader`: A type that implements [`std::io::Read`] such as a
+    ///   [`std::fs::File`] or a byte slice. The data must be UTF-8 encoded.
+    /// - `options`: The [`CsvOptions`] used to read the data.
     ///
     /// # Errors
     ///
@@ -2677,15 +2875,736 @@ impl Worksheet {
     ///   worksheet limits.
     /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
     ///   of 32,767 characters.
+    /// - [`XlsxError::IoError`] - The data couldn't be read or isn't valid
+    ///   UTF-8.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates writing some strings to a worksheet.
-    /// The UTF-8 strings are taken from the UTF-8 example in the [Rust
-    /// Programming Language] book.
+    /// The following example demonstrates writing tab separated data to a
+    /// worksheet.
     ///
-    /// [Rust Programming Language]:
-    ///     https://doc.rust-lang.org/book/ch08-02-strings.html#creating-a-new-string
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_csv_tsv.rs
+    /// #
+    /// # use rust_xlsxwriter::{CsvOptions, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some sample tab separated data.
+    ///     let data = "Name\tScore\tPassed\nAlice\t93.5\ttrue\nBob\t48\tfalse\n";
+    ///
+    ///     // Read the data with a tab delimiter.
+    ///     let options = CsvOptions::new().set_delimiter('\t');
+    ///
+    ///     worksheet.write_csv(0, 0, data.as_bytes(), &options)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_csv<R>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        reader: R,
+        options: &CsvOptions,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        R: std::io::Read,
+    {
+        let records = options.read_records(reader)?;
+
+        for (row_num, record) in (row..).zip(records.iter()) {
+            let is_header = options.header_row && row_num == row;
+
+            for (col, field) in (col..).zip(record) {
+                if is_header {
+                    if field.is_empty() {
+                        continue;
+                    }
+
+                    match &options.header_format {
+                        Some(format) => {
+                            self.write_string_with_format(row_num, col, field, format)?
+                        }
+                        None => self.write_string(row_num, col, field)?,
+                    };
+
+                    continue;
+                }
+
+                match options.convert_field(field) {
+                    CsvValue::Blank => {}
+                    CsvValue::Boolean(value) => {
+                        self.write_boolean(row_num, col, value)?;
+                    }
+                    CsvValue::Number(value) => {
+                        self.write_number(row_num, col, value)?;
+                    }
+                    CsvValue::String(value) => {
+                        self.write_string(row_num, col, value)?;
+                    }
+                    CsvValue::Date(value) => {
+                        self.write_datetime_with_format(
+                            row_num,
+                            col,
+                            &value,
+                            &options.date_format,
+                        )?;
+                    }
+                    CsvValue::DateTime(value) => {
+                        self.write_datetime_with_format(
+                            row_num,
+                            col,
+                            &value,
+                            &options.datetime_format,
+                        )?;
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write an array of JSON objects to a worksheet as rows of data.
+    ///
+    /// Write an array of [`serde_json::Value`] objects to a worksheet, with a
+    /// header row made from the object keys and a row of data for each object.
+    /// This is useful for applications where the data is already dynamic JSON
+    /// and there isn't a Rust struct to serialize.
+    ///
+    /// The headers are the unique keys of the objects in the order that they
+    /// are first seen. Objects that don't contain a key leave the cell in that
+    /// column blank. Note, `serde_json` stores object keys in sorted order
+    /// unless its `preserve_order` feature is enabled.
+    ///
+    /// The JSON values are mapped to Excel types as follows:
+    ///
+    /// - `null`: No cell is written.
+    /// - `bool`: Written as an Excel boolean.
+    /// - `number`: Written as an Excel number. Note, integers with more than 15
+    ///   digits will lose precision, see [`Worksheet::write_number()`].
+    /// - `string`: Written as an Excel string.
+    /// - `array` or `object`: Written as a string in compact JSON form.
+    ///
+    /// This method requires the `serde_json` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the header row.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `data`: A slice of JSON object values.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::ParameterError`] - One of the values isn't a JSON object.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing JSON objects to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_json_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use serde_json::json;
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some dynamic JSON data.
+    ///     let data = json!([
+    ///         {"fruit": "Apple", "cost": 1.25, "in_stock": true},
+    ///         {"fruit": "Pear", "cost": 0.75, "in_stock": false},
+    ///         {"fruit": "Peach", "cost": 2.5, "in_stock": null},
+    ///     ]);
+    ///
+    ///     // Write the JSON objects to the worksheet.
+    ///     if let Some(rows) = data.as_array() {
+    ///         worksheet.write_json_rows(0, 0, rows)?;
+    ///     }
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde_json")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde_json")))]
+    pub fn write_json_rows(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        data: &[serde_json::Value],
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let mut headers: Vec<&str> = vec![];
+        let mut header_cols: HashMap<&str, ColNum> = HashMap::new();
+
+        // Collect the unique headers in the order they are first seen.
+        for value in data {
+            let Some(object) = value.as_object() else {
+                let error = format!("JSON value isn't an object: '{value}'");
+                return Err(XlsxError::ParameterError(error));
+            };
+
+            for key in object.keys() {
+                if !header_cols.contains_key(key.as_str()) {
+                    let header_col = ColNum::try_from(headers.len())
+                        .ok()
+                        .and_then(|offset| col.checked_add(offset))
+                        .ok_or_else(|| {
+                            self.limit_error(
+                                "write_json_rows",
+                                &format!("header '{key}' in column offset {}", headers.len()),
+                            )
+                        })?;
+
+                    header_cols.insert(key, header_col);
+                    headers.push(key);
+                }
+            }
+        }
+
+        for header in &headers {
+            self.write_string(row, header_cols[header], *header)?;
+        }
+
+        let first_row = row
+            .checked_add(1)
+            .ok_or_else(|| self.limit_error("write_json_rows", &format!("row {row}")))?;
+
+        for (offset, value) in data.iter().enumerate() {
+            let row_num = RowNum::try_from(offset)
+                .ok()
+                .and_then(|offset| first_row.checked_add(offset))
+                .ok_or_else(|| {
+                    self.limit_error(
+                        "write_json_rows",
+                        &format!("row {row}, row offset {offset}"),
+                    )
+                })?;
+
+            let Some(object) = value.as_object() else {
+                continue;
+            };
+
+            for (key, value) in object {
+                let col_num = header_cols[key.as_str()];
+
+                match value {
+                    serde_json::Value::Null => {}
+                    serde_json::Value::Bool(value) => {
+                        self.write_boolean(row_num, col_num, *value)?;
+                    }
+                    serde_json::Value::Number(value) => {
+                        if let Some(number) = value.as_f64() {
+                            self.write_number(row_num, col_num, number)?;
+                        }
+                    }
+                    serde_json::Value::String(value) => {
+                        self.write_string(row_num, col_num, value)?;
+                    }
+                    serde_json::Value::Array(_) | serde_json::Value::Object(_) => {
+                        self.write_string(row_num, col_num, value.to_string())?;
+                    }
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write rows of data from an [`ExcelRowSource`] to a worksheet.
+    ///
+    /// Write the headers and then each row of typed [`ExcelCellValue`](crate::ExcelCellValue) values
+    /// returned by an [`ExcelRowSource`] implementation, until the source is
+    /// exhausted. This can be used to stream data such as database query
+    /// results to a worksheet with an adapter for the data source. See the
+    /// [`ExcelRowSource`] docs for an example adapter for `sqlx` rows.
+    ///
+    /// If the source returns an empty list of headers then the data is written
+    /// starting at `row`.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the header row.
+    /// - `col`: The zero indexed column number of the first column.
+    /// - `source`: A mutable reference to a type that implements the
+    ///   [`ExcelRowSource`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - Any error returned by [`ExcelRowSource::next_row()`].
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing data from a simple row
+    /// source to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_rows_from.rs
+    /// #
+    /// # use rust_xlsxwriter::{ExcelCellValue, ExcelRowSource, Workbook, XlsxError};
+    /// #
+    /// // A simple row source for some in-memory sales data.
+    /// struct SalesData {
+    ///     rows: std::vec::IntoIter<(&'static str, f64)>,
+    /// }
+    ///
+    /// impl ExcelRowSource for SalesData {
+    ///     fn headers(&self) -> Vec<String> {
+    ///         vec!["Region".to_string(), "Sales".to_string()]
+    ///     }
+    ///
+    ///     fn next_row(&mut self) -> Result<Option<Vec<ExcelCellValue>>, XlsxError> {
+    ///         let row = self
+    ///             .rows
+    ///             .next()
+    ///             .map(|(region, sales)| vec![region.into(), sales.into()]);
+    ///
+    ///         Ok(row)
+    ///     }
+    /// }
+    ///
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     let mut source = SalesData {
+    ///         rows: vec![("North", 1200.0), ("South", 950.5), ("East", 1420.0)].into_iter(),
+    ///     };
+    ///
+    ///     // Write the rows from the source to the worksheet.
+    ///     worksheet.write_rows_from(0, 0, &mut source)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_rows_from<S>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        source: &mut S,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        S: ExcelRowSource + ?Sized,
+    {
+        let headers = source.headers();
+        let mut row_num = row;
+
+        if !headers.is_empty() {
+            self.write_row(row, col, headers)?;
+            row_num += 1;
+        }
+
+        while let Some(values) = source.next_row()? {
+            self.write_row(row_num, col, values)?;
+            row_num += 1;
+        }
+
+        Ok(self)
+    }
+
+    /// Write a range of cell data read by `calamine` to a worksheet.
+    ///
+    /// Write a [`calamine::Range`] of cell data, read from an existing
+    /// workbook by the [`calamine`](https://docs.rs/calamine) crate, to a
+    /// worksheet. This can be used in read-modify-write workflows where the
+    /// data in an existing file is loaded into a new workbook, updated, and
+    /// saved.
+    ///
+    /// The `calamine` cell types are mapped to Excel types as follows:
+    ///
+    /// - `Empty`: No cell is written.
+    /// - `Int` and `Float`: Written as an Excel number.
+    /// - `Bool`: Written as an Excel boolean.
+    /// - `String`: Written as an Excel string.
+    /// - `DateTime`: Written as an Excel datetime number with a default format
+    ///   of `yyyy-mm-dd` for dates, `hh:mm:ss` for times and `yyyy-mm-dd
+    ///   hh:mm:ss` for datetimes.
+    /// - `DateTimeIso`: Parsed and written as an Excel datetime, as above.
+    /// - `Error` and `DurationIso`: Written as a string.
+    ///
+    /// Note, `calamine` reads cell values but not formulas or cell formatting,
+    /// apart from identifying dates, so only the values are preserved. Dates
+    /// are stored as the serial number of the source file so if the source
+    /// workbook uses the 1904 epoch the target workbook should too.
+    ///
+    /// This method requires the `calamine` feature.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the top left cell.
+    /// - `col`: The zero indexed column number of the top left cell.
+    /// - `range`: A reference to a `calamine` range of cell data. Use
+    ///   `range.start()` as the `row` and `col` to write the data in the same
+    ///   position as the source worksheet.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates reading the data from a workbook
+    /// with `calamine`, loading it into a new worksheet and adding to it.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_calamine_range.rs
+    /// #
+    /// # use std::io::Cursor;
+    /// #
+    /// # use calamine::{Reader, Xlsx};
+    /// # use rust_xlsxwriter::{Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a source file to read.
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #     worksheet.write_row(0, 0, ["Region", "Sales"])?;
+    /// #     worksheet.write_column(1, 0, ["North", "South", "East"])?;
+    /// #     worksheet.write_column(1, 1, [1200, 950, 1420])?;
+    /// #     let buffer = workbook.save_to_buffer()?;
+    /// #
+    ///     // Read the data from an existing file with calamine.
+    ///     let mut source: Xlsx<_> = Xlsx::new(Cursor::new(buffer))
+    ///         .map_err(|e| XlsxError::CustomError(e.to_string()))?;
+    ///     let range = source
+    ///         .worksheet_range("Sheet1")
+    ///         .map_err(|e| XlsxError::CustomError(e.to_string()))?;
+    ///
+    ///     // Load the data into a new worksheet.
+    ///     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     let (row, col) = range.start().unwrap_or_default();
+    ///     worksheet.write_calamine_range(row, col as u16, &range)?;
+    ///
+    ///     // Add a total to the data.
+    ///     worksheet.write(4, 0, "Total")?;
+    ///     worksheet.write(4, 1, Formula::new("=SUM(B2:B4)"))?;
+    ///
+    ///     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "calamine")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "calamine")))]
+    pub fn write_calamine_range(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        range: &calamine::Range<calamine::Data>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let date_format = Format::new().set_num_format("yyyy-mm-dd");
+        let time_format = Format::new().set_num_format("hh:mm:ss");
+        let datetime_format = Format::new().set_num_format("yyyy-mm-dd hh:mm:ss");
+
+        for (cell_row, cell_col, data) in range.used_cells() {
+            let cell_row = RowNum::try_from(cell_row)
+                .ok()
+                .and_then(|cell_row| row.checked_add(cell_row));
+            let cell_col = ColNum::try_from(cell_col)
+                .ok()
+                .and_then(|cell_col| col.checked_add(cell_col));
+
+            let (Some(row), Some(col)) = (cell_row, cell_col) else {
+                return Err(self.limit_error(
+                    "write_calamine_range",
+                    &format!("range offset from {}", cell_location(row, col)),
+                ));
+            };
+
+            match data {
+                calamine::Data::Empty => {}
+                calamine::Data::Int(value) => {
+                    self.write_number(row, col, *value as f64)?;
+                }
+                calamine::Data::Float(value) => {
+                    self.write_number(row, col, *value)?;
+                }
+                calamine::Data::Bool(value) => {
+                    self.write_boolean(row, col, *value)?;
+                }
+                calamine::Data::String(value) => {
+                    self.write_string(row, col, value)?;
+                }
+                calamine::Data::DateTime(value) => {
+                    let number = value.as_f64();
+                    let format = if number < 1.0 {
+                        &time_format
+                    } else if number.fract() == 0.0 {
+                        &date_format
+                    } else {
+                        &datetime_format
+                    };
+
+                    self.write_number_with_format(row, col, number, format)?;
+                }
+                calamine::Data::DateTimeIso(value) => match ExcelDateTime::parse_from_str(value) {
+                    Ok(datetime) => {
+                        let format = if value.contains(['T', ' ']) {
+                            &datetime_format
+                        } else if value.contains(':') {
+                            &time_format
+                        } else {
+                            &date_format
+                        };
+
+                        self.write_datetime_with_format(row, col, &datetime, format)?;
+                    }
+                    Err(_) => {
+                        self.write_string(row, col, value)?;
+                    }
+                },
+                calamine::Data::DurationIso(value) => {
+                    self.write_string(row, col, value)?;
+                }
+                calamine::Data::Error(value) => {
+                    self.write_string(row, col, value.to_string())?;
+                }
+            }
+        }
+
+        Ok(self)
+    }
+
+    /// Write an unformatted number to a cell.
+    ///
+    /// Write an unformatted number to a worksheet cell. To write a formatted
+    /// number see the
+    /// [`Worksheet::write_number_with_format()`]
+    /// method below.
+    ///
+    /// All numerical values in Excel are stored as [IEEE 754] Doubles which are
+    /// the equivalent of rust's [`f64`] type. This method will accept any rust
+    /// type that will convert [`Into`] a f64. These include i8, u8, i16, u16,
+    /// i32, u32 and f32 but not i64 or u64, see below.
+    ///
+    /// IEEE 754 Doubles and f64 have around 15 digits of precision. Anything
+    /// beyond that cannot be stored as a number by Excel without a loss of
+    /// precision and may need to be stored as a string instead.
+    ///
+    /// [IEEE 754]: https://en.wikipedia.org/wiki/IEEE_754
+    ///
+    /// For i64/u64 you can cast the numbers `as f64` which will allow you to
+    /// store the number with a loss of precision outside Excel's integer range
+    /// of +/- 999,999,999,999,999 (15 digits).
+    ///
+    /// Excel doesn't have handling for NaN or INF floating point numbers.
+    /// These will be stored as the strings "Nan", "INF", and "-INF" strings
+    /// instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `number`: The number to write to the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing unformatted numbers to an
+    /// Excel worksheet. Any numeric type that will convert [`Into`] f64 can be
+    /// transferred to Excel.
+    ///
+    /// ```
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Write some different Rust number types to a worksheet.
+    ///     // Note, u64 isn't supported by Excel.
+    ///     worksheet.write_number(0, 0, 1_u8)?;
+    ///     worksheet.write_number(1, 0, 2_i16)?;
+    ///     worksheet.write_number(2, 0, 3_u32)?;
+    ///     worksheet.write_number(3, 0, 4_f32)?;
+    ///     worksheet.write_number(4, 0, 5_f64)?;
+    ///
+    ///     // Write some numbers with implicit types.
+    ///     worksheet.write_number(5, 0, 1234)?;
+    ///     worksheet.write_number(6, 0, 1234.5)?;
+    ///
+    ///     // Note Excel normally ignores trailing decimal zeros
+    ///     // when the number is unformatted.
+    ///     worksheet.write_number(7, 0, 1234.50000)?;
+    /// #
+    /// #     workbook.save("numbers.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_number.png">
+    ///
+    pub fn write_number(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: impl Into<f64>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Store the cell data.
+        self.store_number(row, col, number, None)
+    }
+
+    /// Write a formatted number to a worksheet cell.
+    ///
+    /// Write a number with formatting to a worksheet cell. The format is set
+    /// via a [`Format`] struct which can control the numerical formatting of
+    /// the number, for example as a currency or a percentage value, or the
+    /// visual format, such as bold and italic text.
+    ///
+    /// All numerical values in Excel are stored as [IEEE 754] Doubles which are
+    /// the equivalent of rust's [`f64`] type. This method will accept any rust
+    /// type that will convert [`Into`] a f64. These include i8, u8, i16, u16,
+    /// i32, u32 and f32 but not i64 or u64, see below.
+    ///
+    /// IEEE 754 Doubles and f64 have around 15 digits of precision. Anything
+    /// beyond that cannot be stored as a number by Excel without a loss of
+    /// precision and may need to be stored as a string instead.
+    ///
+    /// [IEEE 754]: https://en.wikipedia.org/wiki/IEEE_754
+    ///
+    /// For i64/u64 you can cast the numbers `as f64` which will allow you to
+    /// store the number with a loss of precision outside Excel's integer range
+    /// of +/- 999,999,999,999,999 (15 digits).
+    ///
+    /// Excel doesn't have handling for NaN or INF floating point numbers. These
+    /// will be stored as the strings "Nan", "INF", and "-INF" strings instead.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `number`: The number to write to the cell.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting different formatting for
+    /// numbers in an Excel worksheet.
+    ///
+    /// ```
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///
+    ///     // Create some formats to use with the numbers below.
+    ///     let number_format = Format::new().set_num_format("#,##0.00");
+    ///     let currency_format = Format::new().set_num_format("€#,##0.00");
+    ///     let percentage_format = Format::new().set_num_format("0.0%");
+    ///     let bold_italic_format = Format::new().set_bold().set_italic();
+    ///
+    ///     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_number_with_format(0, 0, 1234.5, &number_format)?;
+    ///     worksheet.write_number_with_format(1, 0, 1234.5, &currency_format)?;
+    ///     worksheet.write_number_with_format(2, 0, 0.3300, &percentage_format)?;
+    ///     worksheet.write_number_with_format(3, 0, 1234.5, &bold_italic_format)?;
+    /// #
+    /// #     workbook.save("numbers.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_number_with_format.png">
+    ///
+    ///
+    pub fn write_number_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: impl Into<f64>,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Store the cell data.
+        self.store_number(row, col, number.into(), Some(format))
+    }
+
+    /// Write an unformatted string to a worksheet cell.
+    ///
+    /// Write an unformatted string to a worksheet cell. To write a formatted
+    /// string see the [`Worksheet::write_string_with_format()`] method below.
+    ///
+    /// Excel only supports UTF-8 text in the xlsx file format. Any Rust UTF-8
+    /// encoded string can be written with this method. The maximum string size
+    /// supported by Excel is 32,767 characters.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `string`: The string to write to the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing some strings to a worksheet.
+    /// The UTF-8 strings are taken from the UTF-8 example in the [Rust
+    /// Programming Language] book.
+    ///
+    /// [Rust Programming Language]:
+    ///     https://doc.rust-lang.org/book/ch08-02-strings.html#creating-a-new-string
     ///
     /// ```
     /// # use rust_xlsxwriter::{Workbook, XlsxError};
@@ -4094,6 +5013,163 @@ impl Worksheet {
         self.store_url(row, col, &link, format)
     }
 
+    /// Write an internal link to a cell, range or defined name in the workbook.
+    ///
+    /// This method writes a hyperlink to a location within the workbook, in
+    /// the same way as a `write_url()` url with the `internal:` pseudo-uri.
+    /// The difference is that the target is checked when the workbook is
+    /// saved so that a link to a misspelled or deleted worksheet, or an
+    /// undefined name, raises an error instead of producing a file with a
+    /// broken link. Urls created with [`Url::internal()`] or
+    /// [`Url::defined_name()`] are checked in the same way.
+    ///
+    /// The `target` can be one of the following:
+    ///
+    /// - A worksheet cell or range like `Sheet2!A1` or `Sheet2!A1:G5`, or a
+    ///   whole column or row range like `Sheet2!A:C` or `Sheet2!1:3`.
+    ///   Worksheet names that contain spaces or non alphanumeric characters
+    ///   should be single quoted like `'Sales Data'!A1`.
+    /// - A global defined name, created with
+    ///   [`Workbook::define_name()`](crate::Workbook::define_name), like
+    ///   `SalesTotals`.
+    /// - A worksheet scoped defined name like `Sheet2!Totals`. A defined name
+    ///   without a worksheet prefix can also refer to a name that is scoped
+    ///   to the worksheet that contains the link.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `target`: The worksheet location or defined name to link to.
+    /// - `text`: The string to write to the cell. If this is empty the target
+    ///   string is displayed instead.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxUrlLengthExceeded`] - The target string exceeds
+    ///   Excel's limit of 2080 characters.
+    /// - [`XlsxError::ParameterError`] - The target string is empty or the
+    ///   cell reference part of the target isn't a valid cell or range.
+    ///
+    /// When the workbook is saved the following errors are raised if the
+    /// target can't be found:
+    ///
+    /// - [`XlsxError::UnknownWorksheetNameOrIndex`] - The target worksheet
+    ///   doesn't exist in the workbook.
+    /// - [`XlsxError::ParameterError`] - The target defined name doesn't exist
+    ///   in the workbook.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing internal links to a
+    /// worksheet cell and to a defined name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_url_internal.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     // Add some worksheets to the workbook.
+    ///     let worksheet1 = workbook.add_worksheet();
+    ///
+    ///     // Write internal links to a cell and to a defined name.
+    ///     worksheet1.write_url_internal(0, 0, "Sheet2!A1", "Go to Sheet2")?;
+    ///     worksheet1.write_url_internal(1, 0, "'Sales Data'!B2", "Go to Sales")?;
+    ///     worksheet1.write_url_internal(2, 0, "Totals", "Go to Totals")?;
+    ///
+    ///     workbook.add_worksheet();
+    ///     workbook
+    ///         .add_worksheet()
+    ///         .set_name("Sales Data")?
+    ///         .write(1, 1, "Sales")?;
+    ///
+    ///     // Create the defined name used in the link.
+    ///     workbook.define_name("Totals", "='Sales Data'!$B$10")?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_url_internal(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        target: impl Into<String>,
+        text: impl Into<String>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let link = Url::internal(target).set_text(text.into());
+
+        self.store_url(row, col, &link, None)
+    }
+
+    /// Set the format policy for urls written without a user format.
+    ///
+    /// By default the url write methods such as [`Worksheet::write_url()`]
+    /// apply the Excel "Hyperlink" cell style, a blue underlined font, to the
+    /// cell unless a [`Format`] is passed explicitly with
+    /// [`Worksheet::write_url_with_format()`]. This method changes that
+    /// behavior for the worksheet so that urls can use a custom format, or
+    /// keep the existing format of the cell, without having to pass a format
+    /// with every call. See [`HyperlinkStyle`] for the available options.
+    ///
+    /// The method only affects urls that are written after it is called.
+    /// Urls written with an explicit format always use that format.
+    ///
+    /// # Parameters
+    ///
+    /// - `style`: A [`HyperlinkStyle`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the format policy for urls
+    /// in a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_hyperlink_style.rs
+    /// #
+    /// # use rust_xlsxwriter::{Color, Format, HyperlinkStyle, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Use a red, non-underlined font for urls in the worksheet.
+    ///     let format = Format::new().set_font_color(Color::Red);
+    ///     worksheet.set_hyperlink_style(HyperlinkStyle::Custom(format));
+    ///
+    ///     worksheet.write_url(0, 0, "https://www.rust-lang.org")?;
+    ///
+    ///     // Keep the existing bold format of a cell.
+    ///     let bold = Format::new().set_bold();
+    ///     worksheet.write_blank(2, 0, &bold)?;
+    ///
+    ///     worksheet.set_hyperlink_style(HyperlinkStyle::Inherit);
+    ///     worksheet.write_url(2, 0, "https://crates.io")?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_hyperlink_style(&mut self, style: HyperlinkStyle) -> &mut Worksheet {
+        self.hyperlink_style = style;
+        self
+    }
+
     /// Write a formatted date and/or time to a worksheet cell.
     ///
     /// The method method writes dates/times that implements [`IntoExcelDateTime`]
@@ -4112,6 +5188,17 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4295,6 +5382,17 @@ impl Worksheet {
     /// - [`chrono::NaiveDate`].
     /// - [`chrono::NaiveTime`].
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
     /// [`chrono::NaiveDate`]:
     ///     https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDate.html
     /// [`chrono::NaiveTime`]:
@@ -4345,33 +5443,170 @@ impl Worksheet {
     ///     worksheet.set_column_width(1, 20)?;
     ///     worksheet.set_column_width(2, 20)?;
     ///
-    ///     // Create a datetime object.
-    ///     let datetime = ExcelDateTime::from_ymd(2023, 1, 25)?.and_hms(12, 30, 0)?;
+    ///     // Create a datetime object.
+    ///     let datetime = ExcelDateTime::from_ymd(2023, 1, 25)?.and_hms(12, 30, 0)?;
+    ///
+    ///     // Write the datetime without a formats. The dates will get the column
+    ///     // format instead.
+    ///     worksheet.write_datetime(0, 0, &datetime)?;
+    ///     worksheet.write_datetime(0, 1, &datetime)?;
+    ///     worksheet.write_datetime(0, 2, &datetime)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_datetime_v2.png">
+    ///
+    pub fn write_datetime(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        datetime: impl IntoExcelDateTime,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let datetime = datetime.to_excel_serial_date();
+
+        // Store the cell data.
+        self.store_datetime(row, col, datetime, None)
+    }
+
+    /// Write a formatted timezone aware datetime to a worksheet cell.
+    ///
+    /// Excel datetimes don't have timezone information so timezone aware
+    /// values need to be converted to a "naive" date and time before they are
+    /// written. This method converts the datetime according to a
+    /// [`TimezonePolicy`]:
+    ///
+    /// - [`TimezonePolicy::Local`]: The date and time is written as it would
+    ///   be displayed in the timezone, or offset, of the value. For example
+    ///   `2024-01-01T12:00:00+02:00` is written as `2024-01-01 12:00:00`.
+    /// - [`TimezonePolicy::Utc`]: The date and time is converted to UTC before
+    ///   it is written. For example `2024-01-01T12:00:00+02:00` is written as
+    ///   `2024-01-01 10:00:00`.
+    ///
+    /// Since the timezone information is lost in both cases it is generally
+    /// best to use a consistent policy for all the datetimes in a worksheet
+    /// and, if required, indicate the timezone in a header or cell format.
+    ///
+    /// If the `chrono` feature is enabled you can use the following types:
+    ///
+    /// - [`chrono::DateTime<Tz>`], for example `DateTime<Utc>` or
+    ///   `DateTime<FixedOffset>`.
+    ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::OffsetDateTime`].
+    ///
+    /// [`chrono::DateTime<Tz>`]:
+    ///     https://docs.rs/chrono/latest/chrono/struct.DateTime.html
+    /// [`time::OffsetDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.OffsetDateTime.html
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `datetime`: A timezone aware date/time instance that implements
+    ///   [`IntoExcelDateTimeTz`].
+    /// - `policy`: A [`TimezonePolicy`] enum value.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing timezone aware Chrono
+    /// datetimes to a worksheet. This example requires the `chrono` feature.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_datetime_with_tz.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, TimezonePolicy, Workbook, XlsxError};
+    /// #
+    /// # #[cfg(feature = "chrono")]
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     use chrono::{DateTime, FixedOffset};
+    /// #
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Create a datetime format.
+    ///     let format = Format::new().set_num_format("yyyy-mm-dd hh:mm");
+    ///
+    ///     // Set the column width for clarity.
+    ///     worksheet.set_column_width(0, 20)?;
+    ///     worksheet.set_column_width(1, 20)?;
+    ///
+    ///     // Create a timezone aware datetime.
+    ///     let datetime: DateTime<FixedOffset> =
+    ///         DateTime::parse_from_rfc3339("2024-01-01T12:00:00+02:00").unwrap();
+    ///
+    ///     // Write the datetime in the local time of its offset: 2024-01-01 12:00.
+    ///     worksheet.write_datetime_with_tz_and_format(0, 0, datetime, TimezonePolicy::Local, &format)?;
+    ///
+    ///     // Write the datetime normalized to UTC: 2024-01-01 10:00.
+    ///     worksheet.write_datetime_with_tz_and_format(0, 1, datetime, TimezonePolicy::Utc, &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "chrono"))]
+    /// # fn main() {}
+    /// ```
+    ///
+    pub fn write_datetime_with_tz_and_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        datetime: impl IntoExcelDateTimeTz,
+        policy: TimezonePolicy,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let datetime = datetime.to_excel_serial_date_tz(policy);
+
+        // Store the cell data.
+        self.store_datetime(row, col, datetime, Some(format))
+    }
+
+    /// Write an unformatted timezone aware datetime to a worksheet cell.
+    ///
+    /// This method is the same as
+    /// [`write_datetime_with_tz_and_format()`](Worksheet::write_datetime_with_tz_and_format)
+    /// except that it doesn't apply a cell format. It is provided for cases
+    /// where an implicit format is derived from the column or row format. See
+    /// also [`write_datetime()`](Worksheet::write_datetime).
+    ///
+    /// # Parameters
     ///
-    ///     // Write the datetime without a formats. The dates will get the column
-    ///     // format instead.
-    ///     worksheet.write_datetime(0, 0, &datetime)?;
-    ///     worksheet.write_datetime(0, 1, &datetime)?;
-    ///     worksheet.write_datetime(0, 2, &datetime)?;
-    /// #
-    /// #     workbook.save("worksheet.xlsx")?;
-    /// #
-    /// #     Ok(())
-    /// # }
-    /// ```
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `datetime`: A timezone aware date/time instance that implements
+    ///   [`IntoExcelDateTimeTz`].
+    /// - `policy`: A [`TimezonePolicy`] enum value.
     ///
-    /// Output file:
+    /// # Errors
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_write_datetime_v2.png">
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
     ///
-    pub fn write_datetime(
+    pub fn write_datetime_with_tz(
         &mut self,
         row: RowNum,
         col: ColNum,
-        datetime: impl IntoExcelDateTime,
+        datetime: impl IntoExcelDateTimeTz,
+        policy: TimezonePolicy,
     ) -> Result<&mut Worksheet, XlsxError> {
-        let datetime = datetime.to_excel_serial_date();
+        let datetime = datetime.to_excel_serial_date_tz(policy);
 
         // Store the cell data.
         self.store_datetime(row, col, datetime, None)
@@ -4396,6 +5631,17 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4491,6 +5737,17 @@ impl Worksheet {
     /// [`chrono::NaiveTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveTime.html
     /// [`chrono::NaiveDateTime`]: https://docs.rs/chrono/latest/chrono/naive/struct.NaiveDateTime.html
     ///
+    /// If the `time` feature is enabled you can use the following types:
+    ///
+    /// - [`time::PrimitiveDateTime`].
+    /// - [`time::Date`].
+    /// - [`time::Time`].
+    ///
+    /// [`time::PrimitiveDateTime`]:
+    ///     https://docs.rs/time/latest/time/struct.PrimitiveDateTime.html
+    /// [`time::Date`]: https://docs.rs/time/latest/time/struct.Date.html
+    /// [`time::Time`]: https://docs.rs/time/latest/time/struct.Time.html
+    ///
     /// Excel stores dates and times as a floating point number with a number
     /// format to defined how it is displayed. The number format is set via a
     /// [`Format`] struct which can also control visual formatting such as bold
@@ -4688,10 +5945,11 @@ impl Worksheet {
     /// they act as a single area.
     ///
     /// The `merge_range()` method writes a string to the merged cells. In order
-    /// to write other data types, such as a number or a formula, you can
-    /// overwrite the first cell with a call to one of the other
-    /// `worksheet.write_*()` functions. The same [`Format`] instance should be
-    /// used as was used in the merged range, see the example below.
+    /// to write other data types, such as a number or a formula, you can use
+    /// [`Worksheet::merge_range_with()`] or you can overwrite the first cell
+    /// with a call to one of the other `worksheet.write_*()` functions. The
+    /// same [`Format`] instance should be used as was used in the merged range,
+    /// see the example below.
     ///
     /// # Parameters
     ///
@@ -4776,63 +6034,99 @@ impl Worksheet {
         string: &str,
         format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check rows and cols are in the allowed range.
-        if !self.check_dimensions(first_row, first_col)
-            || !self.check_dimensions(last_row, last_col)
-        {
-            return Err(XlsxError::RowColumnLimitError);
-        }
-
-        // Check order of first/last values.
-        if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        // Check that the range isn't a singe cell, which isn't allowed by Excel.
-        if first_row == last_row && first_col == last_col {
-            return Err(XlsxError::MergeRangeSingleCell);
-        }
-
-        // Write the first cell in the range.
-        self.write_string_with_format(first_row, first_col, string, format)?;
-
-        // Pad out the rest of the range with formatted blanks cells.
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                // Skip the first cell which was written above.
-                if row == first_row && col == first_col {
-                    continue;
-                }
-                self.write_blank(row, col, format)?;
-            }
-        }
-
-        // Create a cell range for storage and range testing.
-        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
-
-        // Check if the merged range overlaps any previous merged range. This is
-        // a major error in Excel. Note, the ranges are stored in a separate Vec
-        // to the cells to cut down on storage size.
-        let new_index = self.merged_ranges.len();
-        for row in first_row..=last_row {
-            for col in first_col..=last_col {
-                match self.merged_cells.get_mut(&(row, col)) {
-                    Some(index) => {
-                        let previous_cell_range = self.merged_ranges.get(*index).unwrap();
-                        return Err(XlsxError::MergeRangeOverlaps(
-                            cell_range.to_error_string(),
-                            previous_cell_range.to_error_string(),
-                        ));
-                    }
-                    None => self.merged_cells.insert((row, col), new_index),
-                };
-            }
-        }
-
-        // Store the merge range if everything was okay.
-        self.merged_ranges.push(cell_range);
+        self.store_merged_data(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            string,
+            format,
+            "merge_range",
+        )
+    }
 
-        Ok(self)
+    /// Merge a range of cells and write any supported data type to it.
+    ///
+    /// The `merge_range_with()` method is similar to
+    /// [`Worksheet::merge_range()`] except that it can write any type that
+    /// implements the [`IntoExcelData`] trait, such as numbers, booleans,
+    /// dates, [`Formula`] and [`Url`], to the first cell of the merged range.
+    /// The other cells in the range are written as formatted blank cells.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first row of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last row of the range.
+    /// - `data`: The data to write to the first cell of the range. It can be
+    ///   any type that implements the [`IntoExcelData`] trait.
+    /// - `format`: The [`Format`] property for the cells.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MergeRangeSingleCell`] - A merge range cannot be a single
+    ///   cell in Excel.
+    /// - [`XlsxError::MergeRangeOverlaps`] - The merge range overlaps a
+    ///   previous merge range.
+    /// - Any of the errors that can be returned when writing the data type.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing different data types to
+    /// merged ranges.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_merge_range_with.rs
+    /// #
+    /// # use rust_xlsxwriter::{ExcelDateTime, Format, FormatAlign, Formula, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     let format = Format::new().set_align(FormatAlign::Center);
+    ///     let date_format = Format::new()
+    ///         .set_align(FormatAlign::Center)
+    ///         .set_num_format("yyyy-mm-dd");
+    ///
+    ///     // Write a number, a date and a formula to merged ranges.
+    ///     worksheet.merge_range_with(1, 1, 1, 2, 12345.67, &format)?;
+    ///
+    ///     let date = ExcelDateTime::from_ymd(2024, 7, 1)?;
+    ///     worksheet.merge_range_with(3, 1, 3, 2, &date, &date_format)?;
+    ///
+    ///     let formula = Formula::new("=B2*2");
+    ///     worksheet.merge_range_with(5, 1, 5, 2, formula, &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn merge_range_with(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        data: impl IntoExcelData,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_merged_data(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            data,
+            format,
+            "merge_range_with",
+        )
     }
 
     /// Add an image to a worksheet.
@@ -4987,7 +6281,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_image_with_offset", &cell_location(row, col)));
         }
 
         let mut image = image.clone();
@@ -5217,7 +6511,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_image_fit_to_cell", &cell_location(row, col)));
         }
 
         let width = self.column_pixel_width(col, image.object_movement);
@@ -5371,7 +6665,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_chart_with_offset", &cell_location(row, col)));
         }
 
         let mut chart = chart.clone();
@@ -5485,7 +6779,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_note", &cell_location(row, col)));
         }
 
         //  Check that the string is < Excel limit of 32767 chars, - 54
@@ -5645,7 +6939,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_shape_with_offset", &cell_location(row, col)));
         }
 
         let mut shape = shape.clone();
@@ -5884,7 +7178,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("insert_button_with_offset", &cell_location(row, col)));
         }
 
         let mut button = button.clone();
@@ -5897,6 +7191,102 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Insert a boolean checkbox in a worksheet cell.
+    ///
+    /// Checkboxes are a cell control feature in newer versions of Excel. They
+    /// display a boolean cell value as a clickable checkbox that can be
+    /// checked or unchecked by the user. The cell value remains a `TRUE` or
+    /// `FALSE` boolean and can be used in formulas like any other value.
+    ///
+    /// Note, checkboxes are only supported in Excel 365 and later. In older
+    /// versions of Excel the cells are displayed as `TRUE` or `FALSE`.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `boolean`: The initial checked/unchecked state of the checkbox.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding checkboxes to worksheet
+    /// cells.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_insert_checkbox.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write(0, 0, "Task")?;
+    ///     worksheet.write(0, 1, "Done")?;
+    ///     worksheet.write_column(1, 0, ["Design", "Build", "Test"])?;
+    ///
+    ///     // Add some checked and unchecked checkboxes.
+    ///     worksheet.insert_checkbox(1, 1, true)?;
+    ///     worksheet.insert_checkbox(2, 1, true)?;
+    ///
+    ///     // Add a checkbox with a cell format.
+    ///     let format = Format::new().set_background_color("#FFC7CE");
+    ///     worksheet.insert_checkbox_with_format(3, 1, false, &format)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn insert_checkbox(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        boolean: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let format = Format::new().set_checkbox();
+
+        self.insert_checkbox_with_format(row, col, boolean, &format)
+    }
+
+    /// Insert a boolean checkbox in a worksheet cell, with formatting.
+    ///
+    /// This method is the same as [`Worksheet::insert_checkbox()`] except that
+    /// it also applies a cell [`Format`] such as a fill color or alignment.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `boolean`: The initial checked/unchecked state of the checkbox.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    pub fn insert_checkbox_with_format(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        boolean: bool,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let format = format.clone().set_checkbox();
+
+        self.store_boolean(row, col, boolean, Some(&format))?;
+        self.has_checkboxes = true;
+
+        Ok(self)
+    }
+
     /// Set the height for a row of cells.
     ///
     /// The `set_row_height()` method is used to change the default height of a
@@ -5965,7 +7355,7 @@ impl Worksheet {
 
         // Check row is in the allowed range.
         if !self.check_dimensions(row, min_col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_row_height", &format!("row {row}")));
         }
 
         // Update an existing row metadata object or create a new one.
@@ -5976,6 +7366,8 @@ impl Worksheet {
                     height,
                     xf_index: 0,
                     hidden: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -6109,7 +7501,7 @@ impl Worksheet {
 
         // Check row is in the allowed range.
         if !self.check_dimensions(row, min_col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_row_format", &format!("row {row}")));
         }
 
         // Get the index of the format object.
@@ -6123,6 +7515,8 @@ impl Worksheet {
                     height: self.user_default_row_height,
                     xf_index,
                     hidden: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -6182,7 +7576,7 @@ impl Worksheet {
 
         // Check row is in the allowed range.
         if !self.check_dimensions(row, min_col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_row_hidden", &format!("row {row}")));
         }
 
         // Update an existing row metadata object or create a new one.
@@ -6193,6 +7587,8 @@ impl Worksheet {
                     height: self.user_default_row_height,
                     xf_index: 0,
                     hidden: true,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_rows.insert(row, row_options);
             }
@@ -6213,21 +7609,132 @@ impl Worksheet {
     ///
     /// # Errors
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
-    ///   limits.
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    ///
+    pub fn set_row_unhidden(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+        // Set a suitable column range for the row dimension check/set.
+        let min_col = self.get_min_col();
+
+        // Check row is in the allowed range.
+        if !self.check_dimensions(row, min_col) {
+            return Err(self.limit_error("set_row_unhidden", &format!("row {row}")));
+        }
+
+        // Only update an existing row metadata object.
+        if let Some(row_options) = self.changed_rows.get_mut(&row) {
+            row_options.hidden = false;
+        }
+
+        Ok(self)
+    }
+
+    /// Hide a range of worksheet rows.
+    ///
+    /// This is a syntactic shortcut for hiding a range of contiguous rows. See
+    /// [`Worksheet::set_row_hidden()`] for more details on the single row
+    /// version.
+    ///
+    /// The range is validated once before any rows are changed. Any existing
+    /// row height, format or outline level set via
+    /// [`Worksheet::group_rows()`] is retained.
+    ///
+    /// A range that extends to the last row in the worksheet, row 1,048,575,
+    /// is hidden efficiently via the same Excel optimization as
+    /// [`Worksheet::hide_unused_rows()`] rather than by storing each row.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. Zero indexed.
+    /// - `last_row`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates hiding a range of worksheet rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_row_range_hidden.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Hide rows 2 to 5 (with zero indexing).
+    ///     worksheet.set_row_range_hidden(1, 4)?;
+    ///
+    ///     worksheet.write_string(5, 0, "Rows 2 to 5 are hidden")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
     ///
-    pub fn set_row_unhidden(&mut self, row: RowNum) -> Result<&mut Worksheet, XlsxError> {
+    pub fn set_row_range_hidden(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_row > last_row {
+            return Err(self.order_error(
+                "set_row_range_hidden",
+                &format!("rows {first_row} to {last_row}"),
+            ));
+        }
+
         // Set a suitable column range for the row dimension check/set.
         let min_col = self.get_min_col();
 
-        // Check row is in the allowed range.
-        if !self.check_dimensions(row, min_col) {
-            return Err(XlsxError::RowColumnLimitError);
+        // Check rows are in the allowed range.
+        if !self.check_dimensions_only(first_row, min_col)
+            || !self.check_dimensions_only(last_row, min_col)
+        {
+            return Err(self.limit_error(
+                "set_row_range_hidden",
+                &format!("rows {first_row} to {last_row}"),
+            ));
         }
 
-        // Only update an existing row metadata object.
-        if let Some(row_options) = self.changed_rows.get_mut(&row) {
-            row_options.hidden = false;
+        // A range that extends to the end of the worksheet is hidden using the
+        // same Excel optimization as `hide_unused_rows()`. The rows above the
+        // range are stored so that they remain visible and any rows written in
+        // the range are hidden when the worksheet is saved.
+        if last_row == ROW_MAX - 1 {
+            self.hide_unused_rows = true;
+            self.first_unused_hidden_row = Some(
+                self.first_unused_hidden_row
+                    .map_or(first_row, |row| row.min(first_row)),
+            );
+
+            if first_row > 0 {
+                self.check_dimensions(0, min_col);
+                self.check_dimensions(first_row - 1, min_col);
+
+                for row in 0..first_row {
+                    self.row_options_mut(row);
+                }
+            }
+
+            return Ok(self);
+        }
+
+        self.check_dimensions(first_row, min_col);
+        self.check_dimensions(last_row, min_col);
+
+        for row in first_row..=last_row {
+            self.row_options_mut(row).hidden = true;
         }
 
         Ok(self)
@@ -6455,7 +7962,7 @@ impl Worksheet {
 
         // Check if column is in the allowed range without updating dimensions.
         if col >= COL_MAX {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_column_width", &format!("column {col}")));
         }
 
         // Store the column width.
@@ -6526,16 +8033,7 @@ impl Worksheet {
         col: ColNum,
         width: u16,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
-        let mut width = f64::from(width);
-
-        if width < 12.0 {
-            width /= max_digit_width + padding;
-        } else {
-            width = (width - padding) / max_digit_width;
-        }
+        let width = self.pixels_to_width(width);
 
         self.set_column_width(col, width)
     }
@@ -6609,7 +8107,7 @@ impl Worksheet {
 
         // Check column is in the allowed range.
         if !self.check_dimensions(min_row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_column_format", &format!("column {col}")));
         }
 
         // Get the index of the format object.
@@ -6620,10 +8118,12 @@ impl Worksheet {
             Some(col_options) => col_options.xf_index = xf_index,
             None => {
                 let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
+                    width: self.default_col_width,
                     xf_index,
                     hidden: false,
                     autofit: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -6680,7 +8180,7 @@ impl Worksheet {
     pub fn set_column_hidden(&mut self, col: ColNum) -> Result<&mut Worksheet, XlsxError> {
         // Check if column is in the allowed range without updating dimensions.
         if col >= COL_MAX {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_column_hidden", &format!("column {col}")));
         }
 
         // Update an existing col metadata object or create a new one.
@@ -6688,10 +8188,12 @@ impl Worksheet {
             Some(col_options) => col_options.hidden = true,
             None => {
                 let col_options = ColOptions {
-                    width: DEFAULT_COL_WIDTH,
+                    width: self.default_col_width,
                     xf_index: 0,
                     hidden: true,
                     autofit: false,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -6727,7 +8229,10 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check order of first/last values.
         if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_column_range_width",
+                &format!("columns {first_col} to {last_col}"),
+            ));
         }
 
         let width = width.into();
@@ -6757,41 +8262,303 @@ impl Worksheet {
     /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
     ///   column.
     ///
-    pub fn set_column_range_width_pixels(
+    pub fn set_column_range_width_pixels(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        width: u16,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(self.order_error(
+                "set_column_range_width_pixels",
+                &format!("columns {first_col} to {last_col}"),
+            ));
+        }
+
+        for col_num in first_col..=last_col {
+            self.set_column_width_pixels(col_num, width)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Set the format for a range of columns.
+    ///
+    /// This is a syntactic shortcut for setting the format for a range of
+    /// contiguous cells. See [`Worksheet::set_column_format()`] for more
+    /// details on the single column version.
+    ///
+    /// Note, this method can be used to set the cell format for the entire
+    /// worksheet when applied to all the columns in the worksheet (see the
+    /// example below). This is relatively efficient since it is stored as a
+    /// single XML element. This is also how Excel applies a format to an entire
+    /// worksheet.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: The first row of the range. Zero indexed.
+    /// - `last_col`: The last row of the range.
+    /// - `format`: The [`Format`] property for the cell.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the format for all the
+    /// columns in an Excel worksheet. This effectively, and efficiently, sets
+    /// the format for the entire worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_column_range_format.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Add a format.
+    ///     let cell_format = Format::new()
+    ///         .set_background_color("#F9F2EC")
+    ///         .set_border(FormatBorder::Thin);
+    ///
+    ///     // Set the column format for the entire worksheet.
+    ///     worksheet.set_column_range_format(0, 16_383, &cell_format)?;
+    ///
+    ///     // Add some unformatted text that adopts the column format.
+    ///     worksheet.write_string(1, 1, "Hello")?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_column_range_format.png">
+    ///
+    pub fn set_column_range_format(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        format: &Format,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(self.order_error(
+                "set_column_range_format",
+                &format!("columns {first_col} to {last_col}"),
+            ));
+        }
+
+        for col_num in first_col..=last_col {
+            self.set_column_format(col_num, format)?;
+        }
+
+        Ok(self)
+    }
+
+    /// Hide a range of worksheet columns.
+    ///
+    /// This is a syntactic shortcut for hiding a range of contiguous cells. See
+    /// [`Worksheet::set_column_hidden()`] for more details on the single column
+    /// version.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_col`: The first row of the range. Zero indexed.
+    /// - `last_col`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Column exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
+    ///   column.
+    ///
+    pub fn set_column_range_hidden(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(self.order_error(
+                "set_column_range_hidden",
+                &format!("columns {first_col} to {last_col}"),
+            ));
+        }
+
+        // Check if columns are in the allowed range without updating dimensions.
+        if last_col >= COL_MAX {
+            return Err(self.limit_error(
+                "set_column_range_hidden",
+                &format!("columns {first_col} to {last_col}"),
+            ));
+        }
+
+        for col_num in first_col..=last_col {
+            self.col_options_mut(col_num).hidden = true;
+        }
+
+        Ok(self)
+    }
+
+    /// Group a range of rows into a worksheet outline group.
+    ///
+    /// In Excel an outline is a group of rows or columns that can be collapsed
+    /// or expanded to simplify the display of hierarchical data. The grouped
+    /// rows are displayed with a `-` symbol on a summary row, which is the row
+    /// after the group by default, that can be clicked to collapse the group.
+    ///
+    /// Groups can be nested by calling `group_rows()` on a range that is
+    /// within, or overlaps, a previously grouped range. Each call increments
+    /// the outline level of the rows in the range. Excel supports up to 7
+    /// outline levels.
+    ///
+    /// The position of the summary row can be changed to the row above the
+    /// group via [`Worksheet::set_outline_settings()`]. See also
+    /// [`Worksheet::group_rows_collapsed()`] to add a group that is collapsed
+    /// when the file is opened.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. Zero indexed.
+    /// - `last_row`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - Group nested more than 7
+    ///   levels deep.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates grouping worksheet rows into
+    /// nested outline groups.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_rows.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Add some regional data with a summary row after each region.
+    ///     worksheet.write_column(0, 0, ["North", "North", "North Total"])?;
+    ///     worksheet.write_column(3, 0, ["South", "South", "South Total"])?;
+    ///     worksheet.write(6, 0, "Grand Total")?;
+    ///
+    ///     // Group all the data rows at the first level.
+    ///     worksheet.group_rows(0, 5)?;
+    ///
+    ///     // Group the detail rows of each region at the second level.
+    ///     worksheet.group_rows(0, 1)?;
+    ///     worksheet.group_rows(3, 4)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_rows(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        self.store_row_group(first_row, last_row, false, "group_rows")
+    }
+
+    /// Group a range of rows into a collapsed worksheet outline group.
+    ///
+    /// This method is the same as [`Worksheet::group_rows()`] except that the
+    /// rows in the group are hidden and the summary row is marked as collapsed
+    /// so that the group is displayed with a `+` symbol when the file is
+    /// opened.
+    ///
+    /// The summary row is the row after the group, or the row before it if
+    /// the summary rows are displayed above the groups. For that reason
+    /// [`Worksheet::set_outline_settings()`] should be called before adding a
+    /// collapsed group.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. Zero indexed.
+    /// - `last_row`: The last row of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row exceeds Excel's worksheet
+    ///   limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row larger than the last
+    ///   row.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - Group nested more than 7
+    ///   levels deep.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a collapsed group of rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_rows_collapsed.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_column(0, 0, [10, 20, 30])?;
+    ///     worksheet.write_formula(3, 0, "=SUM(A1:A3)")?;
+    ///
+    ///     // Hide the detail rows under the summary row.
+    ///     worksheet.group_rows_collapsed(0, 2)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_rows_collapsed(
         &mut self,
-        first_col: ColNum,
-        last_col: ColNum,
-        width: u16,
+        first_row: RowNum,
+        last_row: RowNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check order of first/last values.
-        if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        for col_num in first_col..=last_col {
-            self.set_column_width_pixels(col_num, width)?;
-        }
-
-        Ok(self)
+        self.store_row_group(first_row, last_row, true, "group_rows_collapsed")
     }
 
-    /// Set the format for a range of columns.
+    /// Group a range of columns into a worksheet outline group.
     ///
-    /// This is a syntactic shortcut for setting the format for a range of
-    /// contiguous cells. See [`Worksheet::set_column_format()`] for more
-    /// details on the single column version.
+    /// This is the column equivalent of [`Worksheet::group_rows()`]. The
+    /// summary column is the column to the right of the group by default. It
+    /// can be changed to the column to the left of the group via
+    /// [`Worksheet::set_outline_settings()`].
     ///
-    /// Note, this method can be used to set the cell format for the entire
-    /// worksheet when applied to all the columns in the worksheet (see the
-    /// example below). This is relatively efficient since it is stored as a
-    /// single XML element. This is also how Excel applies a format to an entire
-    /// worksheet.
+    /// Groups can be nested up to 7 levels deep. See also
+    /// [`Worksheet::group_columns_collapsed()`].
     ///
     /// # Parameters
     ///
-    /// - `first_col`: The first row of the range. Zero indexed.
-    /// - `last_col`: The last row of the range.
-    /// - `format`: The [`Format`] property for the cell.
+    /// - `first_col`: The first column of the range. Zero indexed.
+    /// - `last_col`: The last column of the range.
     ///
     /// # Errors
     ///
@@ -6799,35 +8566,26 @@ impl Worksheet {
     ///   limits.
     /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
     ///   column.
-    ///
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - Group nested more than 7
+    ///   levels deep.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the format for all the
-    /// columns in an Excel worksheet. This effectively, and efficiently, sets
-    /// the format for the entire worksheet.
+    /// The following example demonstrates grouping worksheet columns.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_column_range_format.rs
+    /// # // This code is available in examples/doc_worksheet_group_columns.rs
     /// #
-    /// # use rust_xlsxwriter::{Format, FormatBorder, Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
-    /// #
-    /// #     // Add a worksheet to the workbook.
-    /// #     let worksheet = workbook.add_worksheet();
-    /// #
-    ///     // Add a format.
-    ///     let cell_format = Format::new()
-    ///         .set_background_color("#F9F2EC")
-    ///         .set_border(FormatBorder::Thin);
+    ///     let worksheet = workbook.add_worksheet();
     ///
-    ///     // Set the column format for the entire worksheet.
-    ///     worksheet.set_column_range_format(0, 16_383, &cell_format)?;
+    ///     worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1"])?;
     ///
-    ///     // Add some unformatted text that adopts the column format.
-    ///     worksheet.write_string(1, 1, "Hello")?;
+    ///     // Group the monthly columns to the left of the quarterly column.
+    ///     worksheet.group_columns(0, 2)?;
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -6835,39 +8593,26 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
-    ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_column_range_format.png">
-    ///
-    pub fn set_column_range_format(
+    pub fn group_columns(
         &mut self,
         first_col: ColNum,
         last_col: ColNum,
-        format: &Format,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check order of first/last values.
-        if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        for col_num in first_col..=last_col {
-            self.set_column_format(col_num, format)?;
-        }
-
-        Ok(self)
+        self.store_column_group(first_col, last_col, false, "group_columns")
     }
 
-    /// Hide a range of worksheet columns.
+    /// Group a range of columns into a collapsed worksheet outline group.
     ///
-    /// This is a syntactic shortcut for hiding a range of contiguous cells. See
-    /// [`Worksheet::set_column_hidden()`] for more details on the single column
-    /// version.
+    /// This method is the same as [`Worksheet::group_columns()`] except that
+    /// the columns in the group are hidden and the summary column is marked as
+    /// collapsed. As with [`Worksheet::group_rows_collapsed()`] any change to
+    /// the summary column position via [`Worksheet::set_outline_settings()`]
+    /// should be made before adding the group.
     ///
     /// # Parameters
     ///
-    /// - `first_col`: The first row of the range. Zero indexed.
-    /// - `last_col`: The last row of the range.
+    /// - `first_col`: The first column of the range. Zero indexed.
+    /// - `last_col`: The last column of the range.
     ///
     /// # Errors
     ///
@@ -6875,22 +8620,39 @@ impl Worksheet {
     ///   limits.
     /// - [`XlsxError::RowColumnOrderError`] - First column larger than the last
     ///   column.
+    /// - [`XlsxError::MaxGroupLevelExceeded`] - Group nested more than 7
+    ///   levels deep.
     ///
-    pub fn set_column_range_hidden(
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a collapsed group of columns.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_group_columns_collapsed.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_row(0, 0, ["Jan", "Feb", "Mar", "Q1"])?;
+    ///
+    ///     // Hide the monthly columns behind the quarterly column.
+    ///     worksheet.group_columns_collapsed(0, 2)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn group_columns_collapsed(
         &mut self,
         first_col: ColNum,
         last_col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check order of first/last values.
-        if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
-        }
-
-        for col_num in first_col..=last_col {
-            self.set_column_hidden(col_num)?;
-        }
-
-        Ok(self)
+        self.store_column_group(first_col, last_col, true, "group_columns_collapsed")
     }
 
     /// Set the autofilter area in the worksheet.
@@ -6977,12 +8739,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "autofilter",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "autofilter",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Store the defined name information.
@@ -7117,7 +8885,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check if column is in the allowed range without updating dimensions.
         if col >= COL_MAX {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("filter_column", &format!("column {col}")));
         }
 
         // Check that an autofilter has been created before a condition can be
@@ -7143,6 +8911,8 @@ impl Worksheet {
         // Check the filter condition have been set up correctly.
         if filter_condition.list.is_empty()
             && filter_condition.custom1.is_none()
+            && filter_condition.top10.is_none()
+            && filter_condition.dynamic.is_none()
             && !filter_condition.should_match_blanks
         {
             let error =
@@ -7295,12 +9065,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "add_table",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "add_table",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check that the table has at least one valid row.
@@ -7403,6 +9179,265 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Add a table to a worksheet using an `A1:B2` style range reference.
+    ///
+    /// This method is the same as [`Worksheet::add_table()`] except that the
+    /// table range is specified as an `A1:B2` or `$A$1:$B$2` style string.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: An `A1:B2` style range reference string, or any other type
+    ///   that implements [`IntoRangeRef`] such as a
+    ///   [`RangeRef`].
+    /// - `table`: The [`Table`] to add to the worksheet.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Invalid range reference.
+    /// - See [`Worksheet::add_table()`] for the other errors that can be
+    ///   returned.
+    ///
+    pub fn add_table_a1(
+        &mut self,
+        range: impl IntoRangeRef,
+        table: &Table,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let range = range.to_range_ref()?;
+
+        self.add_table(
+            range.first_row(),
+            range.first_col(),
+            range.last_row(),
+            range.last_col(),
+            table,
+        )
+    }
+
+    /// Write the headers and data for a table and add the table in one step.
+    ///
+    /// This method writes a row of column headers and a 2D data set to the
+    /// worksheet and adds a [`Table`] that covers the data, without the need
+    /// to calculate the table range separately. It is equivalent to setting
+    /// the column headers with
+    /// [`TableColumn::set_header()`](crate::TableColumn::set_header), writing
+    /// the data with [`Worksheet::write_row_matrix()`] and then calling
+    /// [`Worksheet::add_table()`] with the matching range.
+    ///
+    /// The table range is sized from the data. The number of columns is the
+    /// larger of the number of headers, the number of columns configured in
+    /// the `table` and the length of the longest data row. The range also
+    /// includes the header row and the total row, if they are enabled in the
+    /// `table`. Any other table properties, such as total functions, column
+    /// formulas and formats, are taken from the `table` and the column
+    /// headers override any headers that have already been set on its
+    /// columns.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number of the top left cell of the table.
+    /// - `col`: The zero indexed column number of the top left cell of the
+    ///   table.
+    /// - `headers`: An array or iterator of header captions for the table
+    ///   columns.
+    /// - `data`: 2D arrays or array-like data structures that implement
+    ///   [`IntoIterator`] and that contain a data type that implements
+    ///   [`IntoExcelData`]. Each item is a row of the table.
+    /// - `table`: The [`Table`] to add to the worksheet.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::TableError`] - A general error that is raised when a
+    ///   table parameter is incorrect or a table is configured incorrectly.
+    /// - [`XlsxError::TableRangeOverlaps`] - The table overlaps a previous
+    ///   table.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates writing a worksheet table from a set
+    /// of headers and a 2D data set.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_write_table.rs
+    /// #
+    /// # use rust_xlsxwriter::{ExcelCellValue, Table, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some sample data for the table.
+    ///     let headers = ["Product", "Quarter 1", "Quarter 2", "Quarter 3"];
+    ///     let data: Vec<Vec<ExcelCellValue>> = vec![
+    ///         vec!["Apples".into(), 10000.into(), 5000.into(), 8000.into()],
+    ///         vec!["Pears".into(), 2000.into(), 3000.into(), 4000.into()],
+    ///         vec!["Bananas".into(), 6000.into(), 6000.into(), 6500.into()],
+    ///         vec!["Oranges".into(), 500.into(), 300.into(), 200.into()],
+    ///     ];
+    ///
+    ///     // Set the column widths for clarity.
+    ///     worksheet.set_column_range_width(1, 4, 12)?;
+    ///
+    ///     // Write the headers, data and table in one step. The table range is
+    ///     // B2:E6.
+    ///     worksheet.write_table(1, 1, headers, data, &Table::new())?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn write_table<H, I, II>(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        headers: H,
+        data: I,
+        table: &Table,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        H: IntoIterator,
+        H::Item: Into<String>,
+        I: IntoIterator,
+        I::Item: IntoIterator<Item = II>,
+        II: IntoExcelData,
+    {
+        let data: Vec<Vec<II>> = data
+            .into_iter()
+            .map(|data_row| data_row.into_iter().collect())
+            .collect();
+
+        // Apply the headers to the table columns, adding columns as required.
+        let mut table = table.clone();
+        for (index, header) in headers.into_iter().enumerate() {
+            match table.columns.get_mut(index) {
+                Some(column) => column.name = header.into(),
+                None => table.columns.push(TableColumn::new().set_header(header)),
+            }
+        }
+
+        let num_cols = data
+            .iter()
+            .map(Vec::len)
+            .fold(table.columns.len(), usize::max);
+
+        if num_cols == 0 {
+            return Err(XlsxError::TableError(
+                "Table must have at least one column".to_string(),
+            ));
+        }
+
+        // Excel tables require at least one data row, even if it is blank.
+        let num_rows = data.len().max(1);
+
+        let first_data_row = row.saturating_add(u32::from(table.show_header_row));
+        let last_row = u32::try_from(num_rows - 1)
+            .unwrap_or(ROW_MAX)
+            .saturating_add(first_data_row)
+            .saturating_add(u32::from(table.show_total_row));
+        let last_col = u16::try_from(num_cols - 1)
+            .unwrap_or(COL_MAX)
+            .saturating_add(col);
+
+        // Check the full range before writing any data.
+        if !self.check_dimensions_only(row, col) || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(
+                self.limit_error("write_table", &range_location(row, col, last_row, last_col))
+            );
+        }
+
+        self.write_row_matrix(first_data_row, col, data)?;
+
+        self.add_table(row, col, last_row, last_col, &table)
+    }
+
+    /// Get the cell range of a table in the worksheet.
+    ///
+    /// Get the range of a worksheet table, including the header and total
+    /// rows, from the table name. This can be used to build defined names,
+    /// chart ranges or formulas that refer to the table without tracking the
+    /// table coordinates separately. The name lookup is case insensitive, like
+    /// Excel.
+    ///
+    /// Tables that use the default `Table1`, `Table2`, etc., names are only
+    /// given a name in the worksheet when the workbook is saved, since the
+    /// number depends on the tables in the other worksheets. Use
+    /// [`Workbook::table_range()`](crate::Workbook::table_range) to look up a
+    /// table by its default name before then, or use [`Table::set_name()`] to
+    /// give it a user defined name.
+    ///
+    /// # Parameters
+    ///
+    /// - `name`: The name of the table.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates getting the range of a table and
+    /// using it to create a defined name.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_table_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{Table, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     // Create a new Excel file object.
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Some sample data for the table.
+    ///     let headers = ["Quarter 1", "Quarter 2", "Quarter 3", "Quarter 4"];
+    ///     let data = [
+    ///         [10000, 5000, 8000, 6000],
+    ///         [2000, 3000, 4000, 5000],
+    ///         [6000, 6000, 6500, 6000],
+    ///     ];
+    ///
+    ///     // Add a named table to the worksheet.
+    ///     let table = Table::new().set_name("Sales");
+    ///     worksheet.write_table(2, 1, headers, data, &table)?;
+    ///
+    ///     // Get the table range and use it to create a defined name.
+    ///     let range = worksheet.table_range("Sales").unwrap();
+    ///     let formula = range.to_sheet_range(&worksheet.name());
+    ///     assert_eq!(formula, "Sheet1!$B$3:$E$6");
+    ///
+    ///     workbook.define_name("SalesData", &format!("={formula}"))?;
+    /// #
+    /// #     // Save the file to disk.
+    /// #     workbook.save("tables.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn table_range(&self, name: &str) -> Option<RangeRef> {
+        let name = name.to_lowercase();
+
+        self.tables
+            .iter()
+            .find(|table| table.name.to_lowercase() == name)
+            .map(|table| {
+                RangeRef::new(
+                    table.cell_range.first_row,
+                    table.cell_range.first_col,
+                    table.cell_range.last_row,
+                    table.cell_range.last_col,
+                )
+            })
+    }
+
     /// Add a conditional format to highlight cells based on rules.
     ///
     /// Conditional formatting is a feature of Excel which allows you to apply a
@@ -7520,16 +9555,79 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "add_conditional_format",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "add_conditional_format",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
-        let mut conditional_format = conditional_format.box_clone();
+        self.store_conditional_format(
+            first_row,
+            first_col,
+            last_row,
+            last_col,
+            conditional_format.box_clone(),
+        )
+    }
+
+    /// Add a conditional format to highlight cells based on rules, using an
+    /// `A1:B2` style range reference.
+    ///
+    /// This method is the same as [`Worksheet::add_conditional_format()`]
+    /// except that the range is specified as an `A1:B2` style string, or any
+    /// other type that implements [`IntoRangeRef`].
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: An `A1:B2` style range reference string, or any other type
+    ///   that implements [`IntoRangeRef`] such as a
+    ///   [`RangeRef`].
+    /// - `conditional_format`: A conditional format instance that implements
+    ///   the [`ConditionalFormat`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Invalid range reference.
+    /// - See [`Worksheet::add_conditional_format()`] for the other errors that
+    ///   can be returned.
+    ///
+    pub fn add_conditional_format_a1<T>(
+        &mut self,
+        range: impl IntoRangeRef,
+        conditional_format: &T,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: ConditionalFormat + Send,
+    {
+        let range = range.to_range_ref()?;
+
+        self.add_conditional_format(
+            range.first_row(),
+            range.first_col(),
+            range.last_row(),
+            range.last_col(),
+            conditional_format,
+        )
+    }
 
+    // Validate and store a conditional format. This is the common code for
+    // user and serialization conditional formats.
+    fn store_conditional_format(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        mut conditional_format: Box<dyn ConditionalFormat + Send>,
+    ) -> Result<&mut Worksheet, XlsxError> {
         // Store the conditional formats based on their range.
         let mut cell_range = utility::cell_range(first_row, first_col, last_row, last_col);
         let multi_range = conditional_format.multi_range();
@@ -7678,12 +9776,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "add_data_validation",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "add_data_validation",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         let mut data_validation = data_validation.clone();
@@ -7785,7 +9889,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("add_sparkline", &cell_location(row, col)));
         }
 
         // Check that the sparkline has a range.
@@ -7921,12 +10025,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "add_sparkline_group",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "add_sparkline_group",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check that the sparkline has a range.
@@ -8320,12 +10430,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "unprotect_range_with_options",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "unprotect_range_with_options",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         let range = utility::cell_range(first_row, first_col, last_row, last_col);
@@ -8341,6 +10457,134 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Ignore an Excel error or warning in a worksheet cell.
+    ///
+    /// Excel flags a number of conditions in cells, such as numbers stored as
+    /// text or formulas that differ from their neighbors, as potential errors
+    /// and displays a small green triangle in the top left corner of the cell.
+    /// The `ignore_error()` method can be used to turn off these warnings for
+    /// cells where the condition is intentional.
+    ///
+    /// The error types are defined by the [`IgnoreError`] enum. Several
+    /// error types can be ignored for the same cell by passing an array or
+    /// slice of values, or with multiple calls.
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    /// - `error_types`: An [`IgnoreError`] enum value, or an array, slice or
+    ///   vector of values.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates ignoring the Excel warnings for
+    /// numbers stored as text.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_ignore_error.rs
+    /// #
+    /// # use rust_xlsxwriter::{IgnoreError, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Write some numbers stored as text, such as zip codes.
+    ///     worksheet.write(0, 0, "02134")?;
+    ///     worksheet.write_column(1, 0, ["02139", "02142", "02151"])?;
+    ///
+    ///     // Turn off the warning for a single cell.
+    ///     worksheet.ignore_error(0, 0, IgnoreError::NumberStoredAsText)?;
+    ///
+    ///     // Turn off the warning for a range of cells.
+    ///     worksheet.ignore_error_range(1, 0, 3, 0, IgnoreError::NumberStoredAsText)?;
+    ///
+    ///     // Turn off several warnings for a cell with a formula.
+    ///     worksheet.write_formula(4, 0, "=1/0")?;
+    ///     worksheet.ignore_error(4, 0, [IgnoreError::EvalError, IgnoreError::FormulaUnlocked])?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn ignore_error(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        error_types: impl AsRef<[IgnoreError]>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(self.limit_error("ignore_error", &cell_location(row, col)));
+        }
+
+        let cell = utility::row_col_to_cell(row, col);
+        self.store_ignored_errors(error_types.as_ref(), &cell);
+
+        Ok(self)
+    }
+
+    /// Ignore an Excel error or warning in a range of worksheet cells.
+    ///
+    /// This is the range version of [`Worksheet::ignore_error()`]. See that
+    /// method for details and an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    /// - `error_types`: An [`IgnoreError`] enum value, or an array, slice or
+    ///   vector of values.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    pub fn ignore_error_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        error_types: impl AsRef<[IgnoreError]>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(self.limit_error(
+                "ignore_error_range",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(self.order_error(
+                "ignore_error_range",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+        self.store_ignored_errors(error_types.as_ref(), &range);
+
+        Ok(self)
+    }
+
     /// Set the selected cell or cells in a worksheet.
     ///
     /// The `set_selection()` method can be used to specify which cell or range
@@ -8408,30 +10652,108 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_selection",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
-        // The first/last order can be reversed to allow a selection to go from
-        // the end to the start. We take the active cell from the user first
-        // row/col and then reverse them as required for the full range.
-        let active_cell = utility::row_col_to_cell(first_row, first_col);
+        self.selected_range = Self::selection_range(first_row, first_col, last_row, last_col);
 
-        let mut first_row = first_row;
-        let mut first_col = first_col;
-        let mut last_row = last_row;
-        let mut last_col = last_col;
+        Ok(self)
+    }
 
-        if first_row > last_row {
-            std::mem::swap(&mut first_row, &mut last_row);
-        }
+    /// Select several ranges of cells in a worksheet.
+    ///
+    /// The `set_selection_ranges()` method is similar to
+    /// [`Worksheet::set_selection()`] except that it can be used to select
+    /// multiple, non-contiguous, ranges of cells. This is the same as holding
+    /// down the `Ctrl` key while selecting cells in Excel.
+    ///
+    /// The active cell is the first cell of the first range, determined in
+    /// the same way as `set_selection()`.
+    ///
+    /// # Parameters
+    ///
+    /// - `ranges`: An iterator of ranges that implement [`IntoRangeRef`] such
+    ///   as [`RangeRef`], `(first_row, first_col, last_row, last_col)` tuples
+    ///   or `A1:B2` style range strings.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::ParameterError`] - No ranges were specified or a range
+    ///   string isn't a valid range reference.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates selecting several ranges of cells in
+    /// a worksheet and scrolling the worksheet to show them.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_selection_ranges.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Select cells B22:C24 and E22:E30. The active cell is B22.
+    ///     worksheet.set_selection_ranges(["B22:C24", "E22:E30"])?;
+    ///
+    ///     // Scroll the worksheet so that the selection is visible.
+    ///     worksheet.set_top_left_cell(20, 0)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_selection_ranges<T>(
+        &mut self,
+        ranges: impl IntoIterator<Item = T>,
+    ) -> Result<&mut Worksheet, XlsxError>
+    where
+        T: IntoRangeRef,
+    {
+        let mut active_cell = String::new();
+        let mut sqref = vec![];
 
-        if first_col > last_col {
-            std::mem::swap(&mut first_col, &mut last_col);
+        for range in ranges {
+            let range = range.to_range_ref()?;
+            let (first_row, first_col) = (range.first_row(), range.first_col());
+            let (last_row, last_col) = (range.last_row(), range.last_col());
+
+            // Check rows and cols are in the allowed range.
+            if !self.check_dimensions_only(first_row, first_col)
+                || !self.check_dimensions_only(last_row, last_col)
+            {
+                return Err(self.limit_error(
+                    "set_selection_ranges",
+                    &range_location(first_row, first_col, last_row, last_col),
+                ));
+            }
+
+            let (cell, range) = Self::selection_range(first_row, first_col, last_row, last_col);
+
+            if active_cell.is_empty() {
+                active_cell = cell;
+            }
+
+            sqref.push(range);
         }
 
-        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+        if sqref.is_empty() {
+            return Err(XlsxError::ParameterError(
+                "set_selection_ranges() requires at least one range".to_string(),
+            ));
+        }
 
-        self.selected_range = (active_cell, range);
+        self.selected_range = (active_cell, sqref.join(" "));
 
         Ok(self)
     }
@@ -8491,7 +10813,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_top_left_cell", &cell_location(row, col)));
         }
 
         // Ignore cell (0, 0) since that is the default top-left cell.
@@ -8726,7 +11048,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_cell_format", &cell_location(row, col)));
         }
 
         // Get the index of the format object.
@@ -8833,12 +11155,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_range_format",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_range_format",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Get the index of the format object.
@@ -8954,12 +11282,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_range_format_with_border",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_range_format_with_border",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         if first_row == last_row && first_col == last_col {
@@ -9132,9 +11466,302 @@ impl Worksheet {
             return self;
         }
 
-        self.clear_cell_format_internal(row, col);
+        self.clear_cell_format_internal(row, col);
+
+        self
+    }
+
+    /// Get the value of a cell that has been written to the worksheet.
+    ///
+    /// The `cell_value()` method returns a copy of the data stored for a cell
+    /// as a [`CellValue`], or `None` if no data has been written to the cell.
+    /// It can be used to inspect previously written data, for example to
+    /// create summary data or for testing, without having to maintain a
+    /// separate copy of it.
+    ///
+    /// Cells that have only been formatted, via [`Worksheet::write_blank()`]
+    /// for example, are returned as [`CellValue::Blank`].
+    ///
+    /// # Parameters
+    ///
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates reading back the values of cells
+    /// written to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_cell_value.rs
+    /// #
+    /// # use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write(0, 0, "Total")?;
+    ///     worksheet.write(0, 1, 123.5)?;
+    ///
+    ///     assert_eq!(
+    ///         worksheet.cell_value(0, 0),
+    ///         Some(CellValue::String("Total".to_string()))
+    ///     );
+    ///     assert_eq!(worksheet.cell_value(0, 1), Some(CellValue::Number(123.5)));
+    ///     assert_eq!(worksheet.cell_value(0, 2), None);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn cell_value(&self, row: RowNum, col: ColNum) -> Option<CellValue> {
+        self.data_table
+            .get(&row)
+            .and_then(|columns| columns.get(&col))
+            .map(CellValue::from_cell)
+    }
+
+    /// Get an iterator over the cells that have been written to the worksheet.
+    ///
+    /// The `cells()` method returns an iterator of `(row, col, value)` tuples
+    /// for the cells that contain data, in row and then column order. See
+    /// [`Worksheet::cell_value()`] to get the value of a single cell.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates iterating over the cells written to
+    /// a worksheet to sum the numeric values.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_cells.rs
+    /// #
+    /// # use rust_xlsxwriter::{CellValue, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     worksheet.write_column(0, 0, ["Apples", "Pears", "Plums"])?;
+    ///     worksheet.write_column(0, 1, [10, 20, 30])?;
+    ///
+    ///     // Sum the numbers in the worksheet.
+    ///     let total: f64 = worksheet
+    ///         .cells()
+    ///         .filter_map(|(_, _, value)| match value {
+    ///             CellValue::Number(number) => Some(number),
+    ///             _ => None,
+    ///         })
+    ///         .sum();
+    ///
+    ///     worksheet.write(3, 1, total)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn cells(&self) -> impl Iterator<Item = (RowNum, ColNum, CellValue)> + '_ {
+        self.data_table.iter().flat_map(|(row, columns)| {
+            columns
+                .iter()
+                .map(move |(col, cell)| (*row, *col, CellValue::from_cell(cell)))
+        })
+    }
+
+    /// Copy a range of cells from another worksheet.
+    ///
+    /// The `copy_range_from()` method copies the data and formatting of a
+    /// range of cells in another worksheet to a location in the current
+    /// worksheet. This can be used to build multi-sheet reports from a
+    /// "template" worksheet.
+    ///
+    /// The following data is copied:
+    ///
+    /// - Cell values and their formats.
+    /// - Formulas. Relative cell references in the formulas are adjusted for
+    ///   the new location in the same way as Excel does when a formula is
+    ///   copied. Absolute references such as `$A$1` aren't changed.
+    ///   References that would be moved outside the worksheet are replaced with
+    ///   `#REF!`.
+    /// - Merged ranges that are fully contained in the source range.
+    ///
+    /// Cells in the destination range that don't have data in the source range
+    /// aren't changed. Images embedded in cells aren't copied.
+    ///
+    /// # Parameters
+    ///
+    /// - `source`: The [`Worksheet`] to copy the cells from.
+    /// - `first_row`: The first row of the source range. (All zero indexed.)
+    /// - `first_col`: The first column of the source range.
+    /// - `last_row`: The last row of the source range.
+    /// - `last_col`: The last column of the source range.
+    /// - `dest_row`: The top row of the destination range.
+    /// - `dest_col`: The leftmost column of the destination range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Source or destination row or
+    ///   column exceeds Excel's worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    /// - [`XlsxError::MergeRangeOverlaps`] - A copied merged range overlaps a
+    ///   merged range in the current worksheet.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates copying a formatted range of cells
+    /// from one worksheet to another.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_copy_range_from.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, Worksheet, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    ///     let mut workbook = Workbook::new();
+    ///     let bold = Format::new().set_bold();
+    ///
+    ///     // Create a template worksheet with a title and some totals.
+    ///     let mut template = Worksheet::new();
+    ///     template.merge_range(0, 0, 0, 2, "Quarterly Report", &bold)?;
+    ///     template.write_row(1, 0, [10, 20, 30])?;
+    ///     template.write_row(2, 0, [40, 50, 60])?;
+    ///     template.write_formula_with_format(3, 0, "=SUM(A2:A3)", &bold)?;
+    ///
+    ///     // Copy the template to the top of a report worksheet.
+    ///     let worksheet = workbook.add_worksheet();
+    ///     worksheet.copy_range_from(&template, 0, 0, 3, 2, 0, 0)?;
+    ///
+    ///     // Copy it again lower down. The formula is adjusted to "=SUM(A8:A9)".
+    ///     worksheet.copy_range_from(&template, 0, 0, 3, 2, 6, 0)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[allow(clippy::too_many_arguments)]
+    pub fn copy_range_from(
+        &mut self,
+        source: &Worksheet,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        dest_row: RowNum,
+        dest_col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(self.order_error(
+                "copy_range_from",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        // Check that the source and destination ranges are in the allowed
+        // range.
+        let dest_last_row = u64::from(dest_row) + u64::from(last_row - first_row);
+        let dest_last_col = u64::from(dest_col) + u64::from(last_col - first_col);
 
-        self
+        if last_row >= ROW_MAX
+            || last_col >= COL_MAX
+            || dest_last_row >= u64::from(ROW_MAX)
+            || dest_last_col >= u64::from(COL_MAX)
+        {
+            return Err(self.limit_error(
+                "copy_range_from",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        let row_offset = i64::from(dest_row) - i64::from(first_row);
+        let col_offset = i64::from(dest_col) - i64::from(first_col);
+
+        for (row, columns) in source.data_table.range(first_row..=last_row) {
+            for (col, cell) in columns.range(first_col..=last_col) {
+                let mut cell = cell.clone();
+
+                // Map the source format to a format index in this worksheet.
+                let xf_index = match &mut cell {
+                    CellType::Blank { xf_index, .. }
+                    | CellType::Boolean { xf_index, .. }
+                    | CellType::Number { xf_index, .. }
+                    | CellType::DateTime { xf_index, .. }
+                    | CellType::String { xf_index, .. }
+                    | CellType::RichString { xf_index, .. }
+                    | CellType::Formula { xf_index, .. }
+                    | CellType::ArrayFormula { xf_index, .. } => xf_index,
+
+                    // Embedded images refer to data in the source workbook.
+                    CellType::Error { .. } => continue,
+                };
+
+                if *xf_index != 0 {
+                    let format = &source.xf_formats[*xf_index as usize];
+                    *xf_index = self.format_xf_index(format);
+                }
+
+                // Update the worksheet properties for the cell data type.
+                match &mut cell {
+                    CellType::String { string_id, .. } | CellType::RichString { string_id, .. } => {
+                        *string_id = 0;
+                        self.uses_string_table = true;
+                    }
+                    CellType::Formula { formula, .. } => {
+                        *formula =
+                            utility::shift_formula_references(formula, row_offset, col_offset)
+                                .into_boxed_str();
+                    }
+                    CellType::ArrayFormula {
+                        formula,
+                        range,
+                        is_dynamic,
+                        ..
+                    } => {
+                        *formula =
+                            utility::shift_formula_references(formula, row_offset, col_offset)
+                                .into_boxed_str();
+                        *range = utility::shift_formula_references(range, row_offset, col_offset)
+                            .into_boxed_str();
+
+                        if *is_dynamic {
+                            self.has_dynamic_arrays = true;
+                        }
+                    }
+                    _ => {}
+                }
+
+                let dest_row = (i64::from(*row) + row_offset) as RowNum;
+                let dest_col = (i64::from(*col) + col_offset) as ColNum;
+
+                self.check_dimensions(dest_row, dest_col);
+                self.insert_cell(dest_row, dest_col, cell);
+            }
+        }
+
+        // Copy the merged ranges that are fully contained in the source range.
+        for merged_range in &source.merged_ranges {
+            if merged_range.first_row >= first_row
+                && merged_range.first_col >= first_col
+                && merged_range.last_row <= last_row
+                && merged_range.last_col <= last_col
+            {
+                self.store_merge_range(
+                    (i64::from(merged_range.first_row) + row_offset) as RowNum,
+                    (i64::from(merged_range.first_col) + col_offset) as ColNum,
+                    (i64::from(merged_range.last_row) + row_offset) as RowNum,
+                    (i64::from(merged_range.last_col) + col_offset) as ColNum,
+                )?;
+            }
+        }
+
+        Ok(self)
     }
 
     // -----------------------------------------------------------------------
@@ -9277,6 +11904,91 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Serialize the items of an iterator to a worksheet.
+    ///
+    /// This method is similar to [`Worksheet::serialize()`] except that it
+    /// serializes each item of an iterator, row by row, beneath the headers.
+    /// This allows data to be generated or read lazily, for example from a
+    /// database cursor or a file, without first collecting it into a `Vec`.
+    ///
+    /// As with `serialize()` the headers and location of the data must be set
+    /// up first with one of the header methods, see [Setting serialization
+    /// headers](crate::serializer#setting-serialization-headers).
+    ///
+    /// # Parameters
+    ///
+    /// - `data`: An iterator, or anything that can be converted into an
+    ///   iterator, of items that implement the [`serde::Serialize`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::MaxStringLengthExceeded`] - String exceeds Excel's limit
+    ///   of 32,767 characters.
+    /// - [`XlsxError::SerdeError`] - Errors encountered during the Serde
+    ///   serialization.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates serializing the items of an iterator
+    /// to a worksheet.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialize_iter.rs
+    /// #
+    /// # use rust_xlsxwriter::{Format, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     // Add a simple format for the headers.
+    /// #     let format = Format::new().set_bold();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct Square {
+    ///         number: u32,
+    ///         square: u32,
+    ///     }
+    ///
+    ///     // Set up the start location and headers of the data to be serialized.
+    ///     worksheet.deserialize_headers_with_format::<Square>(0, 0, &format)?;
+    ///
+    ///     // Serialize the data from an iterator, without collecting it first.
+    ///     let squares = (1..=10).map(|number| Square {
+    ///         number,
+    ///         square: number * number,
+    ///     });
+    ///
+    ///     worksheet.serialize_iter(squares)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialize_iter<I>(&mut self, data: I) -> Result<&mut Worksheet, XlsxError>
+    where
+        I: IntoIterator,
+        I::Item: Serialize,
+    {
+        for data_structure in data {
+            self.serialize_data_structure(&data_structure)?;
+        }
+
+        Ok(self)
+    }
+
     /// Write the location and headers for data serialization.
     ///
     /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
@@ -9547,12 +12259,9 @@ impl Worksheet {
         T: Serialize,
     {
         // Serialize the struct to determine the type name and the fields.
-        let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
-        };
+        let mut headers = SerializerHeader::default();
 
-        data_structure.serialize(&mut headers)?;
+        headers.capture(data_structure)?;
 
         self.store_serialization_headers(row, col, &headers, format)
     }
@@ -9688,16 +12397,34 @@ impl Worksheet {
     where
         T: Serialize,
     {
-        // Serialize the struct to determine the type name and the fields.
+        // Serialize the struct to determine the type name and the fields, and
+        // the fields of any nested structs if group headers are required.
         let mut headers = SerializerHeader {
-            struct_name: String::new(),
-            field_names: vec![],
+            capture_groups: header_options.group_headers,
+            capture_sequences: header_options.expand_sequences,
+            ..Default::default()
         };
 
-        data_structure.serialize(&mut headers)?;
+        headers.capture(data_structure)?;
         self.store_serialization_headers_with_options(row, col, &headers, header_options)
     }
 
+    // Check if the serialization headers for the type of a data structure have
+    // been set up in the worksheet.
+    #[cfg(feature = "serde")]
+    pub(crate) fn has_serialization_headers<T>(&self, data_structure: &T) -> Result<bool, XlsxError>
+    where
+        T: Serialize,
+    {
+        let mut headers = SerializerHeader::default();
+        headers.capture(data_structure)?;
+
+        Ok(self
+            .serializer_state
+            .structs
+            .contains_key(&headers.struct_name))
+    }
+
     /// Write the location and headers for data serialization.
     ///
     /// The [`Worksheet::serialize()`] method, above, serializes Serde derived
@@ -10162,6 +12889,9 @@ impl Worksheet {
     /// range of the data that was written. The dimensions are returned as a
     /// `(min_row, min_col, max_row, max_col)` tuple in a `Result<>`.
     ///
+    /// See also [`Worksheet::serialized_range()`] which gets the dimensions
+    /// from the struct type instead of the name.
+    ///
     /// # Parameters
     ///
     /// - `struct_name`: The name/type of the target struct as a string.
@@ -10389,6 +13119,252 @@ impl Worksheet {
             .get_column_dimensions(struct_name, field_name)
     }
 
+    /// Get the range of a serialized struct type.
+    ///
+    /// This method is similar to [`Worksheet::get_serialize_dimensions()`]
+    /// except that the struct is specified by type rather than by name. This
+    /// avoids hard coding the struct name, which may be different from the
+    /// Rust type name if the struct has a Serde `rename` attribute.
+    ///
+    /// The range is returned as a `(first_row, first_col, last_row, last_col)`
+    /// tuple. It includes the header row, if any, and the data serialized so
+    /// far. As with `deserialize_headers()` the struct type must derive the
+    /// Serde [`Deserialize`] trait.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct type.
+    ///
+    /// # Examples
+    ///
+    /// Example of getting the range of some serialized data and using it to
+    /// add a chart.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_serialized_range.rs
+    /// #
+    /// # use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename = "Sales")]
+    ///     struct MonthlySales {
+    ///         month: &'static str,
+    ///         sales: u32,
+    ///     }
+    ///
+    ///     let data = [
+    ///         MonthlySales {
+    ///             month: "Jan",
+    ///             sales: 120,
+    ///         },
+    ///         MonthlySales {
+    ///             month: "Feb",
+    ///             sales: 150,
+    ///         },
+    ///         MonthlySales {
+    ///             month: "Mar",
+    ///             sales: 135,
+    ///         },
+    ///     ];
+    ///
+    ///     // Set the serialization location and headers and serialize the data.
+    ///     worksheet.deserialize_headers::<MonthlySales>(1, 1)?;
+    ///     worksheet.serialize(&data)?;
+    ///
+    ///     // Get the range of the serialized data.
+    ///     let (first_row, first_col, last_row, last_col) =
+    ///         worksheet.serialized_range::<MonthlySales>()?;
+    ///
+    ///     // Create a chart of the data, skipping the header row.
+    ///     let mut chart = Chart::new(ChartType::Column);
+    ///     chart
+    ///         .add_series()
+    ///         .set_categories(("Sheet1", first_row + 1, first_col, last_row, first_col))
+    ///         .set_values(("Sheet1", first_row + 1, last_col, last_row, last_col));
+    ///
+    ///     worksheet.insert_chart(1, 4, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn serialized_range<'de, T>(
+        &mut self,
+    ) -> Result<(RowNum, ColNum, RowNum, ColNum), XlsxError>
+    where
+        T: Deserialize<'de>,
+    {
+        let headers = deserialize_headers::<T>();
+
+        self.serializer_state.get_dimensions(&headers.struct_name)
+    }
+
+    /// Create a chart from the data of a serialized struct type.
+    ///
+    /// This method creates a new [`Chart`] with data series that refer to the
+    /// columns written by [`Worksheet::serialize()`] for a struct type. The
+    /// first column of the serialized data is used for the chart categories
+    /// and each of the other columns is added as a data series, using the
+    /// column header, if shown, as the series name. If the data was serialized
+    /// from a map with a row label column then that column is used for the
+    /// categories instead. Skipped fields are ignored.
+    ///
+    /// The ranges cover the data serialized up to the point where this method
+    /// is called so it should be called after the data is serialized. The
+    /// chart can be further configured, like any other chart, before it is
+    /// inserted into a worksheet with [`Worksheet::insert_chart()`].
+    ///
+    /// As with `deserialize_headers()` the struct type must derive the Serde
+    /// [`Deserialize`] trait.
+    ///
+    /// # Parameters
+    ///
+    /// - `chart_type`: The [`ChartType`] of the chart.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Unknown or unserialized struct type
+    ///   or no data has been serialized for it.
+    ///
+    /// # Examples
+    ///
+    /// Example of creating a chart from some serialized data.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_chart_from_serialized.rs
+    /// #
+    /// # use rust_xlsxwriter::{ChartType, Workbook, XlsxError};
+    /// # use serde::{Deserialize, Serialize};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Create a serializable struct.
+    ///     #[derive(Deserialize, Serialize)]
+    ///     #[serde(rename_all = "PascalCase")]
+    ///     struct MonthlySales {
+    ///         month: &'static str,
+    ///         north: u32,
+    ///         south: u32,
+    ///     }
+    ///
+    ///     let data = [
+    ///         MonthlySales {
+    ///             month: "Jan",
+    ///             north: 120,
+    ///             south: 90,
+    ///         },
+    ///         MonthlySales {
+    ///             month: "Feb",
+    ///             north: 150,
+    ///             south: 110,
+    ///         },
+    ///         MonthlySales {
+    ///             month: "Mar",
+    ///             north: 135,
+    ///             south: 140,
+    ///         },
+    ///     ];
+    ///
+    ///     // Set the serialization location and headers and serialize the data.
+    ///     worksheet.deserialize_headers::<MonthlySales>(0, 0)?;
+    ///     worksheet.serialize(&data)?;
+    ///
+    ///     // Create a chart of the serialized data, with a series for each region.
+    ///     let mut chart = worksheet.chart_from_serialized::<MonthlySales>(ChartType::Column)?;
+    ///     chart.title().set_name("Sales by region");
+    ///
+    ///     worksheet.insert_chart(1, 4, &chart)?;
+    /// #
+    /// #     // Save the file.
+    /// #     workbook.save("serialize.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    #[cfg(feature = "serde")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
+    pub fn chart_from_serialized<'de, T>(&self, chart_type: ChartType) -> Result<Chart, XlsxError>
+    where
+        T: Deserialize<'de>,
+    {
+        let headers = deserialize_headers::<T>();
+        let struct_name = &headers.struct_name;
+
+        let Some(header_config) = self.serializer_state.structs.get(struct_name) else {
+            return Err(XlsxError::ParameterError(format!(
+                "Unknown serialized struct '{struct_name}'"
+            )));
+        };
+
+        let first_row = header_config.first_data_row;
+        if header_config.max_row <= first_row {
+            return Err(XlsxError::ParameterError(format!(
+                "No data has been serialized for struct '{struct_name}'"
+            )));
+        }
+        let last_row = header_config.max_row - 1;
+
+        // Get the serialized columns in worksheet order.
+        let mut columns: Vec<ColNum> = header_config
+            .fields
+            .values()
+            .filter(|field| !field.skip)
+            .map(|field| field.col)
+            .collect();
+        columns.sort_unstable();
+        columns.dedup();
+
+        // Use the map row label column, or the first column, as the categories.
+        let category_col = match header_config.map_key_col {
+            Some(col) => Some(col),
+            None if columns.len() > 1 => Some(columns.remove(0)),
+            None => None,
+        };
+
+        let sheet_name = self.name.as_str();
+        let mut chart = Chart::new(chart_type);
+
+        for col in columns {
+            let series = chart.add_series();
+            series.set_values((sheet_name, first_row, col, last_row, col));
+
+            if let Some(category_col) = category_col {
+                series.set_categories((
+                    sheet_name,
+                    first_row,
+                    category_col,
+                    last_row,
+                    category_col,
+                ));
+            }
+
+            // Use the header, if there is one, as the series name.
+            if first_row > header_config.min_row {
+                series.set_name((sheet_name, first_row - 1, col));
+            }
+        }
+
+        Ok(chart)
+    }
+
     // Store serialization headers and options.
     #[cfg(feature = "serde")]
     fn store_serialization_headers_with_options(
@@ -10398,8 +13374,43 @@ impl Worksheet {
         headers: &SerializerHeader,
         header_options: &SerializeFieldOptions,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check that any custom field names match the actual field names.
-        let field_names: HashSet<String> = HashSet::from_iter(headers.field_names.clone());
+        // Get the fields of any nested structs to write under group headers.
+        let groups: HashMap<&String, &Vec<String>> = if header_options.group_headers {
+            headers
+                .group_fields
+                .iter()
+                .map(|(group, nested_names)| (group, nested_names))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // Get the sequence fields, and their lengths, to write across several
+        // columns.
+        let sequences: HashMap<&String, usize> = if header_options.expand_sequences {
+            headers
+                .sequence_fields
+                .iter()
+                .map(|(field_name, len)| (field_name, *len))
+                .collect()
+        } else {
+            HashMap::new()
+        };
+
+        // Check that any custom field names match the actual field names. The
+        // fields of nested structs are referred to as "group.field" and the
+        // columns of sequence fields as "field_1", "field_2", etc.
+        let mut field_names: HashSet<String> = HashSet::from_iter(headers.field_names.clone());
+        for (group, nested_names) in &groups {
+            for nested_name in *nested_names {
+                field_names.insert(format!("{group}.{nested_name}"));
+            }
+        }
+        for (field_name, len) in &sequences {
+            for index in 1..=*len {
+                field_names.insert(format!("{field_name}_{index}"));
+            }
+        }
         for custom_header in &header_options.custom_headers {
             if !field_names.contains(&custom_header.field_name) {
                 return Err(XlsxError::ParameterError(format!(
@@ -10419,6 +13430,7 @@ impl Worksheet {
         // Clone the header options to modify it and store it internally.
         let mut header_options = header_options.clone();
         header_options.struct_name = headers.struct_name.clone();
+        header_options.type_path = headers.type_path.clone();
 
         // Create a "custom" header for default fields or replace them with user
         // specified custom fields. The "use_custom_headers_only" overrides the
@@ -10427,6 +13439,62 @@ impl Worksheet {
             let mut custom_headers: Vec<CustomSerializeField> = vec![];
 
             for field_name in &headers.field_names {
+                // Replace nested structs with their fields, unless skipped.
+                if let Some(nested_names) = groups.get(field_name) {
+                    if custom_fields
+                        .get(field_name)
+                        .is_some_and(|field| field.skip)
+                    {
+                        continue;
+                    }
+
+                    for nested_name in *nested_names {
+                        let nested_field = format!("{field_name}.{nested_name}");
+                        match custom_fields.get(&nested_field) {
+                            Some(custom_field) => {
+                                if !custom_field.skip {
+                                    custom_headers.push((*custom_field).clone());
+                                }
+                            }
+                            None => custom_headers.push(CustomSerializeField::new(nested_field)),
+                        }
+                    }
+
+                    continue;
+                }
+
+                // Replace sequences with a field for each element. The options
+                // of the sequence field, if any, are the defaults for each
+                // element field.
+                if let Some(len) = sequences.get(field_name) {
+                    let sequence_field = custom_fields.get(field_name);
+                    if sequence_field.is_some_and(|field| field.skip) {
+                        continue;
+                    }
+
+                    for index in 1..=*len {
+                        let element_field = format!("{field_name}_{index}");
+                        let custom_header =
+                            match (custom_fields.get(&element_field), sequence_field) {
+                                (Some(custom_field), _) => (*custom_field).clone(),
+                                (None, Some(sequence_field)) => {
+                                    let mut custom_field = (*sequence_field).clone();
+                                    custom_field.field_name.clone_from(&element_field);
+                                    custom_field.header_name =
+                                        format!("{}_{index}", sequence_field.header_name);
+                                    custom_field
+                                }
+                                (None, None) => CustomSerializeField::new(&element_field),
+                            };
+
+                        if !custom_header.skip {
+                            custom_headers.push(custom_header);
+                        }
+                    }
+
+                    continue;
+                }
+
                 match custom_fields.get(field_name) {
                     Some(custom_field) => {
                         if !custom_field.skip {
@@ -10440,6 +13508,54 @@ impl Worksheet {
             header_options.custom_headers = custom_headers;
         }
 
+        // Mark the element fields of sequences so that the sequence values are
+        // serialized across the columns.
+        if !sequences.is_empty() {
+            for custom_header in &mut header_options.custom_headers {
+                let Some((field_name, index)) = custom_header.field_name.rsplit_once('_') else {
+                    continue;
+                };
+
+                let Some(len) = sequences.get(&field_name.to_string()) else {
+                    continue;
+                };
+
+                if index
+                    .parse::<usize>()
+                    .is_ok_and(|index| index >= 1 && index <= *len)
+                {
+                    custom_header.sequence_name = Some(field_name.to_string());
+                }
+            }
+        }
+
+        // Set the group header of the nested struct fields. The group header
+        // is the parent field name, or its custom name and format, if any.
+        if !groups.is_empty() {
+            header_options
+                .custom_headers
+                .retain(|field| !groups.contains_key(&field.field_name));
+
+            for custom_header in &mut header_options.custom_headers {
+                let Some((group, nested_name)) = custom_header.field_name.split_once('.') else {
+                    continue;
+                };
+
+                if !groups.contains_key(&group.to_string()) {
+                    continue;
+                }
+
+                if custom_header.header_name == custom_header.field_name {
+                    custom_header.header_name = nested_name.to_string();
+                }
+
+                custom_header.group_header = match custom_fields.get(&group.to_string()) {
+                    Some(field) => Some((field.header_name.clone(), field.header_format.clone())),
+                    None => Some((group.to_string(), None)),
+                };
+            }
+        }
+
         self.store_custom_serialization_headers(row, col, &header_options)
     }
 
@@ -10462,6 +13578,7 @@ impl Worksheet {
         // Transfer the options to a default option struct.
         let header_options = SerializeFieldOptions {
             struct_name: headers.struct_name.clone(),
+            type_path: headers.type_path.clone(),
             header_format: Some(header_format.clone()),
             custom_headers,
             ..Default::default()
@@ -10481,7 +13598,7 @@ impl Worksheet {
         // Check row and columns are in the allowed range.
 
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         // Check for empty struct name.
@@ -10499,14 +13616,49 @@ impl Worksheet {
             )));
         }
 
+        let write_headers = header_options.has_headers;
+
+        // If there are nested struct fields with group headers then the group
+        // headers are written in the first row and the field headers below.
+        let has_groups = write_headers
+            && header_options
+                .custom_headers
+                .iter()
+                .any(|field| !field.skip && field.group_header.is_some());
+        let header_row = if has_groups { row + 1 } else { row };
+
         let mut fields = HashMap::new();
-        let min_row = row;
+        let mut groups = HashSet::new();
+        let mut sequences = HashSet::new();
+        let mut group_ranges: Vec<(String, String, Option<Format>, ColNum, ColNum)> = vec![];
+        let min_row = header_row;
         let min_col = col;
-        let mut max_row = row;
+        let mut max_row = header_row;
         let mut max_col = col;
 
-        let col_initial = col;
-        let write_headers = header_options.has_headers;
+        let mut col_initial = col;
+
+        // Write the row label column header for maps of structs, if required.
+        let mut map_key_col = None;
+        if let Some(map_key_header) = &header_options.map_key_header {
+            if write_headers {
+                match &header_options.header_format {
+                    Some(format) => {
+                        self.write_with_format(header_row, col, map_key_header, format)?
+                    }
+                    None => self.write(header_row, col, map_key_header)?,
+                };
+
+                if has_groups {
+                    if let Some(format) = &header_options.header_format {
+                        self.write_blank(row, col, format)?;
+                    }
+                }
+            }
+
+            map_key_col = Some(col);
+            col_initial += 1;
+        }
 
         let mut col_offset = 0;
         for custom_header in &header_options.custom_headers {
@@ -10532,6 +13684,39 @@ impl Worksheet {
                 self.set_column_format(col, format)?;
             }
 
+            // Store the name of the parent sequence of sequence element fields.
+            if let Some(sequence_name) = &custom_header.sequence_name {
+                sequences.insert(sequence_name.clone());
+            }
+
+            // Store the column range of the group header, if any, for nested
+            // struct fields.
+            if let Some((group_name, group_format)) = &custom_header.group_header {
+                let group = custom_header
+                    .field_name
+                    .split_once('.')
+                    .map_or(String::new(), |(group, _)| group.to_string());
+
+                match group_ranges.last_mut() {
+                    Some(range) if range.0 == group && range.4 + 1 == col => range.4 = col,
+                    _ if !has_groups => {}
+                    _ => group_ranges.push((
+                        group.clone(),
+                        group_name.clone(),
+                        group_format.clone(),
+                        col,
+                        col,
+                    )),
+                }
+
+                groups.insert(group);
+            } else if has_groups {
+                // Extend the header row format to the cell above the header.
+                if let Some(format) = &header_options.header_format {
+                    self.write_blank(row, col, format)?;
+                }
+            }
+
             // Use the column specific header format or else the header row
             // format, and if neither of those have been specified then write
             // without a format.
@@ -10548,6 +13733,19 @@ impl Worksheet {
             fields.insert(custom_header.field_name.clone(), custom_header);
         }
 
+        // Write the group headers, merged across the nested struct columns.
+        for (_, group_name, group_format, first_col, last_col) in group_ranges {
+            let format = group_format
+                .or_else(|| header_options.header_format.clone())
+                .unwrap_or_default();
+
+            if first_col == last_col {
+                self.write_with_format(row, first_col, &group_name, &format)?;
+            } else {
+                self.merge_range(row, first_col, row, last_col, &group_name, &format)?;
+            }
+        }
+
         // Start the data serialization one row down if headers were written.
         if write_headers {
             max_row += 1;
@@ -10560,9 +13758,16 @@ impl Worksheet {
             .structs
             .get_mut(&header_options.struct_name)
         {
-            if let Some(table_data) = header_config.get_table() {
+            let table_data = header_config.get_table();
+            let conditional_formats = header_config.get_conditional_formats();
+
+            if let Some(table_data) = table_data {
                 self.write_serialized_table(&table_data)?;
             }
+
+            for conditional_format_data in conditional_formats {
+                self.write_serialized_conditional_format(conditional_format_data)?;
+            }
         }
 
         // Clone the new user defined table format, if present.
@@ -10586,7 +13791,18 @@ impl Worksheet {
                 min_col,
                 max_row,
                 max_col,
+                map_key_col,
+                enum_format: header_options.enum_format,
+                first_data_row: max_row,
+                row_formats: header_options
+                    .row_formats
+                    .iter()
+                    .map(|format| Arc::new(Some(format.clone())))
+                    .collect(),
                 table,
+                groups,
+                sequences,
+                type_path: header_options.type_path.clone(),
             },
         );
 
@@ -10599,27 +13815,57 @@ impl Worksheet {
     where
         T: Serialize,
     {
+        // Reset the nesting state in case a previous serialization failed.
+        self.serializer_state.depth = 0;
+        self.serializer_state.maps.clear();
+        self.serializer_state.map_key = None;
+        self.serializer_state.field_prefix = None;
+        self.serializer_state.sequence = None;
+
+        // Store the type of the data to match structs with flattened fields.
+        self.serializer_state.type_name = std::any::type_name::<T>();
+
         data_structure.serialize(self)?;
         Ok(())
     }
 
-    // Serialize individual data items to a worksheet cell.
+    // Serialize individual data items to a worksheet cell, applying any user
+    // defined transform for the field. Empty strings are used for `None`
+    // values so they are passed to the transform as blanks.
     #[cfg(feature = "serde")]
     pub(crate) fn serialize_to_worksheet_cell(
+        &mut self,
+        data: impl IntoExcelData + Into<crate::ExcelCellValue>,
+    ) -> Result<(), XlsxError> {
+        match self.serializer_state.current_value_transform() {
+            Some(transform) => {
+                let value = match data.into() {
+                    crate::ExcelCellValue::String(value) if value.is_empty() => {
+                        crate::ExcelCellValue::Blank
+                    }
+                    value => value,
+                };
+
+                self.write_serialized_cell(transform(value))
+            }
+            None => self.write_serialized_cell(data),
+        }
+    }
+
+    // Write serialized data to the current worksheet cell.
+    #[cfg(feature = "serde")]
+    pub(crate) fn write_serialized_cell(
         &mut self,
         data: impl IntoExcelData,
     ) -> Result<(), XlsxError> {
-        let result = self.serializer_state.current_state();
+        let result = self.serializer_state.current_state()?;
 
         match result {
-            Ok(result) => {
-                let (row, col, value_format) = result;
-                match &*value_format {
-                    Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
-                    None => self.write(row, col, data).map(|_| ()),
-                }
-            }
-            Err(()) => Ok(()),
+            Some((row, col, value_format)) => match &*value_format {
+                Some(format) => self.write_with_format(row, col, data, format).map(|_| ()),
+                None => self.write(row, col, data).map(|_| ()),
+            },
+            None => Ok(()),
         }
     }
 
@@ -10632,6 +13878,12 @@ impl Worksheet {
             self.write_serialized_table(&table_data)?;
         }
 
+        let conditional_formats = self.serializer_state.get_conditional_formats();
+
+        for conditional_format_data in conditional_formats {
+            self.write_serialized_conditional_format(conditional_format_data)?;
+        }
+
         Ok(self)
     }
 
@@ -10654,6 +13906,18 @@ impl Worksheet {
         self.add_table(min_row, min_col, max_row, max_col, table)
     }
 
+    // Write a conditional format that is part of serialization formatting.
+    #[cfg(feature = "serde")]
+    pub(crate) fn write_serialized_conditional_format(
+        &mut self,
+        conditional_format_data: ConditionalFormatData,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let ConditionalFormatData(first_row, first_col, last_row, last_col, conditional_format) =
+            conditional_format_data;
+
+        self.store_conditional_format(first_row, first_col, last_row, last_col, conditional_format)
+    }
+
     // -----------------------------------------------------------------------
     // Worksheet page setup methods.
     // -----------------------------------------------------------------------
@@ -11025,6 +14289,65 @@ impl Worksheet {
         self
     }
 
+    /// Set the display settings for the outline symbols of grouped rows and
+    /// columns.
+    ///
+    /// Excel displays the `+` and `-` outline buttons for grouped rows and
+    /// columns on a summary row or column. By default the summary row is below
+    /// the group and the summary column is to the right of the group. This
+    /// method can be used to change the position of the summary rows and
+    /// columns, to turn on Excel's automatic outline styles, or to hide the
+    /// outline symbols.
+    ///
+    /// # Parameters
+    ///
+    /// - `symbols_below`: Display the summary row symbols below the group. If
+    ///   `false` they are displayed above the group. The default is `true`.
+    /// - `symbols_right`: Display the summary column symbols to the right of the
+    ///   group. If `false` they are displayed to the left of the group. The
+    ///   default is `true`.
+    /// - `auto_style`: Turn on Excel's automatic styles for the outline summary
+    ///   rows and columns. The default is `false`.
+    /// - `visible`: Display the outline symbols. The default is `true`.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the outline settings for a
+    /// worksheet so that the summary rows are above the grouped rows.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_outline_settings.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    ///     let worksheet = workbook.add_worksheet();
+    ///
+    ///     // Display the summary rows above the grouped rows.
+    ///     worksheet.set_outline_settings(false, true, false, true);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_outline_settings(
+        &mut self,
+        symbols_below: bool,
+        symbols_right: bool,
+        auto_style: bool,
+        visible: bool,
+    ) -> &mut Worksheet {
+        self.outline_symbols_below = symbols_below;
+        self.outline_symbols_right = symbols_right;
+        self.outline_auto_style = auto_style;
+        self.outline_visible = visible;
+
+        self
+    }
+
     /// Set the paper type/size when printing.
     ///
     /// This method is used to set the paper format for the printed output of a
@@ -11117,6 +14440,70 @@ impl Worksheet {
         self
     }
 
+    /// Set a custom paper size, in millimeters, when printing.
+    ///
+    /// The `set_paper_dimensions()` method is used to set the width and
+    /// height of the printed page for paper that isn't one of the standard
+    /// types supported by [`Worksheet::set_paper_size()`]. For example, non
+    /// standard corporate stationery or labels.
+    ///
+    /// The custom dimensions are stored in the file in addition to any paper
+    /// size index. They are supported by Excel 2010 and later and by most
+    /// printer drivers.
+    ///
+    /// # Parameters
+    ///
+    /// - `width`: The paper width in millimeters.
+    /// - `height`: The paper height in millimeters.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Width or height isn't a finite number
+    ///   greater than 0.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting a custom paper size and
+    /// the "Narrow" margins for the printed output.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_paper_dimensions.rs
+    /// #
+    /// # use rust_xlsxwriter::{MarginPreset, Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     // Set a custom 200mm x 250mm printer paper size.
+    ///     worksheet.set_paper_dimensions(200.0, 250.0)?;
+    ///
+    ///     // Use the Excel "Narrow" margins.
+    ///     worksheet.set_margin_preset(MarginPreset::Narrow);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    pub fn set_paper_dimensions(
+        &mut self,
+        width: f64,
+        height: f64,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if !(width.is_finite() && width > 0.0 && height.is_finite() && height > 0.0) {
+            return Err(XlsxError::ParameterError(format!(
+                "Paper dimensions must be finite and greater than 0: {width}mm x {height}mm"
+            )));
+        }
+
+        self.paper_dimensions = (width, height);
+        self.page_setup_changed = true;
+        Ok(self)
+    }
+
     /// Set the order in which pages are printed.
     ///
     /// The `set_page_order()` method is used to change the default print
@@ -11309,7 +14696,10 @@ impl Worksheet {
 
         // Check max break value is within Excel column limit.
         if *breaks.last().unwrap() >= ROW_MAX {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_page_breaks",
+                &format!("row {}", breaks.last().unwrap()),
+            ));
         }
 
         self.horizontal_breaks = breaks;
@@ -11349,7 +14739,10 @@ impl Worksheet {
 
         // Check max break value is within Excel col limit.
         if *breaks.last().unwrap() >= u32::from(COL_MAX) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_vertical_page_breaks",
+                &format!("column {}", breaks.last().unwrap()),
+            ));
         }
 
         self.vertical_breaks = breaks;
@@ -11420,7 +14813,7 @@ impl Worksheet {
     /// want either the vertical or horizontal split. See the example below.
     ///
     /// In Excel it is also possible to set "split" panes without freezing them.
-    /// That feature isn't currently supported by `rust_xlsxwriter`.
+    /// See [`Worksheet::set_split_panes()`].
     ///
     /// # Parameters
     ///
@@ -11482,13 +14875,74 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error("set_freeze_panes", &cell_location(row, col)));
         }
 
         self.panes.freeze_cell = (row, col);
+        self.panes.split_pixels = (0, 0);
         Ok(self)
     }
 
+    /// Split the worksheet into panes without freezing them.
+    ///
+    /// The `set_split_panes()` method divides a worksheet into horizontal or
+    /// vertical panes, like [`Worksheet::set_freeze_panes()`], but the
+    /// splitter bars remain visible and each pane can be scrolled
+    /// independently. This is the same as the "View -> Split" option in Excel.
+    ///
+    /// The split positions are given in pixels from the top and left of the
+    /// worksheet cell area. Either position can be 0 if you only want a
+    /// vertical or horizontal split. A row height of 20 pixels and a column
+    /// width of 64 pixels are the Excel defaults so, for example, a split of
+    /// `(40, 128)` is below the second row and to the right of the second
+    /// column.
+    ///
+    /// The top left cell of the scrolling area is estimated from the split
+    /// position. It can be set explicitly with
+    /// [`Worksheet::set_freeze_panes_top_cell()`]. The active pane and pane
+    /// selections can be set with [`Worksheet::set_freeze_panes_active_pane()`]
+    /// and [`Worksheet::set_freeze_panes_selection()`].
+    ///
+    /// Split panes and freeze panes are mutually exclusive. The last method
+    /// called takes precedence.
+    ///
+    /// # Parameters
+    ///
+    /// - `y_pixels`: The vertical position of the horizontal split in pixels.
+    /// - `x_pixels`: The horizontal position of the vertical split in pixels.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates splitting a worksheet into panes
+    /// that can be scrolled independently.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_split_panes.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write_string(0, 0, "Scroll down or right")?;
+    /// #
+    ///     // Split the worksheet below row 2 and to the right of column B.
+    ///     worksheet.set_split_panes(40, 128);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_split_panes(&mut self, y_pixels: u32, x_pixels: u32) -> &mut Worksheet {
+        self.panes.split_pixels = (y_pixels, x_pixels);
+        self.panes.freeze_cell = (0, 0);
+        self
+    }
+
     /// Set the top most cell in the scrolling area of a freeze pane.
     ///
     /// This method is used in conjunction with the
@@ -11499,36 +14953,106 @@ impl Worksheet {
     ///
     /// # Parameters
     ///
-    /// - `row`: The zero indexed row number.
-    /// - `col`: The zero indexed column number.
+    /// - `row`: The zero indexed row number.
+    /// - `col`: The zero indexed column number.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates setting the worksheet panes and also
+    /// setting the topmost visible cell in the scrolled area.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    /// #     worksheet.write_string(0, 0, "Scroll down")?;
+    /// #
+    ///     // Freeze the top row only.
+    ///     worksheet.set_freeze_panes(1, 0)?;
+    ///
+    ///     // Pre-scroll to the row 20.
+    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    /// Output file:
+    ///
+    /// <img
+    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    ///
+    pub fn set_freeze_panes_top_cell(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check row and col are in the allowed range.
+        if !self.check_dimensions_only(row, col) {
+            return Err(self.limit_error("set_freeze_panes_top_cell", &cell_location(row, col)));
+        }
+
+        self.panes.top_cell = (row, col);
+        Ok(self)
+    }
+
+    /// Set the active pane of a worksheet with freeze panes.
+    ///
+    /// When a worksheet is divided into panes with
+    /// [`Worksheet::set_freeze_panes()`] one of the panes is the active pane,
+    /// which contains the active cell when the file is opened. By default
+    /// this is the scrolling pane at the bottom right, or the only scrolling
+    /// pane if just rows or columns are frozen.
+    ///
+    /// The pane should be one of the panes created by the frozen rows and
+    /// columns. For example if only rows are frozen then there are no right
+    /// hand panes. Panes that don't exist are ignored and the default active
+    /// pane is used instead.
     ///
-    /// # Errors
+    /// # Parameters
     ///
-    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
-    ///   worksheet limits.
+    /// - `pane`: A [`PanePosition`] enum value.
     ///
     /// # Examples
     ///
-    /// The following example demonstrates setting the worksheet panes and also
-    /// setting the topmost visible cell in the scrolled area.
+    /// The following example demonstrates setting the active pane and the
+    /// selections in the panes of a worksheet with frozen rows and columns.
     ///
     /// ```
-    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_top_cell.rs
+    /// # // This code is available in examples/doc_worksheet_set_freeze_panes_selection.rs
     /// #
-    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// # use rust_xlsxwriter::{PanePosition, Workbook, XlsxError};
     /// #
     /// # fn main() -> Result<(), XlsxError> {
     /// #     let mut workbook = Workbook::new();
     /// #
     /// #     let worksheet = workbook.add_worksheet();
     /// #
-    /// #     worksheet.write_string(0, 0, "Scroll down")?;
-    /// #
-    ///     // Freeze the top row only.
-    ///     worksheet.set_freeze_panes(1, 0)?;
+    ///     // Freeze the top row and leftmost column.
+    ///     worksheet.set_freeze_panes(1, 1)?;
     ///
-    ///     // Pre-scroll to the row 20.
-    ///     worksheet.set_freeze_panes_top_cell(19, 0)?;
+    ///     // Pre-scroll the data area and select a range in it.
+    ///     worksheet.set_freeze_panes_top_cell(20, 1)?;
+    ///     worksheet.set_freeze_panes_selection(PanePosition::BottomRight, 20, 1, 24, 3)?;
+    ///
+    ///     // Select the column headers in the top pane.
+    ///     worksheet.set_freeze_panes_selection(PanePosition::TopRight, 0, 1, 0, 3)?;
+    ///
+    ///     // Make the top right pane active.
+    ///     worksheet.set_freeze_panes_active_pane(PanePosition::TopRight);
     /// #
     /// #     workbook.save("worksheet.xlsx")?;
     /// #
@@ -11536,22 +15060,68 @@ impl Worksheet {
     /// # }
     /// ```
     ///
-    /// Output file:
+    pub fn set_freeze_panes_active_pane(&mut self, pane: PanePosition) -> &mut Worksheet {
+        self.panes.active_pane = Some(pane);
+        self
+    }
+
+    /// Set the selected cell or cells in a pane of a worksheet with freeze
+    /// panes.
     ///
-    /// <img
-    /// src="https://rustxlsxwriter.github.io/images/worksheet_set_freeze_panes_top_cell.png">
+    /// Each of the panes created by [`Worksheet::set_freeze_panes()`] has its
+    /// own active cell and cell selection. This method sets the selection for
+    /// one pane in the same way that [`Worksheet::set_selection()`] sets it
+    /// for a worksheet without panes. The active cell within the selected
+    /// range is determined by the order in which `first_` and `last_` are
+    /// specified.
     ///
-    pub fn set_freeze_panes_top_cell(
+    /// Setting the selection for the [`PanePosition::TopLeft`] pane is the
+    /// same as calling [`Worksheet::set_selection()`]. Selections for panes
+    /// that aren't created by the frozen rows and columns are ignored.
+    ///
+    /// # Parameters
+    ///
+    /// - `pane`: A [`PanePosition`] enum value.
+    /// - `first_row`: The first row of the range. (All zero indexed.)
+    /// - `first_col`: The first column of the range.
+    /// - `last_row`: The last row of the range.
+    /// - `last_col`: The last column of the range.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    ///
+    /// # Examples
+    ///
+    /// See the example for [`Worksheet::set_freeze_panes_active_pane()`]
+    /// above.
+    ///
+    pub fn set_freeze_panes_selection(
         &mut self,
-        row: RowNum,
-        col: ColNum,
+        pane: PanePosition,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
     ) -> Result<&mut Worksheet, XlsxError> {
-        // Check row and col are in the allowed range.
-        if !self.check_dimensions_only(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+        if pane == PanePosition::TopLeft {
+            return self.set_selection(first_row, first_col, last_row, last_col);
         }
 
-        self.panes.top_cell = (row, col);
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions_only(first_row, first_col)
+            || !self.check_dimensions_only(last_row, last_col)
+        {
+            return Err(self.limit_error(
+                "set_freeze_panes_selection",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        let selection = Self::selection_range(first_row, first_col, last_row, last_col);
+        self.panes.selections.insert(pane, selection);
+
         Ok(self)
     }
 
@@ -11917,12 +15487,82 @@ impl Worksheet {
             return Err(XlsxError::ParameterError(error));
         }
 
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = true;
-        self.header_footer_images[position as usize] = Some(image);
+        self.store_header_footer_image(image, position, &HeaderImageOptions::default(), true)
+    }
 
-        Ok(self)
+    /// Insert an image in a worksheet header with scaling, cropping and color
+    /// options.
+    ///
+    /// This method is the same as [`Worksheet::set_header_image()`] except
+    /// that the image in each [`HeaderImagePosition`] can have its own
+    /// scaling, cropping and color mode, such as a washout for watermarks. See
+    /// [`HeaderImageOptions`] for details.
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] to insert into the header.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
+    /// - `options`: The [`HeaderImageOptions`] for the image.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the header string, or
+    ///   if the image cropping is larger than the image.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates adding a washed out, or watermark,
+    /// image to a worksheet header.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_header_image_with_options.rs
+    /// #
+    /// # use rust_xlsxwriter::{
+    /// #     HeaderImageColorMode, HeaderImageOptions, HeaderImagePosition, Image, Workbook, XlsxError,
+    /// # };
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     let image = Image::new("examples/rust_logo.png")?;
+    ///
+    ///     // Scale the image and wash it out so it can be used as a watermark.
+    ///     let options = HeaderImageOptions::new()
+    ///         .set_scale_width(2.0)
+    ///         .set_scale_height(2.0)
+    ///         .set_color_mode(HeaderImageColorMode::Washout);
+    ///
+    ///     // Insert the watermark image in the header.
+    ///     worksheet.set_header("&C&[Picture]");
+    ///     worksheet.set_header_image_with_options(&image, HeaderImagePosition::Center, &options)?;
+    /// #
+    /// #     // Set Page View mode so the watermark is visible.
+    /// #     worksheet.set_view_page_layout();
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_header_image_with_options(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+        options: &HeaderImageOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if !Self::verify_header_footer_image(&self.header, &position) {
+            let error = format!(
+                "No &[Picture] or &[G] variable in header string: '{}' for position = '{:?}'",
+                self.header, position
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        self.store_header_footer_image(image, position, options, true)
     }
 
     /// Insert an image in a worksheet footer.
@@ -11955,12 +15595,43 @@ impl Worksheet {
             return Err(XlsxError::ParameterError(error));
         }
 
-        let mut image = image.clone();
-        image.header_position = position.clone();
-        image.is_header = false;
-        self.header_footer_images[3 + position as usize] = Some(image);
+        self.store_header_footer_image(image, position, &HeaderImageOptions::default(), false)
+    }
 
-        Ok(self)
+    /// Insert an image in a worksheet footer with scaling, cropping and color
+    /// options.
+    ///
+    /// See the documentation for
+    /// [`Worksheet::set_header_image_with_options()`] for more details.
+    ///
+    /// # Parameters
+    ///
+    /// - `image`: The [`Image`] to insert into the footer.
+    /// - `position`: The image position as defined by the
+    ///   [`HeaderImagePosition`] enum.
+    /// - `options`: The [`HeaderImageOptions`] for the image.
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Parameter error if there isn't a
+    ///   corresponding `&[Picture]`/`&[G]` variable in the footer string, or
+    ///   if the image cropping is larger than the image.
+    ///
+    pub fn set_footer_image_with_options(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+        options: &HeaderImageOptions,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if !Self::verify_header_footer_image(&self.footer, &position) {
+            let error = format!(
+                "No &[Picture] or &[G] variable in footer string: '{}' for position = '{:?}'",
+                self.footer, position
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        self.store_header_footer_image(image, position, options, false)
     }
 
     /// Set the page setup option to scale the header/footer with the document.
@@ -12088,6 +15759,40 @@ impl Worksheet {
         self
     }
 
+    /// Set the page margins to one of the Excel margin presets.
+    ///
+    /// The `set_margin_preset()` method sets all of the page margins to the
+    /// values used by the "Normal", "Wide" or "Narrow" options in the Excel
+    /// "Page Layout -> Margins" menu. See [`MarginPreset`] for the values.
+    ///
+    /// Individual margins can be adjusted afterwards with
+    /// [`Worksheet::set_margins()`].
+    ///
+    /// # Parameters
+    ///
+    /// - `preset`: A [`MarginPreset`] enum value.
+    ///
+    /// # Examples
+    ///
+    /// See the example for [`Worksheet::set_paper_dimensions()`].
+    ///
+    pub fn set_margin_preset(&mut self, preset: MarginPreset) -> &mut Worksheet {
+        let (left_right, top_bottom, header_footer) = match preset {
+            MarginPreset::Normal => (0.7, 0.75, 0.3),
+            MarginPreset::Wide => (1.0, 1.0, 0.5),
+            MarginPreset::Narrow => (0.25, 0.75, 0.3),
+        };
+
+        self.set_margins(
+            left_right,
+            left_right,
+            top_bottom,
+            top_bottom,
+            header_footer,
+            header_footer,
+        )
+    }
+
     /// Set the first page number when printing.
     ///
     /// The `set_print_first_page_number()` method is used to set the page
@@ -12362,6 +16067,53 @@ impl Worksheet {
         self
     }
 
+    /// Set the option to turn on/off the screen row and column headings.
+    ///
+    /// The `set_screen_headings()` method is use to turn on/off the row
+    /// numbers and column letters on the displayed worksheet. It is on by
+    /// default. Turning off the headings, along with the screen gridlines, is
+    /// useful for dashboard style worksheets.
+    ///
+    /// To turn on/off the printed headings see the
+    /// [`Worksheet::set_print_headings()`] method below.
+    ///
+    /// # Parameters
+    ///
+    /// - `enable`: Turn the property on/off. It is on by default.
+    ///
+    /// # Examples
+    ///
+    /// The following example demonstrates turning off the worksheet screen
+    /// row and column headings.
+    ///
+    /// ```
+    /// # // This code is available in examples/doc_worksheet_set_screen_headings.rs
+    /// #
+    /// # use rust_xlsxwriter::{Workbook, XlsxError};
+    /// #
+    /// # fn main() -> Result<(), XlsxError> {
+    /// #     let mut workbook = Workbook::new();
+    /// #
+    /// #     // Add a worksheet to the workbook.
+    /// #     let worksheet = workbook.add_worksheet();
+    /// #
+    ///     worksheet.write(0, 0, "Hello")?;
+    ///
+    ///     // Turn off the screen row and column headings.
+    ///     worksheet.set_screen_headings(false);
+    /// #
+    /// #     workbook.save("worksheet.xlsx")?;
+    /// #
+    /// #     Ok(())
+    /// # }
+    /// ```
+    ///
+    pub fn set_screen_headings(&mut self, enable: bool) -> &mut Worksheet {
+        self.screen_headings = enable;
+
+        self
+    }
+
     /// Set the page setup option to turn on printed gridlines.
     ///
     /// The `set_print_gridlines()` method is use to turn on/off gridlines on
@@ -12529,12 +16281,18 @@ impl Worksheet {
         if !self.check_dimensions_only(first_row, first_col)
             || !self.check_dimensions_only(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_print_area",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_print_area",
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
         }
 
         // The print range is the entire worksheet, therefore it is the same
@@ -12555,6 +16313,41 @@ impl Worksheet {
         Ok(self)
     }
 
+    /// Set the print area for the worksheet using an `A1:B2` style range
+    /// reference.
+    ///
+    /// This method is the same as [`Worksheet::set_print_area()`] except that
+    /// the print area is specified as an `A1:B2` or `$A$1:$B$2` style string.
+    /// See [`Worksheet::write_a1()`] for an example.
+    ///
+    /// # Parameters
+    ///
+    /// - `range`: An `A1:B2` style range reference string, or any other type
+    ///   that implements [`IntoRangeRef`] such as a
+    ///   [`RangeRef`].
+    ///
+    /// # Errors
+    ///
+    /// - [`XlsxError::ParameterError`] - Invalid range reference.
+    /// - [`XlsxError::RowColumnLimitError`] - Row or column exceeds Excel's
+    ///   worksheet limits.
+    /// - [`XlsxError::RowColumnOrderError`] - First row or column is larger
+    ///   than the last row or column.
+    ///
+    pub fn set_print_area_a1(
+        &mut self,
+        range: impl IntoRangeRef,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let range = range.to_range_ref()?;
+
+        self.set_print_area(
+            range.first_row(),
+            range.first_col(),
+            range.last_row(),
+            range.last_col(),
+        )
+    }
+
     /// Set the number of rows to repeat at the top of each printed page.
     ///
     /// For large Excel documents it is often desirable to have the first row or
@@ -12614,12 +16407,18 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check rows are in the allowed range.
         if !self.check_dimensions_only(first_row, 0) || !self.check_dimensions_only(last_row, 0) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_repeat_rows",
+                &format!("rows {first_row} to {last_row}"),
+            ));
         }
 
         // Check order of first/last values.
         if first_row > last_row {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_repeat_rows",
+                &format!("rows {first_row} to {last_row}"),
+            ));
         }
 
         // Store the range data.
@@ -12692,12 +16491,18 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check columns are in the allowed range.
         if !self.check_dimensions_only(0, first_col) || !self.check_dimensions_only(0, last_col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.limit_error(
+                "set_repeat_columns",
+                &format!("columns {first_col} to {last_col}"),
+            ));
         }
 
         // Check order of first/last values.
         if first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(self.order_error(
+                "set_repeat_columns",
+                &format!("columns {first_col} to {last_col}"),
+            ));
         }
 
         // Store the defined name information.
@@ -12800,6 +16605,7 @@ impl Worksheet {
             if let Some(columns) = self.data_table.get(&row_num) {
                 for col_num in self.dimensions.first_col..=self.dimensions.last_col {
                     if let Some(cell) = columns.get(&col_num) {
+                        let font = self.cell_font_metrics(cell);
                         let mut pixel_width = match cell {
                             // For strings we do a calculation based on
                             // character widths taken from Excel. For rich
@@ -12812,7 +16618,7 @@ impl Worksheet {
                             } => {
                                 let mut max = 0;
                                 for segment in string.lines() {
-                                    let length = utility::pixel_width(segment);
+                                    let length = Self::text_pixel_width(segment, font);
                                     max = cmp::max(max, length);
                                 }
                                 max
@@ -12822,8 +16628,12 @@ impl Worksheet {
                             // since digits all have a pixel width of 7. This
                             // gives a slightly greater width for the decimal
                             // place and minus sign but only by a few pixels and
-                            // over-estimation is okay.
-                            CellType::Number { number, .. } => 7 * number.to_string().len() as u16,
+                            // over-estimation is okay. Registered fonts are
+                            // measured directly.
+                            CellType::Number { number, .. } => match font {
+                                Some(_) => Self::text_pixel_width(&number.to_string(), font),
+                                None => 7 * number.to_string().len() as u16,
+                            },
 
                             // For Boolean types we use the Excel standard
                             // widths for TRUE and FALSE.
@@ -12842,7 +16652,7 @@ impl Worksheet {
                                 if result.as_ref() == "0" || result.is_empty() {
                                     0
                                 } else {
-                                    utility::pixel_width(result)
+                                    Self::text_pixel_width(result, font)
                                 }
                             }
 
@@ -12887,7 +16697,7 @@ impl Worksheet {
 
         // Set the max character width for each column.
         for (col, pixels) in &max_widths {
-            let width = Self::pixels_to_width(*pixels + 7);
+            let width = self.pixels_to_width(*pixels + 7);
             self.store_column_width(*col, width, true);
         }
 
@@ -12934,9 +16744,162 @@ impl Worksheet {
         Ok(self)
     }
 
-    // -----------------------------------------------------------------------
-    // Crate level helper methods.
-    // -----------------------------------------------------------------------
+    // -----------------------------------------------------------------------
+    // Crate level helper methods.
+    // -----------------------------------------------------------------------
+
+    // Increment the outline level of a range of rows and, optionally, hide the
+    // rows and mark the summary row as collapsed.
+    fn store_row_group(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+        collapsed: bool,
+        caller: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_row > last_row {
+            return Err(self.order_error(caller, &format!("rows {first_row} to {last_row}")));
+        }
+
+        // Set a suitable column range for the row dimension check/set.
+        let min_col = self.get_min_col();
+
+        // Check rows are in the allowed range.
+        if !self.check_dimensions(first_row, min_col) || !self.check_dimensions(last_row, min_col) {
+            return Err(self.limit_error(caller, &format!("rows {first_row} to {last_row}")));
+        }
+
+        // Check that the group doesn't exceed the maximum outline level before
+        // changing any of the rows.
+        let max_level = (first_row..=last_row)
+            .filter_map(|row| self.changed_rows.get(&row))
+            .map(|row_options| row_options.level)
+            .max()
+            .unwrap_or(0);
+
+        if max_level >= MAX_OUTLINE_LEVEL {
+            return Err(XlsxError::MaxGroupLevelExceeded);
+        }
+
+        for row in first_row..=last_row {
+            let row_options = self.row_options_mut(row);
+            row_options.level += 1;
+
+            if collapsed {
+                row_options.hidden = true;
+            }
+        }
+
+        // Mark the summary row, after or before the group, as collapsed.
+        if collapsed {
+            let summary_row = if self.outline_symbols_below {
+                last_row.checked_add(1).filter(|row| *row < ROW_MAX)
+            } else {
+                first_row.checked_sub(1)
+            };
+
+            if let Some(row) = summary_row {
+                self.check_dimensions(row, min_col);
+                self.row_options_mut(row).collapsed = true;
+            }
+        }
+
+        Ok(self)
+    }
+
+    // Increment the outline level of a range of columns and, optionally, hide
+    // the columns and mark the summary column as collapsed.
+    fn store_column_group(
+        &mut self,
+        first_col: ColNum,
+        last_col: ColNum,
+        collapsed: bool,
+        caller: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check order of first/last values.
+        if first_col > last_col {
+            return Err(self.order_error(caller, &format!("columns {first_col} to {last_col}")));
+        }
+
+        // Check if columns are in the allowed range without updating dimensions.
+        if last_col >= COL_MAX {
+            return Err(self.limit_error(caller, &format!("columns {first_col} to {last_col}")));
+        }
+
+        // Check that the group doesn't exceed the maximum outline level before
+        // changing any of the columns.
+        let max_level = (first_col..=last_col)
+            .filter_map(|col| self.changed_cols.get(&col))
+            .map(|col_options| col_options.level)
+            .max()
+            .unwrap_or(0);
+
+        if max_level >= MAX_OUTLINE_LEVEL {
+            return Err(XlsxError::MaxGroupLevelExceeded);
+        }
+
+        for col in first_col..=last_col {
+            let col_options = self.col_options_mut(col);
+            col_options.level += 1;
+
+            if collapsed {
+                col_options.hidden = true;
+            }
+        }
+
+        // Mark the summary column, after or before the group, as collapsed.
+        if collapsed {
+            let summary_col = if self.outline_symbols_right {
+                last_col.checked_add(1).filter(|col| *col < COL_MAX)
+            } else {
+                first_col.checked_sub(1)
+            };
+
+            if let Some(col) = summary_col {
+                self.col_options_mut(col).collapsed = true;
+            }
+        }
+
+        Ok(self)
+    }
+
+    // Get an existing row metadata object or create a new default one.
+    fn row_options_mut(&mut self, row: RowNum) -> &mut RowOptions {
+        let height = self.user_default_row_height;
+
+        self.changed_rows.entry(row).or_insert_with(|| RowOptions {
+            height,
+            xf_index: 0,
+            hidden: false,
+            level: 0,
+            collapsed: false,
+        })
+    }
+
+    // Store a cell or range for each of the error types to ignore.
+    fn store_ignored_errors(&mut self, error_types: &[IgnoreError], range: &str) {
+        for error_type in error_types {
+            self.ignored_errors
+                .entry(*error_type)
+                .or_default()
+                .push(range.to_string());
+        }
+    }
+
+    // Get an existing column metadata object or create a new default one.
+    fn col_options_mut(&mut self, col: ColNum) -> &mut ColOptions {
+        let width = self.default_col_width;
+
+        self.changed_cols.entry(col).or_insert_with(|| ColOptions {
+            width,
+            xf_index: 0,
+            hidden: false,
+            autofit: false,
+            level: 0,
+            collapsed: false,
+        })
+    }
 
     // Get the minimum row number for the dimension check/set.
     fn get_min_row(&self) -> RowNum {
@@ -12970,6 +16933,24 @@ impl Worksheet {
         for col_num in self.filter_conditions.clone().keys() {
             // Iterate through each column filter conditions.
             let filter_condition = self.filter_conditions.get(col_num).unwrap().clone();
+
+            // Handle top 10 and average filters, which depend on all the
+            // numbers in the column.
+            if filter_condition.top10.is_some()
+                || filter_condition
+                    .dynamic
+                    .is_some_and(FilterDynamicType::is_average)
+            {
+                self.hide_autofilter_rows_by_rank(first_row, last_row, *col_num, &filter_condition);
+                continue;
+            }
+
+            // Rows aren't hidden for date based dynamic filters since they
+            // depend on the date that the file is opened.
+            if filter_condition.dynamic.is_some() {
+                continue;
+            }
+
             for row_num in first_row..=last_row {
                 if filter_condition.is_list_filter {
                     // Handle list filters.
@@ -12986,6 +16967,67 @@ impl Worksheet {
         }
     }
 
+    // Hide the rows that don't match a top 10 or above/below average filter.
+    // These are evaluated against the numeric cells in the column. Rows
+    // without a number are always hidden, as in Excel.
+    fn hide_autofilter_rows_by_rank(
+        &mut self,
+        first_row: RowNum,
+        last_row: RowNum,
+        col_num: ColNum,
+        filter_condition: &FilterCondition,
+    ) {
+        let numbers: HashMap<RowNum, f64> = (first_row..=last_row)
+            .filter_map(
+                |row_num| match self.data_table.get(&row_num)?.get(&col_num)? {
+                    CellType::Number { number, .. } => Some((row_num, *number)),
+                    _ => None,
+                },
+            )
+            .collect();
+
+        // Get the smallest/largest number that is in the top/bottom range.
+        let threshold = filter_condition.top10.and_then(|top10| {
+            let mut sorted: Vec<f64> = numbers.values().copied().collect();
+            sorted.sort_by(|a, b| a.total_cmp(b));
+            if !top10.is_bottom {
+                sorted.reverse();
+            }
+
+            let count = if top10.is_percent {
+                (sorted.len() * usize::from(top10.count) / 100).max(1)
+            } else {
+                usize::from(top10.count)
+            };
+
+            sorted.get(count.min(sorted.len()).checked_sub(1)?).copied()
+        });
+
+        let average = numbers.values().sum::<f64>() / numbers.len().max(1) as f64;
+
+        let is_match = |number: f64| match (filter_condition.top10, filter_condition.dynamic) {
+            (Some(top10), _) => threshold.is_some_and(|threshold| {
+                if top10.is_bottom {
+                    number <= threshold
+                } else {
+                    number >= threshold
+                }
+            }),
+            (None, Some(FilterDynamicType::AboveAverage)) => number > average,
+            (None, Some(FilterDynamicType::BelowAverage)) => number < average,
+            _ => true,
+        };
+
+        for row_num in first_row..=last_row {
+            if !numbers
+                .get(&row_num)
+                .is_some_and(|number| is_match(*number))
+            {
+                self.set_row_hidden(row_num).unwrap();
+            }
+        }
+    }
+
     // Check if the data in a cell matches one of the values in the list of
     // filter conditions (which in the list filter case is a list of strings or
     // number values).
@@ -13033,10 +17075,8 @@ impl Worksheet {
                             }
                         }
                     }
-                    CellType::Blank { .. } => {
-                        if filter_condition.should_match_blanks {
-                            return true;
-                        }
+                    CellType::Blank { .. } if filter_condition.should_match_blanks => {
+                        return true;
                     }
                     // We don't currently try to handle matching any other data types.
                     _ => {}
@@ -13211,6 +17251,31 @@ impl Worksheet {
         self.store_number_type(row, col, number.into(), format, false)
     }
 
+    // Store a `BigInt` cell in the worksheet data table structure, according
+    // to the worksheet policy for values outside Excel's integer range.
+    #[cfg(feature = "num-bigint")]
+    fn store_bigint(
+        &mut self,
+        row: RowNum,
+        col: ColNum,
+        number: &BigInt,
+        format: Option<&Format>,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        if let Some(number) = bigint_to_f64(number) {
+            return self.store_number(row, col, number, format);
+        }
+
+        match self.bigint_precision {
+            BigIntPrecision::String => self.store_string(row, col, number.to_string(), format),
+            BigIntPrecision::Number => {
+                self.store_number(row, col, bigint_to_lossy_f64(number), format)
+            }
+            BigIntPrecision::Error => Err(XlsxError::ParameterError(format!(
+                "BigInt value '{number}' is outside Excel's integer range of +/- 999,999,999,999,999"
+            ))),
+        }
+    }
+
     // Store a datetime cell in the worksheet data table structure.
     fn store_datetime(
         &mut self,
@@ -13233,7 +17298,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         // Excel doesn't have a NAN type/value so write a string instead.
@@ -13283,7 +17348,7 @@ impl Worksheet {
 
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         //  Check that the string is < Excel limit of 32767 chars.
@@ -13330,7 +17395,7 @@ impl Worksheet {
 
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         //  Check that the string is < Excel limit of 32767 chars.
@@ -13373,7 +17438,7 @@ impl Worksheet {
 
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         // Get the index of the format object, if any.
@@ -13417,12 +17482,16 @@ impl Worksheet {
         if !self.check_dimensions(first_row, first_col)
             || !self.check_dimensions(last_row, last_col)
         {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(
+                self.store_limit_error(&range_location(first_row, first_col, last_row, last_col))
+            );
         }
 
         // Check order of first/last values.
         if first_row > last_row || first_col > last_col {
-            return Err(XlsxError::RowColumnOrderError);
+            return Err(
+                self.store_order_error(&range_location(first_row, first_col, last_row, last_col))
+            );
         }
 
         // Get the index of the format object, if any.
@@ -13486,7 +17555,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         // Get the index of the format object.
@@ -13510,7 +17579,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and col are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         // Get the index of the format object, if any.
@@ -13538,17 +17607,56 @@ impl Worksheet {
         url: &Url,
         format: Option<&Format>,
     ) -> Result<&mut Worksheet, XlsxError> {
+        // Check the cell or range part of a "Sheet1!A1" style internal target.
+        // Other targets are defined names which are checked when the file is
+        // saved.
+        if let Some(target) = &url.internal_target {
+            if target.is_empty() {
+                let error = "Internal link target cannot be empty".to_string();
+                return Err(XlsxError::ParameterError(error));
+            }
+
+            if let Some((_, reference)) = target.rsplit_once('!') {
+                if utility::is_cell_link_reference(reference) {
+                    utility::validate_cell_link_reference(reference)?;
+                }
+            }
+        }
+
         let mut hyperlink = url.clone();
         hyperlink.initialize()?;
 
-        match format {
+        // Get the url format from the worksheet hyperlink policy if the user
+        // didn't supply one.
+        let hyperlink_format = match format {
+            Some(format) => Some(format.clone()),
+            None => match &self.hyperlink_style {
+                HyperlinkStyle::Default => {
+                    let mut format = Format::new().set_hyperlink();
+                    format.is_default_hyperlink = true;
+                    Some(format)
+                }
+                HyperlinkStyle::Custom(format) => Some(format.clone()),
+                HyperlinkStyle::Inherit => self
+                    .cell_format(row, col)
+                    .or_else(|| self.row_col_format(row, col)),
+            },
+        };
+
+        match &hyperlink_format {
             Some(format) => {
                 self.write_string_with_format(row, col, &hyperlink.user_text, format)?
             }
-            None => {
-                let hyperlink_format = Format::new().set_hyperlink();
-                self.write_string_with_format(row, col, &hyperlink.user_text, &hyperlink_format)?
-            }
+            None => self.write_string(row, col, &hyperlink.user_text)?,
+        };
+
+        // Store internal targets so that they can be checked when the file is
+        // saved.
+        match &url.internal_target {
+            Some(target) => self
+                .internal_link_targets
+                .insert((row, col), target.clone()),
+            None => self.internal_link_targets.remove(&(row, col)),
         };
 
         self.hyperlinks.insert((row, col), hyperlink);
@@ -13566,7 +17674,7 @@ impl Worksheet {
     ) -> Result<&mut Worksheet, XlsxError> {
         // Check row and columns are in the allowed range.
         if !self.check_dimensions(row, col) {
-            return Err(XlsxError::RowColumnLimitError);
+            return Err(self.store_limit_error(&cell_location(row, col)));
         }
 
         let image_id = match self.embedded_image_ids.get(&image.hash) {
@@ -13681,6 +17789,98 @@ impl Worksheet {
         Ok((styler.writer.read_to_string(), raw_string))
     }
 
+    // Write data to the first cell of a merged range, pad the rest of the range
+    // with formatted blank cells and store the range.
+    #[allow(clippy::too_many_arguments)]
+    fn store_merged_data(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+        data: impl IntoExcelData,
+        format: &Format,
+        method: &str,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check rows and cols are in the allowed range.
+        if !self.check_dimensions(first_row, first_col)
+            || !self.check_dimensions(last_row, last_col)
+        {
+            return Err(self.limit_error(
+                method,
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        // Check order of first/last values.
+        if first_row > last_row || first_col > last_col {
+            return Err(self.order_error(
+                method,
+                &range_location(first_row, first_col, last_row, last_col),
+            ));
+        }
+
+        // Check that the range isn't a singe cell, which isn't allowed by Excel.
+        if first_row == last_row && first_col == last_col {
+            return Err(XlsxError::MergeRangeSingleCell);
+        }
+
+        // Write the first cell in the range.
+        data.write_with_format(self, first_row, first_col, format)?;
+
+        // Pad out the rest of the range with formatted blanks cells.
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                // Skip the first cell which was written above.
+                if row == first_row && col == first_col {
+                    continue;
+                }
+                self.write_blank(row, col, format)?;
+            }
+        }
+
+        self.store_merge_range(first_row, first_col, last_row, last_col)?;
+
+        Ok(self)
+    }
+
+    // Store a merged range after checking that it doesn't overlap any previous
+    // merged range.
+    fn store_merge_range(
+        &mut self,
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> Result<(), XlsxError> {
+        // Create a cell range for storage and range testing.
+        let cell_range = CellRange::new(first_row, first_col, last_row, last_col);
+
+        // Check if the merged range overlaps any previous merged range. This is
+        // a major error in Excel. Note, the ranges are stored in a separate Vec
+        // to the cells to cut down on storage size.
+        let new_index = self.merged_ranges.len();
+        for row in first_row..=last_row {
+            for col in first_col..=last_col {
+                match self.merged_cells.get_mut(&(row, col)) {
+                    Some(index) => {
+                        let previous_cell_range = self.merged_ranges.get(*index).unwrap();
+                        return Err(XlsxError::MergeRangeOverlaps(
+                            cell_range.to_error_string(),
+                            previous_cell_range.to_error_string(),
+                        ));
+                    }
+                    None => self.merged_cells.insert((row, col), new_index),
+                };
+            }
+        }
+
+        // Store the merge range if everything was okay.
+        self.merged_ranges.push(cell_range);
+
+        Ok(())
+    }
+
     // Insert a cell value into the worksheet data table structure.
     fn insert_cell(&mut self, row: RowNum, col: ColNum, cell: CellType) {
         match self.data_table.entry(row) {
@@ -13698,6 +17898,109 @@ impl Worksheet {
         }
     }
 
+    // Create a row/column limit error with the worksheet name, the location of
+    // the error and the public method that raised it.
+    fn limit_error(&self, method: &str, location: &str) -> XlsxError {
+        XlsxError::RowColumnLimitError(self.error_context(method, location))
+    }
+
+    // Create a row/column order error with the worksheet name, the location of
+    // the error and the public method that raised it.
+    fn order_error(&self, method: &str, location: &str) -> XlsxError {
+        XlsxError::RowColumnOrderError(self.error_context(method, location))
+    }
+
+    // Create a row/column limit error for the internal store functions. These
+    // are shared by several public methods, and by the generic write() methods
+    // via IntoExcelData, so the error doesn't name a method.
+    fn store_limit_error(&self, location: &str) -> XlsxError {
+        XlsxError::RowColumnLimitError(format!("worksheet '{}', {location}", self.name))
+    }
+
+    // Create a row/column order error for the internal store functions.
+    fn store_order_error(&self, location: &str) -> XlsxError {
+        XlsxError::RowColumnOrderError(format!("worksheet '{}', {location}", self.name))
+    }
+
+    // Format the context string for row/column errors.
+    fn error_context(&self, method: &str, location: &str) -> String {
+        format!(
+            "worksheet '{}', {location}, in Worksheet::{method}()",
+            self.name
+        )
+    }
+
+    // Get the active cell and cell range for a selection. The first/last order
+    // can be reversed to allow a selection to go from the end to the start.
+    // We take the active cell from the user first row/col and then reverse
+    // them as required for the full range.
+    fn selection_range(
+        first_row: RowNum,
+        first_col: ColNum,
+        last_row: RowNum,
+        last_col: ColNum,
+    ) -> (String, String) {
+        let active_cell = utility::row_col_to_cell(first_row, first_col);
+
+        let mut first_row = first_row;
+        let mut first_col = first_col;
+        let mut last_row = last_row;
+        let mut last_col = last_col;
+
+        if first_row > last_row {
+            std::mem::swap(&mut first_row, &mut last_row);
+        }
+
+        if first_col > last_col {
+            std::mem::swap(&mut first_col, &mut last_col);
+        }
+
+        let range = utility::cell_range(first_row, first_col, last_row, last_col);
+
+        (active_cell, range)
+    }
+
+    // Get the user format of an existing cell, if any.
+    fn cell_format(&self, row: RowNum, col: ColNum) -> Option<Format> {
+        let cell = self.data_table.get(&row)?.get(&col)?;
+
+        let xf_index = match cell {
+            CellType::Blank { xf_index, .. }
+            | CellType::Error { xf_index, .. }
+            | CellType::String { xf_index, .. }
+            | CellType::Number { xf_index, .. }
+            | CellType::Boolean { xf_index, .. }
+            | CellType::Formula { xf_index, .. }
+            | CellType::DateTime { xf_index, .. }
+            | CellType::RichString { xf_index, .. }
+            | CellType::ArrayFormula { xf_index, .. } => *xf_index,
+        };
+
+        if xf_index == 0 {
+            None
+        } else {
+            self.xf_formats.get(xf_index as usize).cloned()
+        }
+    }
+
+    // Get the row or column format that applies to a cell, if any. As in Excel
+    // the row format takes precedence over the column format.
+    fn row_col_format(&self, row: RowNum, col: ColNum) -> Option<Format> {
+        let xf_index = self
+            .changed_rows
+            .get(&row)
+            .map(|row_options| row_options.xf_index)
+            .filter(|xf_index| *xf_index != 0)
+            .or_else(|| {
+                self.changed_cols
+                    .get(&col)
+                    .map(|col_options| col_options.xf_index)
+                    .filter(|xf_index| *xf_index != 0)
+            })?;
+
+        self.xf_formats.get(xf_index as usize).cloned()
+    }
+
     // Insert a cell format value into the worksheet data table structure. This
     // function creates a new blank cell if no other cell value exists.
     fn insert_cell_format(&mut self, row: RowNum, col: ColNum, format_id: u32) {
@@ -14039,6 +18342,8 @@ impl Worksheet {
                     xf_index: 0,
                     hidden: false,
                     autofit,
+                    level: 0,
+                    collapsed: false,
                 };
                 self.changed_cols.insert(col, col_options);
             }
@@ -14101,9 +18406,6 @@ impl Worksheet {
                 let xf_index = self.xf_formats.len() as u32;
                 self.xf_formats.push(format.clone());
                 self.xf_indices.insert(format.clone(), xf_index);
-                if format.font.is_hyperlink {
-                    self.has_hyperlink_style = true;
-                }
                 xf_index
             }
         }
@@ -14133,9 +18435,6 @@ impl Worksheet {
                 dxf_format.is_dxf_format = true;
                 self.dxf_formats.push(dxf_format.clone());
                 self.dxf_indices.insert(dxf_format, dxf_index);
-                if format.font.is_hyperlink {
-                    self.has_hyperlink_style = true;
-                }
                 dxf_index
             }
         }
@@ -14286,9 +18585,7 @@ impl Worksheet {
     // Convert the shape dimensions into drawing dimensions and add them to
     // the Drawing object. Also set the rel linkages between the files.
     pub(crate) fn prepare_worksheet_shapes(&mut self, shape_id: u32, drawing_id: u32) {
-        let mut shape_id = shape_id;
-
-        for (cell, shape) in &self.shapes.clone() {
+        for ((cell, shape), shape_id) in self.shapes.clone().iter().zip(shape_id..) {
             let row = cell.0;
             let col = cell.1;
             let mut drawing_hyperlink = None;
@@ -14330,8 +18627,6 @@ impl Worksheet {
             drawing_info.url.clone_from(&drawing_hyperlink);
             self.drawing.drawings.push(drawing_info);
             self.drawing.shapes.push(shape.clone());
-
-            shape_id += 1;
         }
 
         // Store the linkage to the worksheets rels file.
@@ -14504,12 +18799,23 @@ impl Worksheet {
 
     // Convert the chart dimensions into drawing dimensions and add them to the
     // Drawing object. Also set the rel linkages between the files.
-    pub(crate) fn prepare_worksheet_charts(&mut self, chart_id: u32, drawing_id: u32) {
-        let mut chart_id = chart_id;
+    pub(crate) fn prepare_worksheet_charts(
+        &mut self,
+        mut chart_id: u32,
+        mut chartex_id: u32,
+        drawing_id: u32,
+    ) -> (u32, u32) {
+        // The newer chartex charts, such as Treemap, are numbered separately to
+        // the standard charts since they are stored in chartExN.xml files.
         for chart in self.charts.values_mut() {
-            chart.id = chart_id;
-            chart.add_axis_ids(chart_id);
-            chart_id += 1;
+            if chart.is_chartex() {
+                chart.id = chartex_id;
+                chartex_id += 1;
+            } else {
+                chart.id = chart_id;
+                chart.add_axis_ids(chart_id);
+                chart_id += 1;
+            }
         }
 
         let mut rel_id = self.drawing_relationships.len() as u32;
@@ -14522,9 +18828,15 @@ impl Worksheet {
             let chart_id = chart.id;
 
             // Store the linkage to the charts rels file.
-            let chart_name = format!("../charts/chart{chart_id}.xml");
-            self.drawing_relationships
-                .push(("chart".to_string(), chart_name, String::new()));
+            if chart.is_chartex() {
+                let chart_name = format!("../charts/chartEx{chart_id}.xml");
+                self.drawing_relationships
+                    .push(("chartEx".to_string(), chart_name, String::new()));
+            } else {
+                let chart_name = format!("../charts/chart{chart_id}.xml");
+                self.drawing_relationships
+                    .push(("chart".to_string(), chart_name, String::new()));
+            }
 
             // Convert the chart dimensions to drawing dimensions and store the
             // drawing object.
@@ -14557,6 +18869,8 @@ impl Worksheet {
                 String::new(),
             ));
         }
+
+        (chart_id, chartex_id)
     }
 
     // Set a unique table id for each table and also set the rel linkages
@@ -14766,11 +19080,95 @@ impl Worksheet {
         }
     }
 
+    // Get the registered font metrics, and the font size, for the format of a
+    // cell, if any. Used by autofit().
+    fn cell_font_metrics(&self, cell: &CellType) -> Option<(&FontMetrics, f64)> {
+        if self.font_metrics.is_empty() {
+            return None;
+        }
+
+        let xf_index = match cell {
+            CellType::Blank { xf_index, .. }
+            | CellType::Error { xf_index, .. }
+            | CellType::String { xf_index, .. }
+            | CellType::Number { xf_index, .. }
+            | CellType::Boolean { xf_index, .. }
+            | CellType::Formula { xf_index, .. }
+            | CellType::DateTime { xf_index, .. }
+            | CellType::RichString { xf_index, .. }
+            | CellType::ArrayFormula { xf_index, .. } => *xf_index,
+        };
+
+        let font = &self.xf_formats.get(xf_index as usize)?.font;
+        let metrics = self.font_metrics.get(&font.name)?;
+        let size = font.size.parse::<f64>().unwrap_or(metrics.size);
+
+        Some((metrics, size))
+    }
+
+    // Get the pixel width of a string using the registered font metrics, if
+    // any, or the default Calibri 11 character widths.
+    fn text_pixel_width(string: &str, font: Option<(&FontMetrics, f64)>) -> u16 {
+        match font {
+            Some((metrics, size)) => metrics.string_width(string, size),
+            None => utility::pixel_width(string),
+        }
+    }
+
+    // Get the maximum digit width of the default workbook font, Calibri 11,
+    // which Excel uses as the unit of column widths. This can be changed by
+    // registering font metrics for the font or by setting a workbook default
+    // format.
+    fn max_digit_width(&self) -> f64 {
+        if let Some(max_digit_width) = self.default_max_digit_width {
+            return max_digit_width;
+        }
+
+        match self.font_metrics.get("Calibri") {
+            Some(metrics) => metrics.max_digit_width(11.0),
+            None => 7.0,
+        }
+    }
+
+    // Set the default row height and column width, in pixels, for a workbook
+    // with a non-standard default format. Excel rounds the default column
+    // width up to a multiple of 8 pixels so the maximum digit width of the
+    // default font, and the cell padding, can be derived from it.
+    #[allow(clippy::cast_precision_loss)]
+    pub(crate) fn set_default_dimensions(&mut self, row_height: u32, col_width: u32) {
+        let mut max_digit_width = 1;
+        let mut padding = 3;
+
+        for digit_width in 1..=100_u32 {
+            max_digit_width = digit_width;
+            padding = 2 * digit_width.div_ceil(4) + 1;
+
+            if (8 * digit_width + padding).div_ceil(8) * 8 >= col_width {
+                break;
+            }
+        }
+
+        let max_digit_width = f64::from(max_digit_width);
+        let padding = f64::from(padding);
+        let width = (f64::from(col_width) - padding) / max_digit_width;
+
+        self.default_max_digit_width = Some(max_digit_width);
+        self.cell_padding = padding;
+        self.default_col_width = (width * 100.0).round() / 100.0;
+        self.default_col_width_pixels = col_width;
+        // Only change the user default row height if it hasn't been set.
+        let row_height = 0.75 * f64::from(row_height);
+        if self.user_default_row_height == self.default_row_height {
+            self.user_default_row_height = row_height;
+        }
+        self.default_row_height = row_height;
+    }
+
     // Convert the width of a cell from character units to pixels. Excel rounds
     // the column width to the nearest pixel.
     fn column_pixel_width(&mut self, col: ColNum, position: ObjectMovement) -> u32 {
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
+        let max_digit_width = self.max_digit_width();
+        let padding = self.cell_padding;
 
         match self.changed_cols.get(&col) {
             Some(col_options) => {
@@ -14788,7 +19186,7 @@ impl Worksheet {
                 }
             }
             // If the width hasn't been set we use the default value.
-            None => 64,
+            None => self.default_col_width_pixels,
         }
     }
 
@@ -14843,6 +19241,106 @@ impl Worksheet {
             || !self.table_relationships.is_empty()
     }
 
+    // Check the worksheet for accessibility issues. Used by
+    // Workbook::accessibility_report().
+    pub(crate) fn accessibility_issues(&self) -> Vec<AccessibilityIssue> {
+        let mut issues = vec![];
+        let name = Some(self.name.as_str());
+
+        // Check for a default sheet name like "Sheet1" or "Chart1".
+        let prefix = if self.is_chartsheet { "Chart" } else { "Sheet" };
+        if let Some(number) = self.name.strip_prefix(prefix) {
+            if !number.is_empty() && number.bytes().all(|b| b.is_ascii_digit()) {
+                issues.push(AccessibilityIssue::new(
+                    AccessibilityIssueType::DefaultSheetName,
+                    name,
+                    None,
+                    "Worksheet has a default name instead of a descriptive name.",
+                ));
+            }
+        }
+
+        // Check for images, charts and shapes without alternative text.
+        let missing_alt_text = |(row, col): &(RowNum, ColNum), object: &str| {
+            AccessibilityIssue::new(
+                AccessibilityIssueType::MissingAltText,
+                name,
+                Some(utility::row_col_to_cell(*row, *col)),
+                format!("{object} doesn't have alternative text."),
+            )
+        };
+
+        for (cell, image) in &self.images {
+            if image.alt_text.is_empty() && !image.decorative {
+                issues.push(missing_alt_text(cell, "Image"));
+            }
+        }
+
+        for (cell, chart) in &self.charts {
+            if chart.alt_text.is_empty() && !chart.decorative {
+                issues.push(missing_alt_text(cell, "Chart"));
+            }
+        }
+
+        for (cell, shape) in &self.shapes {
+            if shape.alt_text.is_empty() && shape.text.is_empty() && !shape.decorative {
+                issues.push(missing_alt_text(cell, "Shape"));
+            }
+        }
+
+        // Check for merged cells.
+        for range in &self.merged_ranges {
+            issues.push(AccessibilityIssue::new(
+                AccessibilityIssueType::MergedCells,
+                name,
+                Some(range.to_range_string()),
+                "Merged cells can make the worksheet difficult to navigate.",
+            ));
+        }
+
+        // Check for cell formats with low contrast text. Only the first cell
+        // that uses each format is reported.
+        let mut low_contrast_ratios = HashMap::new();
+        for (xf_index, format) in self.xf_formats.iter().enumerate().skip(1) {
+            if let Some(ratio) = format_contrast_ratio(format) {
+                if ratio < MIN_CONTRAST_RATIO {
+                    low_contrast_ratios.insert(xf_index as u32, ratio);
+                }
+            }
+        }
+
+        if !low_contrast_ratios.is_empty() {
+            for (row, columns) in &self.data_table {
+                for (col, cell) in columns {
+                    let xf_index = match cell {
+                        CellType::Blank { xf_index, .. }
+                        | CellType::Error { xf_index, .. }
+                        | CellType::String { xf_index, .. }
+                        | CellType::Number { xf_index, .. }
+                        | CellType::Boolean { xf_index, .. }
+                        | CellType::Formula { xf_index, .. }
+                        | CellType::DateTime { xf_index, .. }
+                        | CellType::RichString { xf_index, .. }
+                        | CellType::ArrayFormula { xf_index, .. } => xf_index,
+                    };
+
+                    if let Some(ratio) = low_contrast_ratios.remove(xf_index) {
+                        issues.push(AccessibilityIssue::new(
+                            AccessibilityIssueType::LowContrast,
+                            name,
+                            Some(utility::row_col_to_cell(*row, *col)),
+                            format!(
+                                "Font and fill colors have a low contrast ratio of {ratio:.2}:1."
+                            ),
+                        ));
+                    }
+                }
+            }
+        }
+
+        issues
+    }
+
     // Check if there is a header image.
     pub(crate) fn has_header_footer_images(&self) -> bool {
         self.header_footer_images[0].is_some()
@@ -14853,6 +19351,39 @@ impl Worksheet {
             || self.header_footer_images[5].is_some()
     }
 
+    // Store a header or footer image along with its options.
+    fn store_header_footer_image(
+        &mut self,
+        image: &Image,
+        position: HeaderImagePosition,
+        options: &HeaderImageOptions,
+        is_header: bool,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        // Check that the cropping leaves some of the image visible.
+        let width = image.width() * 96.0 / image.width_dpi();
+        let height = image.height() * 96.0 / image.height_dpi();
+
+        if f64::from(options.crop_left) + f64::from(options.crop_right) >= width
+            || f64::from(options.crop_top) + f64::from(options.crop_bottom) >= height
+        {
+            let error = format!(
+                "Header/footer image cropping ({}, {}, {}, {}) is larger than the image size ({width}, {height})",
+                options.crop_left, options.crop_top, options.crop_right, options.crop_bottom
+            );
+            return Err(XlsxError::ParameterError(error));
+        }
+
+        let mut image = image.clone();
+        image.header_position = position.clone();
+        image.is_header = is_header;
+        image.header_options = options.clone();
+
+        let index = if is_header { 0 } else { 3 };
+        self.header_footer_images[index + position as usize] = Some(image);
+
+        Ok(self)
+    }
+
     // Check that there is a header/footer &[Picture] variable in the correct
     // position to match the corresponding image object.
     fn verify_header_footer_image(string: &str, position: &HeaderImagePosition) -> bool {
@@ -14906,10 +19437,9 @@ impl Worksheet {
     }
 
     // Convert column pixel width to character width.
-    pub(crate) fn pixels_to_width(pixels: u16) -> f64 {
-        // Properties for Calibri 11.
-        let max_digit_width = 7.0_f64;
-        let padding = 5.0_f64;
+    pub(crate) fn pixels_to_width(&self, pixels: u16) -> f64 {
+        let max_digit_width = self.max_digit_width();
+        let padding = self.cell_padding;
         let mut width = f64::from(pixels);
 
         if width < 12.0 {
@@ -14963,7 +19493,11 @@ impl Worksheet {
                         }
                     }
                 }
-                None => data.push(String::new()),
+                None => {
+                    for _ in first_col..=last_col {
+                        data.push(String::new());
+                    }
+                }
             }
         }
 
@@ -15099,6 +19633,11 @@ impl Worksheet {
             self.write_col_breaks();
         }
 
+        // Write the ignoredErrors element.
+        if !self.ignored_errors.is_empty() {
+            self.write_ignored_errors();
+        }
+
         // Write the drawing element.
         if !self.drawing.drawings.is_empty() {
             self.write_drawing();
@@ -15232,6 +19771,7 @@ impl Worksheet {
             && (self.tab_color == Color::Default || self.tab_color == Color::Automatic)
             && self.vba_codename.is_none()
             && !self.is_chartsheet
+            && !self.has_outline_settings()
         {
             return;
         }
@@ -15246,21 +19786,60 @@ impl Worksheet {
 
         if self.fit_to_page
             || (self.tab_color != Color::Default && self.tab_color != Color::Automatic)
+            || self.has_outline_settings()
         {
             self.writer.xml_start_tag("sheetPr", &attributes);
 
-            // Write the pageSetUpPr element.
-            self.write_page_set_up_pr();
-
             // Write the tabColor element.
             self.write_tab_color();
 
+            // Write the outlinePr element.
+            self.write_outline_pr();
+
+            // Write the pageSetUpPr element.
+            self.write_page_set_up_pr();
+
             self.writer.xml_end_tag("sheetPr");
         } else {
             self.writer.xml_empty_tag("sheetPr", &attributes);
         }
     }
 
+    // Check if the outline settings have been changed from the defaults.
+    fn has_outline_settings(&self) -> bool {
+        !self.outline_symbols_below
+            || !self.outline_symbols_right
+            || self.outline_auto_style
+            || !self.outline_visible
+    }
+
+    // Write the <outlinePr> element.
+    fn write_outline_pr(&mut self) {
+        if !self.has_outline_settings() {
+            return;
+        }
+
+        let mut attributes = vec![];
+
+        if self.outline_auto_style {
+            attributes.push(("applyStyles", "1"));
+        }
+
+        if !self.outline_symbols_below {
+            attributes.push(("summaryBelow", "0"));
+        }
+
+        if !self.outline_symbols_right {
+            attributes.push(("summaryRight", "0"));
+        }
+
+        if !self.outline_visible {
+            attributes.push(("showOutlineSymbols", "0"));
+        }
+
+        self.writer.xml_empty_tag("outlinePr", &attributes);
+    }
+
     // Write the <pageSetUpPr> element.
     fn write_page_set_up_pr(&mut self) {
         if !self.fit_to_page {
@@ -15333,6 +19912,10 @@ impl Worksheet {
             attributes.push(("showGridLines", "0".to_string()));
         }
 
+        if !self.screen_headings {
+            attributes.push(("showRowColHeaders", "0".to_string()));
+        }
+
         if self.right_to_left {
             attributes.push(("rightToLeft", "1".to_string()));
         }
@@ -15403,49 +19986,74 @@ impl Worksheet {
             return;
         }
 
-        let row = self.panes.freeze_cell.0;
-        let col = self.panes.freeze_cell.1;
+        let (row, col) = self.panes.split_cell();
+        let (positions, default_pane) = self.panes.positions();
 
-        // Write the pane and selection elements.
-        if row > 0 && col > 0 {
-            self.write_pane("bottomRight");
-            self.write_selection(
-                "topRight",
-                &utility::row_col_to_cell(0, col),
-                &utility::row_col_to_cell(0, col),
-            );
-            self.write_selection(
-                "bottomLeft",
-                &utility::row_col_to_cell(row, 0),
-                &utility::row_col_to_cell(row, 0),
-            );
-            self.write_selection("bottomRight", "", "");
-        } else if col > 0 {
-            self.write_pane("topRight");
-            self.write_selection("topRight", "", "");
-        } else {
-            self.write_pane("bottomLeft");
-            self.write_selection("bottomLeft", "", "");
+        // Ignore a user active pane that isn't created by the frozen row/col.
+        let active_pane = match self.panes.active_pane {
+            Some(pane) if pane == PanePosition::TopLeft || positions.contains(&pane) => pane,
+            _ => default_pane,
+        };
+
+        self.write_pane(active_pane.as_str());
+
+        // Write the selection elements for each pane.
+        for pane in positions {
+            let (active_cell, range) = match self.panes.selections.get(&pane) {
+                Some(selection) => selection.clone(),
+                None => match pane {
+                    PanePosition::TopRight if row > 0 => {
+                        let cell = utility::row_col_to_cell(0, col);
+                        (cell.clone(), cell)
+                    }
+                    PanePosition::BottomLeft if col > 0 => {
+                        let cell = utility::row_col_to_cell(row, 0);
+                        (cell.clone(), cell)
+                    }
+                    _ => (String::new(), String::new()),
+                },
+            };
+
+            self.write_selection(pane.as_str(), &active_cell, &range);
         }
     }
 
     // Write the <pane> element.
     fn write_pane(&mut self, active_pane: &str) {
-        let row = self.panes.freeze_cell.0;
-        let col = self.panes.freeze_cell.1;
         let mut attributes = vec![];
 
-        if col > 0 {
-            attributes.push(("xSplit", col.to_string()));
-        }
+        if self.panes.is_split() {
+            // Split positions are stored in twips (1/20 of a point) and are
+            // measured from the edge of the window so they include the row
+            // and column headers.
+            let (y_pixels, x_pixels) = self.panes.split_pixels;
+
+            if x_pixels > 0 {
+                attributes.push(("xSplit", (u64::from(x_pixels) * 15 + 390).to_string()));
+            }
+
+            if y_pixels > 0 {
+                attributes.push(("ySplit", (u64::from(y_pixels) * 15 + 300).to_string()));
+            }
+        } else {
+            let (row, col) = self.panes.freeze_cell;
+
+            if col > 0 {
+                attributes.push(("xSplit", col.to_string()));
+            }
 
-        if row > 0 {
-            attributes.push(("ySplit", row.to_string()));
+            if row > 0 {
+                attributes.push(("ySplit", row.to_string()));
+            }
         }
 
         attributes.push(("topLeftCell", self.panes.top_left()));
         attributes.push(("activePane", active_pane.to_string()));
-        attributes.push(("state", "frozen".to_string()));
+
+        // The default state, "split", is omitted like Excel.
+        if !self.panes.is_split() {
+            attributes.push(("state", "frozen".to_string()));
+        }
 
         self.writer.xml_empty_tag("pane", &attributes);
     }
@@ -15473,7 +20081,7 @@ impl Worksheet {
     fn write_sheet_format_pr(&mut self) {
         let mut attributes = vec![("defaultRowHeight", self.user_default_row_height.to_string())];
 
-        if self.user_default_row_height != DEFAULT_ROW_HEIGHT {
+        if self.user_default_row_height != self.default_row_height {
             attributes.push(("customHeight", "1".to_string()));
         }
 
@@ -15481,6 +20089,17 @@ impl Worksheet {
             attributes.push(("zeroHeight", "1".to_string()));
         }
 
+        // Write the maximum row and column outline levels, if any.
+        let row_level = self.changed_rows.values().map(|row| row.level).max();
+        if let Some(level) = row_level.filter(|level| *level > 0) {
+            attributes.push(("outlineLevelRow", level.to_string()));
+        }
+
+        let col_level = self.changed_cols.values().map(|col| col.level).max();
+        if let Some(level) = col_level.filter(|level| *level > 0) {
+            attributes.push(("outlineLevelCol", level.to_string()));
+        }
+
         if self.use_x14_extensions {
             attributes.push(("x14ac:dyDescent", "0.25".to_string()));
         }
@@ -15862,6 +20481,12 @@ impl Worksheet {
             attributes.push(("paperSize", self.paper_size.to_string()));
         }
 
+        let (paper_width, paper_height) = self.paper_dimensions;
+        if paper_width > 0.0 && paper_height > 0.0 {
+            attributes.push(("paperHeight", format!("{paper_height}mm")));
+            attributes.push(("paperWidth", format!("{paper_width}mm")));
+        }
+
         if self.print_scale != 100 {
             attributes.push(("scale", self.print_scale.to_string()));
         }
@@ -15929,7 +20554,11 @@ impl Worksheet {
 
         self.writer.xml_start_tag("filterColumn", &attributes);
 
-        if filter_condition.is_list_filter {
+        if let Some(top10) = filter_condition.top10 {
+            self.write_top10(top10);
+        } else if let Some(dynamic) = filter_condition.dynamic {
+            self.write_dynamic_filter(dynamic);
+        } else if filter_condition.is_list_filter {
             self.write_list_filters(filter_condition);
         } else {
             self.write_custom_filters(filter_condition);
@@ -15987,6 +20616,30 @@ impl Worksheet {
         self.writer.xml_end_tag("customFilters");
     }
 
+    // Write the <top10> element.
+    fn write_top10(&mut self, top10: FilterTop10) {
+        let mut attributes = vec![];
+
+        if top10.is_bottom {
+            attributes.push(("top", "0".to_string()));
+        }
+
+        if top10.is_percent {
+            attributes.push(("percent", "1".to_string()));
+        }
+
+        attributes.push(("val", top10.count.to_string()));
+
+        self.writer.xml_empty_tag("top10", &attributes);
+    }
+
+    // Write the <dynamicFilter> element.
+    fn write_dynamic_filter(&mut self, dynamic: FilterDynamicType) {
+        let attributes = [("type", dynamic.as_str().to_string())];
+
+        self.writer.xml_empty_tag("dynamicFilter", &attributes);
+    }
+
     // Write the <customFilter> element.
     fn write_custom_filter(&mut self, data: &FilterData) {
         let mut attributes = vec![];
@@ -16029,6 +20682,29 @@ impl Worksheet {
     fn write_data_table(&mut self) {
         let spans = self.calculate_spans();
 
+        // Hide the rows that are written in a hidden range that extends to the
+        // end of the worksheet. The unused rows are hidden by default.
+        if let Some(first_row) = self.first_unused_hidden_row {
+            let rows: Vec<RowNum> = self
+                .data_table
+                .range(first_row..)
+                .map(|(row, _)| *row)
+                .chain(self.notes.keys().copied().filter(|row| *row >= first_row))
+                .collect();
+
+            for row in rows {
+                self.row_options_mut(row).hidden = true;
+            }
+
+            for (_, row_options) in self
+                .changed_rows
+                .iter_mut()
+                .filter(|(row, _)| **row >= first_row)
+            {
+                row_options.hidden = true;
+            }
+        }
+
         // Swap out the worksheet data structures so we can iterate over them and
         // still call self.write_xml() methods.
         let mut temp_table: BTreeMap<RowNum, BTreeMap<ColNum, CellType>> = BTreeMap::new();
@@ -16199,7 +20875,7 @@ impl Worksheet {
                 attributes.push(("customFormat", "1".to_string()));
             }
 
-            if row_options.height != DEFAULT_ROW_HEIGHT {
+            if row_options.height != self.default_row_height {
                 attributes.push(("ht", row_options.height.to_string()));
             }
 
@@ -16207,10 +20883,18 @@ impl Worksheet {
                 attributes.push(("hidden", "1".to_string()));
             }
 
-            if row_options.height != DEFAULT_ROW_HEIGHT {
+            if row_options.height != self.default_row_height {
                 attributes.push(("customHeight", "1".to_string()));
             }
-        } else if self.user_default_row_height != DEFAULT_ROW_HEIGHT {
+
+            if row_options.level > 0 {
+                attributes.push(("outlineLevel", row_options.level.to_string()));
+            }
+
+            if row_options.collapsed {
+                attributes.push(("collapsed", "1".to_string()));
+            }
+        } else if self.user_default_row_height != self.default_row_height {
             attributes.push(("ht", self.user_default_row_height.to_string()));
             attributes.push(("customHeight", "1".to_string()));
         }
@@ -16486,19 +21170,19 @@ impl Worksheet {
         let last_col = last_col + 1;
         let mut width = col_options.width;
         let xf_index = col_options.xf_index;
-        let has_custom_width = width != DEFAULT_COL_WIDTH;
+        let has_custom_width = width != self.default_col_width;
         let hidden = col_options.hidden;
 
         // The default col width changes to 0 for hidden columns.
-        if width == DEFAULT_COL_WIDTH && hidden {
+        if width == self.default_col_width && hidden {
             width = 0.0;
         }
 
         // Convert column width from user units to character width.
         if width > 0.0 {
-            // Properties for Calibri 11.
-            let max_digit_width = 7.0_f64;
-            let padding = 5.0_f64;
+            // Properties for Calibri 11 or the workbook default font.
+            let max_digit_width = self.default_max_digit_width.unwrap_or(7.0);
+            let padding = self.cell_padding;
 
             if width < 1.0 {
                 width = ((width * (max_digit_width + padding)).round() / max_digit_width * 256.0)
@@ -16534,6 +21218,14 @@ impl Worksheet {
             attributes.push(("customWidth", "1".to_string()));
         }
 
+        if col_options.level > 0 {
+            attributes.push(("outlineLevel", col_options.level.to_string()));
+        }
+
+        if col_options.collapsed {
+            attributes.push(("collapsed", "1".to_string()));
+        }
+
         self.writer.xml_empty_tag("col", &attributes);
     }
 
@@ -16813,6 +21505,22 @@ impl Worksheet {
         self.writer.xml_empty_tag("brk", &attributes);
     }
 
+    // Write the <ignoredErrors> element.
+    fn write_ignored_errors(&mut self) {
+        self.writer.xml_start_tag_only("ignoredErrors");
+
+        for (error_type, ranges) in &self.ignored_errors {
+            let attributes = [
+                ("sqref", ranges.join(" ")),
+                (error_type.as_str(), "1".to_string()),
+            ];
+
+            self.writer.xml_empty_tag("ignoredError", &attributes);
+        }
+
+        self.writer.xml_end_tag("ignoredErrors");
+    }
+
     // Write the <extLst> element.
     fn write_extensions(&mut self) {
         self.writer.xml_start_tag_only("extLst");
@@ -17190,37 +21898,163 @@ macro_rules! write_number_trait_impl {
         }
     )*)
 }
-write_number_trait_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+write_number_trait_impl!(u8 i8 u16 i16 u32 i32 f32 f64);
+
+// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
+// is documented as a loss of precision.
+macro_rules! write_number_trait_impl {
+    ($($t:ty)*) => ($(
+        impl IntoExcelData for $t {
+            fn write(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                #[allow(clippy::cast_precision_loss)]
+                worksheet.store_number(row, col, self as f64, None)
+            }
+
+            fn write_with_format<'a>(
+                self,
+                worksheet: &'a mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                format: &Format,
+            ) -> Result<&'a mut Worksheet, XlsxError> {
+                #[allow(clippy::cast_precision_loss)]
+                worksheet.store_number(row, col, self as f64, Some(format))
+            }
+        }
+    )*)
+}
+write_number_trait_impl!(u64 i64);
+
+// The NonZero integer types are written via their underlying integer values.
+macro_rules! write_nonzero_trait_impl {
+    ($($t:ty)*) => ($(
+        impl IntoExcelData for $t {
+            fn write(
+                self,
+                worksheet: &mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+            ) -> Result<&mut Worksheet, XlsxError> {
+                self.get().write(worksheet, row, col)
+            }
+
+            fn write_with_format<'a>(
+                self,
+                worksheet: &'a mut Worksheet,
+                row: RowNum,
+                col: ColNum,
+                format: &Format,
+            ) -> Result<&'a mut Worksheet, XlsxError> {
+                self.get().write_with_format(worksheet, row, col, format)
+            }
+        }
+    )*)
+}
+write_nonzero_trait_impl!(
+    NonZeroU8 NonZeroI8 NonZeroU16 NonZeroI16 NonZeroU32 NonZeroI32 NonZeroU64 NonZeroI64
+);
+
+// Note: Excel stores numbers as f64 so `Decimal` values with more than 15-17
+// significant digits are documented as a loss of precision.
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+impl IntoExcelData for Decimal {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_number(row, col, decimal_to_f64(self)?, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        worksheet.store_number(row, col, decimal_to_f64(self)?, Some(format))
+    }
+}
+
+#[cfg(feature = "rust_decimal")]
+#[cfg_attr(docsrs, doc(cfg(feature = "rust_decimal")))]
+impl IntoExcelData for &Decimal {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        (*self).write(worksheet, row, col)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        (*self).write_with_format(worksheet, row, col, format)
+    }
+}
+
+// Note: `BigInt` values outside Excel's integer range of +/-
+// 999,999,999,999,999 (15 digits) can't be stored as numbers without a loss of
+// precision so they are handled according to the worksheet `BigIntPrecision`.
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+impl IntoExcelData for &BigInt {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_bigint(row, col, self, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        worksheet.store_bigint(row, col, self, Some(format))
+    }
+}
 
-// Note: Excel doesn't support saving the full range of i64/u64 in f64 so this
-// is documented as a loss of precision.
-macro_rules! write_number_trait_impl {
-    ($($t:ty)*) => ($(
-        impl IntoExcelData for $t {
-            fn write(
-                self,
-                worksheet: &mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-            ) -> Result<&mut Worksheet, XlsxError> {
-                #[allow(clippy::cast_precision_loss)]
-                worksheet.store_number(row, col, self as f64, None)
-            }
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+impl IntoExcelData for BigInt {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        (&self).write(worksheet, row, col)
+    }
 
-            fn write_with_format<'a>(
-                self,
-                worksheet: &'a mut Worksheet,
-                row: RowNum,
-                col: ColNum,
-                format: &Format,
-            ) -> Result<&'a mut Worksheet, XlsxError> {
-                #[allow(clippy::cast_precision_loss)]
-                worksheet.store_number(row, col, self as f64, Some(format))
-            }
-        }
-    )*)
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        (&self).write_with_format(worksheet, row, col, format)
+    }
 }
-write_number_trait_impl!(u64 i64);
 
 impl IntoExcelData for bool {
     fn write(
@@ -17364,6 +22198,81 @@ impl IntoExcelData for &NaiveTime {
     }
 }
 
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &PrimitiveDateTime {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_datetime_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &Date {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_date_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
+#[cfg(feature = "time")]
+#[cfg_attr(docsrs, doc(cfg(feature = "time")))]
+impl IntoExcelData for &Time {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_time_to_excel(self);
+        worksheet.store_datetime(row, col, number, None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        let number = ExcelDateTime::time_time_to_excel(self);
+        worksheet.store_datetime(row, col, number, Some(format))
+    }
+}
+
 impl IntoExcelData for Formula {
     fn write(
         self,
@@ -17502,15 +22411,346 @@ impl<T: IntoExcelData, E: IntoExcelData> IntoExcelData for Result<T, E> {
     }
 }
 
+/// The `AsString` struct is a wrapper to write any type that implements
+/// [`Display`] as a string.
+///
+/// The [`Worksheet::write()`] and related methods only accept types that
+/// implement the [`IntoExcelData`] trait, so that data isn't converted to a
+/// string by accident. The `AsString` wrapper is an opt-in way to write other
+/// types, such as user defined ids or enums, using their [`Display`]
+/// representation without having to implement [`IntoExcelData`] for them.
+///
+/// # Examples
+///
+/// The following example demonstrates writing a type that implements
+/// [`Display`] to a worksheet.
+///
+/// ```
+/// # // This code is available in examples/doc_worksheet_write_as_string.rs
+/// #
+/// # use std::fmt;
+/// #
+/// # use rust_xlsxwriter::{AsString, Workbook, XlsxError};
+/// #
+/// // A user defined type that implements Display.
+/// struct OrderId(u32);
+///
+/// impl fmt::Display for OrderId {
+///     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+///         write!(f, "ORD-{:06}", self.0)
+///     }
+/// }
+///
+/// # fn main() -> Result<(), XlsxError> {
+/// #     // Create a new Excel file object.
+/// #     let mut workbook = Workbook::new();
+/// #
+/// #     // Add a worksheet to the workbook.
+///     let worksheet = workbook.add_worksheet();
+///
+///     // Write the Display representation of the types as strings.
+///     worksheet.write(0, 0, AsString(OrderId(42)))?;
+///     worksheet.write(1, 0, AsString(std::net::Ipv4Addr::LOCALHOST))?;
+/// #
+/// #     workbook.save("worksheet.xlsx")?;
+/// #
+/// #     Ok(())
+/// # }
+/// ```
+///
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct AsString<T: Display>(pub T);
+
+impl<T: Display> IntoExcelData for AsString<T> {
+    fn write(
+        self,
+        worksheet: &mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+    ) -> Result<&mut Worksheet, XlsxError> {
+        worksheet.store_string(row, col, self.0.to_string(), None)
+    }
+
+    fn write_with_format<'a>(
+        self,
+        worksheet: &'a mut Worksheet,
+        row: RowNum,
+        col: ColNum,
+        format: &Format,
+    ) -> Result<&'a mut Worksheet, XlsxError> {
+        worksheet.store_string(row, col, self.0.to_string(), Some(format))
+    }
+}
+
+/// The `PanePosition` enum defines the panes of a worksheet with freeze panes.
+///
+/// It is used with [`Worksheet::set_freeze_panes_active_pane()`] and
+/// [`Worksheet::set_freeze_panes_selection()`]. The panes that exist depend on
+/// the frozen rows and columns set with [`Worksheet::set_freeze_panes()`]:
+/// frozen rows add a bottom pane, frozen columns add a right pane, and both
+/// together divide the worksheet into four panes.
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub enum PanePosition {
+    /// The top left pane. This is the fixed pane when both rows and columns
+    /// are frozen.
+    TopLeft,
+
+    /// The top right pane, to the right of the frozen columns.
+    TopRight,
+
+    /// The bottom left pane, below the frozen rows.
+    BottomLeft,
+
+    /// The bottom right pane, below and to the right of the frozen rows and
+    /// columns.
+    BottomRight,
+}
+
+impl PanePosition {
+    fn as_str(self) -> &'static str {
+        match self {
+            PanePosition::TopLeft => "topLeft",
+            PanePosition::TopRight => "topRight",
+            PanePosition::BottomLeft => "bottomLeft",
+            PanePosition::BottomRight => "bottomRight",
+        }
+    }
+}
+
+/// The `MarginPreset` enum defines the Excel page margin presets.
+///
+/// It is used with [`Worksheet::set_margin_preset()`] to set all the page
+/// margins to the values of the "Normal", "Wide" or "Narrow" options in the
+/// Excel "Page Layout -> Margins" menu. The values are in inches.
+///
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum MarginPreset {
+    /// The Excel default margins: left/right 0.7, top/bottom 0.75 and
+    /// header/footer 0.3.
+    #[default]
+    Normal,
+
+    /// Wide margins: left/right 1.0, top/bottom 1.0 and header/footer 0.5.
+    Wide,
+
+    /// Narrow margins: left/right 0.25, top/bottom 0.75 and header/footer
+    /// 0.3.
+    Narrow,
+}
+
+/// The `BigIntPrecision` enum defines how `BigInt` values that can't be
+/// stored by Excel without a loss of precision are written.
+///
+/// It is used with [`Worksheet::set_bigint_precision()`]. Excel stores numbers
+/// as f64 values so only integers in the range +/- 999,999,999,999,999 (15
+/// digits) can be stored exactly. Values in this range are always written as
+/// numbers.
+///
+#[cfg(feature = "num-bigint")]
+#[cfg_attr(docsrs, doc(cfg(feature = "num-bigint")))]
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub enum BigIntPrecision {
+    /// Write values outside Excel's integer range as strings. This is the
+    /// default.
+    #[default]
+    String,
+
+    /// Write values outside Excel's integer range as numbers, with a loss of
+    /// precision.
+    Number,
+
+    /// Return an [`XlsxError::ParameterError`] error for values outside
+    /// Excel's integer range.
+    Error,
+}
+
+/// The `CellValue` enum represents the data stored in a worksheet cell.
+///
+/// It is returned by [`Worksheet::cell_value()`] and [`Worksheet::cells()`]
+/// to allow previously written data to be read back from a worksheet.
+///
+/// This is a different type from [`ExcelCellValue`](crate::ExcelCellValue),
+/// which is used to write rows of data from an [`ExcelRowSource`], since it
+/// represents the data as it is stored in the worksheet rather than the data
+/// that can be written:
+///
+/// - Dates and times are stored as Excel serial numbers, without the original
+///   [`ExcelDateTime`] value or a date/datetime distinction, so they are
+///   returned as the `f64` serial number. This also avoids a conversion that
+///   can fail for numbers outside the [`ExcelDateTime`] range.
+/// - Formulas and embedded images are stored cell types that can't be
+///   written as values from a row source.
+///
+#[derive(Clone, Debug, PartialEq)]
+pub enum CellValue {
+    /// A cell that has a format but no data.
+    Blank,
+
+    /// A boolean value.
+    Boolean(bool),
+
+    /// A number value.
+    Number(f64),
+
+    /// A date or time value as an Excel serial datetime number. This can be
+    /// converted to an [`ExcelDateTime`] via
+    /// [`ExcelDateTime::from_serial_datetime()`].
+    DateTime(f64),
+
+    /// A string value. For rich strings this is the unformatted text.
+    String(String),
+
+    /// A formula or array formula. The formula is in the form stored in the
+    /// file, without the leading `=` and with any future function prefixes,
+    /// and the result is the value set by
+    /// [`Worksheet::set_formula_result()`], if any.
+    Formula {
+        /// The formula string.
+        formula: String,
+
+        /// The formula result string.
+        result: String,
+    },
+
+    /// An image embedded in the cell via [`Worksheet::embed_image()`].
+    EmbeddedImage,
+}
+
+impl CellValue {
+    // Convert the internal cell data to a user value.
+    fn from_cell(cell: &CellType) -> CellValue {
+        match cell {
+            CellType::Blank { .. } => CellValue::Blank,
+            CellType::Boolean { boolean, .. } => CellValue::Boolean(*boolean),
+            CellType::Number { number, .. } => CellValue::Number(*number),
+            CellType::DateTime { number, .. } => CellValue::DateTime(*number),
+            CellType::String { string, .. }
+            | CellType::RichString {
+                raw_string: string, ..
+            } => CellValue::String(string.to_string()),
+            CellType::Formula {
+                formula, result, ..
+            }
+            | CellType::ArrayFormula {
+                formula, result, ..
+            } => CellValue::Formula {
+                formula: formula.to_string(),
+                result: result.to_string(),
+            },
+            CellType::Error { .. } => CellValue::EmbeddedImage,
+        }
+    }
+}
+
+/// The `IgnoreError` enum defines the Excel cell warnings that can be turned
+/// off with [`Worksheet::ignore_error()`] and
+/// [`Worksheet::ignore_error_range()`].
+///
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub enum IgnoreError {
+    /// Turn off warnings for numbers stored as text.
+    NumberStoredAsText,
+
+    /// Turn off warnings for formula errors such as divide by zero.
+    EvalError,
+
+    /// Turn off warnings for formulas that differ from surrounding formulas.
+    FormulaDiffers,
+
+    /// Turn off warnings for formulas that omit cells in a range.
+    FormulaRange,
+
+    /// Turn off warnings for unlocked cells that contain formulas.
+    FormulaUnlocked,
+
+    /// Turn off warnings for formulas that refer to empty cells.
+    EmptyCellReference,
+
+    /// Turn off warnings for cells that don't match their data validation.
+    ListDataValidation,
+
+    /// Turn off warnings for cells that are inconsistent with a table
+    /// calculated column formula.
+    CalculatedColumn,
+
+    /// Turn off warnings for dates stored as text with a two digit year.
+    TwoDigitTextYear,
+}
+
+// Allow a single error type to be used where a slice of error types is
+// expected.
+impl AsRef<[IgnoreError]> for IgnoreError {
+    fn as_ref(&self) -> &[IgnoreError] {
+        std::slice::from_ref(self)
+    }
+}
+
+impl IgnoreError {
+    fn as_str(self) -> &'static str {
+        match self {
+            IgnoreError::NumberStoredAsText => "numberStoredAsText",
+            IgnoreError::EvalError => "evalError",
+            IgnoreError::FormulaDiffers => "formula",
+            IgnoreError::FormulaRange => "formulaRange",
+            IgnoreError::FormulaUnlocked => "unlockedFormula",
+            IgnoreError::EmptyCellReference => "emptyCellReference",
+            IgnoreError::ListDataValidation => "listDataValidation",
+            IgnoreError::CalculatedColumn => "calculatedColumn",
+            IgnoreError::TwoDigitTextYear => "twoDigitTextYear",
+        }
+    }
+}
+
 // -----------------------------------------------------------------------
 // Helper enums/structs/functions.
 // -----------------------------------------------------------------------
 
+// Format a zero indexed cell location for error messages.
+fn cell_location(row: RowNum, col: ColNum) -> String {
+    format!("row {row}, column {col}")
+}
+
+// Format a zero indexed range location for error messages.
+fn range_location(
+    first_row: RowNum,
+    first_col: ColNum,
+    last_row: RowNum,
+    last_col: ColNum,
+) -> String {
+    format!("rows {first_row} to {last_row}, columns {first_col} to {last_col}")
+}
+
 // Round to the closest integer number of emu units.
 fn round_to_emus(dimension: f64) -> f64 {
     (dimension * 9525.0).round()
 }
 
+// Convert a `Decimal` to the f64 value stored by Excel.
+#[cfg(feature = "rust_decimal")]
+fn decimal_to_f64(decimal: Decimal) -> Result<f64, XlsxError> {
+    f64::try_from(decimal).map_err(|e| XlsxError::ParameterError(e.to_string()))
+}
+
+// Convert a `BigInt` to an f64 if it is within Excel's integer range of +/-
+// 999,999,999,999,999 (15 digits), which can be stored without loss of
+// precision.
+#[cfg(feature = "num-bigint")]
+fn bigint_to_f64(number: &BigInt) -> Option<f64> {
+    match i64::try_from(number) {
+        #[allow(clippy::cast_precision_loss)]
+        Ok(number) if number.unsigned_abs() <= 999_999_999_999_999 => Some(number as f64),
+        _ => None,
+    }
+}
+
+// Convert a `BigInt` of any size to the nearest f64 value, or to infinity for
+// values outside the f64 range.
+#[cfg(feature = "num-bigint")]
+fn bigint_to_lossy_f64(number: &BigInt) -> f64 {
+    number.to_string().parse().unwrap_or(f64::NAN)
+}
+
 // Struct to contain a cell range with some utility debug and other methods.
 #[derive(Clone)]
 pub(crate) struct CellRange {
@@ -17567,6 +22807,8 @@ struct RowOptions {
     height: f64,
     xf_index: u32,
     hidden: bool,
+    level: u8,
+    collapsed: bool,
 }
 
 #[derive(Clone, PartialEq)]
@@ -17575,6 +22817,8 @@ struct ColOptions {
     xf_index: u32,
     hidden: bool,
     autofit: bool,
+    level: u8,
+    collapsed: bool,
 }
 
 #[derive(Clone)]
@@ -17633,17 +22877,65 @@ enum PageView {
 #[derive(Clone)]
 struct Panes {
     freeze_cell: (RowNum, ColNum),
+    split_pixels: (u32, u32),
     top_cell: (RowNum, ColNum),
+    active_pane: Option<PanePosition>,
+    selections: HashMap<PanePosition, (String, String)>,
 }
 
 impl Panes {
     fn is_empty(&self) -> bool {
-        self.freeze_cell.0 == 0 && self.freeze_cell.1 == 0
+        self.freeze_cell == (0, 0) && self.split_pixels == (0, 0)
+    }
+
+    fn is_split(&self) -> bool {
+        self.split_pixels != (0, 0)
+    }
+
+    // Get the first cell after the frozen rows/cols. For split panes this is
+    // estimated from the split position using the default row height and
+    // column width in pixels.
+    fn split_cell(&self) -> (RowNum, ColNum) {
+        if self.is_split() {
+            let (y_pixels, x_pixels) = self.split_pixels;
+            let row = (f64::from(y_pixels) / 20.0).round() as RowNum;
+            let col = (f64::from(x_pixels) / 64.0).round() as ColNum;
+
+            (row.min(ROW_MAX - 1), col.min(COL_MAX - 1))
+        } else {
+            self.freeze_cell
+        }
+    }
+
+    // Get the panes, apart from the top left pane, that are created by the
+    // frozen or split rows/cols and the default active pane.
+    fn positions(&self) -> (Vec<PanePosition>, PanePosition) {
+        let (row, col) = if self.is_split() {
+            self.split_pixels
+        } else {
+            (self.freeze_cell.0, u32::from(self.freeze_cell.1))
+        };
+
+        if row > 0 && col > 0 {
+            (
+                vec![
+                    PanePosition::TopRight,
+                    PanePosition::BottomLeft,
+                    PanePosition::BottomRight,
+                ],
+                PanePosition::BottomRight,
+            )
+        } else if col > 0 {
+            (vec![PanePosition::TopRight], PanePosition::TopRight)
+        } else {
+            (vec![PanePosition::BottomLeft], PanePosition::BottomLeft)
+        }
     }
 
     fn top_left(&self) -> String {
         if self.top_cell.0 == 0 && self.top_cell.1 == 0 {
-            utility::row_col_to_cell(self.freeze_cell.0, self.freeze_cell.1)
+            let (row, col) = self.split_cell();
+            utility::row_col_to_cell(row, col)
         } else {
             utility::row_col_to_cell(self.top_cell.0, self.top_cell.1)
         }
@@ -43,6 +43,708 @@ mod worksheet_tests {
         assert_eq!(expected, got);
     }
 
+    #[test]
+    fn test_assemble_outline_settings() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_tab_color("#FF0000");
+        worksheet.set_outline_settings(false, false, true, false);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr>
+                <tabColor rgb="FFFF0000"/>
+                <outlinePr applyStyles="1" summaryBelow="0" summaryRight="0" showOutlineSymbols="0"/>
+              </sheetPr>
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_screen_headings() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_screen_gridlines(false);
+        worksheet.set_screen_headings(false);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView showGridLines="0" showRowColHeaders="0" tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_row_column_groups() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.group_rows(1, 4).unwrap();
+        worksheet.group_rows_collapsed(2, 3).unwrap();
+        worksheet.group_columns(1, 2).unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A2:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="2" outlineLevelCol="1"/>
+              <cols>
+                <col min="2" max="3" width="9.140625" outlineLevel="1"/>
+              </cols>
+              <sheetData>
+                <row r="2" outlineLevel="1"/>
+                <row r="3" hidden="1" outlineLevel="2"/>
+                <row r="4" hidden="1" outlineLevel="2"/>
+                <row r="5" outlineLevel="1" collapsed="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_hidden_row_column_ranges() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.group_rows(1, 2).unwrap();
+        worksheet.set_row_range_hidden(2, 4).unwrap();
+        worksheet.set_column_range_hidden(1, 3).unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A2:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" outlineLevelRow="1"/>
+              <cols>
+                <col min="2" max="4" width="0" hidden="1" customWidth="1"/>
+              </cols>
+              <sheetData>
+                <row r="2" outlineLevel="1"/>
+                <row r="3" hidden="1" outlineLevel="1"/>
+                <row r="4" hidden="1"/>
+                <row r="5" hidden="1"/>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Test the range errors.
+        let result = worksheet.set_row_range_hidden(4, 2);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError(_))));
+
+        let result = worksheet.set_column_range_hidden(1, 16_384);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+    }
+
+    #[test]
+    fn test_assemble_hidden_rows_to_end() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.write(0, 0, 1).unwrap();
+        worksheet.write(3, 0, 2).unwrap();
+        worksheet.set_row_range_hidden(2, 1_048_575).unwrap();
+        worksheet.write(4, 0, 3).unwrap();
+        worksheet.assemble_xml_file();
+
+        assert_eq!(worksheet.changed_rows.len(), 4);
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1:A5"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15" zeroHeight="1"/>
+              <sheetData>
+                <row r="1" spans="1:1">
+                  <c r="A1">
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="2" spans="1:1"/>
+                <row r="4" spans="1:1" hidden="1">
+                  <c r="A4">
+                    <v>2</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:1" hidden="1">
+                  <c r="A5">
+                    <v>3</v>
+                  </c>
+                </row>
+              </sheetData>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_ignored_errors() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet
+            .ignore_error(0, 0, IgnoreError::NumberStoredAsText)
+            .unwrap();
+        worksheet
+            .ignore_error_range(2, 1, 3, 2, IgnoreError::NumberStoredAsText)
+            .unwrap();
+        worksheet
+            .ignore_error(4, 4, IgnoreError::EvalError)
+            .unwrap();
+        worksheet
+            .ignore_error_range(
+                5,
+                0,
+                6,
+                0,
+                [IgnoreError::NumberStoredAsText, IgnoreError::EvalError],
+            )
+            .unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+              <ignoredErrors>
+                <ignoredError sqref="A1 B3:C4 A6:A7" numberStoredAsText="1"/>
+                <ignoredError sqref="E5 A6:A7" evalError="1"/>
+              </ignoredErrors>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        let result = worksheet.ignore_error_range(3, 0, 2, 0, IgnoreError::FormulaRange);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError(_))));
+    }
+
+    #[test]
+    fn row_column_group_errors() {
+        let mut worksheet = Worksheet::new();
+
+        for _ in 0..7 {
+            worksheet.group_rows(0, 9).unwrap();
+            worksheet.group_columns(0, 9).unwrap();
+        }
+
+        let result = worksheet.group_rows(5, 15);
+        assert!(matches!(result, Err(XlsxError::MaxGroupLevelExceeded)));
+
+        let result = worksheet.group_columns_collapsed(9, 9);
+        assert!(matches!(result, Err(XlsxError::MaxGroupLevelExceeded)));
+
+        // The rows and columns outside the failed groups are unchanged.
+        let result = worksheet.group_rows(10, 15);
+        assert!(result.is_ok());
+
+        let result = worksheet.group_rows(3, 2);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError(_))));
+
+        let result = worksheet.group_columns(0, COL_MAX);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+    }
+
+    #[test]
+    fn test_assemble_freeze_panes_selection() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_freeze_panes(1, 1).unwrap();
+        worksheet.set_freeze_panes_top_cell(20, 1).unwrap();
+        worksheet
+            .set_freeze_panes_selection(PanePosition::BottomRight, 24, 3, 20, 1)
+            .unwrap();
+        worksheet
+            .set_freeze_panes_selection(PanePosition::TopRight, 0, 1, 0, 3)
+            .unwrap();
+        worksheet.set_freeze_panes_active_pane(PanePosition::TopRight);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane xSplit="1" ySplit="1" topLeftCell="B21" activePane="topRight" state="frozen"/>
+                  <selection pane="topRight" activeCell="B1" sqref="B1:D1"/>
+                  <selection pane="bottomLeft" activeCell="A2" sqref="A2"/>
+                  <selection pane="bottomRight" activeCell="D25" sqref="B21:D25"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_split_panes() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_split_panes(40, 128);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane xSplit="2310" ySplit="900" topLeftCell="C3" activePane="bottomRight"/>
+                  <selection pane="topRight" activeCell="C1" sqref="C1"/>
+                  <selection pane="bottomLeft" activeCell="A3" sqref="A3"/>
+                  <selection pane="bottomRight"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_split_panes_horizontal() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        // A freeze pane is replaced by the split pane.
+        worksheet.set_freeze_panes(1, 1).unwrap();
+        worksheet.set_split_panes(60, 0);
+        worksheet.set_freeze_panes_top_cell(10, 0).unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0">
+                  <pane ySplit="1200" topLeftCell="A11" activePane="bottomLeft"/>
+                  <selection pane="bottomLeft"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_split_panes_limits() {
+        let mut worksheet = Worksheet::new();
+
+        // Large pixel values shouldn't overflow when converted to twips.
+        worksheet.set_split_panes(u32::MAX, u32::MAX);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        assert!(got.contains(r#"<pane xSplit="64424509815" ySplit="64424509725""#));
+    }
+
+    #[test]
+    fn test_assemble_selection_ranges() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet
+            .set_selection_ranges([(23, 2, 21, 1), (21, 4, 29, 4)])
+            .unwrap();
+        worksheet.set_top_left_cell(20, 0).unwrap();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" topLeftCell="A21" workbookViewId="0">
+                  <selection activeCell="C24" sqref="B22:C24 E22:E30"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        // Test the range errors.
+        let result = worksheet.set_selection_ranges(Vec::<&str>::new());
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        let result = worksheet.set_selection_ranges([(0, 0, 0, 16_384)]);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+    }
+
+    #[test]
+    fn test_assemble_paper_dimensions() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.set_paper_size(9);
+        worksheet.set_paper_dimensions(200.0, 250.5).unwrap();
+        worksheet.set_margin_preset(MarginPreset::Wide);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="1" right="1" top="1" bottom="1" header="0.5" footer="0.5"/>
+              <pageSetup paperSize="9" paperHeight="250.5mm" paperWidth="200mm" orientation="portrait" horizontalDpi="200" verticalDpi="200"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+
+        for (width, height) in [
+            (0.0, 250.0),
+            (f64::NAN, 250.0),
+            (200.0, f64::NAN),
+            (f64::INFINITY, 250.0),
+        ] {
+            let result = worksheet.set_paper_dimensions(width, height);
+            assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+        }
+    }
+
+    #[test]
+    fn test_assemble_top10_and_average_filters() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.write_column(1, 0, [3, 8, 5, 4, 7, 9]).unwrap();
+        worksheet.write_column(1, 1, [2, 1, 3, 6, 2, 4]).unwrap();
+
+        worksheet.autofilter(0, 0, 6, 1).unwrap();
+        worksheet
+            .filter_column(0, &FilterCondition::new().add_top10_filter(3, false, false))
+            .unwrap();
+        worksheet
+            .filter_column(1, &FilterCondition::new().add_below_average_filter())
+            .unwrap();
+
+        worksheet.hide_autofilter_rows();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr filterMode="1"/>
+              <dimension ref="A2:B7"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="2" spans="1:2" hidden="1">
+                  <c r="A2">
+                    <v>3</v>
+                  </c>
+                  <c r="B2">
+                    <v>2</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:2">
+                  <c r="A3">
+                    <v>8</v>
+                  </c>
+                  <c r="B3">
+                    <v>1</v>
+                  </c>
+                </row>
+                <row r="4" spans="1:2" hidden="1">
+                  <c r="A4">
+                    <v>5</v>
+                  </c>
+                  <c r="B4">
+                    <v>3</v>
+                  </c>
+                </row>
+                <row r="5" spans="1:2" hidden="1">
+                  <c r="A5">
+                    <v>4</v>
+                  </c>
+                  <c r="B5">
+                    <v>6</v>
+                  </c>
+                </row>
+                <row r="6" spans="1:2">
+                  <c r="A6">
+                    <v>7</v>
+                  </c>
+                  <c r="B6">
+                    <v>2</v>
+                  </c>
+                </row>
+                <row r="7" spans="1:2" hidden="1">
+                  <c r="A7">
+                    <v>9</v>
+                  </c>
+                  <c r="B7">
+                    <v>4</v>
+                  </c>
+                </row>
+              </sheetData>
+              <autoFilter ref="A1:B7">
+                <filterColumn colId="0">
+                  <top10 val="3"/>
+                </filterColumn>
+                <filterColumn colId="1">
+                  <dynamicFilter type="belowAverage"/>
+                </filterColumn>
+              </autoFilter>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_dynamic_filter() {
+        let mut worksheet = Worksheet {
+            selected: true,
+            ..Default::default()
+        };
+
+        worksheet.write_column(1, 0, [45659, 45700]).unwrap();
+
+        worksheet.autofilter(0, 0, 2, 0).unwrap();
+        worksheet
+            .filter_column(
+                0,
+                &FilterCondition::new().add_dynamic_filter(FilterDynamicType::March),
+            )
+            .unwrap();
+
+        // Rows aren't hidden for date based dynamic filters.
+        worksheet.hide_autofilter_rows();
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <sheetPr filterMode="1"/>
+              <dimension ref="A2:A3"/>
+              <sheetViews>
+                <sheetView tabSelected="1" workbookViewId="0"/>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData>
+                <row r="2" spans="1:1">
+                  <c r="A2">
+                    <v>45659</v>
+                  </c>
+                </row>
+                <row r="3" spans="1:1">
+                  <c r="A3">
+                    <v>45700</v>
+                  </c>
+                </row>
+              </sheetData>
+              <autoFilter ref="A1:A3">
+                <filterColumn colId="0">
+                  <dynamicFilter type="M3"/>
+                </filterColumn>
+              </autoFilter>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
+    #[test]
+    fn test_assemble_freeze_panes_invalid_pane() {
+        let mut worksheet = Worksheet::new();
+
+        // The right hand panes don't exist when only rows are frozen.
+        worksheet.set_freeze_panes(1, 0).unwrap();
+        worksheet
+            .set_freeze_panes_selection(PanePosition::TopRight, 0, 1, 0, 1)
+            .unwrap();
+        worksheet.set_freeze_panes_active_pane(PanePosition::BottomRight);
+        worksheet.assemble_xml_file();
+
+        let got = worksheet.writer.read_to_str();
+        let got = xml_to_vec(got);
+
+        let expected = xml_to_vec(
+            r#"
+            <?xml version="1.0" encoding="UTF-8" standalone="yes"?>
+            <worksheet xmlns="http://schemas.openxmlformats.org/spreadsheetml/2006/main" xmlns:r="http://schemas.openxmlformats.org/officeDocument/2006/relationships">
+              <dimension ref="A1"/>
+              <sheetViews>
+                <sheetView workbookViewId="0">
+                  <pane ySplit="1" topLeftCell="A2" activePane="bottomLeft" state="frozen"/>
+                  <selection pane="bottomLeft"/>
+                </sheetView>
+              </sheetViews>
+              <sheetFormatPr defaultRowHeight="15"/>
+              <sheetData/>
+              <pageMargins left="0.7" right="0.7" top="0.75" bottom="0.75" header="0.3" footer="0.3"/>
+            </worksheet>
+            "#,
+        );
+
+        assert_eq!(expected, got);
+    }
+
     #[test]
     fn verify_header_footer_images() {
         let strings = [
@@ -75,12 +777,133 @@ mod worksheet_tests {
             ),
         ];
 
-        for (string, position, exp) in strings {
-            assert_eq!(
-                exp,
-                Worksheet::verify_header_footer_image(string, &position)
-            );
-        }
+        for (string, position, exp) in strings {
+            assert_eq!(
+                exp,
+                Worksheet::verify_header_footer_image(string, &position)
+            );
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "num-bigint")]
+    fn write_bigint_precision() {
+        use num_bigint::BigInt;
+
+        let mut worksheet = Worksheet::new();
+        let max = BigInt::from(999_999_999_999_999_i64);
+
+        worksheet.write(0, 0, &max).unwrap();
+        worksheet.write(1, 0, -&max).unwrap();
+        worksheet.write(2, 0, &max + 1).unwrap();
+        worksheet.write(3, 0, BigInt::from(u128::MAX)).unwrap();
+
+        let cell_type = |row| worksheet.data_table.get(&row).unwrap().get(&0).unwrap();
+
+        assert!(
+            matches!(cell_type(0), CellType::Number { number, .. } if *number == 999_999_999_999_999.0)
+        );
+        assert!(
+            matches!(cell_type(1), CellType::Number { number, .. } if *number == -999_999_999_999_999.0)
+        );
+        assert!(
+            matches!(cell_type(2), CellType::String { string, .. } if &**string == "1000000000000000")
+        );
+        assert!(matches!(cell_type(3), CellType::String { string, .. } if string.len() == 39));
+
+        worksheet.set_bigint_precision(BigIntPrecision::Number);
+        worksheet.write(4, 0, &max + 1).unwrap();
+
+        let cell_type = |row| worksheet.data_table.get(&row).unwrap().get(&0).unwrap();
+        assert!(
+            matches!(cell_type(4), CellType::Number { number, .. } if *number == 1_000_000_000_000_000.0)
+        );
+
+        worksheet.set_bigint_precision(BigIntPrecision::Error);
+        let result = worksheet.write(5, 0, &max + 1);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.write(5, 0, &max).unwrap();
+    }
+
+    #[test]
+    #[cfg(feature = "serde_json")]
+    fn write_json_rows_limits() {
+        let mut worksheet = Worksheet::new();
+        let data = [serde_json::json!({"a": 1, "b": 2})];
+
+        let result = worksheet.write_json_rows(0, ColNum::MAX, &data);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        let result = worksheet.write_json_rows(0, 16_383, &data);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        // Empty data, or data without keys, has no header to fail the row check.
+        for data in [vec![], vec![serde_json::json!({})]] {
+            let result = worksheet.write_json_rows(RowNum::MAX, 0, &data);
+            assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+        }
+    }
+
+    #[test]
+    #[cfg(feature = "calamine")]
+    fn write_calamine_range_types() {
+        use calamine::{CellErrorType, Data, ExcelDateTime, ExcelDateTimeType, Range};
+
+        let mut worksheet = Worksheet::new();
+        let date = |value| {
+            Data::DateTime(ExcelDateTime::new(
+                value,
+                ExcelDateTimeType::DateTime,
+                false,
+            ))
+        };
+
+        let mut range = Range::new((2, 1), (2, 6));
+        range.set_value((2, 1), Data::Int(7));
+        range.set_value((2, 2), Data::String("abc".to_string()));
+        range.set_value((2, 3), date(45000.0));
+        range.set_value((2, 4), date(45000.5));
+        range.set_value((2, 5), Data::Error(CellErrorType::Div0));
+
+        worksheet.write_calamine_range(0, 0, &range).unwrap();
+
+        let cell_type = |col| worksheet.data_table.get(&0).unwrap().get(&col).unwrap();
+
+        assert!(matches!(cell_type(0), CellType::Number { number, xf_index: 0 } if *number == 7.0));
+        assert!(matches!(cell_type(1), CellType::String { string, .. } if &**string == "abc"));
+        assert!(
+            matches!(cell_type(2), CellType::Number { number, xf_index } if *number == 45000.0 && *xf_index > 0)
+        );
+        assert!(
+            matches!(cell_type(3), CellType::Number { number, xf_index } if *number == 45000.5 && *xf_index > 0)
+        );
+        assert!(matches!(cell_type(4), CellType::String { string, .. } if &**string == "#DIV/0!"));
+        assert!(worksheet.data_table.get(&0).unwrap().get(&5).is_none());
+    }
+
+    #[test]
+    #[cfg(feature = "calamine")]
+    fn write_calamine_range_limits() {
+        use calamine::{Data, Range};
+
+        let mut worksheet = Worksheet::new();
+
+        let mut range = Range::new((0, 0), (0, 1));
+        range.set_value((0, 0), Data::Int(1));
+        range.set_value((0, 1), Data::Int(2));
+
+        let result = worksheet.write_calamine_range(0, ColNum::MAX, &range);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        let result = worksheet.write_calamine_range(RowNum::MAX, 0, &range);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        let mut range = Range::new((0, 0), (0, 70_000));
+        range.set_value((0, 70_000), Data::Int(1));
+
+        let result = worksheet.write_calamine_range(0, 0, &range);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
     }
 
     #[test]
@@ -132,6 +955,489 @@ mod worksheet_tests {
         assert!(matches!(result, Err(XlsxError::ParameterError(_))));
     }
 
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_flattened_structs() {
+        use std::collections::BTreeMap;
+
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct Inner {
+            column2: u8,
+        }
+
+        #[derive(Serialize)]
+        struct First {
+            column1: u8,
+            #[serde(flatten)]
+            inner: Inner,
+        }
+
+        #[derive(Serialize)]
+        struct Second {
+            #[serde(flatten)]
+            inner: Inner,
+            column3: u8,
+        }
+
+        let first = First {
+            column1: 1,
+            inner: Inner { column2: 2 },
+        };
+
+        let second = Second {
+            inner: Inner { column2: 2 },
+            column3: 3,
+        };
+
+        let map = BTreeMap::from([("column4", 4)]);
+
+        worksheet.serialize_headers(0, 0, &first).unwrap();
+        worksheet.serialize_headers(0, 3, &second).unwrap();
+        worksheet.serialize_headers(0, 6, &map).unwrap();
+
+        worksheet.serialize(&[&first, &first]).unwrap();
+        worksheet.serialize(&second).unwrap();
+        worksheet.serialize(&map).unwrap();
+
+        let result = worksheet.get_serialize_dimensions("First").unwrap();
+        assert_eq!((0, 0, 2, 1), result);
+
+        let result = worksheet.get_serialize_dimensions("Second").unwrap();
+        assert_eq!((0, 3, 1, 4), result);
+
+        let result = worksheet.get_serialize_dimensions("Map").unwrap();
+        assert_eq!((0, 6, 1, 6), result);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialized_range() {
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Deserialize, Serialize)]
+        #[serde(rename = "Renamed")]
+        struct MyStruct {
+            column1: u8,
+            column2: u8,
+        }
+
+        #[derive(Deserialize, Serialize)]
+        struct Unserialized {
+            column1: u8,
+        }
+
+        let data = MyStruct {
+            column1: 1,
+            column2: 2,
+        };
+
+        worksheet.deserialize_headers::<MyStruct>(1, 3).unwrap();
+        worksheet.serialize(&data).unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let result = worksheet.serialized_range::<MyStruct>().unwrap();
+        assert_eq!((1, 3, 3, 4), result);
+
+        let result = worksheet.serialized_range::<Unserialized>();
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn chart_from_serialized() {
+        use crate::ChartType;
+
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Data").unwrap();
+
+        #[derive(Deserialize, Serialize)]
+        struct MyStruct {
+            label: &'static str,
+            value1: u8,
+            value2: u8,
+        }
+
+        #[derive(Deserialize, Serialize)]
+        struct Unserialized {
+            column1: u8,
+        }
+
+        let data = MyStruct {
+            label: "a",
+            value1: 1,
+            value2: 2,
+        };
+
+        // Check for a struct without any serialized data.
+        worksheet.deserialize_headers::<MyStruct>(1, 2).unwrap();
+        let result = worksheet.chart_from_serialized::<MyStruct>(ChartType::Column);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+
+        worksheet.serialize(&data).unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let chart = worksheet
+            .chart_from_serialized::<MyStruct>(ChartType::Column)
+            .unwrap();
+
+        assert_eq!(chart.series.len(), 2);
+        assert_eq!(
+            chart.series[0].category_range.formula_abs(),
+            "Data!$C$3:$C$4"
+        );
+        assert_eq!(chart.series[0].value_range.formula_abs(), "Data!$D$3:$D$4");
+        assert_eq!(chart.series[0].title.range.formula_abs(), "Data!$D$2");
+        assert_eq!(chart.series[1].value_range.formula_abs(), "Data!$E$3:$E$4");
+
+        let result = worksheet.chart_from_serialized::<Unserialized>(ChartType::Column);
+        assert!(matches!(result, Err(XlsxError::ParameterError(_))));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_formula_and_url_fields() {
+        use crate::{Formula, Url};
+
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            total: Formula,
+            link: Url,
+        }
+
+        let data = MyStruct {
+            total: Formula::new("=SUM(1, 2)").set_result("3"),
+            link: Url::new("https://www.rust-lang.org").set_text("Rust"),
+        };
+
+        worksheet.serialize_headers(0, 0, &data).unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let cell = worksheet.data_table.get(&1).unwrap().get(&0).unwrap();
+        assert!(
+            matches!(cell, CellType::Formula { formula, result, .. } if &**formula == "SUM(1, 2)" && &**result == "3")
+        );
+
+        let cell = worksheet.data_table.get(&1).unwrap().get(&1).unwrap();
+        assert!(matches!(cell, CellType::String { string, .. } if &**string == "Rust"));
+
+        let url = worksheet.hyperlinks.get(&(1, 1)).unwrap();
+        assert_eq!(url.url_link, "https://www.rust-lang.org");
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_group_headers() {
+        use crate::{CustomSerializeField, SerializeFieldOptions};
+
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct Size {
+            width: u32,
+            height: u32,
+        }
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            name: &'static str,
+            size: Size,
+        }
+
+        let data = MyStruct {
+            name: "Box",
+            size: Size {
+                width: 10,
+                height: 5,
+            },
+        };
+
+        let header_options = SerializeFieldOptions::new()
+            .set_group_headers(true)
+            .set_custom_headers(&[CustomSerializeField::new("size.height").rename("H")]);
+
+        worksheet
+            .serialize_headers_with_options(0, 0, &data, &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let cell = worksheet.data_table.get(&0).unwrap().get(&1).unwrap();
+        assert!(matches!(cell, CellType::String { string, .. } if &**string == "size"));
+
+        let cell = worksheet.data_table.get(&1).unwrap().get(&0).unwrap();
+        assert!(matches!(cell, CellType::String { string, .. } if &**string == "name"));
+
+        let cell = worksheet.data_table.get(&1).unwrap().get(&1).unwrap();
+        assert!(matches!(cell, CellType::String { string, .. } if &**string == "width"));
+
+        let cell = worksheet.data_table.get(&1).unwrap().get(&2).unwrap();
+        assert!(matches!(cell, CellType::String { string, .. } if &**string == "H"));
+
+        let cell = worksheet.data_table.get(&2).unwrap().get(&2).unwrap();
+        assert!(matches!(cell, CellType::Number { number, .. } if *number == 5.0));
+
+        let range = worksheet.merged_ranges.first().unwrap();
+        assert_eq!(
+            (
+                range.first_row,
+                range.first_col,
+                range.last_row,
+                range.last_col
+            ),
+            (0, 1, 0, 2)
+        );
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_conditional_formats() {
+        use crate::{ConditionalFormatDataBar, CustomSerializeField, SerializeFieldOptions};
+
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            name: &'static str,
+            value: u32,
+        }
+
+        let data = [
+            MyStruct {
+                name: "A",
+                value: 1,
+            },
+            MyStruct {
+                name: "B",
+                value: 2,
+            },
+        ];
+
+        let data_bar = ConditionalFormatDataBar::new();
+        let header_options = SerializeFieldOptions::new().set_custom_headers(&[
+            CustomSerializeField::new("name"),
+            CustomSerializeField::new("value").set_conditional_format(&data_bar),
+        ]);
+
+        worksheet
+            .serialize_headers_with_options(1, 2, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        // The conditional formats are applied when the file is saved.
+        assert!(worksheet.conditional_formats.is_empty());
+
+        worksheet.store_serialized_tables().unwrap();
+        assert!(worksheet.conditional_formats.contains_key("D3:D4"));
+
+        // The conditional formats are only applied once.
+        worksheet.store_serialized_tables().unwrap();
+        assert_eq!(worksheet.conditional_formats.get("D3:D4").unwrap().len(), 1);
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_row_limit() {
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            col1: u32,
+        }
+
+        let data = MyStruct { col1: 1 };
+
+        worksheet.serialize_headers(ROW_MAX - 2, 0, &data).unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let result = worksheet.serialize(&data);
+        assert!(matches!(
+            result,
+            Err(XlsxError::RowColumnLimitError(message))
+                if message.contains("'MyStruct'") && message.contains("'col1'")
+        ));
+    }
+
+    #[test]
+    #[cfg(feature = "serde")]
+    fn serialize_expanded_sequences() {
+        use crate::{CustomSerializeField, SerializeFieldOptions};
+
+        let mut worksheet = Worksheet::new();
+
+        #[derive(Serialize)]
+        struct MyStruct {
+            array: [u32; 2],
+            vector: Vec<u32>,
+        }
+
+        let data = [
+            MyStruct {
+                array: [1, 2],
+                vector: vec![3, 4, 5],
+            },
+            MyStruct {
+                array: [6, 7],
+                vector: vec![8],
+            },
+        ];
+
+        let header_options = SerializeFieldOptions::new()
+            .set_expand_sequences(true)
+            .set_custom_headers(&[CustomSerializeField::new("vector_3").rename("Last")]);
+
+        worksheet
+            .serialize_headers_with_options(0, 0, &data[0], &header_options)
+            .unwrap();
+        worksheet.serialize(&data).unwrap();
+
+        let cell_string =
+            |row: RowNum, col: ColNum| match worksheet.data_table.get(&row)?.get(&col)? {
+                CellType::String { string, .. } => Some(string.to_string()),
+                _ => None,
+            };
+        let cell_number =
+            |row: RowNum, col: ColNum| match worksheet.data_table.get(&row)?.get(&col)? {
+                CellType::Number { number, .. } => Some(*number),
+                _ => None,
+            };
+
+        let headers: Vec<_> = (0..5).filter_map(|col| cell_string(0, col)).collect();
+        assert_eq!(
+            headers,
+            vec!["array_1", "array_2", "vector_1", "vector_2", "Last"]
+        );
+
+        let row1: Vec<_> = (0..5).filter_map(|col| cell_number(1, col)).collect();
+        assert_eq!(row1, vec![1.0, 2.0, 3.0, 4.0, 5.0]);
+
+        // Shorter sequences leave the remaining cells blank.
+        let row2: Vec<_> = (0..5).map(|col| cell_number(2, col)).collect();
+        assert_eq!(row2, vec![Some(6.0), Some(7.0), Some(8.0), None, None]);
+    }
+
+    #[test]
+    fn cell_values() {
+        let mut worksheet = Worksheet::new();
+        let datetime = ExcelDateTime::from_ymd(2024, 1, 1).unwrap();
+
+        worksheet.write(0, 0, "Hello").unwrap();
+        worksheet.write(0, 2, 1.5).unwrap();
+        worksheet.write(1, 0, true).unwrap();
+        worksheet.write(1, 1, &datetime).unwrap();
+        worksheet.write_blank(1, 2, &Format::new()).unwrap();
+        worksheet.write_formula(2, 0, "=SUM(C1:C2)").unwrap();
+        worksheet.set_formula_result(2, 0, "1.5");
+
+        assert_eq!(
+            worksheet.cell_value(0, 0),
+            Some(CellValue::String("Hello".to_string()))
+        );
+        assert_eq!(worksheet.cell_value(0, 1), None);
+        assert_eq!(worksheet.cell_value(5, 5), None);
+        assert_eq!(
+            worksheet.cell_value(1, 1),
+            Some(CellValue::DateTime(45292.0))
+        );
+        assert_eq!(
+            worksheet.cell_value(2, 0),
+            Some(CellValue::Formula {
+                formula: "SUM(C1:C2)".to_string(),
+                result: "1.5".to_string()
+            })
+        );
+
+        let cells: Vec<(RowNum, ColNum, CellValue)> = worksheet.cells().collect();
+        assert_eq!(
+            cells,
+            vec![
+                (0, 0, CellValue::String("Hello".to_string())),
+                (0, 2, CellValue::Number(1.5)),
+                (1, 0, CellValue::Boolean(true)),
+                (1, 1, CellValue::DateTime(45292.0)),
+                (1, 2, CellValue::Blank),
+                (
+                    2,
+                    0,
+                    CellValue::Formula {
+                        formula: "SUM(C1:C2)".to_string(),
+                        result: "1.5".to_string()
+                    }
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn copy_range_from() {
+        let bold = Format::new().set_bold();
+        let mut source = Worksheet::new();
+
+        source.merge_range(0, 0, 0, 1, "Title", &bold).unwrap();
+        source.write(1, 0, 10).unwrap();
+        source.write(1, 1, 20).unwrap();
+        source.write_formula(2, 0, "=SUM(A2:B2)*$C$1").unwrap();
+        source.write(5, 5, "Outside").unwrap();
+
+        let mut worksheet = Worksheet::new();
+        worksheet
+            .copy_range_from(&source, 0, 0, 2, 1, 3, 1)
+            .unwrap();
+
+        assert_eq!(
+            worksheet.cell_value(3, 1),
+            Some(CellValue::String("Title".to_string()))
+        );
+        assert_eq!(worksheet.cell_value(3, 2), Some(CellValue::Blank));
+        assert_eq!(worksheet.cell_value(4, 2), Some(CellValue::Number(20.0)));
+        assert_eq!(
+            worksheet.cell_value(5, 1),
+            Some(CellValue::Formula {
+                formula: "SUM(B5:C5)*$C$1".to_string(),
+                result: "0".to_string()
+            })
+        );
+        assert_eq!(worksheet.cells().count(), 5);
+        assert_eq!(worksheet.merged_ranges[0].to_range_string(), "B4:C4");
+        assert_eq!(worksheet.xf_formats.len(), 2);
+
+        // The copied merged range overlaps the previous copy.
+        let result = worksheet.copy_range_from(&source, 0, 0, 0, 1, 3, 2);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+
+        let result = worksheet.copy_range_from(&source, 0, 0, 1, 1, ROW_MAX - 1, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+
+        let result = worksheet.copy_range_from(&source, 1, 0, 0, 1, 0, 0);
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError(_))));
+    }
+
+    #[test]
+    fn merge_range_with() {
+        let format = Format::new().set_bold();
+        let mut worksheet = Worksheet::new();
+
+        worksheet
+            .merge_range_with(0, 0, 1, 1, 123, &format)
+            .unwrap();
+        worksheet
+            .merge_range_with(2, 0, 2, 1, true, &format)
+            .unwrap();
+
+        assert_eq!(worksheet.cell_value(0, 0), Some(CellValue::Number(123.0)));
+        assert_eq!(worksheet.cell_value(1, 1), Some(CellValue::Blank));
+        assert_eq!(worksheet.cell_value(2, 0), Some(CellValue::Boolean(true)));
+        assert_eq!(worksheet.merged_ranges[1].to_range_string(), "A3:B3");
+
+        let result = worksheet.merge_range_with(3, 0, 3, 0, 1, &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeSingleCell)));
+
+        let result = worksheet.merge_range_with(1, 1, 3, 3, 1, &format);
+        assert!(matches!(result, Err(XlsxError::MergeRangeOverlaps(_, _))));
+    }
+
     #[test]
     fn row_matches_list_filter_blanks() {
         let mut worksheet = Worksheet::new();
@@ -220,10 +1526,10 @@ mod worksheet_tests {
 
         // Test row and column limits.
         let result = worksheet.set_page_breaks(&[ROW_MAX]);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_vertical_page_breaks(&[COL_MAX as u32]);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
     }
 
     #[test]
@@ -646,11 +1952,11 @@ mod worksheet_tests {
 
         // Test out of range value.
         let result = worksheet.merge_range(ROW_MAX, 1, 1, 1, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         // Test out reversed values
         let result = worksheet.merge_range(5, 1, 1, 1, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnOrderError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnOrderError(_))));
     }
 
     #[test]
@@ -662,34 +1968,70 @@ mod worksheet_tests {
         assert!(!worksheet.check_dimensions(0, COL_MAX));
 
         let result = worksheet.write_string_with_format(ROW_MAX, 0, "Foo", &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.write_string(ROW_MAX, 0, "Foo");
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.write_number_with_format(ROW_MAX, 0, 0, &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.write_number(ROW_MAX, 0, 0);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_row_height(ROW_MAX, 20);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_row_height_pixels(ROW_MAX, 20);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_row_format(ROW_MAX, &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_column_width(COL_MAX, 20);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_column_width_pixels(COL_MAX, 20);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
 
         let result = worksheet.set_column_format(COL_MAX, &format);
-        assert!(matches!(result, Err(XlsxError::RowColumnLimitError)));
+        assert!(matches!(result, Err(XlsxError::RowColumnLimitError(_))));
+    }
+
+    #[test]
+    fn row_col_error_context() {
+        let mut worksheet = Worksheet::new();
+        worksheet.set_name("Sales").unwrap();
+
+        // The cell writing methods share the store functions so the error
+        // doesn't contain the method name.
+        let error = worksheet.write_number(ROW_MAX, 2, 0).err().unwrap();
+        assert_eq!(
+            "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384): \
+            worksheet 'Sales', row 1048576, column 2.",
+            error.to_string()
+        );
+
+        let error = worksheet.write(0, COL_MAX, "Foo").err().unwrap();
+        assert_eq!(
+            "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384): \
+            worksheet 'Sales', row 0, column 16384.",
+            error.to_string()
+        );
+
+        let error = worksheet.set_column_width(COL_MAX, 20).err().unwrap();
+        assert_eq!(
+            "Row or column exceeds Excel's allowed limits (1,048,576 x 16,384): \
+            worksheet 'Sales', column 16384, in Worksheet::set_column_width().",
+            error.to_string()
+        );
+
+        let error = worksheet.autofilter(5, 0, 1, 3).err().unwrap();
+        assert_eq!(
+            "First row or column in range is greater than last row or column: \
+            worksheet 'Sales', rows 5 to 1, columns 0 to 3, in Worksheet::autofilter().",
+            error.to_string()
+        );
     }
 
     #[test]
@@ -701,4 +2043,52 @@ mod worksheet_tests {
         let result = worksheet.write_string(0, 0, long_string.unwrap());
         assert!(matches!(result, Err(XlsxError::MaxStringLengthExceeded)));
     }
+
+    #[test]
+    fn autofit_with_registered_font() -> Result<(), XlsxError> {
+        let mut workbook = crate::Workbook::new();
+        let format = Format::new().set_font_name("Wide").set_font_size(22);
+
+        // Worksheet added before the font is registered.
+        let worksheet = workbook.add_worksheet();
+        worksheet.write(0, 0, "abc")?;
+        worksheet.write_with_format(0, 1, "abc", &format)?;
+
+        let metrics = crate::FontMetrics::new("Wide", 11.0)
+            .set_default_char_width(20)
+            .set_font_family(1)
+            .set_font_charset(2);
+        workbook.register_font(&metrics);
+
+        let worksheet = workbook.worksheet_from_index(0)?;
+        worksheet.autofit();
+
+        // The default Calibri widths: 7 + 8 + 6 + 7 padding = 28 pixels.
+        assert_eq!(3.2857142857142856, worksheet.changed_cols[&0].width);
+
+        // The registered widths: 3 * 20 * 22/11 + 7 padding = 127 pixels.
+        assert_eq!(17.428571428571427, worksheet.changed_cols[&1].width);
+
+        // Worksheet added after the font is registered.
+        let worksheet = workbook.add_worksheet();
+        assert!(worksheet.font_metrics.contains_key("Wide"));
+
+        Ok(())
+    }
+
+    #[test]
+    fn column_width_with_registered_calibri() -> Result<(), XlsxError> {
+        let mut workbook = crate::Workbook::new();
+
+        let metrics = crate::FontMetrics::new("Calibri", 11.0).set_default_char_width(8);
+        workbook.register_font(&metrics);
+
+        let worksheet = workbook.add_worksheet();
+        worksheet.set_column_width_pixels(0, 85)?;
+
+        // (85 - 5 padding) / 8 max digit width.
+        assert_eq!(10.0, worksheet.changed_cols[&0].width);
+
+        Ok(())
+    }
 }
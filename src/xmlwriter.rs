@@ -17,6 +17,7 @@ pub(crate) const XML_WRITE_ERROR: &str = "Couldn't write to xml file";
 #[derive(Clone)]
 pub struct XMLWriter {
     pub(crate) xmlfile: Cursor<Vec<u8>>,
+    pub(crate) strict_ooxml: bool,
 }
 
 impl Default for XMLWriter {
@@ -31,7 +32,10 @@ impl XMLWriter {
         let buf: Vec<u8> = Vec::with_capacity(2048);
         let xmlfile = Cursor::new(buf);
 
-        XMLWriter { xmlfile }
+        XMLWriter {
+            xmlfile,
+            strict_ooxml: false,
+        }
     }
 
     // Helper function to read back stored xml data for tests.
@@ -70,7 +74,7 @@ impl XMLWriter {
         write!(&mut self.xmlfile, "<{tag}").expect(XML_WRITE_ERROR);
 
         for attribute in attributes {
-            attribute.write_to(&mut self.xmlfile);
+            self.write_attribute(attribute);
         }
 
         self.xmlfile.write_all(b">").expect(XML_WRITE_ERROR);
@@ -94,7 +98,7 @@ impl XMLWriter {
         write!(&mut self.xmlfile, "<{tag}").expect(XML_WRITE_ERROR);
 
         for attribute in attributes {
-            attribute.write_to(&mut self.xmlfile);
+            self.write_attribute(attribute);
         }
 
         self.xmlfile.write_all(b"/>").expect(XML_WRITE_ERROR);
@@ -120,7 +124,7 @@ impl XMLWriter {
         write!(&mut self.xmlfile, "<{tag}").expect(XML_WRITE_ERROR);
 
         for attribute in attributes {
-            attribute.write_to(&mut self.xmlfile);
+            self.write_attribute(attribute);
         }
 
         write!(&mut self.xmlfile, ">{}</{}>", escape_xml_data(data), tag).expect(XML_WRITE_ERROR);
@@ -150,9 +154,19 @@ impl XMLWriter {
         write!(&mut self.xmlfile, r#"<si>{string}</si>"#).expect(XML_WRITE_ERROR);
     }
 
-    // Write the theme string to the theme file.
+    // Write the theme string to the theme file. The theme is a fixed string so
+    // its drawing namespace is converted directly in Strict mode.
     pub(crate) fn write_theme(&mut self, theme: &str) {
-        writeln!(&mut self.xmlfile, "{theme}").expect(XML_WRITE_ERROR);
+        if self.strict_ooxml {
+            let theme = theme.replacen(
+                "http://schemas.openxmlformats.org/drawingml/2006/main",
+                "http://purl.oclc.org/ooxml/drawingml/main",
+                1,
+            );
+            writeln!(&mut self.xmlfile, "{theme}").expect(XML_WRITE_ERROR);
+        } else {
+            writeln!(&mut self.xmlfile, "{theme}").expect(XML_WRITE_ERROR);
+        }
     }
 
     // Write a string with escaped XML data.
@@ -161,20 +175,103 @@ impl XMLWriter {
             .write_all(data.as_bytes())
             .expect(XML_WRITE_ERROR);
     }
+
+    // Write an attribute. In Strict Open XML mode the Transitional namespaces
+    // and relationship types are converted to their Strict equivalents. Only
+    // the attributes that hold schema URIs are converted so that user data is
+    // never changed.
+    fn write_attribute<T>(&mut self, attribute: &T)
+    where
+        T: IntoAttribute,
+    {
+        if self.strict_ooxml {
+            if let Some(value) = strict_ooxml_value(attribute.key(), attribute.value()) {
+                write!(&mut self.xmlfile, r#" {}="{}""#, attribute.key(), value)
+                    .expect(XML_WRITE_ERROR);
+                return;
+            }
+        }
+
+        attribute.write_to(&mut self.xmlfile);
+    }
+}
+
+// Map of the Transitional Office Open XML namespaces and relationship types to
+// their Strict equivalents. The more specific prefixes must come first. The
+// package level namespaces, such as content types and core properties, are the
+// same in both variants.
+const STRICT_OOXML_NAMESPACES: [(&str, &str); 11] = [
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships/custom-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships/customProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/relationships",
+        "http://purl.oclc.org/ooxml/officeDocument/relationships",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/extended-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/extendedProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/custom-properties",
+        "http://purl.oclc.org/ooxml/officeDocument/customProperties",
+    ),
+    (
+        "http://schemas.openxmlformats.org/officeDocument/2006/docPropsVTypes",
+        "http://purl.oclc.org/ooxml/officeDocument/docPropsVTypes",
+    ),
+    (
+        "http://schemas.openxmlformats.org/spreadsheetml/2006/main",
+        "http://purl.oclc.org/ooxml/spreadsheetml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/main",
+        "http://purl.oclc.org/ooxml/drawingml/main",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/chart",
+        "http://purl.oclc.org/ooxml/drawingml/chart",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/spreadsheetDrawing",
+        "http://purl.oclc.org/ooxml/drawingml/spreadsheetDrawing",
+    ),
+    (
+        "http://schemas.openxmlformats.org/drawingml/2006/picture",
+        "http://purl.oclc.org/ooxml/drawingml/picture",
+    ),
+];
+
+// Get the Strict Open XML equivalent of a namespace, relationship type or
+// graphic data uri attribute value, if there is one.
+fn strict_ooxml_value(key: &str, value: &str) -> Option<String> {
+    if !(key == "xmlns" || key.starts_with("xmlns:") || key == "Type" || key == "uri") {
+        return None;
+    }
+
+    STRICT_OOXML_NAMESPACES
+        .iter()
+        .find(|(transitional, _)| value.starts_with(transitional))
+        .map(|(transitional, strict)| format!("{strict}{}", &value[transitional.len()..]))
 }
 
 // Escape XML characters in attributes.
-pub(crate) fn escape_attributes(attribute: &str) -> Cow<str> {
+pub(crate) fn escape_attributes(attribute: &str) -> Cow<'_, str> {
     escape_string(attribute, match_attribute_html_char)
 }
 
 // Escape XML characters in data sections of tags.
-pub(crate) fn escape_xml_data(data: &str) -> Cow<str> {
+pub(crate) fn escape_xml_data(data: &str) -> Cow<'_, str> {
     escape_string(data, match_xml_char)
 }
 
 // Escape non-url characters in a hyperlink/url.
-pub(crate) fn escape_url(data: &str) -> Cow<str> {
+pub(crate) fn escape_url(data: &str) -> Cow<'_, str> {
     escape_string(data, match_url_char)
 }
 
@@ -266,7 +363,7 @@ fn match_url_char(ch: char) -> Option<&'static str> {
 }
 
 // Generic escape function with function pointer for the required handler.
-fn escape_string<F>(original: &str, char_handler: F) -> Cow<str>
+fn escape_string<F>(original: &str, char_handler: F) -> Cow<'_, str>
 where
     F: FnOnce(char) -> Option<&'static str> + Copy,
 {
@@ -293,7 +390,7 @@ where
 // Excel escapes control characters with _xHHHH_, see match_xml_char() above. As
 // a result it also escapes any literal strings of that type by encoding the
 // leading underscore. So  "_x0000_" -> _x005F_x0000_.
-fn escape_xml_escapes(original: &str) -> Cow<str> {
+fn escape_xml_escapes(original: &str) -> Cow<'_, str> {
     if !original.contains("_x00") {
         return Cow::Borrowed(original);
     }
@@ -328,16 +425,34 @@ fn escape_xml_escapes(original: &str) -> Cow<str> {
 
 // Trait to write attribute tuple values to an XML file.
 pub(crate) trait IntoAttribute {
+    fn key(&self) -> &str;
+    fn value(&self) -> &str;
     fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>);
 }
 
 impl IntoAttribute for (&str, &str) {
+    fn key(&self) -> &str {
+        self.0
+    }
+
+    fn value(&self) -> &str {
+        self.1
+    }
+
     fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>) {
         write!(xmlfile, r#" {}="{}""#, self.0, escape_attributes(self.1)).expect(XML_WRITE_ERROR);
     }
 }
 
 impl IntoAttribute for (&str, String) {
+    fn key(&self) -> &str {
+        self.0
+    }
+
+    fn value(&self) -> &str {
+        &self.1
+    }
+
     fn write_to(&self, xmlfile: &mut Cursor<Vec<u8>>) {
         write!(xmlfile, r#" {}="{}""#, self.0, escape_attributes(&self.1)).expect(XML_WRITE_ERROR);
     }
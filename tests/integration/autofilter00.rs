@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Workbook, XlsxError};
+use rust_xlsxwriter::{CsvOptions, ExcelCellValue, ExcelRowSource, Workbook, XlsxError};
 
 // Test to demonstrate autofilters.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -35,6 +35,83 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test to demonstrate writing the same data from CSV text.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Convert the data to CSV text.
+    let mut csv = String::from("Region,Item,Volume,Month\n");
+    for data in common::get_autofilter_data() {
+        csv.push_str(&format!("{},{},{},{}\n", data.0, data.1, data.2, data.3));
+    }
+
+    let options = CsvOptions::new().set_header_row(true);
+    worksheet.write_csv(0, 0, csv.as_bytes(), &options)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test to demonstrate writing the same data from a row source.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    struct AutofilterData {
+        rows: std::vec::IntoIter<(&'static str, &'static str, u16, &'static str)>,
+    }
+
+    impl ExcelRowSource for AutofilterData {
+        fn headers(&self) -> Vec<String> {
+            ["Region", "Item", "Volume", "Month"]
+                .iter()
+                .map(|header| header.to_string())
+                .collect()
+        }
+
+        fn next_row(&mut self) -> Result<Option<Vec<ExcelCellValue>>, XlsxError> {
+            Ok(self
+                .rows
+                .next()
+                .map(|data| vec![data.0.into(), data.1.into(), data.2.into(), data.3.into()]))
+        }
+    }
+
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let mut source = AutofilterData {
+        rows: common::get_autofilter_data().into_iter(),
+    };
+    worksheet.write_rows_from(0, 0, &mut source)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test to demonstrate loading the same data from the Excel file via calamine.
+#[cfg(feature = "calamine")]
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    use calamine::{open_workbook, Reader, Xlsx};
+
+    let mut source: Xlsx<_> = open_workbook("tests/input/autofilter00.xlsx")
+        .map_err(|e: calamine::XlsxError| XlsxError::CustomError(e.to_string()))?;
+    let range = source
+        .worksheet_range("Sheet1")
+        .map_err(|e| XlsxError::CustomError(e.to_string()))?;
+
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.write_calamine_range(0, 0, &range)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_autofilter00() {
     let test_runner = common::TestRunner::new()
@@ -45,3 +122,40 @@ fn test_autofilter00() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_autofilter00_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter00")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_autofilter00_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter00")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "calamine")]
+#[test]
+fn test_autofilter00_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("autofilter00")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
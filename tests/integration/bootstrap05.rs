@@ -5,8 +5,15 @@
 //
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
+#[cfg(feature = "num-bigint")]
+use num_bigint::BigInt;
+
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
 use crate::common;
 use rust_xlsxwriter::{Workbook, XlsxError};
+use std::num::{NonZeroI64, NonZeroU8};
 
 // Test case to demonstrate creating a basic file with some numeric cell data.
 // This tests also verifies the row span ranges.
@@ -27,6 +34,63 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for writing NonZero integer types.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, NonZeroU8::new(1).unwrap())?;
+    worksheet.write(1, 1, NonZeroU8::new(2).unwrap())?;
+    worksheet.write(2, 2, NonZeroU8::new(3).unwrap())?;
+
+    worksheet.write(0, 4, NonZeroI64::new(1).unwrap())?;
+    worksheet.write(1, 5, NonZeroI64::new(2).unwrap())?;
+    worksheet.write(2, 6, NonZeroI64::new(3).unwrap())?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing rust_decimal values.
+#[cfg(feature = "rust_decimal")]
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, Decimal::new(10, 1))?;
+    worksheet.write(1, 1, Decimal::new(2, 0))?;
+    worksheet.write(2, 2, Decimal::new(300, 2))?;
+
+    worksheet.write(0, 4, Decimal::ONE)?;
+    worksheet.write(1, 5, Decimal::TWO)?;
+    worksheet.write(2, 6, Decimal::new(3, 0))?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for writing num_bigint values.
+#[cfg(feature = "num-bigint")]
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, BigInt::from(1))?;
+    worksheet.write(1, 1, BigInt::from(2))?;
+    let bigint = BigInt::from(3);
+    worksheet.write(2, 2, &bigint)?;
+
+    worksheet.write(0, 4, BigInt::from(1))?;
+    worksheet.write(1, 5, BigInt::from(2))?;
+    worksheet.write(2, 6, BigInt::from(3))?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn bootstrap05_test_spans() {
     let test_runner = common::TestRunner::new()
@@ -37,3 +101,41 @@ fn bootstrap05_test_spans() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn bootstrap05_test_spans_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap05")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "rust_decimal")]
+#[test]
+fn bootstrap05_test_spans_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap05")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[cfg(feature = "num-bigint")]
+#[test]
+fn bootstrap05_test_spans_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap05")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Workbook, XlsxError};
+use rust_xlsxwriter::{AsString, Workbook, XlsxError};
 
 // Test case to demonstrate creating a basic file with some string cell data.
 fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
@@ -60,6 +60,26 @@ fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test with a Display type via AsString.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    struct Greeting;
+
+    impl std::fmt::Display for Greeting {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            write!(f, "Hello")
+        }
+    }
+
+    worksheet.write(0, 0, AsString(Greeting))?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn bootstrap06_write_string_1() {
     let test_runner = common::TestRunner::new()
@@ -107,3 +127,15 @@ fn bootstrap06_write_string_4() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn bootstrap06_write_string_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("bootstrap06")
+        .set_function(create_new_xlsx_file_5)
+        .unique("5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
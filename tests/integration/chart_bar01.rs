@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Chart, ChartType, Workbook, XlsxError};
+use rust_xlsxwriter::{Chart, ChartType, RangeRef, Workbook, XlsxError};
 
 // Create rust_xlsxwriter file to compare against Excel file.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -41,6 +41,71 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test writing the chart data from an ndarray 2D array.
+#[cfg(feature = "ndarray")]
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add some test data for the chart(s).
+    let data = ndarray::array![[1, 2, 3], [2, 4, 6], [3, 6, 9], [4, 8, 12], [5, 10, 15]];
+    worksheet.write_array2(0, 0, &data)?;
+
+    let mut chart = Chart::new(ChartType::Bar);
+    chart.set_axis_ids(64052224, 64055552);
+    chart
+        .add_series()
+        .set_categories(("Sheet1", 0, 0, 4, 0))
+        .set_values(("Sheet1", 0, 1, 4, 1));
+
+    chart
+        .add_series()
+        .set_categories(("Sheet1", 0, 0, 4, 0))
+        .set_values(("Sheet1", 0, 2, 4, 2));
+
+    worksheet.insert_chart(8, 4, &chart)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test the chart ranges with RangeRef and A1 cell references.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    // Add some test data for the chart(s).
+    let data = [[1, 2, 3], [2, 4, 6], [3, 6, 9], [4, 8, 12], [5, 10, 15]];
+    for (row_num, row_data) in data.iter().enumerate() {
+        for (col_num, col_data) in row_data.iter().enumerate() {
+            worksheet.write_a1((row_num as u32, col_num as u16), *col_data)?;
+        }
+    }
+
+    let categories = RangeRef::parse("A1:A5")?;
+
+    let mut chart = Chart::new(ChartType::Bar);
+    chart.set_axis_ids(64052224, 64055552);
+    chart
+        .add_series()
+        .set_categories(("Sheet1", &categories))
+        .set_values(("Sheet1", RangeRef::parse("B1:B5")?));
+
+    chart
+        .add_series()
+        .set_categories(("Sheet1", &categories))
+        .set_values(("Sheet1", RangeRef::new(0, 2, 4, 2)));
+
+    worksheet.insert_chart(8, 4, &chart)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_chart_bar01() {
     let test_runner = common::TestRunner::new()
@@ -51,3 +116,28 @@ fn test_chart_bar01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[cfg(feature = "ndarray")]
+#[test]
+fn test_chart_bar01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("chart_bar01")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_chart_bar01_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("chart_bar01")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -18,7 +18,7 @@ macro_rules! assert_result {
 macro_rules! static_regex {
     ($re:literal) => {{
         static RE: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
-        RE.get_or_init(|| regex::Regex::new($re).unwrap())
+        RE.get_or_init(|| new_regex($re))
     }};
 }
 
@@ -70,7 +70,7 @@ where
     }
 
     // Set the testcase name.
-    pub fn set_name(mut self, testcase: &'a str) -> TestRunner<F> {
+    pub fn set_name(mut self, testcase: &'a str) -> TestRunner<'a, F> {
         self.test_name = testcase;
         self
     }
@@ -84,7 +84,7 @@ where
     // Set string to add to the default output filename to make it unique so
     // that the multiple tests can be run in parallel.
     #[allow(dead_code)]
-    pub fn unique(mut self, unique_string: &'a str) -> TestRunner<F> {
+    pub fn unique(mut self, unique_string: &'a str) -> TestRunner<'a, F> {
         self.unique = unique_string;
         self
     }
@@ -503,6 +503,12 @@ fn is_binary_file(filename: &str) -> bool {
         || filename.ends_with(".bin")
 }
 
+// Compile a regex for the `static_regex!` macro. This is only called once per
+// regex via `OnceLock`.
+fn new_regex(re: &str) -> Regex {
+    Regex::new(re).unwrap()
+}
+
 // Create the data structure used in the autofilter tests.
 #[allow(dead_code)]
 pub fn get_autofilter_data() -> Vec<(&'static str, &'static str, u16, &'static str)> {
@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use rust_xlsxwriter::{Format, HyperlinkStyle, Url, Workbook, XlsxError};
 
 // Test to demonstrate simple hyperlinks.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -59,6 +59,105 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Write the test links using the default format for the worksheet.
+fn write_links(workbook: &mut Workbook) -> Result<(), XlsxError> {
+    let worksheet1 = workbook.worksheet_from_index(0)?;
+
+    worksheet1.write_url(0, 0, "internal:Sheet2!A1")?;
+    worksheet1.write_url(2, 0, "internal:Sheet2!A1:A5")?;
+    worksheet1.write_url_internal(4, 0, "'Data Sheet'!D5", "Some text")?;
+    worksheet1.write_url_internal(11, 4, "Sheet1!J1", "")?;
+    worksheet1.write_url_with_text(16, 6, "internal:Sheet2!A1", "Some text")?;
+    worksheet1.write_url(17, 0, Url::new("internal:Sheet2!A1").set_tip("Tool Tip 1"))?;
+    worksheet1.write_url(
+        19,
+        0,
+        Url::new("internal:Sheet2!A1")
+            .set_text("More text")
+            .set_tip("Tool Tip 2"),
+    )?;
+
+    Ok(())
+}
+
+// Test the internal links with the typed Url constructor.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.set_hyperlink_style(HyperlinkStyle::Inherit);
+
+    worksheet1.write_url(0, 0, Url::internal("Sheet2!A1"))?;
+    worksheet1.write_url(2, 0, Url::internal("Sheet2!A1:A5"))?;
+    worksheet1.write_url(4, 0, Url::internal("'Data Sheet'!D5").set_text("Some text"))?;
+    worksheet1.write_url(11, 4, Url::internal("Sheet1!J1"))?;
+    worksheet1.write_url(16, 6, Url::internal("Sheet2!A1").set_text("Some text"))?;
+    worksheet1.write_url(17, 0, Url::internal("Sheet2!A1").set_tip("Tool Tip 1"))?;
+    worksheet1.write_url(
+        19,
+        0,
+        Url::internal("Sheet2!A1")
+            .set_text("More text")
+            .set_tip("Tool Tip 2"),
+    )?;
+
+    let _worksheet2 = workbook.add_worksheet();
+    let _worksheet3 = workbook.add_worksheet().set_name("Data Sheet");
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test the worksheet hyperlink style with a custom format.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.set_hyperlink_style(HyperlinkStyle::Custom(Format::default()));
+    write_links(&mut workbook)?;
+
+    let _worksheet2 = workbook.add_worksheet();
+    let _worksheet3 = workbook.add_worksheet().set_name("Data Sheet");
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test the workbook hyperlink format.
+fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let _worksheet1 = workbook.add_worksheet();
+    write_links(&mut workbook)?;
+
+    let _worksheet2 = workbook.add_worksheet();
+    let _worksheet3 = workbook.add_worksheet().set_name("Data Sheet");
+
+    workbook.set_hyperlink_format(&Format::default());
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test the worksheet hyperlink style that inherits the cell format.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet1 = workbook.add_worksheet();
+    worksheet1.set_hyperlink_style(HyperlinkStyle::Inherit);
+    write_links(&mut workbook)?;
+
+    let _worksheet2 = workbook.add_worksheet();
+    let _worksheet3 = workbook.add_worksheet().set_name("Data Sheet");
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_hyperlink04() {
     let test_runner = common::TestRunner::new()
@@ -69,3 +168,51 @@ fn test_hyperlink04() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_hyperlink04_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink04")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_hyperlink04_3() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink04")
+        .set_function(create_new_xlsx_file_3)
+        .unique("3")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_hyperlink04_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink04")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_hyperlink04_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink04")
+        .set_function(create_new_xlsx_file_5)
+        .unique("5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Format, Workbook, XlsxError};
+use rust_xlsxwriter::{Format, HyperlinkStyle, Url, Workbook, XlsxError};
 
 // Test to demonstrate simple hyperlinks.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -38,6 +38,28 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test the file links with the typed Url constructor.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_hyperlink_style(HyperlinkStyle::Inherit);
+
+    worksheet.write_url(0, 0, Url::file(r"C:\Temp\foo.xlsx"))?;
+    worksheet.write_url(2, 0, Url::file(r"C:\Temp\foo.xlsx#Sheet1!A1"))?;
+    worksheet.write_url(
+        4,
+        0,
+        Url::file(r"C:\Temp\foo.xlsx#Sheet1!A1")
+            .set_text("External")
+            .set_tip("Tip"),
+    )?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_hyperlink06() {
     let test_runner = common::TestRunner::new()
@@ -48,3 +70,15 @@ fn test_hyperlink06() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_hyperlink06_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("hyperlink06")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -24,6 +24,23 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test adding the VBA project from a buffer.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add the VBA macro file.
+    let project = std::fs::read("tests/input/macros/vbaProject01.bin")?;
+    workbook.add_vba_project_from_buffer(&project)?;
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.write(0, 0, 123)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_macro01() {
     let test_runner = common::TestRunner::new()
@@ -35,3 +52,16 @@ fn test_macro01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_macro01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("macro01")
+        .has_macros()
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
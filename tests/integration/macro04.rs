@@ -27,6 +27,24 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test adding the signed VBA project from buffers.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    // Add the VBA macro file.
+    let project = std::fs::read("tests/input/macros/vbaProject05.bin")?;
+    let signature = std::fs::read("tests/input/macros/vbaProjectSignature05.bin")?;
+    workbook.add_vba_project_with_signature_from_buffer(&project, &signature)?;
+
+    let worksheet = workbook.add_worksheet().set_name("Foo")?;
+
+    worksheet.write(0, 0, 123)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_macro01() {
     let test_runner = common::TestRunner::new()
@@ -38,3 +56,16 @@ fn test_macro01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_macro04_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("macro04")
+        .has_macros()
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -5,10 +5,20 @@
 //
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
+use std::collections::BTreeMap;
+
 use crate::common;
-use rust_xlsxwriter::{Workbook, XlsxError, XlsxSerialize};
+use rust_xlsxwriter::{
+    CustomSerializeField, ExcelCellValue, SerializeFieldOptions, Workbook, XlsxError, XlsxSerialize,
+};
 use serde::{Deserialize, Serialize};
 
+#[cfg(feature = "rust_decimal")]
+use rust_decimal::Decimal;
+
+#[cfg(feature = "rust_decimal")]
+use rust_xlsxwriter::utility::{serialize_decimal_option_to_excel, serialize_decimal_to_excel};
+
 // Test case for Serde serialization. First test isn't serialized.
 fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
@@ -207,6 +217,134 @@ fn create_new_xlsx_file_9(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for writing serde_json values.
+#[cfg(feature = "serde_json")]
+fn create_new_xlsx_file_10(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let data = serde_json::json!([{"col1": 1, "col2": -1}]);
+
+    worksheet.write_json_rows(0, 0, data.as_array().unwrap())?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. Map keys as headers.
+fn create_new_xlsx_file_11(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let mut data = BTreeMap::new();
+    data.insert("col1", 1);
+    data.insert("col2", -1);
+
+    worksheet.serialize_headers(0, 0, &data)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. None values with default values.
+fn create_new_xlsx_file_12(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: Option<u8>,
+        col2: Option<i8>,
+    }
+
+    let data = MyStruct {
+        col1: None,
+        col2: None,
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1").set_none_value(1),
+        CustomSerializeField::new("col2").set_none_value(-1),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data, &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. rust_decimal::Decimal.
+#[cfg(feature = "rust_decimal")]
+fn create_new_xlsx_file_13(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        #[serde(serialize_with = "serialize_decimal_to_excel")]
+        col1: Decimal,
+        #[serde(serialize_with = "serialize_decimal_option_to_excel")]
+        col2: Option<Decimal>,
+    }
+
+    let data = MyStruct {
+        col1: Decimal::new(100, 2),
+        col2: Some(Decimal::new(-1, 0)),
+    };
+
+    worksheet.serialize_headers(0, 0, &data)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. Test value transforms.
+fn create_new_xlsx_file_14(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: u32,
+        col2: bool,
+    }
+
+    let data = MyStruct {
+        col1: 100,
+        col2: false,
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1").set_value_transform(|value| match value {
+            ExcelCellValue::Number(number) => ExcelCellValue::Number(number / 100.0),
+            value => value,
+        }),
+        CustomSerializeField::new("col2").set_value_transform(|value| match value {
+            ExcelCellValue::Boolean(false) => (-1).into(),
+            value => value,
+        }),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data, &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde01_1() {
     let test_runner = common::TestRunner::new()
@@ -314,3 +452,65 @@ fn test_serde01_9() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn test_serde01_10() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde01")
+        .set_function(create_new_xlsx_file_10)
+        .unique("10")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde01_11() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde01")
+        .set_function(create_new_xlsx_file_11)
+        .unique("11")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde01_12() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde01")
+        .set_function(create_new_xlsx_file_12)
+        .unique("12")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+#[cfg(feature = "rust_decimal")]
+fn test_serde01_13() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde01")
+        .set_function(create_new_xlsx_file_13)
+        .unique("13")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde01_14() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde01")
+        .set_function(create_new_xlsx_file_14)
+        .unique("14")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
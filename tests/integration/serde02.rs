@@ -5,8 +5,12 @@
 //
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
+use std::collections::BTreeMap;
+
 use crate::common;
-use rust_xlsxwriter::{Workbook, XlsxError, XlsxSerialize};
+use rust_xlsxwriter::{
+    SerializeEnumFormat, SerializeFieldOptions, Workbook, XlsxError, XlsxSerialize,
+};
 use serde::Serialize;
 
 // Test case for Serde serialization. First test isn't serialized.
@@ -76,6 +80,136 @@ fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for Serde serialization. Map keys as row labels.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col2: char,
+    }
+
+    let mut data = BTreeMap::new();
+    data.insert("aaa", MyStruct { col2: 'a' });
+
+    let header_options = SerializeFieldOptions::new().set_map_key_header("col1");
+
+    worksheet.serialize_headers_with_options(0, 0, &data["aaa"], &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. Unit and newtype enum variants.
+fn create_new_xlsx_file_5(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create some serializable test enums and struct.
+    #[derive(Serialize)]
+    enum MyUnitEnum {
+        #[serde(rename = "aaa")]
+        Aaa,
+    }
+
+    #[derive(Serialize)]
+    enum MyNewtypeEnum {
+        #[serde(rename = "a")]
+        A(u8),
+    }
+
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: MyUnitEnum,
+        col2: MyNewtypeEnum,
+    }
+
+    let data = MyStruct {
+        col1: MyUnitEnum::Aaa,
+        col2: MyNewtypeEnum::A(1),
+    };
+
+    let header_options =
+        SerializeFieldOptions::new().set_enum_format(SerializeEnumFormat::VariantName);
+
+    worksheet.serialize_headers_with_options(0, 0, &data, &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. Flattened sub-struct.
+fn create_new_xlsx_file_6(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create serializable test structs.
+    #[derive(Serialize)]
+    struct MyInnerStruct {
+        col2: char,
+    }
+
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(flatten)]
+        inner: MyInnerStruct,
+    }
+
+    let data = MyStruct {
+        col1: "aaa",
+        inner: MyInnerStruct { col2: 'a' },
+    };
+
+    worksheet.serialize_headers(0, 0, &data)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization. Map of flattened structs.
+fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    // Create serializable test structs.
+    #[derive(Serialize)]
+    struct MyInnerStruct {
+        col2: char,
+    }
+
+    #[derive(Serialize)]
+    struct MyStruct {
+        #[serde(flatten)]
+        inner: MyInnerStruct,
+    }
+
+    let mut data = BTreeMap::new();
+    data.insert(
+        "aaa",
+        MyStruct {
+            inner: MyInnerStruct { col2: 'a' },
+        },
+    );
+
+    let header_options = SerializeFieldOptions::new().set_map_key_header("col1");
+
+    worksheet.serialize_headers_with_options(0, 0, &data["aaa"], &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde02_1() {
     let test_runner = common::TestRunner::new()
@@ -111,3 +245,51 @@ fn test_serde02_3() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde02_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde02")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde02_5() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde02")
+        .set_function(create_new_xlsx_file_5)
+        .unique("5")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde02_6() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde02")
+        .set_function(create_new_xlsx_file_6)
+        .unique("6")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+fn test_serde02_7() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde02")
+        .set_function(create_new_xlsx_file_7)
+        .unique("7")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
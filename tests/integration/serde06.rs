@@ -277,6 +277,33 @@ fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for serialize_iter().
+fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    let bold = Format::new().set_bold();
+
+    worksheet.set_paper_size(9);
+
+    // Create a serializable test struct.
+    #[derive(Deserialize, Serialize)]
+    struct MyStruct {
+        col1: u16,
+        col2: bool,
+    }
+
+    let data = [(123, true), (456, false), (789, true)]
+        .into_iter()
+        .map(|(col1, col2)| MyStruct { col1, col2 });
+
+    worksheet.deserialize_headers_with_format::<MyStruct>(0, 0, &bold)?;
+    worksheet.serialize_iter(data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde06_1() {
     let test_runner = common::TestRunner::new()
@@ -360,3 +387,15 @@ fn test_serde06_7() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde06_8() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde06")
+        .set_function(create_new_xlsx_file_8)
+        .unique("8")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
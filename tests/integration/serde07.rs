@@ -58,7 +58,7 @@ fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
         },
     ];
 
-    worksheet.serialize_headers(0, 0, &data.get(0).unwrap())?;
+    worksheet.serialize_headers(0, 0, &data.first().unwrap())?;
     worksheet.serialize(&data)?;
 
     workbook.save(filename)?;
@@ -99,7 +99,7 @@ fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
         },
     ];
 
-    worksheet.serialize_headers(0, 0, &data.get(0).unwrap())?;
+    worksheet.serialize_headers(0, 0, &data.first().unwrap())?;
     worksheet.serialize(&data)?;
 
     workbook.save(filename)?;
@@ -647,7 +647,7 @@ fn create_new_xlsx_file_16(filename: &str) -> Result<(), XlsxError> {
         },
     ];
 
-    worksheet.serialize_headers(0, 0, &data.get(0).unwrap())?;
+    worksheet.serialize_headers(0, 0, &data.first().unwrap())?;
     worksheet.serialize(&data)?;
 
     workbook.save(filename)?;
@@ -151,6 +151,57 @@ fn create_new_xlsx_file_3(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for Serde serialization. Row formats with value format overrides.
+fn create_new_xlsx_file_4(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+
+    let bold = Format::new().set_bold();
+    let italic = Format::new().set_italic();
+    let bold_italic = Format::new().set_bold().set_italic();
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: u8,
+        col2: u8,
+        col3: Option<u8>,
+    }
+
+    let data = [
+        MyStruct {
+            col1: 1,
+            col2: 4,
+            col3: Some(7),
+        },
+        MyStruct {
+            col1: 2,
+            col2: 5,
+            col3: None,
+        },
+        MyStruct {
+            col1: 3,
+            col2: 6,
+            col3: Some(9),
+        },
+    ];
+
+    let custom_headers = [
+        CustomSerializeField::new("col2").set_value_format(italic),
+        CustomSerializeField::new("col3").set_value_format(bold_italic),
+    ];
+    let header_options = SerializeFieldOptions::new()
+        .set_custom_headers(&custom_headers)
+        .set_row_formats(&[bold]);
+
+    worksheet.serialize_headers_with_options(0, 0, &data[0], &header_options)?;
+    worksheet.serialize(&data)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde08_1() {
     let test_runner = common::TestRunner::new()
@@ -186,3 +237,15 @@ fn test_serde08_3() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_serde08_4() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde08")
+        .set_function(create_new_xlsx_file_4)
+        .unique("4")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -13,7 +13,7 @@ use rust_xlsxwriter::{
 use serde::Serialize;
 
 #[cfg(feature = "chrono")]
-use chrono::{NaiveDate, NaiveDateTime};
+use chrono::{DateTime, FixedOffset, NaiveDate, NaiveDateTime, TimeZone, Utc};
 
 #[cfg(feature = "chrono")]
 use rust_xlsxwriter::utility::serialize_chrono_naive_to_excel;
@@ -21,6 +21,15 @@ use rust_xlsxwriter::utility::serialize_chrono_naive_to_excel;
 #[cfg(feature = "chrono")]
 use rust_xlsxwriter::utility::serialize_chrono_option_naive_to_excel;
 
+#[cfg(feature = "time")]
+use rust_xlsxwriter::utility::{serialize_time_option_to_excel, serialize_time_to_excel};
+
+#[cfg(feature = "chrono")]
+use rust_xlsxwriter::utility::{
+    serialize_chrono_datetime_to_excel, serialize_chrono_datetime_utc_to_excel,
+    serialize_chrono_option_datetime_to_excel,
+};
+
 // Test case for Serde serialization. First test isn't serialized.
 fn create_new_xlsx_file_1(filename: &str) -> Result<(), XlsxError> {
     let mut workbook = Workbook::new();
@@ -289,6 +298,267 @@ fn create_new_xlsx_file_6(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test case for Serde serialization with chrono timezone aware datetimes.
+#[cfg(feature = "chrono")]
+fn create_new_xlsx_file_7(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(serialize_with = "serialize_chrono_datetime_to_excel")]
+        col2: DateTime<FixedOffset>,
+    }
+
+    // The timezone is stripped so the local date is written.
+    let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+
+    let data1 = MyStruct {
+        col1: "aaa",
+        col2: offset.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap(),
+    };
+
+    let data2 = MyStruct {
+        col1: "bbb",
+        col2: offset.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap(),
+    };
+
+    let data3 = MyStruct {
+        col1: "ccc",
+        col2: offset.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap(),
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1"),
+        CustomSerializeField::new("col2").set_value_format(&format),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data1, &header_options)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization with chrono timezone aware datetimes.
+#[cfg(feature = "chrono")]
+fn create_new_xlsx_file_8(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(serialize_with = "serialize_chrono_option_datetime_to_excel")]
+        col2: Option<DateTime<Utc>>,
+    }
+
+    let data1 = MyStruct {
+        col1: "aaa",
+        col2: Some(Utc.with_ymd_and_hms(2024, 1, 1, 0, 0, 0).unwrap()),
+    };
+
+    let data2 = MyStruct {
+        col1: "bbb",
+        col2: Some(Utc.with_ymd_and_hms(2024, 1, 2, 0, 0, 0).unwrap()),
+    };
+
+    let data3 = MyStruct {
+        col1: "ccc",
+        col2: Some(Utc.with_ymd_and_hms(2024, 1, 3, 0, 0, 0).unwrap()),
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1"),
+        CustomSerializeField::new("col2").set_value_format(&format),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data1, &header_options)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization with the time crate.
+#[cfg(feature = "time")]
+fn create_new_xlsx_file_9(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(serialize_with = "serialize_time_to_excel")]
+        col2: time::Date,
+    }
+
+    let data1 = MyStruct {
+        col1: "aaa",
+        col2: time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+    };
+
+    let data2 = MyStruct {
+        col1: "bbb",
+        col2: time::Date::from_calendar_date(2024, time::Month::January, 2).unwrap(),
+    };
+
+    let data3 = MyStruct {
+        col1: "ccc",
+        col2: time::Date::from_calendar_date(2024, time::Month::January, 3).unwrap(),
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1"),
+        CustomSerializeField::new("col2").set_value_format(&format),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data1, &header_options)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization with the time crate.
+#[cfg(feature = "time")]
+fn create_new_xlsx_file_10(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(serialize_with = "serialize_time_option_to_excel")]
+        col2: Option<time::PrimitiveDateTime>,
+    }
+
+    let data1 = MyStruct {
+        col1: "aaa",
+        col2: Some(time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 1).unwrap(),
+            time::Time::MIDNIGHT,
+        )),
+    };
+
+    let data2 = MyStruct {
+        col1: "bbb",
+        col2: Some(time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 2).unwrap(),
+            time::Time::MIDNIGHT,
+        )),
+    };
+
+    let data3 = MyStruct {
+        col1: "ccc",
+        col2: Some(time::PrimitiveDateTime::new(
+            time::Date::from_calendar_date(2024, time::Month::January, 3).unwrap(),
+            time::Time::MIDNIGHT,
+        )),
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1"),
+        CustomSerializeField::new("col2").set_value_format(&format),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data1, &header_options)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
+// Test case for Serde serialization with chrono timezone aware datetimes
+// normalized to UTC.
+#[cfg(feature = "chrono")]
+fn create_new_xlsx_file_11(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+    let worksheet = workbook.add_worksheet();
+    worksheet.set_column_width(1, 11)?;
+
+    let format = Format::new().set_num_format_index(14);
+
+    // Create a serializable test struct.
+    #[derive(Serialize)]
+    struct MyStruct {
+        col1: &'static str,
+        #[serde(serialize_with = "serialize_chrono_datetime_utc_to_excel")]
+        col2: DateTime<FixedOffset>,
+    }
+
+    // The datetimes are converted to midnight UTC.
+    let offset = FixedOffset::east_opt(5 * 3600).unwrap();
+
+    let data1 = MyStruct {
+        col1: "aaa",
+        col2: offset.with_ymd_and_hms(2024, 1, 1, 5, 0, 0).unwrap(),
+    };
+
+    let data2 = MyStruct {
+        col1: "bbb",
+        col2: offset.with_ymd_and_hms(2024, 1, 2, 5, 0, 0).unwrap(),
+    };
+
+    let data3 = MyStruct {
+        col1: "ccc",
+        col2: offset.with_ymd_and_hms(2024, 1, 3, 5, 0, 0).unwrap(),
+    };
+
+    let custom_headers = [
+        CustomSerializeField::new("col1"),
+        CustomSerializeField::new("col2").set_value_format(&format),
+    ];
+    let header_options = SerializeFieldOptions::new().set_custom_headers(&custom_headers);
+
+    worksheet.serialize_headers_with_options(0, 0, &data1, &header_options)?;
+
+    worksheet.serialize(&data1)?;
+    worksheet.serialize(&data2)?;
+    worksheet.serialize(&data3)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_serde10_1() {
     let test_runner = common::TestRunner::new()
@@ -363,3 +633,68 @@ fn test_serde10_6() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_serde10_7() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_7)
+        .unique("7")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_serde10_8() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_8)
+        .unique("8")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_serde10_9() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_9)
+        .unique("9")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+#[cfg(feature = "time")]
+fn test_serde10_10() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_10)
+        .unique("10")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_serde10_11() {
+    let test_runner = common::TestRunner::new()
+        .set_name("serde10")
+        .set_function(create_new_xlsx_file_11)
+        .unique("11")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
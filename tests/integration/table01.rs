@@ -27,6 +27,25 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test the A1 range variant of add_table().
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    worksheet.set_column_width(2, 10.288)?;
+    worksheet.set_column_width(3, 10.288)?;
+    worksheet.set_column_width(4, 10.288)?;
+    worksheet.set_column_width(5, 10.288)?;
+
+    let table = Table::new();
+    worksheet.add_table_a1("C3:F13", &table)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_table01() {
     let test_runner = common::TestRunner::new()
@@ -37,3 +56,15 @@ fn test_table01() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_table01_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("table01")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}
@@ -6,7 +6,7 @@
 // Copyright 2022-2024, John McNamara, jmcnamara@cpan.org
 
 use crate::common;
-use rust_xlsxwriter::{Table, Workbook, XlsxError};
+use rust_xlsxwriter::{ExcelCellValue, Table, Workbook, XlsxError};
 
 // Create rust_xlsxwriter file to compare against Excel file.
 fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
@@ -35,6 +35,30 @@ fn create_new_xlsx_file(filename: &str) -> Result<(), XlsxError> {
     Ok(())
 }
 
+// Test writing the headers, data and table in one step.
+fn create_new_xlsx_file_2(filename: &str) -> Result<(), XlsxError> {
+    let mut workbook = Workbook::new();
+
+    let worksheet = workbook.add_worksheet();
+
+    let headers = ["Column1", "Column2", "Column3", "Column4"];
+    let data: Vec<Vec<ExcelCellValue>> = vec![
+        vec!["Foo".into(), 1234.into(), 2000.into(), 4321.into()],
+        vec!["Bar".into(), 1256.into(), 4000.into(), 4320.into()],
+        vec!["Baz".into(), 2234.into(), 3000.into(), 4332.into()],
+        vec!["Bop".into(), 1324.into(), 1000.into(), 4333.into()],
+    ];
+
+    worksheet.set_column_range_width(2, 5, 10.288)?;
+
+    let table = Table::new();
+    worksheet.write_table(1, 2, headers, data, &table)?;
+
+    workbook.save(filename)?;
+
+    Ok(())
+}
+
 #[test]
 fn test_table11() {
     let test_runner = common::TestRunner::new()
@@ -45,3 +69,15 @@ fn test_table11() {
     test_runner.assert_eq();
     test_runner.cleanup();
 }
+
+#[test]
+fn test_table11_2() {
+    let test_runner = common::TestRunner::new()
+        .set_name("table11")
+        .set_function(create_new_xlsx_file_2)
+        .unique("2")
+        .initialize();
+
+    test_runner.assert_eq();
+    test_runner.cleanup();
+}